@@ -118,6 +118,7 @@ where
     C::Api: BlockBuilder<Block>,
     C::Api: node_rpc::nft::NFTRuntimeApi<Block>,
     C::Api: node_rpc::settlement::SettlementRuntimeApi<Block>,
+    C::Api: node_rpc::multisig::MultisigRuntimeApi<Block, AccountId, Moment>,
     P: TransactionPool + 'static,
     SC: SelectChain<Block> + 'static,
     B: sc_client_api::Backend<Block> + Send + Sync + 'static,
@@ -126,6 +127,7 @@ where
     use node_rpc::{
         asset::{Asset, AssetApiServer},
         identity::{Identity, IdentityApiServer},
+        multisig::{Multisig, MultisigApiServer},
         nft::{NFTApiServer, NFT},
         pips::{Pips, PipsApiServer},
         settlement::{Settlement, SettlementApiServer},
@@ -217,7 +219,8 @@ where
     io.merge(Asset::new(client.clone()).into_rpc())?;
     io.merge(Group::from(client.clone()).into_rpc())?;
     io.merge(NFT::new(client.clone()).into_rpc())?;
-    io.merge(Settlement::new(client).into_rpc())?;
+    io.merge(Settlement::new(client.clone()).into_rpc())?;
+    io.merge(Multisig::new(client).into_rpc())?;
 
     Ok(io)
 }
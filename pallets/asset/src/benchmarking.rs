@@ -13,10 +13,13 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
+use codec::{Compact, CompactLen, Encode};
 use frame_benchmarking::benchmarks;
-use frame_support::StorageValue;
+use frame_support::{StorageDoubleMap, StorageValue};
 use frame_system::RawOrigin;
 use scale_info::prelude::format;
+use scale_info::prelude::string::String;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::{convert::TryInto, iter, prelude::*};
 
@@ -30,12 +33,12 @@ use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::{AssetName, NonFungibleType};
 use polymesh_primitives::asset_metadata::{
     AssetMetadataDescription, AssetMetadataKey, AssetMetadataName, AssetMetadataSpec,
-    AssetMetadataValue, AssetMetadataValueDetail,
+    AssetMetadataTypeDef, AssetMetadataValue, AssetMetadataValueDetail,
 };
 use polymesh_primitives::ticker::TICKER_LEN;
 use polymesh_primitives::{
     AuthorizationData, Fund, FundDescription, IdentityId, NFTCollectionKeys, PortfolioKind,
-    PortfolioName, PortfolioNumber, Signatory, Ticker, Url, WeightMeter,
+    PortfolioLockReason, PortfolioName, PortfolioNumber, Signatory, Ticker, Url, WeightMeter,
 };
 
 use crate::*;
@@ -62,8 +65,23 @@ fn make_metadata_name<T: Config>() -> AssetMetadataName {
 }
 
 /// Make metadata value for benchmarking.
+///
+/// The value is a `Vec<bool>` of the maximum length, which is validated one byte at a time.
 fn make_metadata_value<T: Config>() -> AssetMetadataValue {
-    AssetMetadataValue(vec![b'v'; T::AssetMetadataValueMaxLength::get() as usize])
+    let max_len = T::AssetMetadataValueMaxLength::get();
+    let len = max_len.saturating_sub(Compact::<u32>::compact_len(&max_len) as u32);
+    AssetMetadataValue(vec![true; len as usize].encode())
+}
+
+/// Make the type definition of the metadata value, padded to the maximum length.
+fn make_metadata_type_def<T: Config>() -> Vec<u8> {
+    let mut type_def = AssetMetadataTypeDef::new_from_type::<Vec<bool>>();
+    let max_len = T::AssetMetadataTypeDefMaxLength::get() as usize;
+    let pad = max_len.saturating_sub(type_def.encode().len());
+    let pad = pad.saturating_sub(Compact::<u32>::compact_len(&(pad as u32)));
+    let docs = String::from_utf8(vec![b'd'; pad]).unwrap();
+    type_def.types.types[0].ty.docs.push(docs);
+    type_def.encode()
 }
 
 /// Make metadata spec for benchmarking.
@@ -74,7 +92,7 @@ fn make_metadata_spec<T: Config>() -> AssetMetadataSpec {
             b'd';
             T::MaxLen::get() as usize
         ])),
-        type_def: Some(vec![b'x'; T::AssetMetadataTypeDefMaxLength::get() as usize]),
+        type_def: Some(make_metadata_type_def::<T>()),
     }
 }
 
@@ -120,6 +138,32 @@ pub(crate) fn create_sample_asset<T: Config>(asset_owner: &User<T>, divisible: b
     asset_id
 }
 
+/// Transfers `n` units of `asset_id` from the default portfolio of `asset_owner` to `portfolio_id`,
+/// each under a vesting schedule of one unit ending at moment 1.
+fn add_vesting_schedules<T: Config>(
+    asset_owner: &User<T>,
+    asset_id: AssetId,
+    portfolio_id: PortfolioId,
+    n: u32,
+    release: VestingRelease,
+) {
+    for _ in 0..n {
+        Module::<T>::add_vesting_schedule(
+            asset_owner.origin().into(),
+            asset_id,
+            PortfolioKind::Default,
+            portfolio_id,
+            ONE_UNIT,
+            0u32.into(),
+            0u32.into(),
+            1u32.into(),
+            release,
+            None,
+        )
+        .unwrap();
+    }
+}
+
 pub(crate) fn create_and_issue_sample_asset<T: Config>(asset_owner: &User<T>) -> AssetId {
     let asset_id = create_sample_asset::<T>(asset_owner, true);
     Module::<T>::issue(
@@ -765,4 +809,259 @@ benchmarks! {
         )
         .unwrap();
     }: _(alice.origin, ticker, asset_id)
+
+    set_transfer_fee {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let currency = create_sample_asset::<T>(&alice, true);
+        let fee = TransferFee {
+            amount: TransferFeeAmount::Flat(ONE_UNIT),
+            currency: Some(currency),
+            beneficiary: PortfolioId::default_portfolio(alice.did()),
+        };
+    }: _(alice.origin, asset_id, fee)
+
+    remove_transfer_fee {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let fee = TransferFee {
+            amount: TransferFeeAmount::BasisPoints(100),
+            currency: None,
+            beneficiary: PortfolioId::default_portfolio(alice.did()),
+        };
+        Module::<T>::set_transfer_fee(alice.clone().origin().into(), asset_id, fee).unwrap();
+    }: _(alice.origin, asset_id)
+
+    initiate_identity_recovery {
+        let n in 1 .. T::MaxIdentityRecoveryAssets::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_ids: BTreeSet<AssetId> = (0..n)
+            .map(|_| create_sample_asset::<T>(&alice, true))
+            .collect();
+    }: _(RawOrigin::Root, alice.did(), bob.did(), asset_ids)
+
+    confirm_identity_recovery {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        Module::<T>::initiate_identity_recovery(
+            RawOrigin::Root.into(),
+            bob.did(),
+            alice.did(),
+            [asset_id].into(),
+        )
+        .unwrap();
+        let auth_id = Identity::<T>::current_auth_id();
+    }: _(alice.origin, auth_id)
+
+    veto_identity_recovery {
+        let n in 1 .. T::MaxIdentityRecoveryAssets::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_ids: BTreeSet<AssetId> = (0..n)
+            .map(|_| create_sample_asset::<T>(&bob, true))
+            .collect();
+        Module::<T>::initiate_identity_recovery(
+            RawOrigin::Root.into(),
+            alice.did(),
+            bob.did(),
+            asset_ids,
+        )
+        .unwrap();
+        let recovery_id = IdentityRecoveryCounter::get();
+    }: _(alice.origin, recovery_id)
+
+    execute_identity_recovery {
+        // Number of fungible assets, portfolios, portfolio locks and NFTs being moved.
+        let a in 1 .. T::MaxIdentityRecoveryAssets::get();
+        let p in 1 .. 10;
+        let l in 0 .. 100;
+        let n in 0 .. 100;
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let user_portfolios: Vec<PortfolioId> = (1..p)
+            .map(|i| create_portfolio::<T>(&alice, &format!("Portfolio{}", i)))
+            .collect();
+
+        let mut confirmed_assets = BTreeSet::new();
+        for _ in 0..a {
+            let asset_id = create_and_issue_sample_asset::<T>(&alice);
+            for portfolio_id in &user_portfolios {
+                move_from_default_portfolio::<T>(&alice, asset_id, ONE_UNIT, *portfolio_id);
+            }
+            confirmed_assets.insert(asset_id);
+        }
+        let locked_asset_id = *confirmed_assets.iter().next().unwrap();
+        for _ in 0..l {
+            Portfolio::<T>::lock_portfolio_assets(
+                alice.origin().into(),
+                PortfolioId::default_portfolio(alice.did()),
+                locked_asset_id,
+                1,
+                PortfolioLockReason::Collateral,
+                None,
+                None,
+            )
+            .unwrap();
+        }
+
+        let nft_asset_id = Module::<T>::generate_asset_id(alice.account(), false);
+        T::NFTFn::create_nft_collection(
+            alice.origin().into(),
+            None,
+            Some(NonFungibleType::Derivative),
+            NFTCollectionKeys::default(),
+        )
+        .unwrap();
+        for _ in 0..n {
+            T::NFTFn::issue_nft(alice.origin().into(), nft_asset_id, PortfolioKind::Default).unwrap();
+        }
+        confirmed_assets.insert(nft_asset_id);
+
+        let recovery_id = IdentityRecoveryId(1);
+        IdentityRecoveryCounter::put(recovery_id);
+        IdentityRecoveries::<T>::insert(
+            recovery_id,
+            IdentityRecovery {
+                old_did: alice.did(),
+                new_did: bob.did(),
+                initiator: None,
+                pending_assets: BTreeMap::new(),
+                confirmed_assets,
+                execute_after: pallet_timestamp::Pallet::<T>::get(),
+            },
+        );
+    }: _(bob.origin, recovery_id, p, l, n)
+
+    pause_asset_operations {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let operations = AssetOperations::from_iter(AssetOperation::ALL);
+    }: _(alice.origin, asset_id, operations)
+
+    unpause_asset_operations {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        let operations = AssetOperations::from_iter(AssetOperation::ALL);
+        Module::<T>::pause_asset_operations(alice.clone().origin().into(), asset_id, operations)
+            .unwrap();
+    }: _(alice.origin, asset_id, operations)
+
+    pause_venue_settlement {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+    }: _(alice.origin, asset_id, VenueId(1))
+
+    unpause_venue_settlement {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_sample_asset::<T>(&alice, true);
+        Module::<T>::pause_venue_settlement(alice.clone().origin().into(), asset_id, VenueId(1))
+            .unwrap();
+    }: _(alice.origin, asset_id, VenueId(1))
+
+    add_vesting_schedule {
+        let n in 0..T::MaxVestingSchedules::get() - 1;
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+        let portfolio_id = PortfolioId::default_portfolio(bob.did());
+        add_vesting_schedules::<T>(&alice, asset_id, portfolio_id, n, VestingRelease::OnClaim);
+    }: _(alice.origin, asset_id, PortfolioKind::Default, portfolio_id, ONE_UNIT, 0u32.into(), 0u32.into(), 1u32.into(), VestingRelease::OnClaim, None)
+    verify {
+        assert_eq!(VestingSchedules::<T>::get(portfolio_id, asset_id).len(), n as usize + 1);
+    }
+
+    claim_vested_assets {
+        let n in 1..T::MaxVestingSchedules::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+        let portfolio_id = PortfolioId::default_portfolio(bob.did());
+        add_vesting_schedules::<T>(&alice, asset_id, portfolio_id, n, VestingRelease::OnClaim);
+        pallet_timestamp::Pallet::<T>::set_timestamp(1u32.into());
+    }: _(bob.origin, asset_id, portfolio_id)
+    verify {
+        assert!(VestingSchedules::<T>::get(portfolio_id, asset_id).is_empty());
+    }
+
+    revoke_vesting_schedule {
+        let n in 1..T::MaxVestingSchedules::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did());
+        add_vesting_schedules::<T>(&alice, asset_id, bob_portfolio, n, VestingRelease::Automatic);
+        let schedule_id = VestingScheduleCounter::get();
+    }: _(alice.origin, asset_id, bob_portfolio, schedule_id, PortfolioKind::Default)
+    verify {
+        assert_eq!(VestingSchedules::<T>::get(bob_portfolio, asset_id).len(), n as usize - 1);
+    }
+
+    approve {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+    }: _(alice.origin, asset_id, PortfolioKind::Default, bob.did(), ONE_UNIT, Some(1u32.into()))
+    verify {
+        assert!(Allowances::<T>::contains_key((PortfolioId::default_portfolio(alice.did()), asset_id), bob.did()));
+    }
+
+    transfer_from {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did());
+        // The spender pays a transfer fee to the beneficiary
+        let fee = TransferFee {
+            amount: TransferFeeAmount::BasisPoints(100),
+            currency: None,
+            beneficiary: alice_portfolio,
+        };
+        Module::<T>::set_transfer_fee(alice.origin().into(), asset_id, fee).unwrap();
+        Module::<T>::approve(
+            alice.origin().into(),
+            asset_id,
+            PortfolioKind::Default,
+            bob.did(),
+            ONE_UNIT * 2,
+            None
+        )
+        .unwrap();
+    }: _(bob.origin.clone(), asset_id, alice_portfolio, PortfolioKind::Default, ONE_UNIT, None)
+    verify {
+        assert_eq!(Module::<T>::balance_of(asset_id, bob.did()), ONE_UNIT - ONE_UNIT / 100);
+        assert_eq!(Allowances::<T>::get((alice_portfolio, asset_id), bob.did()).unwrap().amount, ONE_UNIT);
+    }
+
+    set_fee_asset_rate {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+    }: _(RawOrigin::Root, asset_id, Some(ONE_UNIT))
+    verify {
+        assert_eq!(FeeAssetRates::get(asset_id), Some(ONE_UNIT));
+    }
+
+    set_fee_collector {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let portfolio_id = PortfolioId::default_portfolio(alice.did());
+    }: _(RawOrigin::Root, portfolio_id)
+    verify {
+        assert_eq!(FeeCollector::get(), Some(portfolio_id));
+    }
+
+    set_fee_asset {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let asset_id = create_and_issue_sample_asset::<T>(&alice);
+        Module::<T>::set_fee_asset_rate(RawOrigin::Root.into(), asset_id, Some(ONE_UNIT)).unwrap();
+    }: _(alice.origin, Some(asset_id))
+    verify {
+        assert_eq!(FeeAssets::get(alice.did()), Some(asset_id));
+    }
 }
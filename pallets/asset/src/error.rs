@@ -103,6 +103,70 @@ decl_error! {
         /// The given asset is already linked to a ticker.
         AssetIsAlreadyLinkedToATicker,
         /// The given ticker is not linked to the given asset.
-        TickerIsNotLinkedToTheAsset
+        TickerIsNotLinkedToTheAsset,
+        /// The transfer fee can't be charged for the asset.
+        InvalidTransferFee,
+        /// The asset doesn't have a transfer fee.
+        TransferFeeNotFound,
+        /// The identity recovery was not found.
+        IdentityRecoveryNotFound,
+        /// The identities or the assets of the recovery are not valid.
+        InvalidIdentityRecovery,
+        /// Only CDD providers and governance can initiate an identity recovery.
+        NotAuthorizedToInitiateIdentityRecovery,
+        /// The asset is not pending confirmation in the identity recovery.
+        IdentityRecoveryAssetNotPending,
+        /// The veto period of the identity recovery is not over.
+        IdentityRecoveryVetoPeriodNotOver,
+        /// The veto period of the identity recovery is over.
+        IdentityRecoveryVetoPeriodOver,
+        /// Not all assets in the identity recovery have been confirmed.
+        IdentityRecoveryNotConfirmed,
+        /// The number of portfolios or NFTs being recovered exceeds the given limits.
+        IdentityRecoveryLimitsExceeded,
+        /// The maximum number of assets in an identity recovery was exceeded.
+        MaxIdentityRecoveryAssetsExceeded,
+        /// Only the primary key of the identity being recovered can veto the recovery.
+        NotPrimaryKeyOfRecoveredIdentity,
+        /// The operations must be a non-empty set of known [`AssetOperation`](polymesh_primitives::asset::AssetOperation).
+        InvalidAssetOperations,
+        /// The operation has been paused for the asset.
+        AssetOperationPaused,
+        /// The amount must be non-zero and the vesting must satisfy `start <= cliff <= end`.
+        InvalidVestingSchedule,
+        /// The maximum number of vesting schedules of an asset in a portfolio was exceeded.
+        MaxVestingSchedulesExceeded,
+        /// The vesting schedule was not found.
+        VestingScheduleNotFound,
+        /// The portfolio doesn't hold enough units that are not subject to vesting.
+        InsufficientVestedBalance,
+        /// The allowance expiry must be in the future.
+        InvalidAllowanceExpiry,
+        /// The spender has no allowance, or not enough of it, over the portfolio.
+        InsufficientAllowance,
+        /// The allowance has expired.
+        AllowanceExpired,
+        /// Only divisible fungible assets can pay transaction fees.
+        InvalidFeeAsset,
+        /// The rate of an asset paying transaction fees must be non-zero.
+        InvalidFeeAssetRate,
+        /// The asset is not approved to pay transaction fees.
+        FeeAssetNotApproved,
+        /// The metadata value doesn't conform to the type definition of its key.
+        AssetMetadataValueInvalid,
+        /// Funds can only be moved between portfolios of the same identity.
+        InvalidPortfolioTransfer,
+        /// The settlement of the asset through the venue is not paused.
+        VenueSettlementNotPaused,
+        /// The weight limit is less than the minimum weight of the call.
+        InputWeightIsLessThanMinimum,
+        /// The transfers of the asset are frozen, e.g. by a pending reorganization.
+        TransfersFrozen,
+        /// The portfolio holds units locked by pending settlements.
+        UnitsLockedBySettlement,
+        /// No more units of the asset can be issued.
+        IssuanceClosed,
+        /// Assets paying transaction fees can't have compliance requirements or transfer conditions.
+        RestrictedFeeAsset
     }
 }
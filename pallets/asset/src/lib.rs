@@ -55,6 +55,13 @@
 //! - `register_asset_metadata_local_type` - Register asset metadata local type.
 //! - `register_asset_metadata_global_type` - Register asset metadata global type.
 //! - `redeem_from_portfolio` - Redeems tokens from the caller's portfolio.
+//! - `add_vesting_schedule` - Transfers units to a portfolio under a linear or cliff vesting schedule.
+//! - `claim_vested_assets` - Releases the vested units of schedules released on claim.
+//! - `revoke_vesting_schedule` - Moves the unvested units of a schedule back to a treasury portfolio.
+//! - `approve` - Allows a spender identity to move units of an asset out of the caller's portfolio.
+//! - `transfer_from` - Moves units of an asset out of a portfolio using an allowance.
+//! - `pause_venue_settlement` - Pauses the settlement of an asset through a single venue.
+//! - `unpause_venue_settlement` - Unpauses the settlement of an asset through a venue.
 //!
 //! ### Public Functions
 //!
@@ -85,15 +92,21 @@ mod types;
 use codec::{Decode, Encode};
 use core::mem;
 use currency::*;
-use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::dispatch::{
+    DispatchError, DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo,
+    PostDispatchInfo,
+};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use frame_support::BoundedBTreeSet;
 use frame_support::{decl_module, decl_storage, ensure};
+use frame_support::{BoundedBTreeSet, BoundedVec};
 use frame_system::ensure_root;
 use sp_io::hashing::blake2_128;
 use sp_runtime::traits::Zero;
+use sp_runtime::transaction_validity::InvalidTransaction;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
+use sp_std::iter;
 use sp_std::prelude::*;
 
 use pallet_base::{
@@ -104,28 +117,34 @@ use pallet_portfolio::{Error as PortfolioError, PortfolioAssetBalances};
 use polymesh_common_utilities::asset::AssetFnTrait;
 use polymesh_common_utilities::compliance_manager::ComplianceFnConfig;
 use polymesh_common_utilities::constants::*;
+use polymesh_common_utilities::portfolio::PortfolioSubTrait;
 use polymesh_common_utilities::protocol_fee::{ChargeProtocolFee, ProtocolOp};
 pub use polymesh_common_utilities::traits::asset::{Config, Event, RawEvent, WeightInfo};
+use polymesh_common_utilities::traits::group::GroupTrait;
 use polymesh_common_utilities::traits::nft::NFTTrait;
+use polymesh_common_utilities::traits::transaction_payment::AssetFeeHandler;
+use polymesh_common_utilities::{with_transaction, GC_DID};
 use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::{
-    AssetId, AssetName, AssetType, CheckpointId, CustomAssetTypeId, FundingRoundName,
+    AssetAllowance, AssetId, AssetName, AssetOperation, AssetOperations, AssetType, CheckpointId,
+    CustomAssetTypeId, FundingRoundName, IdentityRecoveryId, TransferFee, TransferFeeAmount,
+    VestingRelease, VestingSchedule, VestingScheduleId,
 };
 use polymesh_primitives::asset_metadata::{
     AssetMetadataGlobalKey, AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataName,
-    AssetMetadataSpec, AssetMetadataValue, AssetMetadataValueDetail,
+    AssetMetadataSpec, AssetMetadataTypeDef, AssetMetadataValue, AssetMetadataValueDetail,
 };
-use polymesh_primitives::settlement::InstructionId;
+use polymesh_primitives::settlement::{InstructionId, VenueId};
 use polymesh_primitives::{
-    extract_auth, storage_migrate_on, storage_migration_ver, AssetIdentifier, Balance, Document,
-    DocumentId, IdentityId, Memo, PortfolioId, PortfolioKind, PortfolioUpdateReason, SecondaryKey,
-    Ticker, WeightMeter,
+    extract_auth, storage_migrate_on, storage_migration_ver, AssetIdentifier, AuthorizationData,
+    Balance, Document, DocumentId, IdentityId, Memo, Moment, NFTId, PortfolioId, PortfolioKind,
+    PortfolioUpdateReason, SecondaryKey, Signatory, Ticker, WeightMeter,
 };
 
 pub use error::Error;
 pub use types::{
-    AssetDetails, AssetOwnershipRelation, TickerRegistration, TickerRegistrationConfig,
-    TickerRegistrationStatus,
+    AssetDetails, AssetOwnershipRelation, IdentityRecovery, TickerRegistration,
+    TickerRegistrationConfig, TickerRegistrationStatus,
 };
 
 type Checkpoint<T> = checkpoint::Module<T>;
@@ -242,6 +261,52 @@ decl_storage! {
         /// A per account nonce that is used for generating an [`AssetId`].
         pub AssetNonce: map hasher(identity) T::AccountId => u64;
 
+        /// The [`TransferFee`] charged by the settlement engine for transfers of an asset.
+        pub TransferFees get(fn transfer_fees): map hasher(blake2_128_concat) AssetId => Option<TransferFee>;
+
+        /// The last [`IdentityRecoveryId`] used.
+        pub IdentityRecoveryCounter get(fn identity_recovery_counter): IdentityRecoveryId;
+
+        /// All pending recoveries of the assets of identities that lost their keys.
+        pub IdentityRecoveries get(fn identity_recoveries):
+            map hasher(twox_64_concat) IdentityRecoveryId => Option<IdentityRecovery<T::Moment>>;
+
+        /// The [`AssetOperations`] that are paused for an asset.
+        pub PausedOperations get(fn paused_operations): map hasher(blake2_128_concat) AssetId => AssetOperations;
+
+        /// The venues through which the settlement of an asset is paused.
+        pub PausedVenues get(fn paused_venue):
+            double_map hasher(blake2_128_concat) AssetId, hasher(twox_64_concat) VenueId => bool;
+
+        /// The last [`VestingScheduleId`] used.
+        pub VestingScheduleCounter get(fn vesting_schedule_counter): VestingScheduleId;
+
+        /// The [`VestingSchedule`]s of the units of an asset held in a portfolio.
+        pub VestingSchedules get(fn vesting_schedules):
+            double_map hasher(twox_64_concat) PortfolioId, hasher(blake2_128_concat) AssetId =>
+                BoundedVec<VestingSchedule<T::Moment>, T::MaxVestingSchedules>;
+
+        /// The [`AssetAllowance`] of each spender over the units of an asset held in a portfolio.
+        pub Allowances get(fn allowance):
+            double_map hasher(blake2_128_concat) (PortfolioId, AssetId), hasher(identity) IdentityId =>
+                Option<AssetAllowance<T::Moment>>;
+
+        /// The rate of each asset approved to pay transaction fees, i.e. the amount of the asset worth one POLYX.
+        pub FeeAssetRates get(fn fee_asset_rate): map hasher(blake2_128_concat) AssetId => Option<Balance>;
+
+        /// The [`PortfolioId`] collecting the transaction fees paid in assets.
+        pub FeeCollector get(fn fee_collector): Option<PortfolioId>;
+
+        /// The asset each identity pays transaction fees in, instead of POLYX.
+        pub FeeAssets get(fn fee_asset): map hasher(identity) IdentityId => Option<AssetId>;
+
+        /// The period, from the first moment until the second one, during which transfers, issuance and redemption
+        /// of an asset are frozen, e.g. by a pending reorganization.
+        pub TransfersFrozen get(fn transfers_frozen): map hasher(blake2_128_concat) AssetId => Option<(Moment, Moment)>;
+
+        /// Whether no more units of an asset can be issued, e.g. once a fixed income asset matured.
+        pub IssuanceClosed get(fn issuance_closed): map hasher(blake2_128_concat) AssetId => bool;
+
         /// Storage version.
         StorageVersion get(fn storage_version) build(|_| Version::new(5)): Version;
     }
@@ -284,6 +349,8 @@ decl_module! {
         const AssetMetadataValueMaxLength: u32 = T::AssetMetadataValueMaxLength::get();
         const AssetMetadataTypeDefMaxLength: u32 = T::AssetMetadataTypeDefMaxLength::get();
         const MaxAssetMediators: u32 = T::MaxAssetMediators::get();
+        const MaxIdentityRecoveryAssets: u32 = T::MaxIdentityRecoveryAssets::get();
+        const MaxVestingSchedules: u32 = T::MaxVestingSchedules::get();
 
         /// initialize the default event for this module
         fn deposit_event() = default;
@@ -594,6 +661,8 @@ decl_module! {
 
         /// Set asset metadata value.
         ///
+        /// If the spec of `key` has a type definition, `value` must be a SCALE encoded value of that type.
+        ///
         /// # Arguments
         /// * `origin`: is a signer that has permissions to act as an agent of `asset_id`.
         /// * `asset_id`: the [`AssetId`] associated to the token.
@@ -857,6 +926,426 @@ decl_module! {
         pub fn unlink_ticker_from_asset_id(origin, ticker: Ticker, asset_id: AssetId) {
             Self::base_unlink_ticker_from_asset_id(origin, ticker, asset_id)?;
         }
+
+        /// Sets the fee charged by the settlement engine whenever `asset_id` is transferred.
+        /// The fee is paid by the sending portfolio of each leg and replaces any previous fee.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the asset the fee is charged for.
+        /// * `fee`: the [`TransferFee`] that will be charged.
+        ///
+        /// # Errors
+        /// * `InvalidTransferFee` if the fee can't be computed or paid for the asset.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::set_transfer_fee()]
+        pub fn set_transfer_fee(origin, asset_id: AssetId, fee: TransferFee) {
+            Self::base_set_transfer_fee(origin, asset_id, fee)?;
+        }
+
+        /// Removes the transfer fee of `asset_id`.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the asset that will have its fee removed.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::remove_transfer_fee()]
+        pub fn remove_transfer_fee(origin, asset_id: AssetId) {
+            Self::base_remove_transfer_fee(origin, asset_id)?;
+        }
+
+        /// Starts the recovery of the assets held by `old_did`, an identity that lost all its keys.
+        /// An authorization to confirm the recovery is added to the owner of each asset.
+        ///
+        /// Once all assets have been confirmed and the veto period is over, the free balance of all
+        /// portfolios of `old_did` can be moved to the default portfolio of `new_did`.
+        ///
+        /// # Arguments
+        /// * `origin`: root or the secondary key of a CDD provider.
+        /// * `old_did`: the [`IdentityId`] that lost its keys.
+        /// * `new_did`: the [`IdentityId`] that will receive the assets.
+        /// * `asset_ids`: the assets that will be recovered.
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToInitiateIdentityRecovery` if the caller is not a CDD provider.
+        /// * `InvalidIdentityRecovery` if the identities are not valid or no asset was given.
+        /// * `MaxIdentityRecoveryAssetsExceeded` if more than `MaxIdentityRecoveryAssets` were given.
+        #[weight = <T as Config>::WeightInfo::initiate_identity_recovery(asset_ids.len() as u32)]
+        pub fn initiate_identity_recovery(
+            origin,
+            old_did: IdentityId,
+            new_did: IdentityId,
+            asset_ids: BTreeSet<AssetId>
+        ) {
+            Self::base_initiate_identity_recovery(origin, old_did, new_did, asset_ids)?;
+        }
+
+        /// Confirms the recovery of an asset, accepting a `ConfirmIdentityRecovery` authorization.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `auth_id`: the authorization ID.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::confirm_identity_recovery()]
+        pub fn confirm_identity_recovery(origin, auth_id: u64) {
+            Self::base_confirm_identity_recovery(origin, auth_id)?;
+        }
+
+        /// Vetoes the identity recovery. Can only be called by the primary key of the identity being recovered,
+        /// before the veto period is over. The authorizations of the assets that have not been confirmed are revoked.
+        ///
+        /// # Arguments
+        /// * `origin`: the primary key of the identity being recovered.
+        /// * `recovery_id`: the [`IdentityRecoveryId`] of the recovery.
+        #[weight = <T as Config>::WeightInfo::veto_identity_recovery(T::MaxIdentityRecoveryAssets::get())]
+        pub fn veto_identity_recovery(origin, recovery_id: IdentityRecoveryId) {
+            Self::base_veto_identity_recovery(origin, recovery_id)?;
+        }
+
+        /// Moves the units of all confirmed assets from every portfolio of the old identity to
+        /// the default portfolio of the new identity. Portfolio locks and vesting schedules of these assets are
+        /// moved together with their units. Units and NFTs locked by pending instructions are not moved.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `recovery_id`: the [`IdentityRecoveryId`] of the recovery.
+        /// * `max_portfolios`: the maximum number of portfolios of the old identity, including its default portfolio.
+        /// * `max_locks`: the maximum number of portfolio locks held by the portfolios of the old identity.
+        /// * `max_nfts`: the maximum number of NFTs held by the portfolios of the old identity.
+        ///
+        /// # Errors
+        /// * `IdentityRecoveryNotConfirmed` if not all assets have been confirmed.
+        /// * `IdentityRecoveryVetoPeriodNotOver` if the veto period is not over.
+        /// * `IdentityRecoveryLimitsExceeded` if `max_portfolios`, `max_locks` or `max_nfts` are too low.
+        /// * `MaxVestingSchedulesExceeded` if the new portfolio can't hold the moved vesting schedules.
+        #[weight = <T as Config>::WeightInfo::execute_identity_recovery(
+            T::MaxIdentityRecoveryAssets::get(),
+            *max_portfolios,
+            *max_locks,
+            *max_nfts,
+        )]
+        pub fn execute_identity_recovery(
+            origin,
+            recovery_id: IdentityRecoveryId,
+            max_portfolios: u32,
+            max_locks: u32,
+            max_nfts: u32
+        ) {
+            let mut weight_meter = WeightMeter::max_limit_no_minimum();
+            Self::base_execute_identity_recovery(
+                origin,
+                recovery_id,
+                max_portfolios,
+                max_locks,
+                max_nfts,
+                &mut weight_meter
+            )?;
+        }
+
+        /// Pauses the given operations of an asset. Operations that are already paused remain paused.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the asset.
+        /// * `operations`: the [`AssetOperations`] that will be paused.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::pause_asset_operations()]
+        pub fn pause_asset_operations(origin, asset_id: AssetId, operations: AssetOperations) {
+            Self::base_set_paused_operations(origin, asset_id, operations, true)?;
+        }
+
+        /// Unpauses the given operations of an asset.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the asset.
+        /// * `operations`: the [`AssetOperations`] that will be unpaused.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::unpause_asset_operations()]
+        pub fn unpause_asset_operations(origin, asset_id: AssetId, operations: AssetOperations) {
+            Self::base_set_paused_operations(origin, asset_id, operations, false)?;
+        }
+
+        /// Transfers `amount` units of `asset_id` from the caller's `funding` portfolio to `portfolio_id`
+        /// and adds a vesting schedule to them. Only the transferred units are vested, so the units already
+        /// held in `portfolio_id` can't be locked by an agent. Until they vest, and are claimed if `release`
+        /// is [`VestingRelease::OnClaim`], the units can't be moved away from the portfolio.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the vested asset.
+        /// * `funding`: the [`PortfolioKind`] of the caller's portfolio sending the units.
+        /// * `portfolio_id`: the [`PortfolioId`] receiving the units.
+        /// * `amount`: the amount being vested.
+        /// * `start`: the moment the vesting starts.
+        /// * `cliff`: the moment before which nothing vests.
+        /// * `end`: the moment everything has vested.
+        /// * `release`: when the vested units become transferable.
+        /// * `weight_limit`: an optional maximum [`Weight`] to be charged, covering the compliance check of the transfer.
+        ///
+        /// # Errors
+        /// * `InputWeightIsLessThanMinimum` if `weight_limit` is less than the minimum weight of the call.
+        /// * `InvalidVestingSchedule` if `amount` is zero or `start <= cliff <= end` doesn't hold.
+        /// * `MaxVestingSchedulesExceeded` if the portfolio already has `MaxVestingSchedules` schedules for the asset.
+        /// * Any error of the transfer, e.g. `InsufficientBalance` or a failed compliance check.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::add_vesting_schedule_weight_limit(weight_limit, T::MaxVestingSchedules::get())]
+        pub fn add_vesting_schedule(
+            origin,
+            asset_id: AssetId,
+            funding: PortfolioKind,
+            portfolio_id: PortfolioId,
+            amount: Balance,
+            start: T::Moment,
+            cliff: T::Moment,
+            end: T::Moment,
+            release: VestingRelease,
+            weight_limit: Option<Weight>
+        ) -> DispatchResultWithPostInfo {
+            let mut weight_meter = Self::ensure_valid_weight_meter(
+                <T as Config>::WeightInfo::add_vesting_schedule(0),
+                weight_limit.unwrap_or(<T as Config>::WeightInfo::add_vesting_schedule(
+                    T::MaxVestingSchedules::get(),
+                )),
+            )?;
+            Self::base_add_vesting_schedule(
+                origin,
+                asset_id,
+                funding,
+                portfolio_id,
+                amount,
+                start,
+                cliff,
+                end,
+                release,
+                &mut weight_meter
+            )
+            .map_err(|error| DispatchErrorWithPostInfo {
+                post_info: Some(weight_meter.consumed()).into(),
+                error,
+            })?;
+            Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
+        }
+
+        /// Releases the vested units of all schedules of `asset_id` in `portfolio_id` that are released on claim,
+        /// and removes the schedules that have been fully released.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the custodian of `portfolio_id`.
+        /// * `asset_id`: the [`AssetId`] of the vested asset.
+        /// * `portfolio_id`: the [`PortfolioId`] holding the units.
+        ///
+        /// # Errors
+        /// * `VestingScheduleNotFound` if the portfolio has no vesting schedule for the asset.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::claim_vested_assets(T::MaxVestingSchedules::get())]
+        pub fn claim_vested_assets(origin, asset_id: AssetId, portfolio_id: PortfolioId) {
+            Self::base_claim_vested_assets(origin, asset_id, portfolio_id)?;
+        }
+
+        /// Revokes a vesting schedule, moving its unvested units to the `treasury` portfolio of the caller.
+        /// The units that already vested stay in the portfolio and become transferable.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the vested asset.
+        /// * `portfolio_id`: the [`PortfolioId`] holding the units.
+        /// * `schedule_id`: the [`VestingScheduleId`] of the revoked schedule.
+        /// * `treasury`: the [`PortfolioKind`] of the caller's portfolio receiving the unvested units.
+        ///
+        /// # Errors
+        /// * `VestingScheduleNotFound` if the schedule doesn't exist.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::revoke_vesting_schedule(T::MaxVestingSchedules::get())]
+        pub fn revoke_vesting_schedule(
+            origin,
+            asset_id: AssetId,
+            portfolio_id: PortfolioId,
+            schedule_id: VestingScheduleId,
+            treasury: PortfolioKind
+        ) {
+            let mut weight_meter = WeightMeter::max_limit_no_minimum();
+            Self::base_revoke_vesting_schedule(
+                origin,
+                asset_id,
+                portfolio_id,
+                schedule_id,
+                treasury,
+                &mut weight_meter
+            )?;
+        }
+
+        /// Allows `spender` to move up to `amount` units of `asset_id` out of the caller's portfolio,
+        /// replacing any previous allowance. An `amount` of zero removes the allowance.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the fungible asset.
+        /// * `portfolio_kind`: the [`PortfolioKind`] of the caller's portfolio the units can be moved from.
+        /// * `spender`: the [`IdentityId`] allowed to move the units.
+        /// * `amount`: the amount that can be moved.
+        /// * `expiry`: the moment from which the allowance can no longer be used, if any.
+        ///
+        /// # Errors
+        /// * `UnexpectedNonFungibleToken` if the asset is not fungible.
+        /// * `InvalidAllowanceExpiry` if `expiry` is not in the future.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::approve()]
+        pub fn approve(
+            origin,
+            asset_id: AssetId,
+            portfolio_kind: PortfolioKind,
+            spender: IdentityId,
+            amount: Balance,
+            expiry: Option<T::Moment>
+        ) {
+            Self::base_approve(origin, asset_id, portfolio_kind, spender, amount, expiry)?;
+        }
+
+        /// Moves `amount` units of `asset_id` from `from_portfolio` to a portfolio of the caller,
+        /// using the allowance granted to the caller by the owner of `from_portfolio`.
+        /// The transfer is subject to the same compliance and statistics checks as any other transfer.
+        /// If the asset has a [`TransferFee`], the caller pays it from the receiving portfolio.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the spender.
+        /// * `asset_id`: the [`AssetId`] of the fungible asset.
+        /// * `from_portfolio`: the [`PortfolioId`] the units are moved from.
+        /// * `to_portfolio_kind`: the [`PortfolioKind`] of the caller's portfolio receiving the units.
+        /// * `amount`: the amount being moved.
+        /// * `weight_limit`: an optional maximum [`Weight`] to be charged, covering the compliance checks
+        /// of the transfer and of the fee payment.
+        ///
+        /// # Errors
+        /// * `InputWeightIsLessThanMinimum` if `weight_limit` is less than the minimum weight of the call.
+        /// * `InsufficientAllowance` if the caller's allowance is missing or lower than `amount`.
+        /// * `AllowanceExpired` if the caller's allowance has expired.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::transfer_from_weight_limit(weight_limit)]
+        pub fn transfer_from(
+            origin,
+            asset_id: AssetId,
+            from_portfolio: PortfolioId,
+            to_portfolio_kind: PortfolioKind,
+            amount: Balance,
+            weight_limit: Option<Weight>
+        ) -> DispatchResultWithPostInfo {
+            let mut weight_meter = Self::ensure_valid_weight_meter(
+                <T as Config>::WeightInfo::transfer_from(),
+                weight_limit.unwrap_or(<T as Config>::WeightInfo::transfer_from()),
+            )?;
+            Self::base_transfer_from(
+                origin,
+                asset_id,
+                from_portfolio,
+                to_portfolio_kind,
+                amount,
+                &mut weight_meter
+            )
+            .map_err(|error| DispatchErrorWithPostInfo {
+                post_info: Some(weight_meter.consumed()).into(),
+                error,
+            })?;
+            Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
+        }
+
+        /// Approves `asset_id` to pay transaction fees at `rate`, or withdraws the approval if `rate` is `None`.
+        ///
+        /// # Arguments
+        /// * `origin`: must be root.
+        /// * `asset_id`: the [`AssetId`] of a divisible fungible asset.
+        /// * `rate`: the amount of the asset worth one POLYX.
+        ///
+        /// # Errors
+        /// * `InvalidFeeAsset` if the asset is not fungible or not divisible.
+        /// * `InvalidFeeAssetRate` if `rate` is zero.
+        /// * `RestrictedFeeAsset` if the asset has compliance requirements or transfer conditions.
+        #[weight = <T as Config>::WeightInfo::set_fee_asset_rate()]
+        pub fn set_fee_asset_rate(origin, asset_id: AssetId, rate: Option<Balance>) {
+            Self::base_set_fee_asset_rate(origin, asset_id, rate)?;
+        }
+
+        /// Sets the portfolio collecting the transaction fees paid in assets.
+        ///
+        /// # Arguments
+        /// * `origin`: must be root.
+        /// * `portfolio_id`: the [`PortfolioId`] receiving the fees.
+        #[weight = <T as Config>::WeightInfo::set_fee_collector()]
+        pub fn set_fee_collector(origin, portfolio_id: PortfolioId) {
+            Self::base_set_fee_collector(origin, portfolio_id)?;
+        }
+
+        /// Opts the caller's identity in to paying transaction fees in `asset_id`,
+        /// withdrawn from its default portfolio, or back to POLYX if `asset_id` is `None`.
+        ///
+        /// Fees are paid in POLYX while no fee collector is set, when a subsidiser pays for the caller,
+        /// or while the asset has compliance requirements or transfer conditions.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of an asset approved to pay fees.
+        ///
+        /// # Errors
+        /// * `FeeAssetNotApproved` if `asset_id` is not approved to pay fees.
+        /// * `RestrictedFeeAsset` if the asset has compliance requirements or transfer conditions.
+        #[weight = <T as Config>::WeightInfo::set_fee_asset()]
+        pub fn set_fee_asset(origin, asset_id: Option<AssetId>) {
+            Self::base_set_fee_asset(origin, asset_id)?;
+        }
+
+        /// Pauses the settlement of an asset through the given venue. Other venues and
+        /// instructions without a venue are not affected.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the asset.
+        /// * `venue_id`: the [`VenueId`] of the paused venue.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::pause_venue_settlement()]
+        pub fn pause_venue_settlement(origin, asset_id: AssetId, venue_id: VenueId) {
+            Self::base_set_venue_settlement_paused(origin, asset_id, venue_id, true)?;
+        }
+
+        /// Unpauses the settlement of an asset through the given venue.
+        ///
+        /// # Arguments
+        /// * `origin`: the secondary key of the sender.
+        /// * `asset_id`: the [`AssetId`] of the asset.
+        /// * `venue_id`: the [`VenueId`] of the unpaused venue.
+        ///
+        /// # Errors
+        /// * `VenueSettlementNotPaused` if the settlement through `venue_id` is not paused.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::WeightInfo::unpause_venue_settlement()]
+        pub fn unpause_venue_settlement(origin, asset_id: AssetId, venue_id: VenueId) {
+            Self::base_set_venue_settlement_paused(origin, asset_id, venue_id, false)?;
+        }
     }
 }
 
@@ -1023,6 +1512,8 @@ impl<T: Config> Module<T> {
             portfolio_kind,
             false,
         )?;
+        Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Issue)?;
+        Self::ensure_issuance_not_closed(&asset_id)?;
         let mut weight_meter = WeightMeter::max_limit_no_minimum();
         let mut asset_details = Self::try_get_asset_details(&asset_id)?;
         Self::validate_issuance_rules(&asset_details, amount_to_issue)?;
@@ -1051,7 +1542,120 @@ impl<T: Config> Module<T> {
             portfolio_kind,
             true,
         )?;
+        Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Redeem)?;
+
+        Self::unchecked_redeem(asset_id, portfolio, value, weight_meter)
+    }
+
+    /// Issues `amount` tokens of `asset_id` into `portfolio`.
+    ///
+    /// Unlike `issue`, this doesn't check the caller's permissions, whether issuance is paused, nor charge fees.
+    pub fn unchecked_issue(
+        asset_id: AssetId,
+        portfolio: PortfolioId,
+        amount: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        Self::ensure_issuance_not_closed(&asset_id)?;
+        let mut asset_details = Self::try_get_asset_details(&asset_id)?;
+        Self::validate_issuance_rules(&asset_details, amount)?;
+        Self::unverified_issue_tokens(
+            asset_id,
+            &mut asset_details,
+            portfolio,
+            amount,
+            false,
+            weight_meter,
+        )
+    }
+
+    /// Reorganizes the units of `asset_id` held in `portfolio` into `new_balance` units of `target_id`,
+    /// which is `asset_id` itself for splits and reverse splits.
+    /// The portfolio locks, vesting schedules and acquisition lots of the units are rescaled by `new / old` in place,
+    /// so their ids, vesting dates and acquisition times are kept.
+    ///
+    /// Unlike `issue` and `redeem`, this doesn't check the caller's permissions, whether the operations are paused,
+    /// nor charge fees. Fails if `portfolio` has units locked by settlements or more than `max_locks` portfolio locks.
+    pub fn unchecked_reorganize_portfolio(
+        caller_did: IdentityId,
+        asset_id: AssetId,
+        target_id: AssetId,
+        portfolio: PortfolioId,
+        new_balance: Balance,
+        (new, old): (u32, u32),
+        max_locks: u32,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        // Pending instructions would settle units at the old scale.
+        ensure!(
+            Portfolio::<T>::locked_assets(&portfolio, &asset_id).is_zero(),
+            Error::<T>::UnitsLockedBySettlement
+        );
+        let balance = Portfolio::<T>::portfolio_asset_balances(&portfolio, &asset_id);
+        let target_divisible = Self::is_divisible(&target_id);
+        let rescale = |amount: Balance| {
+            let amount = amount.saturating_mul(new.into()) / Balance::from(old.max(1));
+            if target_divisible {
+                amount
+            } else {
+                amount / currency::ONE_UNIT * currency::ONE_UNIT
+            }
+        };
+
+        // The acquisition time of the units doesn't change, so the lots are rescaled after the issuance or redemption.
+        let mut lots = Statistics::<T>::acquisition_lots(asset_id, &portfolio);
+        lots.rescale(rescale);
+        let mut target_lots = Statistics::<T>::acquisition_lots(target_id, &portfolio);
+
+        Portfolio::<T>::unchecked_rescale_portfolio_locks(
+            caller_did, &portfolio, &asset_id, &target_id, max_locks, rescale,
+        )?;
+
+        let schedules = VestingSchedules::<T>::take(portfolio, asset_id);
+        if !schedules.is_empty() {
+            VestingSchedules::<T>::try_mutate(portfolio, target_id, |target_schedules| {
+                schedules
+                    .into_iter()
+                    .filter_map(|mut schedule| {
+                        schedule.amount = rescale(schedule.amount);
+                        schedule.claimed = rescale(schedule.claimed);
+                        (!schedule.amount.is_zero()).then_some(schedule)
+                    })
+                    .try_for_each(|schedule| target_schedules.try_push(schedule))
+                    .map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)
+            })?;
+        }
+
+        if asset_id == target_id {
+            if new_balance > balance {
+                Self::unchecked_issue(asset_id, portfolio, new_balance - balance, weight_meter)?;
+            } else if new_balance < balance {
+                Self::unchecked_redeem(asset_id, portfolio, balance - new_balance, weight_meter)?;
+            }
+            Statistics::<T>::set_acquisition_lots(asset_id, &portfolio, lots);
+        } else {
+            if !balance.is_zero() {
+                Self::unchecked_redeem(asset_id, portfolio, balance, weight_meter)?;
+            }
+            if !new_balance.is_zero() {
+                Self::unchecked_issue(target_id, portfolio, new_balance, weight_meter)?;
+            }
+            target_lots.merge(lots, T::MaxAcquisitionLots::get() as usize);
+            Statistics::<T>::set_acquisition_lots(asset_id, &portfolio, Default::default());
+            Statistics::<T>::set_acquisition_lots(target_id, &portfolio, target_lots);
+        }
+        Ok(())
+    }
 
+    /// Reduces `value` tokens from `portfolio` and [`AssetDetails::total_supply`].
+    ///
+    /// Unlike `redeem`, this doesn't check the caller's permissions nor whether redemption is paused.
+    pub fn unchecked_redeem(
+        asset_id: AssetId,
+        portfolio: PortfolioId,
+        value: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
         let mut asset_details = Self::try_get_asset_details(&asset_id)?;
         Self::ensure_token_granular(&asset_details, &value)?;
 
@@ -1082,7 +1686,7 @@ impl<T: Config> Module<T> {
         // Update statistic info.
         Statistics::<T>::update_asset_stats(
             asset_id,
-            Some(&portfolio.did),
+            Some(&portfolio),
             None,
             Some(updated_balance),
             None,
@@ -1602,38 +2206,1050 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    pub fn base_link_ticker_to_asset_id(
-        origin: T::RuntimeOrigin,
-        ticker: Ticker,
+    /// Moves `transfer_value` between two portfolios of the same identity as part of a settlement.
+    /// The identity's balance doesn't change, so compliance rules don't apply and only the acquisition lots are moved.
+    pub fn base_portfolio_transfer(
+        from_portfolio: PortfolioId,
+        to_portfolio: PortfolioId,
         asset_id: AssetId,
+        transfer_value: Balance,
+        instruction_id: Option<InstructionId>,
+        instruction_memo: Option<Memo>,
+        caller_did: IdentityId,
+        weight_meter: &mut WeightMeter,
     ) -> DispatchResult {
-        // Verifies if the caller has the correct permissions for this asset
-        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
-        // The caller must own the ticker and the ticker can't be expired
-        UniqueTickerRegistration::<T>::try_mutate(
-            ticker,
-            |ticker_registration| -> DispatchResult {
-                match ticker_registration {
-                    Some(ticker_registration) => {
-                        ensure!(
-                            ticker_registration.owner == caller_did,
-                            Error::<T>::TickerNotRegisteredToCaller
-                        );
-                        if let Some(ticker_expiry) = ticker_registration.expiry {
-                            ensure!(
-                                ticker_expiry > pallet_timestamp::Pallet::<T>::get(),
-                                Error::<T>::TickerRegistrationExpired
-                            );
-                        }
-                        ticker_registration.expiry = None;
-                        Ok(())
-                    }
-                    None => Err(Error::<T>::TickerRegistrationNotFound.into()),
-                }
-            },
-        )?;
-        // The ticker can't be linked to any other asset
-        Self::ensure_ticker_not_linked(&ticker)?;
+        ensure!(
+            from_portfolio.did == to_portfolio.did,
+            Error::<T>::InvalidPortfolioTransfer
+        );
+        Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Transfer)?;
+        Self::ensure_sufficient_vested_balance(&from_portfolio, &asset_id, transfer_value)?;
+        Portfolio::<T>::ensure_portfolio_validity(&from_portfolio)?;
+        Portfolio::<T>::ensure_portfolio_validity(&to_portfolio)?;
+        Portfolio::<T>::ensure_sufficient_balance(&from_portfolio, &asset_id, transfer_value)?;
+
+        Portfolio::<T>::unchecked_transfer_portfolio_balance(
+            &from_portfolio,
+            &to_portfolio,
+            &asset_id,
+            transfer_value,
+        );
+        Statistics::<T>::move_acquisition_lots(
+            asset_id,
+            &from_portfolio,
+            &to_portfolio,
+            transfer_value,
+            weight_meter,
+        )?;
+        Self::deposit_event(RawEvent::AssetBalanceUpdated(
+            caller_did,
+            asset_id,
+            transfer_value,
+            Some(from_portfolio),
+            Some(to_portfolio),
+            PortfolioUpdateReason::Transferred {
+                instruction_id,
+                instruction_memo,
+            },
+        ));
+        Ok(())
+    }
+
+    /// Returns `Ok` if the asset can be transferred between `sender_did` and `receiver_did` according to the asset's
+    /// frozen and paused state, the identities' CDD and the asset's compliance rules.
+    /// Unlike [`Self::validate_asset_transfer`], balances and statistics are not verified.
+    pub fn validate_transfer_compliance(
+        asset_id: AssetId,
+        sender_did: IdentityId,
+        receiver_did: IdentityId,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        ensure!(
+            !Frozen::get(asset_id),
+            Error::<T>::InvalidTransferFrozenAsset
+        );
+        Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Transfer)?;
+        ensure!(
+            Identity::<T>::has_valid_cdd(receiver_did),
+            Error::<T>::InvalidTransferInvalidReceiverCDD
+        );
+        ensure!(
+            Identity::<T>::has_valid_cdd(sender_did),
+            Error::<T>::InvalidTransferInvalidSenderCDD
+        );
+        if !T::ComplianceManager::is_compliant(&asset_id, sender_did, receiver_did, weight_meter)? {
+            return Err(Error::<T>::InvalidTransferComplianceFailure.into());
+        }
+        Ok(())
+    }
+
+    /// Returns `Ok` if a gross transfer of `transfer_value`, settled through netting, satisfies the asset's compliance
+    /// rules and statistics restrictions, given the balances of the sender and receiver at that point of the gross transfers.
+    /// Transfers between portfolios of the same identity are only subject to the asset's paused state, as in settlements.
+    pub fn validate_netted_transfer(
+        asset_id: AssetId,
+        sender_portfolio: &PortfolioId,
+        receiver_portfolio: &PortfolioId,
+        sender_balance: Balance,
+        receiver_balance: Balance,
+        transfer_value: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        if sender_portfolio.did == receiver_portfolio.did {
+            return Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Transfer);
+        }
+        Self::validate_transfer_compliance(
+            asset_id,
+            sender_portfolio.did,
+            receiver_portfolio.did,
+            weight_meter,
+        )?;
+        Statistics::<T>::verify_transfer_restrictions(
+            asset_id,
+            sender_portfolio,
+            receiver_portfolio,
+            sender_balance,
+            receiver_balance,
+            transfer_value,
+            Self::try_get_asset_details(&asset_id)?.total_supply,
+            weight_meter,
+        )
+    }
+
+    /// Moves the net amount `transfer_value` of netted settlement legs from `sender_portfolio` to `receiver_portfolio`.
+    ///
+    /// The gross legs must have been verified through [`Self::validate_netted_transfer`], so only balances are verified,
+    /// while balances, checkpoints and statistics are updated. The event emitted for the move has no instruction,
+    /// as the net amount settles the legs of several instructions.
+    pub fn unchecked_net_transfer(
+        sender_portfolio: PortfolioId,
+        receiver_portfolio: PortfolioId,
+        asset_id: AssetId,
+        transfer_value: Balance,
+        caller_did: IdentityId,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        if sender_portfolio.did == receiver_portfolio.did {
+            return Self::base_portfolio_transfer(
+                sender_portfolio,
+                receiver_portfolio,
+                asset_id,
+                transfer_value,
+                None,
+                None,
+                caller_did,
+                weight_meter,
+            );
+        }
+        Self::ensure_sufficient_vested_balance(&sender_portfolio, &asset_id, transfer_value)?;
+        Portfolio::<T>::ensure_portfolio_transfer_validity(
+            &sender_portfolio,
+            &receiver_portfolio,
+            &asset_id,
+            transfer_value,
+        )?;
+        ensure!(
+            BalanceOf::get(asset_id, &sender_portfolio.did) >= transfer_value,
+            Error::<T>::InsufficientBalance
+        );
+        ensure!(
+            BalanceOf::get(asset_id, &receiver_portfolio.did)
+                .checked_add(transfer_value)
+                .is_some(),
+            Error::<T>::BalanceOverflow
+        );
+        Self::unverified_transfer_asset(
+            sender_portfolio,
+            receiver_portfolio,
+            asset_id,
+            transfer_value,
+            None,
+            None,
+            caller_did,
+            weight_meter,
+        )
+    }
+
+    fn base_set_transfer_fee(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        fee: TransferFee,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        Self::ensure_valid_transfer_fee(&asset_id, &fee)?;
+
+        TransferFees::insert(asset_id, fee.clone());
+        Self::deposit_event(RawEvent::TransferFeeSet(caller_did, asset_id, fee));
+        Ok(())
+    }
+
+    fn base_remove_transfer_fee(origin: T::RuntimeOrigin, asset_id: AssetId) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        ensure!(
+            TransferFees::take(asset_id).is_some(),
+            Error::<T>::TransferFeeNotFound
+        );
+        Self::deposit_event(RawEvent::TransferFeeRemoved(caller_did, asset_id));
+        Ok(())
+    }
+
+    fn base_initiate_identity_recovery(
+        origin: T::RuntimeOrigin,
+        old_did: IdentityId,
+        new_did: IdentityId,
+        asset_ids: BTreeSet<AssetId>,
+    ) -> DispatchResult {
+        // Governance or a CDD provider can initiate a recovery
+        let initiator = match ensure_root(origin.clone()) {
+            Ok(()) => None,
+            Err(_) => {
+                let caller_did = Identity::<T>::ensure_perms(origin)?;
+                ensure!(
+                    T::CddServiceProviders::is_member(&caller_did),
+                    Error::<T>::NotAuthorizedToInitiateIdentityRecovery
+                );
+                Some(caller_did)
+            }
+        };
+
+        ensure!(
+            old_did != new_did
+                && !asset_ids.is_empty()
+                && Identity::<T>::is_identity_exists(&old_did)
+                && Identity::<T>::has_valid_cdd(new_did),
+            Error::<T>::InvalidIdentityRecovery
+        );
+        ensure!(
+            asset_ids.len() <= T::MaxIdentityRecoveryAssets::get() as usize,
+            Error::<T>::MaxIdentityRecoveryAssetsExceeded
+        );
+        let asset_owners = asset_ids
+            .iter()
+            .map(|asset_id| Ok((*asset_id, Self::try_get_asset_details(asset_id)?.owner_did)))
+            .collect::<Result<Vec<_>, DispatchError>>()?;
+
+        let recovery_id = IdentityRecoveryCounter::try_mutate(try_next_pre::<T, _>)?;
+        let execute_after =
+            pallet_timestamp::Pallet::<T>::get() + T::IdentityRecoveryVetoPeriod::get();
+        let mut pending_assets = BTreeMap::new();
+        for (asset_id, owner_did) in asset_owners {
+            let auth_id = Identity::<T>::add_auth(
+                initiator.unwrap_or(GC_DID),
+                Signatory::Identity(owner_did),
+                AuthorizationData::ConfirmIdentityRecovery(recovery_id, asset_id),
+                None,
+            )?;
+            pending_assets.insert(asset_id, auth_id);
+        }
+        IdentityRecoveries::<T>::insert(
+            recovery_id,
+            IdentityRecovery {
+                old_did,
+                new_did,
+                initiator,
+                pending_assets,
+                confirmed_assets: BTreeSet::new(),
+                execute_after,
+            },
+        );
+
+        Self::deposit_event(RawEvent::IdentityRecoveryInitiated(
+            initiator,
+            recovery_id,
+            old_did,
+            new_did,
+            asset_ids,
+            execute_after,
+        ));
+        Ok(())
+    }
+
+    fn base_confirm_identity_recovery(origin: T::RuntimeOrigin, auth_id: u64) -> DispatchResult {
+        let caller_did = Identity::<T>::ensure_perms(origin)?;
+
+        <Identity<T>>::accept_auth_with(&caller_did.into(), auth_id, |auth_data, _| {
+            let (recovery_id, asset_id) =
+                extract_auth!(auth_data, ConfirmIdentityRecovery(recovery_id, asset_id));
+
+            // The caller must be a permissioned agent of the asset
+            <ExternalAgents<T>>::ensure_agent_permissioned(&asset_id, caller_did)?;
+
+            IdentityRecoveries::<T>::try_mutate(recovery_id, |recovery| -> DispatchResult {
+                let recovery = recovery
+                    .as_mut()
+                    .ok_or(Error::<T>::IdentityRecoveryNotFound)?;
+                ensure!(
+                    recovery.pending_assets.remove(&asset_id).is_some(),
+                    Error::<T>::IdentityRecoveryAssetNotPending
+                );
+                recovery.confirmed_assets.insert(asset_id);
+                Ok(())
+            })?;
+
+            Self::deposit_event(RawEvent::IdentityRecoveryConfirmed(
+                caller_did,
+                recovery_id,
+                asset_id,
+            ));
+            Ok(())
+        })
+    }
+
+    fn base_veto_identity_recovery(
+        origin: T::RuntimeOrigin,
+        recovery_id: IdentityRecoveryId,
+    ) -> DispatchResult {
+        let PermissionedCallOriginData { sender, .. } =
+            Identity::<T>::ensure_origin_call_permissions(origin)?;
+
+        let recovery = IdentityRecoveries::<T>::get(recovery_id)
+            .ok_or(Error::<T>::IdentityRecoveryNotFound)?;
+        ensure!(
+            Identity::<T>::is_primary_key(&recovery.old_did, &sender),
+            Error::<T>::NotPrimaryKeyOfRecoveredIdentity
+        );
+        ensure!(
+            pallet_timestamp::Pallet::<T>::get() < recovery.execute_after,
+            Error::<T>::IdentityRecoveryVetoPeriodOver
+        );
+
+        // The authorizations of the assets that have not been confirmed are no longer needed
+        let authorizer = recovery.initiator.unwrap_or(GC_DID);
+        for auth_id in recovery.pending_assets.values() {
+            Identity::<T>::revoke_given_auth(&authorizer, *auth_id);
+        }
+        IdentityRecoveries::<T>::remove(recovery_id);
+        Self::deposit_event(RawEvent::IdentityRecoveryVetoed(
+            recovery.old_did,
+            recovery_id,
+        ));
+        Ok(())
+    }
+
+    fn base_execute_identity_recovery(
+        origin: T::RuntimeOrigin,
+        recovery_id: IdentityRecoveryId,
+        max_portfolios: u32,
+        max_locks: u32,
+        max_nfts: u32,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        let caller_did = Identity::<T>::ensure_perms(origin)?;
+
+        let recovery = IdentityRecoveries::<T>::get(recovery_id)
+            .ok_or(Error::<T>::IdentityRecoveryNotFound)?;
+        ensure!(
+            recovery.pending_assets.is_empty(),
+            Error::<T>::IdentityRecoveryNotConfirmed
+        );
+        ensure!(
+            pallet_timestamp::Pallet::<T>::get() >= recovery.execute_after,
+            Error::<T>::IdentityRecoveryVetoPeriodNotOver
+        );
+        ensure!(
+            Identity::<T>::has_valid_cdd(recovery.new_did),
+            Error::<T>::InvalidIdentityRecovery
+        );
+
+        // All iterations are bounded by the limits given by the caller, which are accounted for in the weight
+        let old_portfolios: Vec<PortfolioId> =
+            iter::once(PortfolioId::default_portfolio(recovery.old_did))
+                .chain(
+                    pallet_portfolio::Portfolios::iter_key_prefix(recovery.old_did)
+                        .map(|number| PortfolioId::user_portfolio(recovery.old_did, number)),
+                )
+                .take(max_portfolios.saturating_add(1) as usize)
+                .collect();
+        ensure!(
+            old_portfolios.len() <= max_portfolios as usize,
+            Error::<T>::IdentityRecoveryLimitsExceeded
+        );
+
+        let new_portfolio = PortfolioId::default_portfolio(recovery.new_did);
+        let mut remaining_locks = max_locks as usize;
+        let mut remaining_nfts = max_nfts as usize;
+        for old_portfolio in &old_portfolios {
+            // Portfolio locks of the recovered assets are moved before their units
+            let old_locks: Vec<_> =
+                pallet_portfolio::PortfolioLocks::<T>::iter_prefix(old_portfolio)
+                    .take(remaining_locks.saturating_add(1))
+                    .collect();
+            remaining_locks = remaining_locks
+                .checked_sub(old_locks.len())
+                .ok_or(Error::<T>::IdentityRecoveryLimitsExceeded)?;
+            for (lock_id, lock) in old_locks {
+                if recovery.confirmed_assets.contains(&lock.asset_id) {
+                    Portfolio::<T>::unchecked_move_portfolio_lock(
+                        caller_did,
+                        old_portfolio,
+                        &new_portfolio,
+                        lock_id,
+                        lock,
+                    );
+                }
+            }
+
+            let old_nfts: Vec<(AssetId, NFTId)> =
+                pallet_portfolio::PortfolioNFT::iter_key_prefix(old_portfolio)
+                    .take(remaining_nfts.saturating_add(1))
+                    .collect();
+            remaining_nfts = remaining_nfts
+                .checked_sub(old_nfts.len())
+                .ok_or(Error::<T>::IdentityRecoveryLimitsExceeded)?;
+            let mut nfts_per_asset: BTreeMap<AssetId, Vec<NFTId>> = BTreeMap::new();
+            for (asset_id, nft_id) in old_nfts {
+                if recovery.confirmed_assets.contains(&asset_id)
+                    && !pallet_portfolio::PortfolioLockedNFT::get(old_portfolio, (asset_id, nft_id))
+                {
+                    nfts_per_asset.entry(asset_id).or_default().push(nft_id);
+                }
+            }
+            for (asset_id, nft_ids) in nfts_per_asset {
+                T::NFTFn::controller_transfer_nfts(
+                    asset_id,
+                    nft_ids,
+                    *old_portfolio,
+                    new_portfolio,
+                    caller_did,
+                )?;
+            }
+
+            for asset_id in &recovery.confirmed_assets {
+                if !Self::try_get_asset_details(asset_id)?
+                    .asset_type
+                    .is_fungible()
+                {
+                    continue;
+                }
+                // Vesting schedules are moved with the unvested units
+                let schedules = VestingSchedules::<T>::take(old_portfolio, asset_id);
+                if !schedules.is_empty() {
+                    VestingSchedules::<T>::try_mutate(new_portfolio, asset_id, |new_schedules| {
+                        schedules
+                            .into_iter()
+                            .try_for_each(|schedule| new_schedules.try_push(schedule))
+                            .map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)
+                    })?;
+                }
+
+                // Only units locked by pending instructions remain in the old portfolio
+                let amount = PortfolioAssetBalances::get(old_portfolio, asset_id)
+                    .saturating_sub(Portfolio::<T>::locked_assets(old_portfolio, asset_id));
+                if amount.is_zero() {
+                    continue;
+                }
+                Self::validate_asset_transfer(
+                    *asset_id,
+                    old_portfolio,
+                    &new_portfolio,
+                    amount,
+                    true,
+                    weight_meter,
+                )?;
+                Self::unverified_transfer_asset(
+                    *old_portfolio,
+                    new_portfolio,
+                    *asset_id,
+                    amount,
+                    None,
+                    None,
+                    caller_did,
+                    weight_meter,
+                )?;
+            }
+        }
+
+        IdentityRecoveries::<T>::remove(recovery_id);
+        Self::deposit_event(RawEvent::IdentityRecoveryExecuted(
+            caller_did,
+            recovery_id,
+            recovery.old_did,
+            recovery.new_did,
+        ));
+        Ok(())
+    }
+
+    /// Pauses or unpauses `operations` for the asset associated to `asset_id`.
+    fn base_set_paused_operations(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        operations: AssetOperations,
+        pause: bool,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        Self::ensure_asset_exists(&asset_id)?;
+        ensure!(operations.is_valid(), Error::<T>::InvalidAssetOperations);
+
+        let mut paused_operations = PausedOperations::get(asset_id);
+        if pause {
+            paused_operations.insert(operations);
+        } else {
+            paused_operations.remove(operations);
+        }
+        // Nothing is kept in storage once all operations have been unpaused
+        if paused_operations.is_empty() {
+            PausedOperations::remove(asset_id);
+        } else {
+            PausedOperations::insert(asset_id, paused_operations);
+        }
+
+        if pause {
+            Self::deposit_event(RawEvent::AssetOperationsPaused(
+                caller_did, asset_id, operations,
+            ));
+        } else {
+            Self::deposit_event(RawEvent::AssetOperationsUnpaused(
+                caller_did, asset_id, operations,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Pauses or unpauses the settlement of the asset associated to `asset_id` through `venue_id`.
+    fn base_set_venue_settlement_paused(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        venue_id: VenueId,
+        pause: bool,
+    ) -> DispatchResult {
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        Self::ensure_asset_exists(&asset_id)?;
+
+        if pause {
+            PausedVenues::insert(asset_id, venue_id, true);
+            Self::deposit_event(RawEvent::VenueSettlementPaused(
+                caller_did, asset_id, venue_id,
+            ));
+        } else {
+            ensure!(
+                PausedVenues::take(asset_id, venue_id),
+                Error::<T>::VenueSettlementNotPaused
+            );
+            Self::deposit_event(RawEvent::VenueSettlementUnpaused(
+                caller_did, asset_id, venue_id,
+            ));
+        }
+        Ok(())
+    }
+
+    fn base_add_vesting_schedule(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        funding: PortfolioKind,
+        portfolio_id: PortfolioId,
+        amount: Balance,
+        start: T::Moment,
+        cliff: T::Moment,
+        end: T::Moment,
+        release: VestingRelease,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        let funding_portfolio =
+            Self::ensure_origin_asset_and_portfolio_permissions(origin, asset_id, funding, true)?;
+        let caller_did = funding_portfolio.did;
+        let asset_details = Self::try_get_asset_details(&asset_id)?;
+        ensure!(
+            asset_details.asset_type.is_fungible(),
+            Error::<T>::UnexpectedNonFungibleToken
+        );
+
+        let mut schedule = VestingSchedule {
+            id: VestingScheduleId::default(),
+            amount,
+            start,
+            cliff,
+            end,
+            release,
+            claimed: 0,
+        };
+        ensure!(
+            amount > 0 && schedule.is_valid(),
+            Error::<T>::InvalidVestingSchedule
+        );
+        // Only the units transferred by the agent are vested, so the receiver's own units are never locked
+        Self::validate_asset_transfer(
+            asset_id,
+            &funding_portfolio,
+            &portfolio_id,
+            amount,
+            false,
+            weight_meter,
+        )?;
+        Self::unverified_transfer_asset(
+            funding_portfolio,
+            portfolio_id,
+            asset_id,
+            amount,
+            None,
+            None,
+            caller_did,
+            weight_meter,
+        )?;
+
+        schedule.id = VestingScheduleCounter::try_mutate(try_next_pre::<T, _>)?;
+        VestingSchedules::<T>::try_mutate(portfolio_id, asset_id, |schedules| {
+            schedules
+                .try_push(schedule.clone())
+                .map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)
+        })?;
+
+        Self::deposit_event(RawEvent::VestingScheduleAdded(
+            caller_did,
+            asset_id,
+            portfolio_id,
+            schedule,
+        ));
+        Ok(())
+    }
+
+    fn base_claim_vested_assets(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_id: PortfolioId,
+    ) -> DispatchResult {
+        let origin_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        Portfolio::<T>::ensure_portfolio_custody_and_permission(
+            portfolio_id,
+            origin_data.primary_did,
+            origin_data.secondary_key.as_ref(),
+        )?;
+
+        let mut schedules = VestingSchedules::<T>::get(portfolio_id, asset_id);
+        ensure!(!schedules.is_empty(), Error::<T>::VestingScheduleNotFound);
+
+        let now = <pallet_timestamp::Pallet<T>>::get();
+        let mut claimed: Balance = 0;
+        for schedule in schedules.iter_mut() {
+            if schedule.release == VestingRelease::OnClaim {
+                let vested = schedule.vested(now);
+                claimed = claimed.saturating_add(vested - schedule.claimed);
+                schedule.claimed = vested;
+            }
+        }
+        // Schedules without locked units are no longer needed
+        schedules.retain(|schedule| schedule.locked(now) > 0);
+        if schedules.is_empty() {
+            VestingSchedules::<T>::remove(portfolio_id, asset_id);
+        } else {
+            VestingSchedules::<T>::insert(portfolio_id, asset_id, schedules);
+        }
+
+        Self::deposit_event(RawEvent::VestedAssetsClaimed(
+            origin_data.primary_did,
+            asset_id,
+            portfolio_id,
+            claimed,
+        ));
+        Ok(())
+    }
+
+    fn base_revoke_vesting_schedule(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_id: PortfolioId,
+        schedule_id: VestingScheduleId,
+        treasury: PortfolioKind,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        let treasury_portfolio =
+            Self::ensure_origin_asset_and_portfolio_permissions(origin, asset_id, treasury, true)?;
+
+        let mut schedules = VestingSchedules::<T>::get(portfolio_id, asset_id);
+        let index = schedules
+            .iter()
+            .position(|schedule| schedule.id == schedule_id)
+            .ok_or(Error::<T>::VestingScheduleNotFound)?;
+        let schedule = schedules.remove(index);
+        if schedules.is_empty() {
+            VestingSchedules::<T>::remove(portfolio_id, asset_id);
+        } else {
+            VestingSchedules::<T>::insert(portfolio_id, asset_id, schedules);
+        }
+
+        // The schedule has been removed, so its unvested units are no longer locked
+        let unvested = schedule.amount - schedule.vested(<pallet_timestamp::Pallet<T>>::get());
+        if !unvested.is_zero() {
+            Self::validate_asset_transfer(
+                asset_id,
+                &portfolio_id,
+                &treasury_portfolio,
+                unvested,
+                true,
+                weight_meter,
+            )?;
+            Self::unverified_transfer_asset(
+                portfolio_id,
+                treasury_portfolio,
+                asset_id,
+                unvested,
+                None,
+                None,
+                treasury_portfolio.did,
+                weight_meter,
+            )?;
+        }
+
+        Self::deposit_event(RawEvent::VestingScheduleRevoked(
+            treasury_portfolio.did,
+            asset_id,
+            portfolio_id,
+            schedule_id,
+            treasury_portfolio,
+            unvested,
+        ));
+        Ok(())
+    }
+
+    fn base_approve(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_kind: PortfolioKind,
+        spender: IdentityId,
+        amount: Balance,
+        expiry: Option<T::Moment>,
+    ) -> DispatchResult {
+        let portfolio_id = Self::ensure_origin_portfolio_custody(origin, portfolio_kind)?;
+        let asset_details = Self::try_get_asset_details(&asset_id)?;
+        ensure!(
+            asset_details.asset_type.is_fungible(),
+            Error::<T>::UnexpectedNonFungibleToken
+        );
+        if let Some(expiry) = expiry {
+            ensure!(
+                expiry > <pallet_timestamp::Pallet<T>>::get(),
+                Error::<T>::InvalidAllowanceExpiry
+            );
+        }
+
+        if amount.is_zero() {
+            Allowances::<T>::remove((portfolio_id, asset_id), spender);
+        } else {
+            Allowances::<T>::insert(
+                (portfolio_id, asset_id),
+                spender,
+                AssetAllowance { amount, expiry },
+            );
+        }
+
+        Self::deposit_event(RawEvent::AllowanceApproved(
+            portfolio_id.did,
+            asset_id,
+            portfolio_id,
+            spender,
+            amount,
+            expiry,
+        ));
+        Ok(())
+    }
+
+    fn base_transfer_from(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        from_portfolio: PortfolioId,
+        to_portfolio_kind: PortfolioKind,
+        amount: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        let to_portfolio = Self::ensure_origin_portfolio_custody(origin, to_portfolio_kind)?;
+        let spender = to_portfolio.did;
+
+        let mut allowance = Allowances::<T>::get((from_portfolio, asset_id), spender)
+            .ok_or(Error::<T>::InsufficientAllowance)?;
+        ensure!(
+            !allowance.is_expired(&<pallet_timestamp::Pallet<T>>::get()),
+            Error::<T>::AllowanceExpired
+        );
+        ensure!(
+            allowance.amount >= amount,
+            Error::<T>::InsufficientAllowance
+        );
+        // The allowance was granted by the owner, so it can only be used while they hold custody
+        Portfolio::<T>::ensure_portfolio_custody(from_portfolio, from_portfolio.did)?;
+
+        Self::validate_asset_transfer(
+            asset_id,
+            &from_portfolio,
+            &to_portfolio,
+            amount,
+            false,
+            weight_meter,
+        )?;
+        Self::unverified_transfer_asset(
+            from_portfolio,
+            to_portfolio,
+            asset_id,
+            amount,
+            None,
+            None,
+            spender,
+            weight_meter,
+        )?;
+
+        // The spender initiates the transfer, so it pays the fee from the receiving portfolio
+        if let Some(fee) = TransferFees::get(asset_id) {
+            let fee_amount = fee.fungible_fee(amount);
+            if fee_amount > 0 && to_portfolio != fee.beneficiary {
+                let fee_asset_id = fee.fee_asset_id(asset_id);
+                Self::base_transfer(
+                    to_portfolio,
+                    fee.beneficiary,
+                    fee_asset_id,
+                    fee_amount,
+                    None,
+                    None,
+                    spender,
+                    weight_meter,
+                )?;
+                Self::deposit_event(RawEvent::TransferFromFeeCharged(
+                    spender,
+                    asset_id,
+                    to_portfolio,
+                    fee.beneficiary,
+                    fee_asset_id,
+                    fee_amount,
+                ));
+            }
+        }
+
+        allowance.amount -= amount;
+        if allowance.amount.is_zero() {
+            Allowances::<T>::remove((from_portfolio, asset_id), spender);
+        } else {
+            Allowances::<T>::insert((from_portfolio, asset_id), spender, &allowance);
+        }
+
+        Self::deposit_event(RawEvent::AllowanceUsed(
+            spender,
+            asset_id,
+            from_portfolio,
+            to_portfolio,
+            amount,
+            allowance.amount,
+        ));
+        Ok(())
+    }
+
+    fn base_set_fee_asset_rate(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        rate: Option<Balance>,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+        match rate {
+            Some(rate) => {
+                ensure!(!rate.is_zero(), Error::<T>::InvalidFeeAssetRate);
+                let asset_details = Self::try_get_asset_details(&asset_id)?;
+                ensure!(
+                    asset_details.asset_type.is_fungible() && asset_details.divisible,
+                    Error::<T>::InvalidFeeAsset
+                );
+                Self::ensure_unrestricted_fee_asset(&asset_id)?;
+                FeeAssetRates::insert(asset_id, rate);
+            }
+            None => FeeAssetRates::remove(asset_id),
+        }
+        Self::deposit_event(RawEvent::FeeAssetRateSet(asset_id, rate));
+        Ok(())
+    }
+
+    fn base_set_fee_collector(
+        origin: T::RuntimeOrigin,
+        portfolio_id: PortfolioId,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+        Portfolio::<T>::ensure_portfolio_validity(&portfolio_id)?;
+        FeeCollector::put(portfolio_id);
+        Self::deposit_event(RawEvent::FeeCollectorSet(portfolio_id));
+        Ok(())
+    }
+
+    fn base_set_fee_asset(origin: T::RuntimeOrigin, asset_id: Option<AssetId>) -> DispatchResult {
+        let caller_did = Identity::<T>::ensure_perms(origin)?;
+        match asset_id {
+            Some(asset_id) => {
+                ensure!(
+                    FeeAssetRates::contains_key(asset_id),
+                    Error::<T>::FeeAssetNotApproved
+                );
+                Self::ensure_unrestricted_fee_asset(&asset_id)?;
+                FeeAssets::insert(caller_did, asset_id);
+            }
+            None => FeeAssets::remove(caller_did),
+        }
+        Self::deposit_event(RawEvent::FeeAssetSet(caller_did, asset_id));
+        Ok(())
+    }
+
+    /// Returns `Ok` if `asset_id` has neither compliance requirements nor transfer conditions,
+    /// so that any holder may pay fees in it to the fee collector.
+    fn ensure_unrestricted_fee_asset(asset_id: &AssetId) -> DispatchResult {
+        ensure!(
+            !T::ComplianceManager::has_requirements(asset_id)
+                && !Statistics::<T>::has_transfer_conditions(asset_id),
+            Error::<T>::RestrictedFeeAsset
+        );
+        Ok(())
+    }
+
+    /// Moves `amount` of `asset_id` from the default portfolio of `payer` to the fee collector and locks it there.
+    ///
+    /// Fee assets can't have compliance requirements or transfer conditions, so, unlike other transfers,
+    /// neither are evaluated, and paying fees has a bounded cost.
+    fn withdraw_fee_in_asset(
+        payer: &T::AccountId,
+        asset_id: AssetId,
+        amount: Balance,
+    ) -> Result<(), InvalidTransaction> {
+        let payer_did = Identity::<T>::get_identity(payer).ok_or(InvalidTransaction::Payment)?;
+        let collector = FeeCollector::get().ok_or(InvalidTransaction::Payment)?;
+        let payer_portfolio = PortfolioId::default_portfolio(payer_did);
+        with_transaction(|| {
+            ensure!(
+                !Frozen::get(asset_id),
+                Error::<T>::InvalidTransferFrozenAsset
+            );
+            Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Transfer)?;
+            Self::ensure_unrestricted_fee_asset(&asset_id)?;
+            Self::unchecked_move_fee(payer_portfolio, collector, asset_id, amount, payer_did)?;
+            Portfolio::<T>::lock_tokens(&collector, &asset_id, amount)
+        })
+        .map_err(|_: DispatchError| InvalidTransaction::Payment)
+    }
+
+    /// Unlocks the `paid` fee withdrawn from `payer` and moves `refund` of it back to the default portfolio of `payer`.
+    ///
+    /// The fee was locked in the collector's portfolio, and refunds are not subject to the asset being frozen or paused,
+    /// so they only fail if the collector's portfolio no longer exists.
+    fn refund_fee_in_asset(
+        payer: &T::AccountId,
+        asset_id: AssetId,
+        paid: Balance,
+        refund: Balance,
+    ) -> Result<(), InvalidTransaction> {
+        let payer_did = Identity::<T>::get_identity(payer).ok_or(InvalidTransaction::Payment)?;
+        let collector = FeeCollector::get().ok_or(InvalidTransaction::Payment)?;
+        let payer_portfolio = PortfolioId::default_portfolio(payer_did);
+        with_transaction(|| {
+            Portfolio::<T>::unlock_tokens(&collector, &asset_id, paid)?;
+            Self::unchecked_move_fee(collector, payer_portfolio, asset_id, refund, collector.did)
+        })
+        .map_err(|_: DispatchError| InvalidTransaction::Payment)
+    }
+
+    /// Moves a fee of `amount` of `asset_id` between portfolios, only updating balances, checkpoints and statistics.
+    /// The weight of the statistics is bounded by the weight of a regular transfer.
+    fn unchecked_move_fee(
+        from: PortfolioId,
+        to: PortfolioId,
+        asset_id: AssetId,
+        amount: Balance,
+        caller_did: IdentityId,
+    ) -> DispatchResult {
+        if amount.is_zero() || from == to {
+            return Ok(());
+        }
+        Portfolio::<T>::ensure_sufficient_balance(&from, &asset_id, amount)?;
+        Self::ensure_sufficient_vested_balance(&from, &asset_id, amount)?;
+        let mut weight_meter =
+            WeightMeter::from_limit(Weight::zero(), <T as Config>::WeightInfo::base_transfer())
+                .map_err(|_| Error::<T>::InputWeightIsLessThanMinimum)?;
+        if from.did == to.did {
+            Portfolio::<T>::unchecked_transfer_portfolio_balance(&from, &to, &asset_id, amount);
+            return Statistics::<T>::move_acquisition_lots(
+                asset_id,
+                &from,
+                &to,
+                amount,
+                &mut weight_meter,
+            );
+        }
+        ensure!(
+            BalanceOf::get(asset_id, &to.did)
+                .checked_add(amount)
+                .is_some(),
+            Error::<T>::BalanceOverflow
+        );
+        Self::unverified_transfer_asset(
+            from,
+            to,
+            asset_id,
+            amount,
+            None,
+            None,
+            caller_did,
+            &mut weight_meter,
+        )
+    }
+
+    /// Returns `Ok` if `fee` can be charged for transfers of `asset_id`.
+    fn ensure_valid_transfer_fee(asset_id: &AssetId, fee: &TransferFee) -> DispatchResult {
+        let asset_details = Self::try_get_asset_details(asset_id)?;
+        match fee.amount {
+            TransferFeeAmount::Flat(amount) => {
+                ensure!(amount > 0, Error::<T>::InvalidTransferFee);
+            }
+            TransferFeeAmount::BasisPoints(bps) => {
+                // A fraction of the amount can only be charged in the transferred asset itself
+                ensure!(
+                    bps > 0
+                        && bps <= TransferFee::MAX_BASIS_POINTS
+                        && fee.currency.is_none()
+                        && asset_details.asset_type.is_fungible(),
+                    Error::<T>::InvalidTransferFee
+                );
+            }
+        }
+        match fee.currency {
+            Some(currency) => {
+                let currency_details = Self::try_get_asset_details(&currency)?;
+                ensure!(
+                    currency != *asset_id && currency_details.asset_type.is_fungible(),
+                    Error::<T>::InvalidTransferFee
+                );
+            }
+            None => {
+                ensure!(
+                    asset_details.asset_type.is_fungible(),
+                    Error::<T>::InvalidTransferFee
+                );
+            }
+        }
+        Portfolio::<T>::ensure_portfolio_validity(&fee.beneficiary)?;
+        Ok(())
+    }
+
+    pub fn base_link_ticker_to_asset_id(
+        origin: T::RuntimeOrigin,
+        ticker: Ticker,
+        asset_id: AssetId,
+    ) -> DispatchResult {
+        // Verifies if the caller has the correct permissions for this asset
+        let caller_did = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        // The caller must own the ticker and the ticker can't be expired
+        UniqueTickerRegistration::<T>::try_mutate(
+            ticker,
+            |ticker_registration| -> DispatchResult {
+                match ticker_registration {
+                    Some(ticker_registration) => {
+                        ensure!(
+                            ticker_registration.owner == caller_did,
+                            Error::<T>::TickerNotRegisteredToCaller
+                        );
+                        if let Some(ticker_expiry) = ticker_registration.expiry {
+                            ensure!(
+                                ticker_expiry > pallet_timestamp::Pallet::<T>::get(),
+                                Error::<T>::TickerRegistrationExpired
+                            );
+                        }
+                        ticker_registration.expiry = None;
+                        Ok(())
+                    }
+                    None => Err(Error::<T>::TickerRegistrationNotFound.into()),
+                }
+            },
+        )?;
+        // The ticker can't be linked to any other asset
+        Self::ensure_ticker_not_linked(&ticker)?;
         // The asset can't be linked to any other ticker
         ensure!(
             !AssetIdTicker::contains_key(asset_id),
@@ -1855,6 +3471,19 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Returns a [`WeightMeter`] charging at least `minimum_weight` and at most `weight_limit`.
+    fn ensure_valid_weight_meter(
+        minimum_weight: Weight,
+        weight_limit: Weight,
+    ) -> Result<WeightMeter, DispatchErrorWithPostInfo> {
+        WeightMeter::from_limit(minimum_weight, weight_limit).map_err(|_| {
+            DispatchErrorWithPostInfo {
+                post_info: Some(weight_limit).into(),
+                error: Error::<T>::InputWeightIsLessThanMinimum.into(),
+            }
+        })
+    }
+
     /// Ensures that `origin` is a permissioned agent for `asset_id`, that the portfolio is valid and that calller
     /// has the access to the portfolio. If `ensure_custody` is `true`, also enforces the caller to have custody
     /// of the portfolio.
@@ -1877,6 +3506,23 @@ impl<T: Config> Module<T> {
         Ok(portfolio_id)
     }
 
+    /// Returns the [`PortfolioId`] of the caller's `portfolio_kind` portfolio if it's valid,
+    /// in the caller's custody and the caller has permissions over it.
+    fn ensure_origin_portfolio_custody(
+        origin: T::RuntimeOrigin,
+        portfolio_kind: PortfolioKind,
+    ) -> Result<PortfolioId, DispatchError> {
+        let origin_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let portfolio_id = PortfolioId::new(origin_data.primary_did, portfolio_kind);
+        Portfolio::<T>::ensure_portfolio_validity(&portfolio_id)?;
+        Portfolio::<T>::ensure_portfolio_custody_and_permission(
+            portfolio_id,
+            origin_data.primary_did,
+            origin_data.secondary_key.as_ref(),
+        )?;
+        Ok(portfolio_id)
+    }
+
     /// Returns `Ok` if all rules for creating a custom type are satisfied.
     fn validate_custom_asset_type_rules(asset_type_bytes: &[u8]) -> DispatchResult {
         ensure_string_limited::<T>(asset_type_bytes)?;
@@ -1913,6 +3559,32 @@ impl<T: Config> Module<T> {
         })
     }
 
+    /// Returns the type definition of the metadata `key` of `asset_id`, if it has one.
+    ///
+    /// Type definitions that can't be decoded predate value validation and are ignored.
+    fn asset_metadata_type_def(
+        asset_id: &AssetId,
+        key: &AssetMetadataKey,
+    ) -> Option<AssetMetadataTypeDef> {
+        let spec = match key {
+            AssetMetadataKey::Global(key) => AssetMetadataGlobalSpecs::get(key),
+            AssetMetadataKey::Local(key) => AssetMetadataLocalSpecs::get(asset_id, key),
+        }?;
+        spec.decode_type_def().ok().flatten()
+    }
+
+    /// Returns the metadata value of `key` for `asset_id` as a JSON document.
+    ///
+    /// Values are decoded using the type definition of their key. Values without one,
+    /// or that don't conform to it, are returned as a hex string.
+    pub fn asset_metadata_json(asset_id: &AssetId, key: &AssetMetadataKey) -> Option<Vec<u8>> {
+        let value = AssetMetadataValues::get(asset_id, key)?;
+        let json = Self::asset_metadata_type_def(asset_id, key)
+            .and_then(|type_def| type_def.decode_value_to_json(&value).ok())
+            .unwrap_or_else(|| value.to_json_hex());
+        Some(json)
+    }
+
     /// Ensure asset metadata `value` is within the global limit.
     fn ensure_asset_metadata_value_limited(value: &AssetMetadataValue) -> DispatchResult {
         ensure!(
@@ -1990,6 +3662,9 @@ impl<T: Config> Module<T> {
             Error::<T>::BalanceOverflow
         );
 
+        // Unvested units can't be transferred, not even by controllers
+        Self::ensure_sufficient_vested_balance(sender_portfolio, &asset_id, transfer_value)?;
+
         // Verifies that both portfolios exist an that the sender portfolio has sufficient balance
         Portfolio::<T>::ensure_portfolio_transfer_validity(
             sender_portfolio,
@@ -1998,9 +3673,9 @@ impl<T: Config> Module<T> {
             transfer_value,
         )?;
 
-        // Controllers are exempt from statistics, compliance and frozen rules.
+        // Controllers are exempt from statistics, compliance and frozen rules, but not from a reorganization's freeze.
         if is_controller_transfer {
-            return Ok(());
+            return Self::ensure_transfers_not_frozen(&asset_id);
         }
 
         // Verifies that the asset is not frozen
@@ -2008,6 +3683,7 @@ impl<T: Config> Module<T> {
             !Frozen::get(asset_id),
             Error::<T>::InvalidTransferFrozenAsset
         );
+        Self::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Transfer)?;
 
         ensure!(
             Identity::<T>::has_valid_cdd(receiver_portfolio.did),
@@ -2022,8 +3698,8 @@ impl<T: Config> Module<T> {
         // Verifies that the statistics restrictions are satisfied
         Statistics::<T>::verify_transfer_restrictions(
             asset_id,
-            &sender_portfolio.did,
-            &receiver_portfolio.did,
+            &sender_portfolio,
+            &receiver_portfolio,
             Self::balance_of(asset_id, sender_portfolio.did),
             Self::balance_of(asset_id, receiver_portfolio.did),
             transfer_value,
@@ -2096,6 +3772,12 @@ impl<T: Config> Module<T> {
             asset_transfer_errors.push(e);
         }
 
+        if let Err(e) =
+            Self::ensure_sufficient_vested_balance(sender_portfolio, asset_id, transfer_value)
+        {
+            asset_transfer_errors.push(e);
+        }
+
         if skip_locked_check {
             if PortfolioAssetBalances::get(sender_portfolio, asset_id) < transfer_value {
                 asset_transfer_errors
@@ -2123,10 +3805,15 @@ impl<T: Config> Module<T> {
             asset_transfer_errors.push(Error::<T>::InvalidTransferFrozenAsset.into());
         }
 
+        if let Err(e) = Self::ensure_asset_operation_not_paused(asset_id, AssetOperation::Transfer)
+        {
+            asset_transfer_errors.push(e);
+        }
+
         if let Err(e) = Statistics::<T>::verify_transfer_restrictions(
             *asset_id,
-            &sender_portfolio.did,
-            &receiver_portfolio.did,
+            &sender_portfolio,
+            &receiver_portfolio,
             sender_current_balance,
             receiver_current_balance,
             transfer_value,
@@ -2231,6 +3918,98 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Returns the amount of `asset_id` held in `portfolio_id` that can't be transferred due to vesting.
+    pub fn unvested_balance(portfolio_id: &PortfolioId, asset_id: &AssetId) -> Balance {
+        let schedules = VestingSchedules::<T>::get(portfolio_id, asset_id);
+        if schedules.is_empty() {
+            return 0;
+        }
+        let now = <pallet_timestamp::Pallet<T>>::get();
+        schedules.iter().fold(0, |unvested: Balance, schedule| {
+            unvested.saturating_add(schedule.locked(now))
+        })
+    }
+
+    /// Returns an error if `portfolio_id` holds `value` units of `asset_id`, but not enough of them have vested.
+    /// An insufficient portfolio balance is reported by the portfolio checks.
+    fn ensure_sufficient_vested_balance(
+        portfolio_id: &PortfolioId,
+        asset_id: &AssetId,
+        value: Balance,
+    ) -> DispatchResult {
+        let balance = PortfolioAssetBalances::get(portfolio_id, asset_id);
+        let unvested = Self::unvested_balance(portfolio_id, asset_id);
+        ensure!(
+            balance < value || balance.saturating_sub(unvested) >= value,
+            Error::<T>::InsufficientVestedBalance
+        );
+        Ok(())
+    }
+
+    /// Returns `Ok` if settlement is not paused for the asset associated to `asset_id`,
+    /// neither for all venues nor for `venue_id`.
+    pub fn ensure_settlement_not_paused(
+        asset_id: &AssetId,
+        venue_id: Option<&VenueId>,
+    ) -> DispatchResult {
+        Self::ensure_asset_operation_not_paused(asset_id, AssetOperation::Settlement)?;
+        if let Some(venue_id) = venue_id {
+            ensure!(
+                !PausedVenues::get(asset_id, venue_id),
+                Error::<T>::AssetOperationPaused
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns all venues through which the settlement of the asset associated to `asset_id` is paused.
+    pub fn paused_settlement_venues(asset_id: &AssetId) -> Vec<VenueId> {
+        PausedVenues::iter_key_prefix(asset_id).collect()
+    }
+
+    /// Returns `Ok` if `operation` is not paused for the asset associated to `asset_id`.
+    /// Operations that change balances are also rejected while the asset's transfers are frozen.
+    pub fn ensure_asset_operation_not_paused(
+        asset_id: &AssetId,
+        operation: AssetOperation,
+    ) -> DispatchResult {
+        ensure!(
+            !PausedOperations::get(asset_id).contains(operation),
+            Error::<T>::AssetOperationPaused
+        );
+        if operation != AssetOperation::Settlement {
+            Self::ensure_transfers_not_frozen(asset_id)?;
+        }
+        Ok(())
+    }
+
+    /// Freezes the transfers, issuance and redemption of `asset_id` from the first moment of `period`
+    /// until the second one, or unfreezes them if `None`.
+    /// Controller transfers and portfolio moves are frozen as well.
+    pub fn freeze_transfers(asset_id: AssetId, period: Option<(Moment, Moment)>) {
+        TransfersFrozen::mutate_exists(asset_id, |frozen| *frozen = period);
+    }
+
+    /// Returns `Ok` if the transfers of `asset_id` are not frozen at the current moment.
+    pub fn ensure_transfers_not_frozen(asset_id: &AssetId) -> DispatchResult {
+        if let Some((from, until)) = TransfersFrozen::get(asset_id) {
+            let now = Checkpoint::<T>::now_unix();
+            ensure!(now < from || now >= until, Error::<T>::TransfersFrozen);
+        }
+        Ok(())
+    }
+
+    /// Closes the issuance of `asset_id` for good, e.g. once the principal of its units has been locked.
+    pub fn close_issuance(asset_id: AssetId) {
+        IssuanceClosed::insert(asset_id, true);
+    }
+
+    /// Returns `Ok` if more units of `asset_id` can still be issued.
+    fn ensure_issuance_not_closed(asset_id: &AssetId) -> DispatchResult {
+        ensure!(!IssuanceClosed::get(asset_id), Error::<T>::IssuanceClosed);
+        Ok(())
+    }
+
     pub fn generate_asset_id(caller_acc: T::AccountId, update: bool) -> AssetId {
         let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
         let nonce = Self::get_nonce(&caller_acc, update);
@@ -2380,7 +4159,7 @@ impl<T: Config> Module<T> {
         Statistics::<T>::update_asset_stats(
             asset_id,
             None,
-            Some(&issuer_portfolio.did),
+            Some(&issuer_portfolio),
             None,
             Some(new_issuer_balance),
             amount_to_issue,
@@ -2444,8 +4223,8 @@ impl<T: Config> Module<T> {
         // Update statistics info.
         Statistics::<T>::update_asset_stats(
             asset_id,
-            Some(&sender_portfolio.did),
-            Some(&receiver_portfolio.did),
+            Some(&sender_portfolio),
+            Some(&receiver_portfolio),
             Some(sender_new_balance),
             Some(receiver_new_balance),
             transfer_value,
@@ -2516,6 +4295,13 @@ impl<T: Config> Module<T> {
             Error::<T>::AssetMetadataValueIsLocked
         );
 
+        // Check value conforms to the type definition of the key.
+        if let Some(type_def) = Self::asset_metadata_type_def(&asset_id, &key) {
+            type_def
+                .validate_value(&value)
+                .map_err(|_| Error::<T>::AssetMetadataValueInvalid)?;
+        }
+
         // Set asset metadata value for asset.
         AssetMetadataValues::insert(asset_id, key, &value);
 
@@ -2606,6 +4392,40 @@ impl<T: Config> Module<T> {
 // Trait implementation!
 //==========================================================================
 
+impl<T: Config> AssetFeeHandler<T::AccountId> for Module<T> {
+    fn fee_asset(payer: &T::AccountId) -> Option<(AssetId, Balance)> {
+        // Fees can only be paid in assets once a collector has been set.
+        FeeCollector::get()?;
+        let payer_did = Identity::<T>::get_identity(payer)?;
+        let asset_id = FeeAssets::get(payer_did)?;
+        let rate = FeeAssetRates::get(asset_id)?;
+        // Fees are paid in POLYX once the asset's transfers are restricted.
+        Self::ensure_unrestricted_fee_asset(&asset_id).ok()?;
+        Some((asset_id, rate))
+    }
+
+    fn fee_collector_key() -> Option<T::AccountId> {
+        Identity::<T>::get_primary_key(FeeCollector::get()?.did)
+    }
+
+    fn withdraw_fee(
+        payer: &T::AccountId,
+        asset_id: AssetId,
+        amount: Balance,
+    ) -> Result<(), InvalidTransaction> {
+        Self::withdraw_fee_in_asset(payer, asset_id, amount)
+    }
+
+    fn refund_fee(
+        payer: &T::AccountId,
+        asset_id: AssetId,
+        paid: Balance,
+        refund: Balance,
+    ) -> Result<(), InvalidTransaction> {
+        Self::refund_fee_in_asset(payer, asset_id, paid, refund)
+    }
+}
+
 impl<T: Config> AssetFnTrait<T::AccountId, T::RuntimeOrigin> for Module<T> {
     fn ensure_granular(asset_id: &AssetId, value: Balance) -> DispatchResult {
         let asset_details = Self::try_get_asset_details(&asset_id)?;
@@ -2631,6 +4451,34 @@ impl<T: Config> AssetFnTrait<T::AccountId, T::RuntimeOrigin> for Module<T> {
         Ok(Self::try_get_asset_details(&asset_id)?.total_supply)
     }
 
+    fn unvested_balance(portfolio_id: &PortfolioId, asset_id: &AssetId) -> Balance {
+        Self::unvested_balance(portfolio_id, asset_id)
+    }
+
+    fn portfolio_asset_balance(portfolio_id: &PortfolioId, asset_id: &AssetId) -> Balance {
+        Portfolio::<T>::portfolio_asset_balances(portfolio_id, asset_id)
+    }
+
+    fn ensure_transfers_not_frozen(asset_id: &AssetId) -> DispatchResult {
+        Self::ensure_transfers_not_frozen(asset_id)
+    }
+
+    fn move_acquisition_lots(
+        asset_id: &AssetId,
+        from_portfolio: &PortfolioId,
+        to_portfolio: &PortfolioId,
+        amount: Balance,
+    ) {
+        // The lots are bounded by `MaxAcquisitionLots`, their weight is part of the portfolio move weight.
+        let _ = Statistics::<T>::move_acquisition_lots(
+            *asset_id,
+            from_portfolio,
+            to_portfolio,
+            amount,
+            &mut WeightMeter::max_limit_no_minimum(),
+        );
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     fn register_unique_ticker(origin: T::RuntimeOrigin, ticker: Ticker) -> DispatchResult {
         Self::register_unique_ticker(origin, ticker)
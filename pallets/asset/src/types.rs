@@ -4,7 +4,10 @@ use sp_runtime::{Deserialize, Serialize};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
-use polymesh_primitives::asset::AssetType;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
+
+use polymesh_primitives::asset::{AssetId, AssetType};
 use polymesh_primitives::{Balance, IdentityId};
 
 /// Ownership status of a ticker/token.
@@ -87,3 +90,20 @@ impl TickerRegistrationStatus {
         self.charge_fee
     }
 }
+
+/// A pending recovery of the assets held by an identity that lost its keys.
+#[derive(Clone, Debug, Decode, Encode, TypeInfo, PartialEq, Eq)]
+pub struct IdentityRecovery<Moment> {
+    /// The [`IdentityId`] that lost its keys.
+    pub old_did: IdentityId,
+    /// The [`IdentityId`] receiving the assets.
+    pub new_did: IdentityId,
+    /// The CDD provider that initiated the recovery, or `None` if it was initiated by governance.
+    pub initiator: Option<IdentityId>,
+    /// The assets that still need to be confirmed by one of their agents, and the id of the authorization to confirm them.
+    pub pending_assets: BTreeMap<AssetId, u64>,
+    /// The assets that have been confirmed by one of their agents.
+    pub confirmed_assets: BTreeSet<AssetId>,
+    /// The recovery can only be executed after this moment. Until then the old identity can veto it.
+    pub execute_after: Moment,
+}
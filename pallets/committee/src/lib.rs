@@ -77,6 +77,7 @@ use polymesh_common_utilities::{
     identity::Config as IdentityConfig,
     MaybeBlock, SystematicIssuers, GC_DID,
 };
+use polymesh_primitives::dry_run::{dry_run, DryRunOutcome};
 use polymesh_primitives::{storage_migration_ver, IdentityId};
 use scale_info::TypeInfo;
 use sp_runtime::traits::Hash;
@@ -540,6 +541,17 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         }
     }
 
+    /// Dry-runs the execution of the pending `proposal` against the current state,
+    /// as if it was approved by the committee.
+    ///
+    /// The changes are rolled back, see [`dry_run`].
+    pub fn dry_run_proposal(
+        proposal: T::Hash,
+    ) -> Result<DryRunOutcome<<T as frame_system::Config>::RuntimeEvent>, DispatchError> {
+        let call = Self::proposal_of(&proposal).ok_or(Error::<T, I>::NoSuchProposal)?;
+        dry_run::<T, _>(call, RawOrigin::Endorsed(PhantomData).into())
+    }
+
     fn execute(did: Option<IdentityId>, proposal: <T as Config<I>>::Proposal, hash: T::Hash) {
         let origin = RawOrigin::Endorsed(PhantomData).into();
         let res = proposal.dispatch(origin).map_err(|e| e.error).map(drop);
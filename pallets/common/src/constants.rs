@@ -45,6 +45,9 @@ pub mod queue_priority {
 
     /// Queue priority for the settlement instruction execution.
     pub const SETTLEMENT_INSTRUCTION_EXECUTION_PRIORITY: Priority = 100;
+
+    /// Queue priority for releasing the assets of an expired hash locked instruction.
+    pub const SETTLEMENT_HASH_LOCK_EXPIRY_PRIORITY: Priority = 100;
 }
 
 // ERC1400 transfer status codes
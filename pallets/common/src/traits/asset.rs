@@ -24,12 +24,14 @@ use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::Vec;
 
 use polymesh_primitives::asset::{
-    AssetId, AssetName, AssetType, CustomAssetTypeId, FundingRoundName,
+    AssetId, AssetName, AssetOperations, AssetType, CustomAssetTypeId, FundingRoundName,
+    IdentityRecoveryId, TransferFee, VestingSchedule, VestingScheduleId,
 };
 use polymesh_primitives::asset_metadata::{
     AssetMetadataGlobalKey, AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataName,
     AssetMetadataSpec, AssetMetadataValue, AssetMetadataValueDetail,
 };
+use polymesh_primitives::settlement::VenueId;
 use polymesh_primitives::{
     AssetIdentifier, Balance, Document, DocumentId, IdentityId, PortfolioId, PortfolioUpdateReason,
     Ticker,
@@ -79,6 +81,15 @@ pub trait Config:
 
     /// Maximum number of mediators for an asset.
     type MaxAssetMediators: Get<u32>;
+
+    /// The period during which the primary key of an identity can veto the recovery of its assets.
+    type IdentityRecoveryVetoPeriod: Get<Self::Moment>;
+
+    /// Maximum number of assets in an identity recovery.
+    type MaxIdentityRecoveryAssets: Get<u32>;
+
+    /// Maximum number of vesting schedules of an asset in a portfolio.
+    type MaxVestingSchedules: Get<u32>;
 }
 
 decl_event! {
@@ -185,6 +196,68 @@ decl_event! {
         /// An identity has unlinked a ticker from an asset.
         /// Parameters: [`IdentityId`] of caller, unlinked [`Ticker`], the asset identifier [`AssetId`].
         TickerUnlinkedFromAsset(IdentityId, Ticker, AssetId),
+        /// The fee charged for transfers of an asset has been set.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the new [`TransferFee`].
+        TransferFeeSet(IdentityId, AssetId, TransferFee),
+        /// The fee charged for transfers of an asset has been removed.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset.
+        TransferFeeRemoved(IdentityId, AssetId),
+        /// The recovery of the assets of an identity that lost its keys has been initiated.
+        /// Parameters: [`IdentityId`] of the CDD provider (`None` for governance), [`IdentityRecoveryId`], old [`IdentityId`], new [`IdentityId`],
+        /// the assets being recovered and the moment after which the recovery can be executed.
+        IdentityRecoveryInitiated(Option<IdentityId>, IdentityRecoveryId, IdentityId, IdentityId, BTreeSet<AssetId>, Moment),
+        /// An agent of the asset has confirmed the identity recovery.
+        /// Parameters: [`IdentityId`] of the agent, [`IdentityRecoveryId`], [`AssetId`] of the confirmed asset.
+        IdentityRecoveryConfirmed(IdentityId, IdentityRecoveryId, AssetId),
+        /// The old identity has vetoed the recovery.
+        /// Parameters: old [`IdentityId`], [`IdentityRecoveryId`].
+        IdentityRecoveryVetoed(IdentityId, IdentityRecoveryId),
+        /// All assets have been moved from the old identity to the new identity.
+        /// Parameters: [`IdentityId`] of the caller, [`IdentityRecoveryId`], old [`IdentityId`], new [`IdentityId`].
+        IdentityRecoveryExecuted(IdentityId, IdentityRecoveryId, IdentityId, IdentityId),
+        /// Operations of an asset have been paused.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the paused [`AssetOperations`].
+        AssetOperationsPaused(IdentityId, AssetId, AssetOperations),
+        /// Operations of an asset have been unpaused.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the unpaused [`AssetOperations`].
+        AssetOperationsUnpaused(IdentityId, AssetId, AssetOperations),
+        /// A vesting schedule has been added to a portfolio.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the [`PortfolioId`] holding the units, the [`VestingSchedule`].
+        VestingScheduleAdded(IdentityId, AssetId, PortfolioId, VestingSchedule<Moment>),
+        /// Vested units have been claimed.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the [`PortfolioId`] holding the units, the claimed amount.
+        VestedAssetsClaimed(IdentityId, AssetId, PortfolioId, Balance),
+        /// A vesting schedule has been revoked and its unvested units moved to a treasury portfolio.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the [`PortfolioId`] holding the units,
+        /// the [`VestingScheduleId`], the treasury [`PortfolioId`], the revoked amount.
+        VestingScheduleRevoked(IdentityId, AssetId, PortfolioId, VestingScheduleId, PortfolioId, Balance),
+        /// An allowance over the units of an asset held in a portfolio has been set.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, the [`PortfolioId`] holding the units,
+        /// [`IdentityId`] of the spender, the allowed amount, the optional expiry.
+        AllowanceApproved(IdentityId, AssetId, PortfolioId, IdentityId, Balance, Option<Moment>),
+        /// An allowance has been used to move units of an asset.
+        /// Parameters: [`IdentityId`] of the spender, [`AssetId`] of the asset, the sender [`PortfolioId`],
+        /// the receiver [`PortfolioId`], the moved amount, the remaining allowance.
+        AllowanceUsed(IdentityId, AssetId, PortfolioId, PortfolioId, Balance, Balance),
+        /// An asset has been approved to pay transaction fees, or its approval withdrawn.
+        /// Parameters: [`AssetId`] of the asset, the amount of the asset worth one POLYX, if approved.
+        FeeAssetRateSet(AssetId, Option<Balance>),
+        /// The portfolio collecting transaction fees paid in assets has been set.
+        /// Parameters: the [`PortfolioId`] of the fee collector.
+        FeeCollectorSet(PortfolioId),
+        /// An identity has chosen the asset it pays transaction fees in.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, or `None` for POLYX.
+        FeeAssetSet(IdentityId, Option<AssetId>),
+        /// The settlement of an asset through a venue has been paused.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, [`VenueId`] of the venue.
+        VenueSettlementPaused(IdentityId, AssetId, VenueId),
+        /// The settlement of an asset through a venue has been unpaused.
+        /// Parameters: [`IdentityId`] of caller, [`AssetId`] of the asset, [`VenueId`] of the venue.
+        VenueSettlementUnpaused(IdentityId, AssetId, VenueId),
+        /// The transfer fee of a `transfer_from` has been paid by the spender.
+        /// Parameters: [`IdentityId`] of the spender, [`AssetId`] of the transferred asset, the paying [`PortfolioId`],
+        /// the beneficiary [`PortfolioId`], [`AssetId`] the fee was paid in, the fee amount.
+        TransferFromFeeCharged(IdentityId, AssetId, PortfolioId, PortfolioId, AssetId, Balance),
     }
 }
 
@@ -222,6 +295,38 @@ pub trait WeightInfo {
     fn remove_mandatory_mediators(n: u32) -> Weight;
     fn link_ticker_to_asset_id() -> Weight;
     fn unlink_ticker_from_asset_id() -> Weight;
+    fn set_transfer_fee() -> Weight;
+    fn remove_transfer_fee() -> Weight;
+    fn initiate_identity_recovery(n: u32) -> Weight;
+    fn confirm_identity_recovery() -> Weight;
+    fn veto_identity_recovery(n: u32) -> Weight;
+    fn execute_identity_recovery(a: u32, p: u32, l: u32, n: u32) -> Weight;
+    fn pause_asset_operations() -> Weight;
+    fn unpause_asset_operations() -> Weight;
+    fn add_vesting_schedule(n: u32) -> Weight;
+    fn claim_vested_assets(n: u32) -> Weight;
+    fn revoke_vesting_schedule(n: u32) -> Weight;
+    fn approve() -> Weight;
+    fn transfer_from() -> Weight;
+    fn set_fee_asset_rate() -> Weight;
+    fn set_fee_collector() -> Weight;
+    fn set_fee_asset() -> Weight;
+    fn pause_venue_settlement() -> Weight;
+    fn unpause_venue_settlement() -> Weight;
+
+    fn add_vesting_schedule_weight_limit(weight_limit: &Option<Weight>, n: u32) -> Weight {
+        if let Some(weight_limit) = weight_limit {
+            return *weight_limit;
+        }
+        Self::add_vesting_schedule(n)
+    }
+
+    fn transfer_from_weight_limit(weight_limit: &Option<Weight>) -> Weight {
+        if let Some(weight_limit) = weight_limit {
+            return *weight_limit;
+        }
+        Self::transfer_from()
+    }
 }
 
 pub trait AssetFnTrait<Account, Origin> {
@@ -240,6 +345,24 @@ pub trait AssetFnTrait<Account, Origin> {
     /// Returns the total supply for the given `asset_id`.
     fn asset_total_supply(asset_id: &AssetId) -> Result<Balance, DispatchError>;
 
+    /// Returns the amount of `asset_id` held in `portfolio_id` that can't be transferred due to vesting.
+    fn unvested_balance(portfolio_id: &PortfolioId, asset_id: &AssetId) -> Balance;
+
+    /// Returns the amount of `asset_id` held in `portfolio_id`.
+    fn portfolio_asset_balance(portfolio_id: &PortfolioId, asset_id: &AssetId) -> Balance;
+
+    /// Returns `Ok` if the transfers of `asset_id` are not frozen at the current moment.
+    fn ensure_transfers_not_frozen(asset_id: &AssetId) -> DispatchResult;
+
+    /// Moves the acquisition lots of `amount` units of `asset_id` between two portfolios of the same identity.
+    /// Must be called after the portfolio balances have been updated.
+    fn move_acquisition_lots(
+        asset_id: &AssetId,
+        from_portfolio: &PortfolioId,
+        to_portfolio: &PortfolioId,
+        amount: Balance,
+    );
+
     #[cfg(feature = "runtime-benchmarks")]
     fn register_unique_ticker(origin: Origin, ticker: Ticker) -> DispatchResult;
 
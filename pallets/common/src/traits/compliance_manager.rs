@@ -89,6 +89,9 @@ pub trait ComplianceFnConfig {
         weight_meter: &mut WeightMeter,
     ) -> Result<bool, DispatchError>;
 
+    /// Returns `true` if the asset has compliance requirements that are not paused.
+    fn has_requirements(asset_id: &AssetId) -> bool;
+
     fn verify_restriction_granular(
         asset_id: &AssetId,
         from_did_opt: Option<IdentityId>,
@@ -26,8 +26,8 @@ use polymesh_primitives::identity::limits::{
     MAX_ASSETS, MAX_EXTRINSICS, MAX_PALLETS, MAX_PORTFOLIOS,
 };
 use polymesh_primitives::{
-    secondary_key::SecondaryKey, AuthorizationData, Balance, CustomClaimTypeId, IdentityClaim,
-    IdentityId, Permissions, Ticker,
+    secondary_key::SecondaryKey, AuthorizationData, Balance, CustomClaimTypeId,
+    CustomClaimValueType, IdentityClaim, IdentityId, Permissions, Ticker,
 };
 
 use crate::traits::group::GroupTrait;
@@ -129,6 +129,22 @@ pub trait WeightInfo {
     fn add_secondary_keys_with_authorization(n: u32) -> Weight;
     fn revoke_claim_by_index() -> Weight;
     fn register_custom_claim_type(n: u32) -> Weight;
+    fn register_custom_claim_type_with_value(n: u32, v: u32) -> Weight;
+
+    /// Weight of `register_custom_claim_type_with_value`,
+    /// where `v` is the total length of the enum values, if any.
+    fn register_custom_claim_type_with_value_full(
+        ty: &[u8],
+        value_type: &CustomClaimValueType,
+    ) -> Weight {
+        let values_len = match value_type {
+            CustomClaimValueType::Enum(values) => values
+                .iter()
+                .fold(0usize, |len, value| len.saturating_add(value.len())),
+            _ => 0,
+        };
+        Self::register_custom_claim_type_with_value(ty.len() as u32, values_len as u32)
+    }
 
     /// Add complexity cost of Permissions to `add_secondary_keys_with_authorization` extrinsic.
     fn add_secondary_keys_full<AccountId>(
@@ -155,6 +171,9 @@ pub trait WeightInfo {
     fn add_authorization_full<AccountId>(data: &AuthorizationData<AccountId>) -> Weight {
         let perm_cost = match data {
             AuthorizationData::JoinIdentity(perms) => Self::permissions_cost_perms(perms),
+            AuthorizationData::AddRelayerPayingKey(.., Some(scope)) => {
+                Self::permissions_cost_perms(&scope.permissions())
+            }
             _ => Weight::zero(),
         };
         perm_cost.saturating_add(Self::add_authorization())
@@ -323,6 +342,11 @@ decl_event!(
         /// (DID, id, Type)
         CustomClaimTypeAdded(IdentityId, CustomClaimTypeId, Vec<u8>),
 
+        /// The value type of a CustomClaimType was set.
+        ///
+        /// (DID, id, value type)
+        CustomClaimValueTypeSet(IdentityId, CustomClaimTypeId, CustomClaimValueType),
+
         /// Child identity created.
         ///
         /// (Parent DID, Child DID, primary key)
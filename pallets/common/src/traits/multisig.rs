@@ -41,6 +41,15 @@ pub trait WeightInfo {
     fn approve_join_identity() -> Weight;
     fn join_identity() -> Weight;
     fn remove_admin() -> Weight;
+    fn set_signer_weights(signers: u32) -> Weight;
+    fn set_signer_weights_via_admin(signers: u32) -> Weight;
+    fn set_call_policies(policies: u32) -> Weight;
+    fn set_call_policies_via_admin(policies: u32) -> Weight;
+    fn create_scheduled_proposal() -> Weight;
+    fn amend_proposal() -> Weight;
+    fn cancel_proposal() -> Weight;
+    fn veto() -> Weight;
+    fn execute_scheduled_proposal() -> Weight;
 
     fn default_max_weight(max_weight: &Option<Weight>) -> Weight {
         max_weight.unwrap_or_else(|| {
@@ -1,17 +1,19 @@
 #[cfg(feature = "runtime-benchmarks")]
-use frame_support::dispatch::DispatchResult;
-#[cfg(feature = "runtime-benchmarks")]
 use polymesh_primitives::asset::NonFungibleType;
 #[cfg(feature = "runtime-benchmarks")]
 use polymesh_primitives::nft::NFTCollectionKeys;
+#[cfg(feature = "runtime-benchmarks")]
+use polymesh_primitives::PortfolioKind;
 
 use frame_support::decl_event;
+use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::asset_metadata::AssetMetadataKey;
 use polymesh_primitives::nft::{NFTCollectionId, NFTs};
 use polymesh_primitives::{IdentityId, NFTId, PortfolioId, PortfolioUpdateReason};
+use sp_std::vec::Vec;
 
 use crate::compliance_manager::ComplianceFnConfig;
 use crate::{asset, base, identity, portfolio};
@@ -60,6 +62,14 @@ pub trait NFTTrait<Origin> {
     fn is_collection_key(asset_id: &AssetId, metadata_key: &AssetMetadataKey) -> bool;
     /// Updates the NFTOwner storage after moving funds.
     fn move_portfolio_owner(asset_id: AssetId, nft_id: NFTId, new_owner_portfolio: PortfolioId);
+    /// Moves the `nft_ids` of `asset_id` from `source_portfolio` to `destination_portfolio`, following the rules of a controller transfer.
+    fn controller_transfer_nfts(
+        asset_id: AssetId,
+        nft_ids: Vec<NFTId>,
+        source_portfolio: PortfolioId,
+        destination_portfolio: PortfolioId,
+        caller_did: IdentityId,
+    ) -> DispatchResult;
 
     #[cfg(feature = "runtime-benchmarks")]
     fn create_nft_collection(
@@ -68,4 +78,11 @@ pub trait NFTTrait<Origin> {
         nft_type: Option<NonFungibleType>,
         collection_keys: NFTCollectionKeys,
     ) -> DispatchResult;
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn issue_nft(
+        origin: Origin,
+        asset_id: AssetId,
+        portfolio_kind: PortfolioKind,
+    ) -> DispatchResult;
 }
@@ -24,8 +24,8 @@ use frame_support::pallet_prelude::Get;
 use frame_support::weights::Weight;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::{
-    Balance, Fund, FundDescription, IdentityId, Memo, NFTId, PortfolioId, PortfolioName,
-    PortfolioNumber, SecondaryKey,
+    Balance, Fund, FundDescription, IdentityId, Memo, NFTId, PortfolioId, PortfolioLockId,
+    PortfolioLockReason, PortfolioName, PortfolioNumber, SecondaryKey,
 };
 use sp_std::vec::Vec;
 
@@ -115,6 +115,8 @@ pub trait WeightInfo {
     fn allow_identity_to_create_portfolios() -> Weight;
     fn revoke_create_portfolios_permission() -> Weight;
     fn create_custody_portfolio() -> Weight;
+    fn lock_portfolio_assets() -> Weight;
+    fn release_portfolio_lock() -> Weight;
 }
 
 pub trait Config: CommonConfig + identity::Config + base::Config {
@@ -201,6 +203,47 @@ decl_event! {
             IdentityId,
             PortfolioId,
             AssetId
+        ),
+        /// Assets have been locked in a portfolio.
+        ///
+        /// # Parameters
+        /// * [`IdentityId`] of the caller.
+        /// * [`PortfolioId`] where the assets are locked.
+        /// * [`PortfolioLockId`] of the new lock.
+        /// * [`AssetId`] of the locked asset.
+        /// * The locked amount.
+        /// * [`PortfolioLockReason`] of the lock.
+        PortfolioAssetsLocked(
+            IdentityId,
+            PortfolioId,
+            PortfolioLockId,
+            AssetId,
+            Balance,
+            PortfolioLockReason
+        ),
+        /// A portfolio lock has been released.
+        ///
+        /// # Parameters
+        /// * [`IdentityId`] of the caller.
+        /// * [`PortfolioId`] where the assets were locked.
+        /// * [`PortfolioLockId`] of the released lock.
+        PortfolioLockReleased(
+            IdentityId,
+            PortfolioId,
+            PortfolioLockId
+        ),
+        /// A portfolio lock has been moved to another portfolio, together with the assets it locks.
+        ///
+        /// # Parameters
+        /// * [`IdentityId`] of the caller.
+        /// * [`PortfolioId`] where the assets were locked.
+        /// * [`PortfolioId`] where the assets are now locked.
+        /// * [`PortfolioLockId`] of the moved lock.
+        PortfolioLockMoved(
+            IdentityId,
+            PortfolioId,
+            PortfolioId,
+            PortfolioLockId
         )
     }
 }
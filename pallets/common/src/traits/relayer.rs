@@ -1,7 +1,9 @@
 use crate::{traits::identity, CommonConfig};
 use frame_support::{decl_event, weights::Weight};
-use polymesh_primitives::{Balance, EventDid};
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::{Balance, EventDid, SubsidyScope};
 use sp_runtime::transaction_validity::InvalidTransaction;
+use sp_std::vec::Vec;
 
 pub trait WeightInfo {
     fn set_paying_key() -> Weight;
@@ -26,11 +28,32 @@ pub trait SubsidiserTrait<AccountId> {
     ) -> Result<Option<AccountId>, InvalidTransaction>;
 }
 
+/// Inspects the calls paid by a relayer subsidy, to check them against the scope of the subsidy.
+pub trait SubsidyCallInspector<Call> {
+    /// Returns the assets `call` operates on, or `None` if the call doesn't operate on assets.
+    fn call_assets(call: &Call) -> Option<Vec<AssetId>>;
+
+    /// Returns the calls dispatched by `call` (e.g. the calls of a batch), which must also be
+    /// covered by the scope of the subsidy.
+    fn nested_calls(_call: &Call) -> Vec<&Call> {
+        Vec::new()
+    }
+}
+
+impl<Call> SubsidyCallInspector<Call> for () {
+    fn call_assets(_call: &Call) -> Option<Vec<AssetId>> {
+        None
+    }
+}
+
 pub trait Config: CommonConfig + identity::Config {
     /// The overarching event type.
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
 
     type WeightInfo: WeightInfo;
+
+    /// Inspects calls to check them against the scope of a subsidy.
+    type CallInspector: SubsidyCallInspector<<Self as frame_system::Config>::RuntimeCall>;
 }
 
 decl_event! {
@@ -57,5 +80,10 @@ decl_event! {
         ///
         /// (Caller DID, User Key, Paying Key, POLYX limit, old remaining POLYX)
         UpdatedPolyxLimit(EventDid, AccountId, AccountId, Balance, Balance),
+
+        /// The accepted subsidy is scoped.
+        ///
+        /// (Caller DID, User Key, Paying Key, Scope)
+        SubsidyScoped(EventDid, AccountId, AccountId, SubsidyScope),
     }
 }
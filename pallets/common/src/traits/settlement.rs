@@ -1,15 +1,16 @@
 use frame_support::decl_event;
 use frame_support::dispatch::DispatchError;
 use frame_support::weights::Weight;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::settlement::{
-    AffirmationCount, AssetCount, InstructionId, Leg, LegId, ReceiptMetadata, SettlementType,
-    VenueDetails, VenueId, VenueType,
+    AffirmationCount, AssetCount, HashLock, InstructionId, Leg, LegId, LegTransferFee, NetTransfer,
+    ReceiptMetadata, SettlementType, VenueDetails, VenueId, VenueType,
 };
-use polymesh_primitives::{IdentityId, Memo, PortfolioId};
+use polymesh_primitives::{Balance, IdentityId, Memo, PortfolioId};
 
 decl_event!(
     pub enum Event<T>
@@ -86,6 +87,36 @@ decl_event!(
         /// An instruction with mediators has been created.
         /// Parameters: [`InstructionId`] of the instruction and the [`IdentityId`] of all mediators.
         InstructionMediators(InstructionId, BTreeSet<IdentityId>),
+        /// A hash lock has been set for an instruction.
+        /// Parameters: [`InstructionId`] of the instruction and its [`HashLock`].
+        InstructionHashLocked(InstructionId, HashLock<BlockNumber>),
+        /// The preimage of an instruction's hash lock has been revealed and its hash locked legs were executed.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction and the preimage.
+        HashLockPreimageRevealed(IdentityId, InstructionId, Vec<u8>),
+        /// The hash lock of an instruction has expired and all locked assets have been released.
+        /// Parameters: [`InstructionId`] of the instruction.
+        HashLockExpired(InstructionId),
+        /// The amount of some legs of an instruction have been amended.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction, the new revision number and the new amount for each amended leg.
+        InstructionAmended(IdentityId, InstructionId, u32, BTreeMap<LegId, Balance>),
+        /// Part of a fungible leg has been executed, the remaining amount is still pending.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction, [`LegId`] of the leg, the executed amount and the remaining amount.
+        LegPartiallyExecuted(IdentityId, InstructionId, LegId, Balance, Balance),
+        /// The fungible legs of multiple instructions of a venue have been netted and all instructions were executed.
+        /// Parameters: [`IdentityId`] of the caller, [`VenueId`] of the venue and the [`InstructionId`] of each executed instruction.
+        InstructionsNetted(IdentityId, VenueId, Vec<InstructionId>),
+        /// The transfer fee of a leg has been paid to the beneficiary of the asset's fee.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction and the charged [`LegTransferFee`].
+        TransferFeeCharged(IdentityId, InstructionId, LegTransferFee),
+        /// A hash locked leg has been executed by revealing the preimage of the instruction's hash lock.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction and the [`LegId`] of the executed leg.
+        HashLockedLegExecuted(IdentityId, InstructionId, LegId),
+        /// A net transfer settling the fungible legs of netted instructions has been executed.
+        /// Parameters: [`IdentityId`] of the caller, [`VenueId`] of the venue and the executed [`NetTransfer`].
+        NetTransferExecuted(IdentityId, VenueId, NetTransfer),
+        /// A fungible leg has been settled through the net transfers of its venue, rather than transferred as is.
+        /// Parameters: [`IdentityId`] of the caller, [`InstructionId`] of the instruction and the [`LegId`] of the settled leg.
+        FungibleLegNetted(IdentityId, InstructionId, LegId),
     }
 );
 
@@ -115,6 +146,12 @@ pub trait WeightInfo {
     fn affirm_instruction_as_mediator() -> Weight;
     fn withdraw_affirmation_as_mediator() -> Weight;
     fn reject_instruction_as_mediator(f: u32, n: u32, o: u32) -> Weight;
+    fn add_instruction_with_hash_lock(f: u32, n: u32, o: u32) -> Weight;
+    fn execute_with_preimage(f: u32, n: u32, o: u32) -> Weight;
+    fn expire_hash_lock(f: u32, n: u32, o: u32) -> Weight;
+    fn amend_instruction(f: u32, n: u32, o: u32) -> Weight;
+    fn partially_execute_instruction(f: u32) -> Weight;
+    fn execute_netted_instructions(i: u32, f: u32) -> Weight;
 
     fn add_and_affirm_with_mediators_legs(
         legs: &[Leg],
@@ -136,6 +173,27 @@ pub trait WeightInfo {
         let (f, n, o) = Self::get_transfer_by_asset(legs, portfolios);
         Self::add_and_affirm_instruction(f, n, o)
     }
+    fn add_instruction_with_hash_lock_legs(legs: &[Leg]) -> Weight {
+        let (f, n, o) = Self::get_transfer_by_asset(legs, 0);
+        Self::add_instruction_with_hash_lock(f, n, o)
+    }
+    fn execute_with_preimage_weight_limit(
+        weight_limit: &Option<Weight>,
+        f: &u32,
+        n: &u32,
+        o: &u32,
+    ) -> Weight {
+        if let Some(weight_limit) = weight_limit {
+            return *weight_limit;
+        }
+        Self::execute_with_preimage(*f, *n, *o)
+    }
+    fn partially_execute_instruction_weight_limit(weight_limit: &Option<Weight>, f: u32) -> Weight {
+        if let Some(weight_limit) = weight_limit {
+            return *weight_limit;
+        }
+        Self::partially_execute_instruction(f)
+    }
     fn execute_manual_weight_limit(
         weight_limit: &Option<Weight>,
         f: &u32,
@@ -147,6 +205,23 @@ pub trait WeightInfo {
         }
         Self::execute_manual_instruction(*f, *n, *o)
     }
+    fn execute_netted_instructions_weight_limit(
+        weight_limit: &Option<Weight>,
+        n_instructions: u32,
+        number_of_assets: &AssetCount,
+    ) -> Weight {
+        if let Some(weight_limit) = weight_limit {
+            return *weight_limit;
+        }
+        // Non-fungible and off-chain legs are never netted
+        Self::execute_netted_instructions(n_instructions, number_of_assets.fungible())
+            .saturating_add(Self::execute_manual_instruction(
+                0,
+                number_of_assets.non_fungible(),
+                number_of_assets.off_chain(),
+            ))
+            .saturating_sub(Self::execute_manual_instruction(0, 0, 0))
+    }
     fn get_transfer_by_asset(legs: &[Leg], portfolios: u32) -> (u32, u32, u32) {
         let asset_count =
             AssetCount::try_from_legs(legs).unwrap_or(AssetCount::new(1024, 1024, 1024));
@@ -283,6 +358,16 @@ pub trait WeightInfo {
             }
         }
     }
+    fn amend_instruction_input(asset_count: Option<AssetCount>) -> Weight {
+        match asset_count {
+            Some(asset_count) => Self::amend_instruction(
+                asset_count.fungible(),
+                asset_count.non_fungible(),
+                asset_count.off_chain(),
+            ),
+            None => Self::amend_instruction(10, 100, 10),
+        }
+    }
     fn reject_instruction_input(asset_count: Option<AssetCount>, as_mediator: bool) -> Weight {
         match asset_count {
             Some(asset_count) => {
@@ -22,6 +22,12 @@ pub trait Config:
     type MaxStatsPerAsset: Get<u32>;
     /// Maximum transfer conditions that can be enabled for an Asset.
     type MaxTransferConditionsPerAsset: Get<u32>;
+    /// Length of each period of the volume stats, in milliseconds.
+    type VolumePeriodLength: Get<u64>;
+    /// Maximum number of periods in the rolling window of a volume transfer condition.
+    type MaxVolumePeriods: Get<u32>;
+    /// Maximum number of acquisition lots tracked for each investor.
+    type MaxAcquisitionLots: Get<u32>;
     /// Weights for extrinsics.
     type WeightInfo: WeightInfo;
 }
@@ -41,6 +47,10 @@ pub trait WeightInfo {
     fn update_asset_balance_stats(a: u32) -> Weight;
     fn active_asset_statistics_load(_a: u32) -> Weight;
     fn is_exempt() -> Weight;
+    fn volume_restriction() -> Weight;
+    fn update_asset_volume_stats() -> Weight;
+    fn holding_period_restriction() -> Weight;
+    fn update_acquisition_lots() -> Weight;
     fn verify_requirements(i: u32) -> Weight;
     fn verify_requirements_loop(i: u32) -> Weight {
         Self::verify_requirements(i)
@@ -1,4 +1,6 @@
 use frame_support::dispatch::DispatchInfo;
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::Balance;
 use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidity};
 
 // Polymesh note: This was specifically added for Polymesh
@@ -10,9 +12,35 @@ pub trait CddAndFeeDetails<AccountId, Call> {
     fn clear_context();
     fn set_payer_context(payer: Option<AccountId>);
     fn get_payer_from_context() -> Option<AccountId>;
+    /// Returns the maximum transaction fee the payer of `call` accepts to pay, if capped.
+    fn get_max_fee(call: &Call) -> Option<Balance>;
 }
 
 // Polymesh note: This was specifically added for Polymesh
 pub trait ChargeTxFee {
     fn charge_fee(len: u32, info: DispatchInfo) -> TransactionValidity;
 }
+
+// Polymesh note: This was specifically added for Polymesh
+/// Pays transaction fees in an approved asset instead of POLYX.
+pub trait AssetFeeHandler<AccountId> {
+    /// Returns the asset `payer` pays fees in, if they opted in,
+    /// together with its rate, i.e. the amount of the asset worth one POLYX.
+    fn fee_asset(payer: &AccountId) -> Option<(AssetId, Balance)>;
+    /// Returns the account of the fee collector, which pays in POLYX the fees paid to it in assets.
+    fn fee_collector_key() -> Option<AccountId>;
+    /// Moves `amount` of `asset_id` from the default portfolio of `payer` to the fee collector,
+    /// where it stays locked until the fee is refunded.
+    fn withdraw_fee(
+        payer: &AccountId,
+        asset_id: AssetId,
+        amount: Balance,
+    ) -> Result<(), InvalidTransaction>;
+    /// Unlocks the `paid` fee and moves `refund` of it from the fee collector back to the default portfolio of `payer`.
+    fn refund_fee(
+        payer: &AccountId,
+        asset_id: AssetId,
+        paid: Balance,
+        refund: Balance,
+    ) -> Result<(), InvalidTransaction>;
+}
@@ -92,11 +92,12 @@ pub use polymesh_common_utilities::traits::compliance_manager::{
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::compliance_manager::{
     AssetCompliance, AssetComplianceResult, ComplianceReport, ComplianceRequirement,
-    ConditionReport, ConditionResult, RequirementReport,
+    ConditionReport, ConditionResult, ExpressionReport, RequirementReport,
 };
 use polymesh_primitives::{
-    proposition, storage_migrate_on, storage_migration_ver, Claim, Condition, ConditionType,
-    Context, IdentityId, TargetIdentity, TrustedFor, TrustedIssuer, WeightMeter,
+    proposition, storage_migrate_on, storage_migration_ver, Claim, ClaimType, Condition,
+    ConditionExpression, ConditionType, Context, IdentityId, Scope, TargetIdentity, TrustedFor,
+    TrustedIssuer, WeightMeter,
 };
 
 type ExternalAgents<T> = pallet_external_agents::Module<T>;
@@ -130,7 +131,9 @@ decl_error! {
         /// The worst case scenario of the compliance requirement is too complex.
         ComplianceRequirementTooComplex,
         /// The maximum weight limit for executing the function was exceeded.
-        WeightLimitExceeded
+        WeightLimitExceeded,
+        /// A condition holding an expression has trusted issuers, which must be set on the conditions of the expression instead.
+        ExpressionWithTrustedIssuers
     }
 }
 
@@ -440,9 +443,17 @@ impl<T: Config> Module<T> {
         claim: &'a Claim,
         issuers: &'a [TrustedIssuer],
     ) -> impl 'a + Iterator<Item = Claim> {
-        let claim_type = claim.claim_type();
-        let scope = claim.as_scope();
+        Self::fetch_claims_by_key(target, claim.claim_type(), claim.as_scope(), issuers)
+    }
 
+    /// Fetches all claims of `target` identity with `claim_type`
+    /// and `scope` and generated by any of `issuers`.
+    fn fetch_claims_by_key<'a>(
+        target: IdentityId,
+        claim_type: ClaimType,
+        scope: Option<&'a Scope>,
+        issuers: &'a [TrustedIssuer],
+    ) -> impl 'a + Iterator<Item = Claim> {
         issuers
             .iter()
             .filter(move |issuer| issuer.is_trusted_for(claim_type))
@@ -477,9 +488,10 @@ impl<T: Config> Module<T> {
         weight_meter: &mut WeightMeter,
     ) -> Result<proposition::Context<impl 'a + Iterator<Item = Claim>>, DispatchError> {
         // Because of `-> impl Iterator`, we need to return a **single type** in each of the branches below.
-        // To do this, we use `Either<Either<MatchArm1, MatchArm2>, MatchArm3>`,
-        // equivalent to a 3-variant enum with iterators in each variant corresponding to the branches below.
-        // `Left(Left(arm1))`, `Left(Right(arm2))` and `Right(arm3)` correspond to arms 1, 2 and 3 respectively.
+        // To do this, we use `Either<Either<MatchArm1, MatchArm2>, Either<MatchArm3, MatchArm4>>`,
+        // equivalent to a 4-variant enum with iterators in each variant corresponding to the branches below.
+        // `Left(Left(arm1))`, `Left(Right(arm2))`, `Right(Left(arm3))` and `Right(Right(arm4))`
+        // correspond to arms 1, 2, 3 and 4 respectively.
         use either::Either::{Left, Right};
 
         let claims = match &condition.condition_type {
@@ -509,13 +521,36 @@ impl<T: Config> Module<T> {
                     Self::fetch_claims(id, claim, trusted_issuers)
                 })))
             }
+            ConditionType::ClaimValueGreaterThan(claim_type, scope, _)
+            | ConditionType::ClaimValueLessThan(claim_type, scope, _)
+            | ConditionType::ClaimValueInSet(claim_type, scope, _)
+            | ConditionType::ClaimValueGreaterOrEqual(claim_type, scope, _)
+            | ConditionType::ClaimValueLessOrEqual(claim_type, scope, _)
+            | ConditionType::ClaimValueEqual(claim_type, scope, _)
+            | ConditionType::ClaimValueNotEqual(claim_type, scope, _) => {
+                let trusted_issuers = Self::issuers_for(asset_id, condition, slot);
+                // Consumes the weight for this condition
+                Self::consume_weight_meter(
+                    weight_meter,
+                    <T as Config>::WeightInfo::is_condition_satisfied(
+                        trusted_issuers.len() as u32,
+                        condition.issuers.is_empty() as u32,
+                    ),
+                )?;
+                Right(Left(Self::fetch_claims_by_key(
+                    id,
+                    ClaimType::Custom(*claim_type),
+                    scope.as_ref(),
+                    trusted_issuers,
+                )))
+            }
             ConditionType::IsIdentity(TargetIdentity::ExternalAgent) => {
                 // Consumes the weight for this condition
                 Self::consume_weight_meter(
                     weight_meter,
                     <T as Config>::WeightInfo::is_identity_condition(1),
                 )?;
-                Right(core::iter::empty())
+                Right(Right(core::iter::empty()))
             }
             ConditionType::IsIdentity(TargetIdentity::Specific(_)) => {
                 // Consumes the weight for this condition
@@ -523,8 +558,10 @@ impl<T: Config> Module<T> {
                     weight_meter,
                     <T as Config>::WeightInfo::is_identity_condition(0),
                 )?;
-                Right(core::iter::empty())
+                Right(Right(core::iter::empty()))
             }
+            // The weight is consumed by each condition of the expression.
+            ConditionType::IsSatisfied(_) => Right(Right(core::iter::empty())),
         };
 
         Ok(proposition::Context { claims, id })
@@ -554,6 +591,11 @@ impl<T: Config> Module<T> {
         slot: &mut Option<Vec<TrustedIssuer>>,
         weight_meter: &mut WeightMeter,
     ) -> Result<bool, DispatchError> {
+        if let ConditionType::IsSatisfied(expression) = &condition.condition_type {
+            return proposition::run_expression(expression, &mut |condition| {
+                Self::is_condition_satisfied(asset_id, did, condition, slot, weight_meter)
+            });
+        }
         let context = Self::fetch_context(did, asset_id, slot, &condition, weight_meter)?;
         let any_ea = |ctx: Context<_>| ExternalAgents::<T>::agents(asset_id, ctx.id).is_some();
         Ok(proposition::run(&condition, context, any_ea))
@@ -641,9 +683,17 @@ impl<T: Config> Module<T> {
     fn ensure_custom_scopes_limited<'a>(
         condition: impl Iterator<Item = &'a Condition>,
     ) -> DispatchResult {
-        condition
-            .flat_map(|c| c.claims())
-            .try_for_each(Identity::<T>::ensure_custom_scopes_limited)
+        condition.flat_map(Condition::flatten).try_for_each(|c| {
+            c.claims()
+                .try_for_each(Identity::<T>::ensure_custom_scopes_limited)?;
+            // Claim value conditions have a scope, but no claim.
+            match c.condition_type.claim_value_key() {
+                Some((id, scope)) => {
+                    Identity::<T>::ensure_custom_scopes_limited(&Claim::Custom(id, scope.cloned()))
+                }
+                None => Ok(()),
+            }
+        })
     }
 
     fn dedup_and_ensure_requirement_limited(req: &mut ComplianceRequirement) -> DispatchResult {
@@ -655,12 +705,18 @@ impl<T: Config> Module<T> {
     }
 
     fn ensure_issuers_in_req_limited(req: &ComplianceRequirement) -> DispatchResult {
-        req.conditions().try_for_each(|cond| {
-            ensure_length_ok::<T>(cond.issuers.len())?;
-            cond.issuers
-                .iter()
-                .try_for_each(Self::ensure_issuer_limited)
-        })
+        req.conditions()
+            .flat_map(Condition::flatten)
+            .try_for_each(|cond| {
+                ensure!(
+                    !cond.is_expression() || cond.issuers.is_empty(),
+                    Error::<T>::ExpressionWithTrustedIssuers
+                );
+                ensure_length_ok::<T>(cond.issuers.len())?;
+                cond.issuers
+                    .iter()
+                    .try_for_each(Self::ensure_issuer_limited)
+            })
     }
 
     fn ensure_issuer_limited(issuer: &TrustedIssuer) -> DispatchResult {
@@ -730,6 +786,11 @@ impl<T: Config> ComplianceFnConfig for Module<T> {
         )
     }
 
+    fn has_requirements(asset_id: &AssetId) -> bool {
+        let asset_compliance = Self::asset_compliance(asset_id);
+        !asset_compliance.paused && !asset_compliance.requirements.is_empty()
+    }
+
     /// verifies all requirements and returns the result in an array of booleans.
     /// this does not care if the requirements are paused or not. It is meant to be
     /// called only in failure conditions
@@ -842,16 +903,82 @@ impl<T: Config> Module<T> {
     ) -> Result<Vec<ConditionReport>, DispatchError> {
         let mut conditions_report = Vec::new();
         for condition in conditions {
-            let is_condition_satisfied = Self::is_condition_satisfied(
-                asset_id,
-                identity,
-                &condition,
-                &mut None,
-                weight_meter,
-            )?;
-            conditions_report.push(ConditionReport::new(condition, is_condition_satisfied));
-            *requirement_satisfied = *requirement_satisfied && is_condition_satisfied;
+            let condition_report =
+                Self::get_condition_report(asset_id, identity, condition, weight_meter)?;
+            *requirement_satisfied = *requirement_satisfied && condition_report.satisfied;
+            conditions_report.push(condition_report);
         }
         Ok(conditions_report)
     }
+
+    /// Returns the [`ConditionReport`] for the given `condition`.
+    /// Expressions are reported node by node, evaluating all of their conditions.
+    fn get_condition_report(
+        asset_id: &AssetId,
+        identity: IdentityId,
+        condition: Condition,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<ConditionReport, DispatchError> {
+        if let ConditionType::IsSatisfied(expression) = &condition.condition_type {
+            let expression_report =
+                Self::get_expression_report(asset_id, identity, expression, weight_meter)?;
+            return Ok(ConditionReport::with_expression(
+                condition,
+                expression_report,
+            ));
+        }
+        let is_condition_satisfied =
+            Self::is_condition_satisfied(asset_id, identity, &condition, &mut None, weight_meter)?;
+        Ok(ConditionReport::new(condition, is_condition_satisfied))
+    }
+
+    /// Returns the [`ExpressionReport`] for the given `expression`.
+    fn get_expression_report(
+        asset_id: &AssetId,
+        identity: IdentityId,
+        expression: &ConditionExpression,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<ExpressionReport, DispatchError> {
+        let report = match expression {
+            ConditionExpression::Condition(condition) => {
+                ExpressionReport::Condition(Box::new(Self::get_condition_report(
+                    asset_id,
+                    identity,
+                    (**condition).clone(),
+                    weight_meter,
+                )?))
+            }
+            ConditionExpression::And(expressions) => {
+                let reports =
+                    Self::get_expressions_report(asset_id, identity, expressions, weight_meter)?;
+                ExpressionReport::And(reports.iter().all(|r| r.is_satisfied()), reports)
+            }
+            ConditionExpression::Or(expressions) => {
+                let reports =
+                    Self::get_expressions_report(asset_id, identity, expressions, weight_meter)?;
+                ExpressionReport::Or(reports.iter().any(|r| r.is_satisfied()), reports)
+            }
+            ConditionExpression::Not(expression) => {
+                let report =
+                    Self::get_expression_report(asset_id, identity, expression, weight_meter)?;
+                ExpressionReport::Not(!report.is_satisfied(), Box::new(report))
+            }
+        };
+        Ok(report)
+    }
+
+    /// Returns the [`ExpressionReport`] for each of the given `expressions`.
+    fn get_expressions_report(
+        asset_id: &AssetId,
+        identity: IdentityId,
+        expressions: &[ConditionExpression],
+        weight_meter: &mut WeightMeter,
+    ) -> Result<Vec<ExpressionReport>, DispatchError> {
+        expressions
+            .iter()
+            .map(|expression| {
+                Self::get_expression_report(asset_id, identity, expression, weight_meter)
+            })
+            .collect()
+    }
 }
@@ -595,7 +595,10 @@ impl<T: Config> Module<T> {
     }
 
     // Compute `balance * per_share`, i.e. DID's benefit.
-    fn benefit_of(balance: Balance, per_share: Balance) -> Result<Balance, DispatchError> {
+    pub(crate) fn benefit_of(
+        balance: Balance,
+        per_share: Balance,
+    ) -> Result<Balance, DispatchError> {
         balance
             .checked_mul(per_share)
             // `per_share` was entered as a multiple of 1_000_000.
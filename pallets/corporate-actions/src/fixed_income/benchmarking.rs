@@ -0,0 +1,223 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+
+use pallet_compliance_manager::Module as ComplianceManager;
+use polymesh_common_utilities::asset::WeightInfo as _;
+use polymesh_common_utilities::benchs::{user, AccountIdOf, User};
+use polymesh_common_utilities::TestUtilsFn;
+use polymesh_primitives::asset::AssetName;
+use polymesh_primitives::calendar::{CalendarPeriod, CalendarUnit};
+use polymesh_primitives::{PortfolioKind, PortfolioLockReason, PortfolioName};
+
+use super::*;
+use crate::benchmarking::SEED;
+
+const FUNDING: PortfolioNumber = PortfolioNumber(1);
+const MAX_LOCKS: u32 = 50;
+
+fn create<T: Config + pallet_compliance_manager::Config>(
+    owner: &User<T>,
+    name: &[u8],
+    asset_type: AssetType,
+    kind: PortfolioKind,
+) -> AssetId {
+    let asset_id = <Asset<T>>::generate_asset_id(owner.account(), false);
+    <Asset<T>>::create_asset(
+        owner.origin().into(),
+        AssetName(name.to_vec()),
+        true,
+        asset_type,
+        vec![],
+        None,
+    )
+    .unwrap();
+    <Asset<T>>::issue(owner.origin().into(), asset_id, 1_000_000u32.into(), kind).unwrap();
+    <ComplianceManager<T>>::add_compliance_requirement(
+        owner.origin().into(),
+        asset_id,
+        vec![],
+        vec![],
+    )
+    .unwrap();
+    asset_id
+}
+
+fn terms(currency: AssetId, coupons: u32) -> FixedIncomeTerms {
+    FixedIncomeTerms {
+        portfolio: Some(FUNDING),
+        currency,
+        coupon_schedule: CheckpointSchedule {
+            start: 2000,
+            period: CalendarPeriod {
+                unit: CalendarUnit::Second,
+                amount: 1,
+            },
+        },
+        coupons,
+        coupon_per_share: 1,
+        coupon_payment_delay: 0,
+        coupon_claim_period: None,
+        maturity: 2000 + u64::from(MAX_COUPONS) * 1000,
+        principal_per_share: 1_000,
+    }
+}
+
+fn setup<T: Config + pallet_compliance_manager::Config + TestUtilsFn<AccountIdOf<T>>>(
+) -> (User<T>, AssetId, AssetId) {
+    <pallet_timestamp::Now<T>>::set(1000u32.into());
+    <Checkpoint<T>>::set_schedules_max_complexity(RawOrigin::Root.into(), MAX_COUPONS.into())
+        .unwrap();
+
+    let owner = user("owner", SEED);
+    <Portfolio<T>>::create_portfolio(owner.origin().into(), PortfolioName(b"Funding".to_vec()))
+        .unwrap();
+    let bond = create::<T>(
+        &owner,
+        b"Bond",
+        AssetType::FixedIncome,
+        PortfolioKind::Default,
+    );
+    let currency = create::<T>(
+        &owner,
+        b"Currency",
+        AssetType::default(),
+        PortfolioKind::User(FUNDING),
+    );
+    (owner, bond, currency)
+}
+
+fn fixed_income<T: Config + pallet_compliance_manager::Config + TestUtilsFn<AccountIdOf<T>>>(
+    coupons: u32,
+) -> (User<T>, AssetId) {
+    let (owner, bond, currency) = setup::<T>();
+    <Module<T>>::create_fixed_income(owner.origin().into(), bond, terms(currency, coupons))
+        .unwrap();
+    (owner, bond)
+}
+
+fn matured<T: Config + pallet_compliance_manager::Config + TestUtilsFn<AccountIdOf<T>>>(
+) -> (User<T>, User<T>, AssetId) {
+    let (owner, bond) = fixed_income::<T>(0);
+
+    // Principal is transferred to holders other than the issuer.
+    let holder = user::<T>("holder", SEED);
+    <Asset<T>>::base_transfer(
+        PortfolioId::default_portfolio(owner.did()),
+        PortfolioId::default_portfolio(holder.did()),
+        bond,
+        1_000_000u32.into(),
+        None,
+        None,
+        owner.did(),
+        &mut WeightMeter::from_limit(
+            Weight::zero(),
+            <T as pallet_asset::Config>::WeightInfo::base_transfer(),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    <pallet_timestamp::Now<T>>::set((2000 + MAX_COUPONS * 1000).into());
+    <Module<T>>::mature(owner.origin().into(), bond).unwrap();
+    (owner, holder, bond)
+}
+
+benchmarks! {
+    where_clause { where
+        T: pallet_compliance_manager::Config,
+        T: TestUtilsFn<AccountIdOf<T>>,
+    }
+
+    create_fixed_income {
+        let c in 0..MAX_COUPONS;
+
+        let (owner, bond, currency) = setup::<T>();
+        let terms = terms(currency, c);
+    }: _(owner.origin(), bond, terms)
+    verify {
+        assert!(FixedIncomes::get(bond).is_some(), "fixed income not created");
+    }
+
+    distribute_coupon {
+        let (owner, bond) = fixed_income::<T>(1);
+        <pallet_timestamp::Now<T>>::set(3000u32.into());
+    }: _(owner.origin(), bond)
+    verify {
+        assert!(Coupons::get(bond, 0).is_some(), "coupon not distributed");
+    }
+
+    mature {
+        let (owner, bond) = fixed_income::<T>(0);
+        <pallet_timestamp::Now<T>>::set((2000 + MAX_COUPONS * 1000).into());
+    }: _(owner.origin(), bond)
+    verify {
+        assert!(FixedIncomes::get(bond).unwrap().redemption.is_some(), "not matured");
+    }
+
+    redeem {
+        let (_, holder, bond) = matured::<T>();
+    }: _(holder.origin(), bond, PortfolioKind::Default)
+    verify {
+        assert!(<Asset<T>>::total_supply(&bond).is_zero(), "not redeemed");
+    }
+
+    push_redemption {
+        let l in 0..MAX_LOCKS;
+
+        let (owner, holder, bond) = matured::<T>();
+        let portfolio = PortfolioId::default_portfolio(holder.did());
+        for _ in 0..l {
+            <Portfolio<T>>::lock_portfolio_assets(
+                holder.origin().into(),
+                portfolio,
+                bond,
+                1u32.into(),
+                PortfolioLockReason::Collateral,
+                None,
+                None,
+            )
+            .unwrap();
+        }
+    }: _(owner.origin(), bond, portfolio, l)
+    verify {
+        assert!(<Asset<T>>::total_supply(&bond).is_zero(), "not redeemed");
+    }
+
+    default_coupon {
+        let (owner, bond) = fixed_income::<T>(1);
+        <pallet_timestamp::Now<T>>::set(3000u32.into());
+        let funding = PortfolioId::user_portfolio(owner.did(), FUNDING);
+        let currency = FixedIncomes::get(bond).unwrap().terms.currency;
+        <Portfolio<T>>::unchecked_lock_tokens(
+            &funding,
+            &currency,
+            <Portfolio<T>>::portfolio_asset_balances(funding, currency),
+        );
+    }: _(owner.origin(), bond)
+    verify {
+        assert!(DefaultedCoupons::get(bond, 0).is_some(), "coupon not defaulted");
+    }
+
+    close_fixed_income {
+        let (owner, holder, bond) = matured::<T>();
+        <Module<T>>::redeem(holder.origin().into(), bond, PortfolioKind::Default).unwrap();
+    }: _(owner.origin(), bond)
+    verify {
+        assert!(FixedIncomes::get(bond).unwrap().closed, "not closed");
+    }
+}
@@ -0,0 +1,884 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Fixed Income Module
+//!
+//! The fixed income module provides the lifecycle of bonds and other fixed income assets,
+//! i.e., periodic coupon payments, maturity, and the redemption of principal.
+//!
+//! The process works by first attaching fixed income terms to an asset of type `FixedIncome`
+//! through `create_fixed_income`.
+//! The terms include a coupon schedule, from which a checkpoint schedule is created,
+//! so that a balance snapshot is taken at each coupon record date.
+//!
+//! Once a coupon record date has passed, anyone may call `distribute_coupon`,
+//! which initiates a corporate action (CA) of kind `PredictableBenefit`
+//! with the coupon's checkpoint as its record date,
+//! and attaches a capital distribution to it, funded by the designated portfolio of the issuer.
+//! From there on, holders claim coupons as they would for any other capital distribution.
+//!
+//! If the designated portfolio can't cover a coupon by its payment date,
+//! anyone may call `default_coupon`, which records the coupon as defaulted instead,
+//! so that the lifecycle can move on.
+//!
+//! After the maturity date, and once every coupon has been distributed or defaulted,
+//! anyone may call `mature`, which locks the principal owed for the outstanding units
+//! in the designated portfolio, and closes the issuance of the asset.
+//! Holders then `redeem` the free units of each of their portfolios, burning them and paying the principal for them.
+//! An agent may also push the redemption of all units of any portfolio, including those under portfolio locks
+//! and vesting schedules, which are released, so that no holder can keep the asset from being closed.
+//! Once no units remain, `close_fixed_income` unlocks what remains of the principal.
+//!
+//! ## Overview
+//!
+//! The module provides functions for:
+//!
+//! - Attaching fixed income terms to an asset.
+//! - Distributing coupons.
+//! - Defaulting on unfunded coupons.
+//! - Maturing a fixed income asset.
+//! - Redeeming units for their principal.
+//! - Closing a matured fixed income asset.
+//!
+//! ### Terminology
+//!
+//! - **Coupon:** A periodic payment, per unit held at the coupon's record date.
+//! - **Maturity:** The date after which units are redeemable for their principal.
+//! - **Principal:** The amount paid, per unit, when units are redeemed.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `create_fixed_income` attaches fixed income terms to an asset.
+//! - `distribute_coupon` creates a capital distribution for the next due coupon.
+//! - `default_coupon` records the next coupon as defaulted, if it couldn't be funded.
+//! - `mature` locks the principal for redemption after the maturity date.
+//! - `redeem` redeems the free units of a portfolio of the caller for their principal.
+//! - `push_redemption` redeems all units of any portfolio for their principal.
+//! - `close_fixed_income` unlocks the remaining principal once all units were redeemed.
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+use crate as ca;
+use ca::distribution::{self, Distribution};
+use ca::{CADetails, CAId, CAKind, Config, RecordDateSpec};
+use codec::{Decode, Encode};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::{DispatchError, DispatchResult},
+    ensure,
+    weights::Weight,
+};
+use pallet_asset::{self as asset, checkpoint};
+use pallet_identity::{self as identity, PermissionedCallOriginData};
+use polymesh_common_utilities::{
+    constants::currency::ONE_UNIT,
+    portfolio::PortfolioSubTrait,
+    traits::checkpoint::{ScheduleCheckpoints, ScheduleId},
+    with_transaction,
+};
+use polymesh_primitives::asset::{AssetId, AssetType, CheckpointId};
+use polymesh_primitives::calendar::CheckpointSchedule;
+use polymesh_primitives::{
+    Balance, EventDid, IdentityId, Moment, PortfolioId, PortfolioKind, PortfolioNumber, WeightMeter,
+};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::prelude::*;
+
+/// The maximum number of coupons of a fixed income asset.
+pub const MAX_COUPONS: u32 = 100;
+
+type Asset<T> = asset::Module<T>;
+type Checkpoint<T> = checkpoint::Module<T>;
+type ExternalAgents<T> = pallet_external_agents::Module<T>;
+type Identity<T> = identity::Module<T>;
+type CA<T> = ca::Module<T>;
+type Portfolio<T> = pallet_portfolio::Module<T>;
+
+/// The terms of a fixed income asset, as provided when they're attached to the asset.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct FixedIncomeTerms {
+    /// The portfolio of the agent funding coupons and principal.
+    pub portfolio: Option<PortfolioNumber>,
+    /// The asset in which coupons and principal are paid.
+    pub currency: AssetId,
+    /// The schedule of coupon record dates, starting with the first coupon.
+    pub coupon_schedule: CheckpointSchedule,
+    /// The number of coupons in the schedule.
+    pub coupons: u32,
+    /// The coupon per unit held at the record date, in per-million of a `currency` token.
+    pub coupon_per_share: Balance,
+    /// The time between a coupon's record date and its payment date.
+    pub coupon_payment_delay: Moment,
+    /// The time, if any, after a coupon's payment date during which it can be claimed.
+    pub coupon_claim_period: Option<Moment>,
+    /// The date after which units are redeemable for their principal.
+    pub maturity: Moment,
+    /// The principal per unit, in per-million of a `currency` token.
+    pub principal_per_share: Balance,
+}
+
+/// A fixed income asset, tracking the progress of its lifecycle.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct FixedIncome {
+    /// The agent which attached the terms, owning the funding portfolio.
+    pub agent: IdentityId,
+    /// The terms of the fixed income asset.
+    pub terms: FixedIncomeTerms,
+    /// The checkpoint schedule taking a balance snapshot at each coupon record date, if any coupons.
+    pub schedule_id: Option<ScheduleId>,
+    /// The number of coupons distributed so far.
+    pub coupons_distributed: u32,
+    /// The principal locked for redemption, once matured.
+    pub redemption: Option<Distribution>,
+    /// Whether the fixed income asset has been closed.
+    pub closed: bool,
+}
+
+impl FixedIncome {
+    /// Returns the portfolio funding coupons and principal.
+    pub fn funding_portfolio(&self) -> PortfolioId {
+        PortfolioId {
+            did: self.agent,
+            kind: self.terms.portfolio.into(),
+        }
+    }
+}
+
+/// Weight abstraction for the fixed income module.
+pub trait WeightInfo {
+    fn create_fixed_income(coupons: u32) -> Weight;
+    fn distribute_coupon() -> Weight;
+    fn mature() -> Weight;
+    fn redeem() -> Weight;
+    fn push_redemption(locks: u32) -> Weight;
+    fn close_fixed_income() -> Weight;
+    fn default_coupon() -> Weight;
+}
+
+decl_storage! {
+    trait Store for Module<T: Config> as FixedIncome {
+        /// All fixed income assets, with their terms and lifecycle progress.
+        ///
+        /// (AssetId) => FixedIncome
+        FixedIncomes get(fn fixed_incomes): map hasher(blake2_128_concat) AssetId => Option<FixedIncome>;
+
+        /// The CAs created for the coupons of a fixed income asset, in schedule order.
+        /// A coupon has no CA when there were no units outstanding at its record date.
+        ///
+        /// (AssetId, coupon index) => CA's ID
+        Coupons get(fn coupons): double_map hasher(blake2_128_concat) AssetId, hasher(twox_64_concat) u32 => Option<CAId>;
+
+        /// The coupons of a fixed income asset which weren't funded by their payment date,
+        /// with the amount owed for them.
+        ///
+        /// (AssetId, coupon index) => owed amount
+        DefaultedCoupons get(fn defaulted_coupons): double_map hasher(blake2_128_concat) AssetId, hasher(twox_64_concat) u32 => Option<Balance>;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// Attach fixed income `terms` to the asset identified by `asset_id`.
+        ///
+        /// A checkpoint schedule is created for the coupon record dates,
+        /// which cannot be removed as long as the asset has fixed income terms.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer that has permissions to act as an agent of `asset_id`.
+        /// - `asset_id` of the fixed income asset.
+        /// - `terms` of the fixed income asset, i.e., its coupons, maturity and principal.
+        ///
+        /// # Errors
+        /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
+        /// - `NotFixedIncomeAsset` if the asset is not of type `FixedIncome`.
+        /// - `AlreadyExists` if the asset already has fixed income terms.
+        /// - `MaturityInPast` if the maturity date is not in the future.
+        /// - `InvalidCouponSchedule` if the coupon schedule doesn't yield `coupons` dates
+        ///    in the future, no later than the maturity date.
+        /// - `InvalidPrincipal` if the principal per unit is zero.
+        /// - `DistributionPerShareIsZero` if the coupon per unit is zero, and there are coupons.
+        /// - `UnauthorizedCustodian` if the caller is not the custodian of the funding portfolio.
+        /// - `TooManyCoupons` if there are more than `MAX_COUPONS` coupons.
+        /// - `SchedulesOverMaxComplexity` if there are too many coupons for the checkpoint schedules.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::FixedIncomeWeightInfo::create_fixed_income(terms.coupons)]
+        pub fn create_fixed_income(origin, asset_id: AssetId, terms: FixedIncomeTerms) {
+            Self::base_create_fixed_income(origin, asset_id, terms)?;
+        }
+
+        /// Create a capital distribution for the next coupon of the asset identified by `asset_id`,
+        /// once its record date has passed.
+        ///
+        /// The distribution is funded by the designated portfolio of the issuer,
+        /// and amounts to the coupon per unit for all units outstanding at the record date.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer with an identity.
+        /// - `asset_id` of the fixed income asset.
+        ///
+        /// # Errors
+        /// - `NoSuchFixedIncome` if the asset has no fixed income terms.
+        /// - `AllCouponsDistributed` if every coupon has already been distributed.
+        /// - `CouponNotDue` if the record date of the next coupon hasn't passed yet.
+        /// - `InsufficientPortfolioBalance` if the funding portfolio can't cover the coupon.
+        #[weight = <T as Config>::FixedIncomeWeightInfo::distribute_coupon()]
+        pub fn distribute_coupon(origin, asset_id: AssetId) {
+            Self::base_distribute_coupon(origin, asset_id)?;
+        }
+
+        /// Mature the asset identified by `asset_id`, once its maturity date has passed,
+        /// locking the principal owed for all outstanding units in the funding portfolio.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer with an identity.
+        /// - `asset_id` of the fixed income asset.
+        ///
+        /// # Errors
+        /// - `NoSuchFixedIncome` if the asset has no fixed income terms.
+        /// - `AlreadyMatured` if the asset has already matured.
+        /// - `NotMatured` if the maturity date hasn't passed yet.
+        /// - `CouponsPending` if some coupons haven't been distributed or defaulted yet.
+        /// - `UnauthorizedCustodian` if the issuer is no longer the custodian of the funding portfolio.
+        /// - `InsufficientPortfolioBalance` if the funding portfolio can't cover the principal.
+        #[weight = <T as Config>::FixedIncomeWeightInfo::mature()]
+        pub fn mature(origin, asset_id: AssetId) {
+            Self::base_mature(origin, asset_id)?;
+        }
+
+        /// Redeem the free units of the asset identified by `asset_id`,
+        /// held in the `portfolio` of the caller's DID, for their principal.
+        ///
+        /// The units are burnt and the principal is transferred to the same portfolio.
+        /// Principal in indivisible currencies is rounded down to a whole unit.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer with an identity.
+        /// - `asset_id` of the fixed income asset.
+        /// - `portfolio` of the caller holding the units.
+        ///
+        /// # Errors
+        /// - `UnauthorizedCustodian` if the caller is not the custodian of the portfolio.
+        /// - `NoSuchFixedIncome` if the asset has no fixed income terms.
+        /// - `NotMatured` if the asset hasn't matured yet.
+        /// - `FixedIncomeClosed` if the asset has been closed.
+        /// - `NothingToRedeem` if the portfolio holds no free units.
+        /// - `InsufficientPrincipal` if there's not enough principal left to pay for the units.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::FixedIncomeWeightInfo::redeem()]
+        pub fn redeem(origin, asset_id: AssetId, portfolio: PortfolioKind) {
+            Self::base_redeem(origin, asset_id, portfolio)?;
+        }
+
+        /// Redeem all units of the asset identified by `asset_id`, held in `portfolio`, for their principal.
+        ///
+        /// The units are burnt and the principal is transferred to the same portfolio.
+        /// Unlike `redeem`, units under portfolio locks and vesting schedules are redeemed too,
+        /// and the locks and schedules over them are released.
+        /// Units locked by pending settlements can only be redeemed once the instructions are executed or rejected.
+        /// Principal in indivisible currencies is rounded down to a whole unit.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer that has permissions to act as an agent of `asset_id`.
+        /// - `asset_id` of the fixed income asset.
+        /// - `portfolio` whose units are redeemed.
+        /// - `locks` is the maximum number of portfolio locks of `portfolio`, of any asset.
+        ///
+        /// # Errors
+        /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
+        /// - `NoSuchFixedIncome` if the asset has no fixed income terms.
+        /// - `NotMatured` if the asset hasn't matured yet.
+        /// - `FixedIncomeClosed` if the asset has been closed.
+        /// - `NothingToRedeem` if the portfolio holds no units.
+        /// - `InsufficientPrincipal` if there's not enough principal left to pay for the units.
+        /// - `UnitsLockedBySettlement` if the portfolio holds units locked by pending settlements.
+        /// - `TooManyPortfolioLocks` if the portfolio has more than `locks` portfolio locks.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::FixedIncomeWeightInfo::push_redemption(*locks)]
+        pub fn push_redemption(origin, asset_id: AssetId, portfolio: PortfolioId, locks: u32) {
+            Self::base_push_redemption(origin, asset_id, portfolio, locks)?;
+        }
+
+        /// Close the matured asset identified by `asset_id`, once all units have been redeemed,
+        /// unlocking the remaining principal in the funding portfolio.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer that has permissions to act as an agent of `asset_id`.
+        /// - `asset_id` of the fixed income asset.
+        ///
+        /// # Errors
+        /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
+        /// - `NoSuchFixedIncome` if the asset has no fixed income terms.
+        /// - `NotMatured` if the asset hasn't matured yet.
+        /// - `FixedIncomeClosed` if the asset has already been closed.
+        /// - `UnitsOutstanding` if some units haven't been redeemed yet.
+        ///
+        /// # Permissions
+        /// * Asset
+        #[weight = <T as Config>::FixedIncomeWeightInfo::close_fixed_income()]
+        pub fn close_fixed_income(origin, asset_id: AssetId) {
+            Self::base_close_fixed_income(origin, asset_id)?;
+        }
+
+        /// Record the next coupon of the asset identified by `asset_id` as defaulted,
+        /// once its payment date has passed without the funding portfolio being able to cover it.
+        ///
+        /// The amount owed is kept in `DefaultedCoupons`, and the asset can then proceed
+        /// to its next coupon, or to maturity.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer with an identity.
+        /// - `asset_id` of the fixed income asset.
+        ///
+        /// # Errors
+        /// - `NoSuchFixedIncome` if the asset has no fixed income terms.
+        /// - `AllCouponsDistributed` if every coupon has already been distributed or defaulted.
+        /// - `CouponNotDue` if the record date of the next coupon hasn't passed yet.
+        /// - `CouponPaymentNotDue` if the payment date of the next coupon hasn't passed yet.
+        /// - `CouponFunded` if the funding portfolio can cover the coupon, which must then be distributed.
+        #[weight = <T as Config>::FixedIncomeWeightInfo::default_coupon()]
+        pub fn default_coupon(origin, asset_id: AssetId) {
+            Self::base_default_coupon(origin, asset_id)?;
+        }
+    }
+}
+
+decl_event! {
+    pub enum Event {
+        /// Fixed income terms were attached to an asset by the DID (permissioned agent).
+        ///
+        /// (Agent DID, asset ID, fixed income terms, coupon checkpoint schedule ID, if any)
+        Created(EventDid, AssetId, FixedIncomeTerms, Option<ScheduleId>),
+
+        /// A coupon was distributed through the CA, if any.
+        /// There's no CA if no units were outstanding at the coupon's record date.
+        ///
+        /// (Caller DID, asset ID, coupon index, CA's ID)
+        CouponDistributed(EventDid, AssetId, u32, Option<CAId>),
+
+        /// A fixed income asset matured, and its principal was locked for redemption.
+        ///
+        /// (Caller DID, asset ID, locked principal)
+        Matured(EventDid, AssetId, Balance),
+
+        /// Units held in a portfolio were redeemed for their principal.
+        ///
+        /// (Caller DID, holder's portfolio, asset ID, redeemed units, paid principal)
+        Redeemed(EventDid, PortfolioId, AssetId, Balance, Balance),
+
+        /// A matured fixed income asset was closed.
+        ///
+        /// (Agent DID, asset ID, unlocked principal)
+        Closed(EventDid, AssetId, Balance),
+
+        /// A coupon wasn't funded by its payment date, and was recorded as defaulted.
+        ///
+        /// (Caller DID, asset ID, coupon index, owed amount)
+        CouponDefaulted(EventDid, AssetId, u32, Balance),
+    }
+}
+
+decl_error! {
+    pub enum Error for Module<T: Config> {
+        /// Fixed income terms can only be attached to assets of type `FixedIncome`.
+        NotFixedIncomeAsset,
+        /// The asset already has fixed income terms.
+        AlreadyExists,
+        /// The maturity date must be in the future.
+        MaturityInPast,
+        /// The coupon schedule must yield the number of coupons,
+        /// in the future and no later than the maturity date.
+        InvalidCouponSchedule,
+        /// The principal per unit must be non-zero.
+        InvalidPrincipal,
+        /// The asset has no fixed income terms.
+        NoSuchFixedIncome,
+        /// Every coupon has already been distributed.
+        AllCouponsDistributed,
+        /// The record date of the next coupon hasn't passed yet.
+        CouponNotDue,
+        /// Some coupons haven't been distributed yet.
+        CouponsPending,
+        /// The asset has already matured.
+        AlreadyMatured,
+        /// The asset hasn't matured yet.
+        NotMatured,
+        /// The fixed income asset has been closed.
+        FixedIncomeClosed,
+        /// The portfolio holds no units to redeem.
+        NothingToRedeem,
+        /// There's not enough principal left to pay for the units.
+        InsufficientPrincipal,
+        /// Some units haven't been redeemed yet.
+        UnitsOutstanding,
+        /// The number of coupons exceeds `MAX_COUPONS`.
+        TooManyCoupons,
+        /// The payment date of the next coupon hasn't passed yet.
+        CouponPaymentNotDue,
+        /// The funding portfolio can cover the next coupon, which must be distributed instead.
+        CouponFunded,
+    }
+}
+
+impl<T: Config> Module<T> {
+    fn base_create_fixed_income(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        terms: FixedIncomeTerms,
+    ) -> DispatchResult {
+        let PermissionedCallOriginData {
+            primary_did: agent,
+            secondary_key,
+            ..
+        } = <ExternalAgents<T>>::ensure_agent_asset_perms(origin, asset_id)?;
+
+        // Ensure the asset is a fungible fixed income asset, without terms yet.
+        let details = <Asset<T>>::try_get_asset_details(&asset_id)?;
+        ensure!(
+            details.asset_type == AssetType::FixedIncome,
+            Error::<T>::NotFixedIncomeAsset
+        );
+        ensure!(
+            !FixedIncomes::contains_key(asset_id),
+            Error::<T>::AlreadyExists
+        );
+
+        // Ensure the amounts and dates make sense.
+        let now = <Checkpoint<T>>::now_unix();
+        ensure!(terms.maturity > now, Error::<T>::MaturityInPast);
+        ensure!(
+            !terms.principal_per_share.is_zero(),
+            Error::<T>::InvalidPrincipal
+        );
+        ensure!(
+            terms.coupons == 0 || !terms.coupon_per_share.is_zero(),
+            distribution::Error::<T>::DistributionPerShareIsZero
+        );
+        let coupon_dates = Self::coupon_dates(&terms, now)?;
+
+        // Ensure secondary key has perms for the funding portfolio + it is valid.
+        let funding = PortfolioId {
+            did: agent,
+            kind: terms.portfolio.into(),
+        };
+        <Portfolio<T>>::ensure_portfolio_custody_and_permission(
+            funding,
+            agent,
+            secondary_key.as_ref(),
+        )?;
+        <Portfolio<T>>::ensure_portfolio_validity(&funding)?;
+        <Asset<T>>::try_get_asset_details(&terms.currency)?;
+
+        // Schedule a checkpoint at every coupon record date.
+        // The schedule is kept from removal through its strong reference count of 1.
+        let schedule = ScheduleCheckpoints::new_checkpoints(coupon_dates);
+        let schedule_id = if schedule.is_empty() {
+            None
+        } else {
+            Some(<Checkpoint<T>>::base_create_schedule(agent, asset_id, schedule, 1)?.0)
+        };
+
+        // Commit to storage.
+        FixedIncomes::insert(
+            asset_id,
+            FixedIncome {
+                agent,
+                terms,
+                schedule_id,
+                coupons_distributed: 0,
+                redemption: None,
+                closed: false,
+            },
+        );
+
+        // Emit event.
+        Self::deposit_event(Event::Created(
+            agent.for_event(),
+            asset_id,
+            terms,
+            schedule_id,
+        ));
+        Ok(())
+    }
+
+    fn base_distribute_coupon(origin: T::RuntimeOrigin, asset_id: AssetId) -> DispatchResult {
+        let caller = <Identity<T>>::ensure_perms(origin)?;
+
+        let mut fixed_income = Self::ensure_fixed_income_exists(asset_id)?;
+        let (index, cp_id, record_date, amount) = Self::next_coupon(asset_id, &fixed_income)?;
+        let terms = fixed_income.terms;
+
+        let ca_id = if amount.is_zero() {
+            None
+        } else {
+            let payment_at = record_date.saturating_add(terms.coupon_payment_delay);
+            let expires_at = terms
+                .coupon_claim_period
+                .map(|period| payment_at.saturating_add(period));
+            let ca_id = with_transaction(|| -> Result<CAId, DispatchError> {
+                // The coupon is declared by the issuer at its record date.
+                let ca_id = <CA<T>>::unsafe_initiate_corporate_action(
+                    fixed_income.agent,
+                    asset_id,
+                    CAKind::PredictableBenefit,
+                    record_date,
+                    Some(RecordDateSpec::Existing(cp_id)),
+                    CADetails::default(),
+                    None,
+                    None,
+                    None,
+                )?;
+                <distribution::Module<T>>::unverified_distribute(
+                    fixed_income.agent,
+                    None,
+                    ca_id,
+                    terms.portfolio,
+                    terms.currency,
+                    terms.coupon_per_share,
+                    amount,
+                    payment_at,
+                    expires_at,
+                )?;
+                Ok(ca_id)
+            })?;
+            Coupons::insert(asset_id, index, ca_id);
+            Some(ca_id)
+        };
+
+        // Commit progress to storage.
+        fixed_income.coupons_distributed = index + 1;
+        FixedIncomes::insert(asset_id, fixed_income);
+
+        // Emit event.
+        Self::deposit_event(Event::CouponDistributed(
+            caller.for_event(),
+            asset_id,
+            index,
+            ca_id,
+        ));
+        Ok(())
+    }
+
+    fn base_mature(origin: T::RuntimeOrigin, asset_id: AssetId) -> DispatchResult {
+        let caller = <Identity<T>>::ensure_perms(origin)?;
+
+        let mut fixed_income = Self::ensure_fixed_income_exists(asset_id)?;
+        ensure!(
+            fixed_income.redemption.is_none(),
+            Error::<T>::AlreadyMatured
+        );
+        let terms = fixed_income.terms;
+        let now = <Checkpoint<T>>::now_unix();
+        ensure!(now >= terms.maturity, Error::<T>::NotMatured);
+        ensure!(
+            fixed_income.coupons_distributed == terms.coupons,
+            Error::<T>::CouponsPending
+        );
+
+        // Compute and lock the principal owed for all outstanding units.
+        // No more units can be issued from then on, as their principal wouldn't be locked.
+        let supply = <Asset<T>>::total_supply(&asset_id);
+        let amount = <distribution::Module<T>>::benefit_of(supply, terms.principal_per_share)?;
+        let funding = fixed_income.funding_portfolio();
+        <Portfolio<T>>::ensure_portfolio_custody(funding, fixed_income.agent)?;
+        <Portfolio<T>>::ensure_sufficient_balance(&funding, &terms.currency, amount)?;
+        <Portfolio<T>>::unchecked_lock_tokens(&funding, &terms.currency, amount);
+        <Asset<T>>::close_issuance(asset_id);
+
+        // Commit to storage.
+        fixed_income.redemption = Some(Distribution {
+            from: funding,
+            currency: terms.currency,
+            per_share: terms.principal_per_share,
+            amount,
+            remaining: amount,
+            reclaimed: false,
+            payment_at: now,
+            expires_at: None,
+        });
+        FixedIncomes::insert(asset_id, fixed_income);
+
+        // Emit event.
+        Self::deposit_event(Event::Matured(caller.for_event(), asset_id, amount));
+        Ok(())
+    }
+
+    fn base_redeem(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio: PortfolioKind,
+    ) -> DispatchResult {
+        let PermissionedCallOriginData {
+            primary_did,
+            secondary_key,
+            ..
+        } = <Identity<T>>::ensure_origin_call_permissions(origin)?;
+        let portfolio = PortfolioId {
+            did: primary_did,
+            kind: portfolio,
+        };
+        <Portfolio<T>>::ensure_portfolio_custody_and_permission(
+            portfolio,
+            primary_did,
+            secondary_key.as_ref(),
+        )?;
+        Self::redeem_units(primary_did, portfolio, asset_id, None)
+    }
+
+    fn base_push_redemption(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio: PortfolioId,
+        locks: u32,
+    ) -> DispatchResult {
+        let agent = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+        Self::redeem_units(agent, portfolio, asset_id, Some(locks))
+    }
+
+    /// Redeem the units in `portfolio`, paying their principal into it.
+    ///
+    /// Only the free units are redeemed if `locks` is `None`. Otherwise all units are,
+    /// releasing the portfolio locks and vesting schedules over them,
+    /// provided the portfolio has at most `locks` portfolio locks.
+    fn redeem_units(
+        caller: IdentityId,
+        portfolio: PortfolioId,
+        asset_id: AssetId,
+        locks: Option<u32>,
+    ) -> DispatchResult {
+        let mut fixed_income = Self::ensure_fixed_income_exists(asset_id)?;
+        ensure!(!fixed_income.closed, Error::<T>::FixedIncomeClosed);
+        let mut redemption = fixed_income.redemption.ok_or(Error::<T>::NotMatured)?;
+
+        // Compute the units to redeem and the principal owed for them.
+        let balance = <Portfolio<T>>::portfolio_asset_balances(portfolio, asset_id);
+        let units = match locks {
+            Some(_) => balance,
+            None => balance.saturating_sub(<Portfolio<T>>::total_locked(&portfolio, &asset_id)),
+        };
+        ensure!(!units.is_zero(), Error::<T>::NothingToRedeem);
+        let principal = <distribution::Module<T>>::benefit_of(units, redemption.per_share)?;
+        redemption.remaining = redemption
+            .remaining
+            .checked_sub(principal)
+            .ok_or(Error::<T>::InsufficientPrincipal)?;
+
+        let paid = with_transaction(|| -> Result<Balance, DispatchError> {
+            let weight = match locks {
+                Some(locks) => <T as Config>::FixedIncomeWeightInfo::push_redemption(locks),
+                None => <T as Config>::FixedIncomeWeightInfo::redeem(),
+            };
+            let mut weight_meter = WeightMeter::from_limit(Weight::zero(), weight)
+                .map_err(|_| asset::Error::<T>::InputWeightIsLessThanMinimum)?;
+            match locks {
+                // Reorganizing all units into none also releases the portfolio locks and vesting schedules over them.
+                Some(locks) => <Asset<T>>::unchecked_reorganize_portfolio(
+                    caller,
+                    asset_id,
+                    asset_id,
+                    portfolio,
+                    Zero::zero(),
+                    (0, 1),
+                    locks,
+                    &mut weight_meter,
+                )?,
+                None => {
+                    <Asset<T>>::unchecked_redeem(asset_id, portfolio, units, &mut weight_meter)?
+                }
+            }
+
+            // Unlock `principal` of `currency` from the funding portfolio.
+            <Portfolio<T>>::unlock_tokens(&redemption.from, &redemption.currency, principal)?;
+
+            // Round down to unit multiple if indivisible.
+            let paid = if <Asset<T>>::is_divisible(&redemption.currency) {
+                principal
+            } else {
+                principal / ONE_UNIT * ONE_UNIT
+            };
+            // Principal owed to the issuer itself just stays in the funding portfolio.
+            // The principal was set aside at maturity, so it's moved without the compliance
+            // and transfer checks, which could otherwise keep holders from being paid.
+            if !paid.is_zero() && portfolio.did != redemption.from.did {
+                <Asset<T>>::unverified_transfer_asset(
+                    redemption.from,
+                    portfolio,
+                    redemption.currency,
+                    paid,
+                    None,
+                    None,
+                    fixed_income.agent,
+                    &mut weight_meter,
+                )?;
+            }
+            Ok(paid)
+        })?;
+
+        // Commit `redemption` change to storage.
+        fixed_income.redemption = Some(redemption);
+        FixedIncomes::insert(asset_id, fixed_income);
+
+        // Emit event.
+        Self::deposit_event(Event::Redeemed(
+            caller.for_event(),
+            portfolio,
+            asset_id,
+            units,
+            paid,
+        ));
+        Ok(())
+    }
+
+    fn base_close_fixed_income(origin: T::RuntimeOrigin, asset_id: AssetId) -> DispatchResult {
+        let agent = <ExternalAgents<T>>::ensure_perms(origin, asset_id)?;
+
+        let mut fixed_income = Self::ensure_fixed_income_exists(asset_id)?;
+        ensure!(!fixed_income.closed, Error::<T>::FixedIncomeClosed);
+        let redemption = fixed_income
+            .redemption
+            .as_mut()
+            .ok_or(Error::<T>::NotMatured)?;
+        ensure!(
+            <Asset<T>>::total_supply(&asset_id).is_zero(),
+            Error::<T>::UnitsOutstanding
+        );
+
+        // Unlock the remaining principal.
+        let unlocked = redemption.remaining;
+        <Portfolio<T>>::unlock_tokens(&redemption.from, &redemption.currency, unlocked)?;
+        redemption.remaining = Zero::zero();
+        redemption.reclaimed = true;
+
+        fixed_income.closed = true;
+        FixedIncomes::insert(asset_id, fixed_income);
+
+        Self::deposit_event(Event::Closed(agent.for_event(), asset_id, unlocked));
+        Ok(())
+    }
+
+    fn base_default_coupon(origin: T::RuntimeOrigin, asset_id: AssetId) -> DispatchResult {
+        let caller = <Identity<T>>::ensure_perms(origin)?;
+
+        let mut fixed_income = Self::ensure_fixed_income_exists(asset_id)?;
+        let (index, _, record_date, amount) = Self::next_coupon(asset_id, &fixed_income)?;
+        let terms = fixed_income.terms;
+
+        // The issuer has until the payment date to fund the coupon.
+        let payment_at = record_date.saturating_add(terms.coupon_payment_delay);
+        ensure!(
+            <Checkpoint<T>>::now_unix() >= payment_at,
+            Error::<T>::CouponPaymentNotDue
+        );
+
+        // Ensure the coupon can't be distributed from the funding portfolio.
+        let funding = fixed_income.funding_portfolio();
+        let free = <Portfolio<T>>::portfolio_asset_balances(funding, terms.currency)
+            .saturating_sub(<Portfolio<T>>::total_locked(&funding, &terms.currency));
+        let funded = free >= amount
+            && <Portfolio<T>>::ensure_portfolio_custody(funding, fixed_income.agent).is_ok();
+        ensure!(!funded, Error::<T>::CouponFunded);
+
+        // Commit to storage.
+        DefaultedCoupons::insert(asset_id, index, amount);
+        fixed_income.coupons_distributed = index + 1;
+        FixedIncomes::insert(asset_id, fixed_income);
+
+        // Emit event.
+        Self::deposit_event(Event::CouponDefaulted(
+            caller.for_event(),
+            asset_id,
+            index,
+            amount,
+        ));
+        Ok(())
+    }
+
+    /// Returns the index, checkpoint, record date and amount owed of the next coupon of `fixed_income`,
+    /// ensuring its record date has passed.
+    fn next_coupon(
+        asset_id: AssetId,
+        fixed_income: &FixedIncome,
+    ) -> Result<(u32, CheckpointId, Moment, Balance), DispatchError> {
+        let index = fixed_income.coupons_distributed;
+        ensure!(
+            index < fixed_income.terms.coupons,
+            Error::<T>::AllCouponsDistributed
+        );
+        let schedule_id = fixed_income
+            .schedule_id
+            .ok_or(Error::<T>::AllCouponsDistributed)?;
+
+        // Create any due checkpoints, and find the one for the coupon's record date.
+        <Checkpoint<T>>::advance_update_balances(&asset_id, &[])?;
+        let cp_id = <Checkpoint<T>>::schedule_points(asset_id, schedule_id)
+            .get(index as usize)
+            .copied()
+            .ok_or(Error::<T>::CouponNotDue)?;
+        let record_date = <Checkpoint<T>>::timestamps(asset_id, cp_id);
+
+        // Compute the coupon owed for all units outstanding at the record date.
+        let supply = <Checkpoint<T>>::total_supply_at(asset_id, cp_id);
+        let amount =
+            <distribution::Module<T>>::benefit_of(supply, fixed_income.terms.coupon_per_share)?;
+        Ok((index, cp_id, record_date, amount))
+    }
+
+    /// Computes the coupon record dates of `terms`,
+    /// ensuring they're in the future and no later than the maturity date.
+    fn coupon_dates(
+        terms: &FixedIncomeTerms,
+        now: Moment,
+    ) -> Result<BTreeSet<Moment>, DispatchError> {
+        // Bound the work done here, as the schedule can't be created for more anyways.
+        ensure!(terms.coupons <= MAX_COUPONS, Error::<T>::TooManyCoupons);
+        ensure!(
+            u64::from(terms.coupons) <= <Checkpoint<T>>::schedules_max_complexity(),
+            checkpoint::Error::<T>::SchedulesOverMaxComplexity
+        );
+
+        let mut dates = BTreeSet::new();
+        let mut next = Some(terms.coupon_schedule.start);
+        for _ in 0..terms.coupons {
+            let at = next.ok_or(Error::<T>::InvalidCouponSchedule)?;
+            ensure!(
+                now < at && at <= terms.maturity,
+                Error::<T>::InvalidCouponSchedule
+            );
+            dates.insert(at);
+            next = terms.coupon_schedule.next_checkpoint(at);
+        }
+        Ok(dates)
+    }
+
+    /// Ensure `asset_id` has fixed income terms, and return them.
+    fn ensure_fixed_income_exists(asset_id: AssetId) -> Result<FixedIncome, DispatchError> {
+        FixedIncomes::get(asset_id).ok_or_else(|| Error::<T>::NoSuchFixedIncome.into())
+    }
+}
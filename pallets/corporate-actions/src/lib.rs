@@ -50,10 +50,12 @@
 //! To do so, `link_ca_doc(ca_id, docs)` can be called,
 //! with the ID of the CA specified in `ca_id` as well the IDs of each document in `docs`.
 //!
-//! Beyond this module, two other modules exist dedicated to CAs. These are:
+//! Beyond this module, other modules exist dedicated to CAs. These are:
 //!
 //! - The corporate ballots module, with which e.g., annual general meetings can be conducted on-chain.
 //! - The capital distributions module, with which e.g., dividends and other benefits may be distributed.
+//! - The corporate reorganization module, with which e.g., splits and conversions may be applied.
+//! - The fixed income module, with which bonds pay coupons and redeem their principal at maturity.
 //!
 //! For more details, consult the documentation in those modules.
 //!
@@ -90,7 +92,9 @@ pub mod benchmarking;
 
 pub mod ballot;
 pub mod distribution;
+pub mod fixed_income;
 mod migrations;
+pub mod reorganization;
 
 use codec::{Decode, Encode};
 use distribution::WeightInfo as DistWeightInfoTrait;
@@ -324,6 +328,8 @@ pub trait Config: frame_system::Config + BalancesConfig + IdentityConfig + asset
     type RuntimeEvent: From<Event>
         + From<ballot::Event>
         + From<distribution::Event>
+        + From<reorganization::Event>
+        + From<fixed_income::Event>
         + Into<<Self as frame_system::Config>::RuntimeEvent>;
 
     /// Max number of DID specified in `TargetIdentities`.
@@ -332,6 +338,13 @@ pub trait Config: frame_system::Config + BalancesConfig + IdentityConfig + asset
     /// Max number of per-DID withholding tax overrides.
     type MaxDidWhts: Get<u32>;
 
+    /// Max time, in milliseconds, between the record date and the deadline of a reorganization,
+    /// i.e., for how long the asset can be frozen.
+    type MaxReorganizationPeriod: Get<Moment>;
+
+    /// Max number of portfolios reorganized in one call to `apply_reorganization`.
+    type MaxReorganizationBatch: Get<u32>;
+
     /// Weight information for extrinsics in the corporate actions pallet.
     type WeightInfo: WeightInfo;
 
@@ -340,6 +353,12 @@ pub trait Config: frame_system::Config + BalancesConfig + IdentityConfig + asset
 
     /// Weight information for extrinsics in the capital distribution pallet.
     type DistWeightInfo: distribution::WeightInfo;
+
+    /// Weight information for extrinsics in the corporate reorganization pallet.
+    type ReorgWeightInfo: reorganization::WeightInfo;
+
+    /// Weight information for extrinsics in the fixed income pallet.
+    type FixedIncomeWeightInfo: fixed_income::WeightInfo;
 }
 
 type Asset<T> = pallet_asset::Module<T>;
@@ -347,6 +366,7 @@ type Ballot<T> = ballot::Module<T>;
 type Checkpoint<T> = checkpoint::Module<T>;
 type Distribution<T> = distribution::Module<T>;
 type ExternalAgents<T> = pallet_external_agents::Module<T>;
+type Reorganization<T> = reorganization::Module<T>;
 
 decl_storage! {
     trait Store for Module<T: Config> as CorporateAction {
@@ -638,7 +658,12 @@ decl_module! {
 
             // Remove associated services.
             match ca.kind {
-                CAKind::Other | CAKind::Reorganization => {}
+                CAKind::Other => {}
+                CAKind::Reorganization => {
+                    if let Some(reorg) = <Reorganization<T>>::reorganizations(ca_id) {
+                        <Reorganization<T>>::unverified_remove_reorganization(agent, ca_id, &reorg)?;
+                    }
+                }
                 CAKind::IssuerNotice => {
                     if let Some(range) = <Ballot<T>>::time_ranges(ca_id) {
                         <Ballot<T>>::remove_ballot_base(agent, ca_id, range)?;
@@ -691,7 +716,14 @@ decl_module! {
 
                 // Ensure associated services allow changing the date.
                 match ca.kind {
-                    CAKind::Other | CAKind::Reorganization => {}
+                    CAKind::Other => {}
+                    CAKind::Reorganization => {
+                        if let Some(reorg) = <Reorganization<T>>::reorganizations(ca_id) {
+                            <Reorganization<T>>::change_reorganization_record_date(
+                                ca_id, &ca, &reorg,
+                            )?;
+                        }
+                    }
                     CAKind::IssuerNotice => {
                         if let Some(range) = <Ballot<T>>::time_ranges(ca_id) {
                             Self::ensure_record_date_before_start(&ca, range.start)?;
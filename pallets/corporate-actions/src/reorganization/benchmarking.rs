@@ -0,0 +1,114 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::benchmarks;
+use scale_info::prelude::format;
+
+use pallet_asset::benchmarking::create_portfolio;
+use polymesh_common_utilities::benchs::{AccountIdOf, User};
+use polymesh_common_utilities::TestUtilsFn;
+use polymesh_primitives::{Fund, FundDescription, PortfolioLockReason};
+
+use super::*;
+use crate::benchmarking::{currency, setup_ca};
+
+const MAX_LOCKS: u32 = 50;
+const MAX_PORTFOLIOS: u32 = 50;
+
+/// The deadline of the reorganizations, after the record date of `setup_ca`.
+const DEADLINE: Moment = 10_000;
+
+const SPLIT: ReorganizationKind = ReorganizationKind::Split(ReorganizationRatio { new: 2, old: 1 });
+
+fn cash_in_lieu<T: Config>(owner: &User<T>) -> RemainderPolicy {
+    RemainderPolicy::CashInLieu {
+        portfolio: None,
+        currency: currency::<T>(owner),
+        per_share: 2u32.into(),
+        amount: 1000u32.into(),
+    }
+}
+
+fn reorg<T: Config + TestUtilsFn<AccountIdOf<T>>>() -> (User<T>, CAId) {
+    let (owner, ca_id) = setup_ca::<T>(CAKind::Reorganization);
+    let remainder = cash_in_lieu::<T>(&owner);
+    <Module<T>>::reorganize(owner.origin().into(), ca_id, SPLIT, remainder, DEADLINE).unwrap();
+    (owner, ca_id)
+}
+
+benchmarks! {
+    where_clause { where T: TestUtilsFn<AccountIdOf<T>> }
+
+    reorganize {
+        let (owner, ca_id) = setup_ca::<T>(CAKind::Reorganization);
+        let remainder = cash_in_lieu::<T>(&owner);
+    }: _(owner.origin(), ca_id, SPLIT, remainder, DEADLINE)
+    verify {
+        assert!(Reorganizations::get(ca_id).is_some(), "reorganization not created");
+    }
+
+    apply_reorganization {
+        let p in 1..MAX_PORTFOLIOS;
+        let l in 0..MAX_LOCKS;
+
+        let (owner, ca_id) = reorg::<T>();
+        let default_portfolio = PortfolioId::default_portfolio(owner.did());
+        let mut portfolios = vec![default_portfolio];
+        for i in 1..p {
+            let portfolio = create_portfolio::<T>(&owner, &format!("portfolio{}", i));
+            <Portfolio<T>>::move_portfolio_funds(
+                owner.origin().into(),
+                default_portfolio,
+                portfolio,
+                vec![Fund {
+                    description: FundDescription::Fungible {
+                        asset_id: ca_id.asset_id,
+                        amount: (MAX_LOCKS as Balance + 1) * ONE_UNIT,
+                    },
+                    memo: None,
+                }],
+            )
+            .unwrap();
+            portfolios.push(portfolio);
+        }
+        for portfolio in &portfolios {
+            for _ in 0..l {
+                <Portfolio<T>>::lock_portfolio_assets(
+                    owner.origin().into(),
+                    *portfolio,
+                    ca_id.asset_id,
+                    ONE_UNIT,
+                    PortfolioLockReason::Collateral,
+                    None,
+                    None,
+                )
+                .unwrap();
+            }
+        }
+        <pallet_timestamp::Now<T>>::set(3000u32.into());
+    }: _(owner.origin(), ca_id, portfolios.clone(), l, None)
+    verify {
+        for portfolio in portfolios {
+            assert!(PortfolioReorganized::get((ca_id, portfolio)), "not reorganized");
+        }
+    }
+
+    close_reorganization {
+        let (owner, ca_id) = reorg::<T>();
+    }: _(owner.origin(), ca_id)
+    verify {
+        assert!(Reorganizations::get(ca_id).unwrap().closed, "not closed");
+    }
+}
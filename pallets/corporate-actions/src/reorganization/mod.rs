@@ -0,0 +1,792 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Corporate Reorganization Module
+//!
+//! The corporate reorganization module provides functionality for splits, reverse splits,
+//! and conversions of an asset into another asset.
+//!
+//! The process works by first initiating a corporate action (CA) of kind `Reorganization`,
+//! with a record date, through `initiate_corporate_action`,
+//! and then attaching a reorganization to it, with a deadline, via `reorganize`.
+//!
+//! From the record date until the deadline, transfers, issuance and redemption of the asset are frozen,
+//! and a permissioned external agent applies the reorganization to batches of portfolios
+//! through `apply_reorganization`.
+//! Every `old` units held in a portfolio become `new` units,
+//! either of the same asset (a split) or of another asset (a conversion).
+//! Units are issued into and redeemed from the portfolio itself,
+//! so checkpoints and statistics are updated as they would be for any issuance or redemption,
+//! while the portfolio locks, vesting schedules and acquisition lots of the units are rescaled in place.
+//! The freeze is lifted when the reorganization is closed or removed, and at the latest at the deadline,
+//! which is at most `MaxReorganizationPeriod` after the record date.
+//! Holdings that weren't reorganized by the deadline are left as they are.
+//!
+//! When the resulting asset is indivisible, fractions of a unit are either dropped,
+//! or paid in cash through a capital distribution funded when calling `reorganize`.
+//! Once all holders have been processed, `close_reorganization` unlocks what remains of that cash.
+//!
+//! ## Overview
+//!
+//! The module provides functions for:
+//!
+//! - Attaching a split or a conversion to a CA.
+//! - Applying the reorganization to portfolios.
+//! - Closing a reorganization.
+//!
+//! ### Terminology
+//!
+//! - **Ratio:** The number of `new` units that every `old` units become.
+//!   A forward split has `new > old` while a reverse split has `new < old`.
+//! - **Cash in lieu:** Cash paid to a holder in place of a fraction of a unit of an indivisible asset.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `reorganize` attaches a split or a conversion to a CA.
+//! - `apply_reorganization` reorganizes the holdings of a batch of portfolios.
+//! - `close_reorganization` closes a reorganization, unlocking any remaining cash in lieu.
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+use crate as ca;
+use ca::distribution::{self, Distribution};
+use ca::{CAId, CAKind, Config};
+use codec::{Decode, Encode};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::{
+        DispatchError, DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo,
+        PostDispatchInfo,
+    },
+    ensure,
+    traits::Get,
+    weights::Weight,
+};
+use pallet_asset::{self as asset, checkpoint};
+use pallet_identity::PermissionedCallOriginData;
+use polymesh_common_utilities::{
+    constants::currency::ONE_UNIT, portfolio::PortfolioSubTrait, with_transaction,
+};
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::{
+    Balance, EventDid, IdentityId, Moment, PortfolioId, PortfolioNumber, WeightMeter,
+};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+#[cfg(feature = "std")]
+use sp_runtime::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+type Asset<T> = asset::Module<T>;
+type Checkpoint<T> = checkpoint::Module<T>;
+type ExternalAgents<T> = pallet_external_agents::Module<T>;
+type CA<T> = ca::Module<T>;
+type Portfolio<T> = pallet_portfolio::Module<T>;
+
+/// The number of `new` units that every `old` units become.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct ReorganizationRatio {
+    /// Units after the reorganization.
+    pub new: u32,
+    /// Units before the reorganization.
+    pub old: u32,
+}
+
+impl ReorganizationRatio {
+    /// Returns `balance * new / old`, rounded down, or `None` on overflow.
+    pub fn apply(&self, balance: Balance) -> Option<Balance> {
+        balance
+            .checked_mul(self.new.into())
+            .and_then(|v| v.checked_div(self.old.into()))
+    }
+}
+
+/// What a reorganization turns the holdings of the CA's asset into.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum ReorganizationKind {
+    /// The holdings are rescaled by the ratio, i.e., a split or a reverse split.
+    Split(ReorganizationRatio),
+    /// The holdings are converted into the given asset at the ratio.
+    Conversion(AssetId, ReorganizationRatio),
+}
+
+impl ReorganizationKind {
+    /// Returns the ratio of the reorganization.
+    pub fn ratio(&self) -> ReorganizationRatio {
+        match self {
+            Self::Split(ratio) | Self::Conversion(_, ratio) => *ratio,
+        }
+    }
+
+    /// Returns the asset holders end up with, given that `asset_id` is the asset of the CA.
+    pub fn target_asset(&self, asset_id: AssetId) -> AssetId {
+        match self {
+            Self::Split(_) => asset_id,
+            Self::Conversion(target, _) => *target,
+        }
+    }
+}
+
+/// How fractions of a unit of an indivisible asset, resulting from a reorganization, are handled.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum RemainderPolicy {
+    /// Fractions are dropped.
+    RoundDown,
+    /// Fractions are paid in `currency`, withdrawn from `portfolio` of the agent.
+    /// `per_share` is the price of one unit, in per-million of a `currency` token,
+    /// and `amount` is the total amount of `currency` that can be paid at most.
+    CashInLieu {
+        portfolio: Option<PortfolioNumber>,
+        currency: AssetId,
+        per_share: Balance,
+        amount: Balance,
+    },
+}
+
+/// A split or conversion attached to a CA.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct Reorganization {
+    /// What the holdings are turned into.
+    pub kind: ReorganizationKind,
+    /// The capital distribution paying cash in lieu of fractions, if any.
+    pub cash_in_lieu: Option<Distribution>,
+    /// The moment by which holdings must be reorganized, when the asset is unfrozen at the latest.
+    pub deadline: Moment,
+    /// The number of portfolios whose holdings have been reorganized.
+    pub portfolios: u32,
+    /// Whether the reorganization has been closed.
+    pub closed: bool,
+}
+
+/// Weight abstraction for the corporate reorganization module.
+pub trait WeightInfo {
+    fn reorganize() -> Weight;
+    fn apply_reorganization(p: u32, l: u32) -> Weight;
+    fn close_reorganization() -> Weight;
+
+    fn apply_reorganization_weight_limit(weight_limit: &Option<Weight>, p: u32, l: u32) -> Weight {
+        if let Some(weight_limit) = weight_limit {
+            return *weight_limit;
+        }
+        Self::apply_reorganization(p, l)
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Config> as CorporateReorganization {
+        /// All reorganizations, tied to their respective corporate actions (CAs).
+        ///
+        /// (CAId) => Reorganization
+        Reorganizations get(fn reorganizations): map hasher(blake2_128_concat) CAId => Option<Reorganization>;
+
+        /// Have the holdings of a portfolio been reorganized yet?
+        ///
+        /// (CAId, PortfolioId) -> Were the portfolio's holdings reorganized in the CAId?
+        PortfolioReorganized get(fn portfolio_reorganized): map hasher(blake2_128_concat) (CAId, PortfolioId) => bool;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// Attach a reorganization of `kind` to the CA identified by `ca_id`,
+        /// handling fractions of a unit according to `remainder`.
+        ///
+        /// With `RemainderPolicy::CashInLieu`, the cash is locked in the agent's portfolio
+        /// from when `reorganize` is called.
+        /// Transfers, issuance and redemption of the asset are frozen from the record date
+        /// until the reorganization is closed or removed, and at the latest until `deadline`.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer that has permissions to act as an agent of `ca_id.asset_id`,
+        ///    and of the asset converted into, for conversions.
+        /// - `ca_id` identifies the CA to attach a reorganization to.
+        /// - `kind` of reorganization, i.e., a split or a conversion, and its ratio.
+        /// - `remainder` specifies how fractions of a unit of indivisible assets are handled.
+        /// - `deadline` by which holdings must be reorganized, at most `MaxReorganizationPeriod` after the record date.
+        ///
+        /// # Errors
+        /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for the assets.
+        /// - `NoSuchCA` if `ca_id` does not identify an existing CA.
+        /// - `CANotReorganization` if the CA is not of kind `Reorganization`.
+        /// - `NoRecordDate` if CA has no record date.
+        /// - `RecordDatePassed` if the record date is in the past.
+        /// - `InvalidDeadline` if `deadline` isn't after the record date, or too long after it.
+        /// - `AlreadyExists` if the CA already has a reorganization.
+        /// - `NonFungibleAsset` if the asset of the CA is not fungible.
+        /// - `TransfersAlreadyFrozen` if the asset is already frozen by another reorganization.
+        /// - `InvalidRatio` if a side of the ratio is zero, or a split doesn't change holdings.
+        /// - `InvalidConversion` if the asset is converted into itself or into a non-fungible asset.
+        /// - `InvalidCashInLieu` if the price or amount of cash in lieu is zero, or the cash is the asset itself.
+        /// - `UnauthorizedCustodian` if the caller is not the custodian of the cash portfolio.
+        /// - `InsufficientPortfolioBalance` if the cash portfolio has less than `amount` of `currency`.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::ReorgWeightInfo::reorganize()]
+        pub fn reorganize(
+            origin,
+            ca_id: CAId,
+            kind: ReorganizationKind,
+            remainder: RemainderPolicy,
+            deadline: Moment,
+        ) {
+            Self::base_reorganize(origin, ca_id, kind, remainder, deadline)?;
+        }
+
+        /// Reorganize the holdings of each of `portfolios` for the CA identified by `ca_id`.
+        ///
+        /// Units are issued into and redeemed from each portfolio, which holds the same balance
+        /// it had at the record date since the asset is frozen.
+        /// Its portfolio locks, vesting schedules and acquisition lots are rescaled in place.
+        /// For conversions, the compliance rules of the asset converted into must allow the agent
+        /// to transfer it to the owner of each portfolio.
+        /// Fractions of a unit of an indivisible asset are dropped or paid in cash,
+        /// as specified when the reorganization was attached.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer that has permissions to act as an agent of `ca_id.asset_id`.
+        /// - `ca_id` identifies the CA with a reorganization to apply.
+        /// - `portfolios` whose holdings are reorganized, at most `MaxReorganizationBatch` of them.
+        /// - `locks` is the maximum number of portfolio locks of any of `portfolios`, of any asset.
+        /// - `weight_limit` is the maximum weight the call can consume, used by the compliance checks.
+        ///
+        /// # Errors
+        /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
+        /// - `TooManyPortfolios` if there are more than `MaxReorganizationBatch` portfolios.
+        /// - `NoSuchReorganization` if there's no reorganization for `ca_id`.
+        /// - `ReorganizationClosed` if the reorganization has been closed.
+        /// - `DeadlinePassed` if the deadline of the reorganization has passed.
+        /// - `PortfolioAlreadyReorganized` if the holdings of a portfolio have already been reorganized.
+        /// - `NotTargetedByCA` if the CA does not target the owner of a portfolio.
+        /// - `RecordDateAfterStart` if the record date hasn't passed yet.
+        /// - `BalanceRatioProductOverflowed` if `balance * new` would overflow.
+        /// - `InsufficientCashInLieu` if there's not enough cash left to pay for the fraction.
+        /// - `TooManyPortfolioLocks` if a portfolio has more than `locks` portfolio locks.
+        /// - `UnitsLockedBySettlement` if a portfolio holds units locked by pending settlements.
+        /// - `InputWeightIsLessThanMinimum` if `weight_limit` is less than the weight of the call.
+        #[weight = <T as Config>::ReorgWeightInfo::apply_reorganization_weight_limit(
+            weight_limit,
+            portfolios.len() as u32,
+            *locks,
+        )]
+        pub fn apply_reorganization(
+            origin,
+            ca_id: CAId,
+            portfolios: Vec<PortfolioId>,
+            locks: u32,
+            weight_limit: Option<Weight>,
+        ) -> DispatchResultWithPostInfo {
+            let weight = <T as Config>::ReorgWeightInfo::apply_reorganization(portfolios.len() as u32, locks);
+            let mut weight_meter = Self::ensure_valid_weight_meter(weight, weight_limit.unwrap_or(weight))?;
+            Self::base_apply_reorganization(origin, ca_id, portfolios, locks, &mut weight_meter)
+                .map_err(|error| DispatchErrorWithPostInfo {
+                    post_info: Some(weight_meter.consumed()).into(),
+                    error,
+                })?;
+            Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
+        }
+
+        /// Close the reorganization of the CA identified by `ca_id`,
+        /// unfreezing the asset and unlocking any remaining cash in lieu in the agent's portfolio.
+        ///
+        /// ## Arguments
+        /// - `origin` is a signer that has permissions to act as an agent of `ca_id.asset_id`.
+        /// - `ca_id` identifies the CA with a reorganization to close.
+        ///
+        /// # Errors
+        /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
+        /// - `NoSuchReorganization` if there's no reorganization for `ca_id`.
+        /// - `ReorganizationClosed` if the reorganization has already been closed.
+        /// - `UnauthorizedCustodian` if the caller is not the custodian of the cash portfolio.
+        ///
+        /// # Permissions
+        /// * Asset
+        /// * Portfolio
+        #[weight = <T as Config>::ReorgWeightInfo::close_reorganization()]
+        pub fn close_reorganization(origin, ca_id: CAId) {
+            Self::base_close_reorganization(origin, ca_id)?;
+        }
+    }
+}
+
+decl_event! {
+    pub enum Event {
+        /// A reorganization, with details included,
+        /// was created by the DID (permissioned agent) for the CA identified by `CAId`.
+        ///
+        /// (Agent DID, CA's ID, reorganization details)
+        Created(EventDid, CAId, Reorganization),
+
+        /// The holdings of a portfolio were reorganized.
+        ///
+        /// (Agent DID, CA's ID, portfolio, balance at the record date, new balance, cash paid in lieu)
+        PortfolioReorganized(EventDid, CAId, PortfolioId, Balance, Balance, Balance),
+
+        /// A reorganization was closed.
+        ///
+        /// (Agent DID, CA's ID, unlocked cash in lieu)
+        Closed(EventDid, CAId, Balance),
+
+        /// A reorganization was removed.
+        ///
+        /// (Agent DID, CA's ID)
+        Removed(EventDid, CAId),
+    }
+}
+
+decl_error! {
+    pub enum Error for Module<T: Config> {
+        /// A reorganization was made for a CA which is not of kind `Reorganization`.
+        CANotReorganization,
+        /// A reorganization already exists for this CA.
+        AlreadyExists,
+        /// A side of the ratio is zero, or a split doesn't change the holdings.
+        InvalidRatio,
+        /// An asset can only be converted into another fungible asset.
+        InvalidConversion,
+        /// The price and amount of cash in lieu must be non-zero.
+        InvalidCashInLieu,
+        /// A reorganization doesn't exist for this CA.
+        NoSuchReorganization,
+        /// The reorganization has been closed.
+        ReorganizationClosed,
+        /// The holdings of some portfolios have already been reorganized.
+        ReorganizationStarted,
+        /// The holdings of the portfolio have already been reorganized.
+        PortfolioAlreadyReorganized,
+        /// Multiplication of the balance with the ratio overflowed.
+        BalanceRatioProductOverflowed,
+        /// There's not enough cash left to pay for the fraction of a unit.
+        InsufficientCashInLieu,
+        /// The record date of a reorganization can't be in the past.
+        RecordDatePassed,
+        /// Only fungible assets can be reorganized.
+        NonFungibleAsset,
+        /// The transfers of the asset are already frozen by another reorganization.
+        TransfersAlreadyFrozen,
+        /// The weight limit is less than the minimum weight of the call.
+        InputWeightIsLessThanMinimum,
+        /// The deadline must be after the record date, and at most `MaxReorganizationPeriod` after it.
+        InvalidDeadline,
+        /// The deadline of the reorganization has passed.
+        DeadlinePassed,
+        /// More portfolios than `MaxReorganizationBatch` were given.
+        TooManyPortfolios,
+    }
+}
+
+impl<T: Config> Module<T> {
+    fn base_reorganize(
+        origin: T::RuntimeOrigin,
+        ca_id: CAId,
+        kind: ReorganizationKind,
+        remainder: RemainderPolicy,
+        deadline: Moment,
+    ) -> DispatchResult {
+        let PermissionedCallOriginData {
+            primary_did: agent,
+            secondary_key,
+            ..
+        } = <ExternalAgents<T>>::ensure_agent_asset_perms(origin.clone(), ca_id.asset_id)?;
+
+        // Ensure that `ca_id` exists, that its a reorganization, and that it has a record date.
+        let ca = <CA<T>>::ensure_ca_exists(ca_id)?;
+        ensure!(
+            ca.kind == CAKind::Reorganization,
+            Error::<T>::CANotReorganization
+        );
+        let record_date = ca.record_date.ok_or(ca::Error::<T>::NoRecordDate)?;
+        ensure!(
+            record_date.date >= <Checkpoint<T>>::now_unix(),
+            Error::<T>::RecordDatePassed
+        );
+        Self::ensure_valid_deadline(record_date.date, deadline)?;
+        ensure!(
+            !Reorganizations::contains_key(ca_id),
+            Error::<T>::AlreadyExists
+        );
+        ensure!(
+            <Asset<T>>::try_get_asset_details(&ca_id.asset_id)?
+                .asset_type
+                .is_fungible(),
+            Error::<T>::NonFungibleAsset
+        );
+        // Holdings are frozen from the record date until the reorganization is closed, or the deadline.
+        ensure!(
+            <Asset<T>>::transfers_frozen(ca_id.asset_id).is_none(),
+            Error::<T>::TransfersAlreadyFrozen
+        );
+
+        // Ensure the ratio makes sense for the kind of reorganization.
+        let ratio = kind.ratio();
+        ensure!(ratio.new != 0 && ratio.old != 0, Error::<T>::InvalidRatio);
+        match kind {
+            ReorganizationKind::Split(_) => {
+                ensure!(ratio.new != ratio.old, Error::<T>::InvalidRatio);
+            }
+            ReorganizationKind::Conversion(target, _) => {
+                ensure!(target != ca_id.asset_id, Error::<T>::InvalidConversion);
+                let target_details = <Asset<T>>::try_get_asset_details(&target)?;
+                ensure!(
+                    target_details.asset_type.is_fungible(),
+                    Error::<T>::InvalidConversion
+                );
+                // The agent issues the target asset to holders.
+                <ExternalAgents<T>>::ensure_agent_asset_perms(origin, target)?;
+            }
+        }
+
+        let cash_in_lieu = match remainder {
+            RemainderPolicy::RoundDown => None,
+            RemainderPolicy::CashInLieu {
+                portfolio,
+                currency,
+                per_share,
+                amount,
+            } => {
+                // The asset itself is frozen, so it can't be paid in lieu of fractions.
+                ensure!(
+                    !per_share.is_zero() && !amount.is_zero() && currency != ca_id.asset_id,
+                    Error::<T>::InvalidCashInLieu
+                );
+
+                // Ensure secondary key has perms for `from` + portfolio is valid.
+                let from = PortfolioId {
+                    did: agent,
+                    kind: portfolio.into(),
+                };
+                <Portfolio<T>>::ensure_portfolio_custody_and_permission(
+                    from,
+                    agent,
+                    secondary_key.as_ref(),
+                )?;
+                <Portfolio<T>>::ensure_portfolio_validity(&from)?;
+                <Portfolio<T>>::ensure_sufficient_balance(&from, &currency, amount)?;
+
+                Some(Distribution {
+                    from,
+                    currency,
+                    per_share,
+                    amount,
+                    remaining: amount,
+                    reclaimed: false,
+                    payment_at: record_date.date,
+                    expires_at: None,
+                })
+            }
+        };
+
+        // Lock the cash in lieu in `from`.
+        if let Some(dist) = &cash_in_lieu {
+            <Portfolio<T>>::unchecked_lock_tokens(&dist.from, &dist.currency, dist.amount);
+        }
+
+        // Commit to storage.
+        let reorganization = Reorganization {
+            kind,
+            cash_in_lieu,
+            deadline,
+            portfolios: 0,
+            closed: false,
+        };
+        Reorganizations::insert(ca_id, reorganization);
+        <Asset<T>>::freeze_transfers(ca_id.asset_id, Some((record_date.date, deadline)));
+
+        // Emit event.
+        Self::deposit_event(Event::Created(agent.for_event(), ca_id, reorganization));
+        Ok(())
+    }
+
+    fn base_apply_reorganization(
+        origin: T::RuntimeOrigin,
+        ca_id: CAId,
+        portfolios: Vec<PortfolioId>,
+        locks: u32,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        let agent = <ExternalAgents<T>>::ensure_perms(origin, ca_id.asset_id)?;
+        ensure!(
+            portfolios.len() <= T::MaxReorganizationBatch::get() as usize,
+            Error::<T>::TooManyPortfolios
+        );
+
+        let mut reorganization = Self::ensure_open_reorganization(ca_id)?;
+        let now = <Checkpoint<T>>::now_unix();
+        ensure!(now < reorganization.deadline, Error::<T>::DeadlinePassed);
+
+        // Fetch the CA data + ensure the record date has passed.
+        let ca = <CA<T>>::ensure_ca_exists(ca_id)?;
+        <CA<T>>::ensure_record_date_before_start(&ca, now)?;
+
+        for portfolio in portfolios {
+            Self::reorganize_portfolio(
+                agent,
+                ca_id,
+                &ca,
+                &mut reorganization,
+                portfolio,
+                locks,
+                weight_meter,
+            )?;
+        }
+
+        // Commit `reorganization` change to storage.
+        Reorganizations::insert(ca_id, reorganization);
+        Ok(())
+    }
+
+    /// Reorganizes the holdings of `portfolio` for the CA `ca` identified by `ca_id`,
+    /// updating `reorganization`, which is committed to storage by the caller.
+    fn reorganize_portfolio(
+        agent: IdentityId,
+        ca_id: CAId,
+        ca: &ca::CorporateAction,
+        reorganization: &mut Reorganization,
+        portfolio: PortfolioId,
+        locks: u32,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        ensure!(
+            !PortfolioReorganized::get((ca_id, portfolio)),
+            Error::<T>::PortfolioAlreadyReorganized
+        );
+        <Portfolio<T>>::ensure_portfolio_validity(&portfolio)?;
+        <CA<T>>::ensure_ca_targets(ca, &portfolio.did)?;
+
+        // The asset is frozen since the record date, so the current balance is the balance at the record date.
+        let balance = <Portfolio<T>>::portfolio_asset_balances(&portfolio, &ca_id.asset_id);
+        let ratio = reorganization.kind.ratio();
+        let entitled = ratio
+            .apply(balance)
+            .ok_or(Error::<T>::BalanceRatioProductOverflowed)?;
+
+        // Round down to unit multiple if indivisible.
+        let target = reorganization.kind.target_asset(ca_id.asset_id);
+        let new_balance = if <Asset<T>>::is_divisible(&target) {
+            entitled
+        } else {
+            entitled / ONE_UNIT * ONE_UNIT
+        };
+        let fraction = entitled - new_balance;
+
+        // Compute the cash owed for the fraction, if any.
+        let mut cash = Zero::zero();
+        if let Some(dist) = reorganization.cash_in_lieu.as_mut() {
+            if !fraction.is_zero() {
+                cash = <distribution::Module<T>>::benefit_of(fraction, dist.per_share)?;
+                dist.remaining = dist
+                    .remaining
+                    .checked_sub(cash)
+                    .ok_or(Error::<T>::InsufficientCashInLieu)?;
+            }
+        }
+
+        with_transaction(|| {
+            // Converted units are received from the agent, so they must comply with the target's rules.
+            if target != ca_id.asset_id && !new_balance.is_zero() {
+                <Asset<T>>::validate_transfer_compliance(
+                    target,
+                    agent,
+                    portfolio.did,
+                    weight_meter,
+                )?;
+            }
+            <Asset<T>>::unchecked_reorganize_portfolio(
+                agent,
+                ca_id.asset_id,
+                target,
+                portfolio,
+                new_balance,
+                (ratio.new, ratio.old),
+                locks,
+                weight_meter,
+            )?;
+
+            if let Some(dist) = &reorganization.cash_in_lieu {
+                if !cash.is_zero() {
+                    // Unlock `cash` of `currency` from the agent's portfolio.
+                    <Portfolio<T>>::unlock_tokens(&dist.from, &dist.currency, cash)?;
+
+                    // Round down to unit multiple if indivisible.
+                    let paid = if <Asset<T>>::is_divisible(&dist.currency) {
+                        cash
+                    } else {
+                        cash / ONE_UNIT * ONE_UNIT
+                    };
+                    <Asset<T>>::base_transfer(
+                        dist.from,
+                        portfolio,
+                        dist.currency,
+                        paid,
+                        None,
+                        None,
+                        agent,
+                        weight_meter,
+                    )?;
+                }
+            }
+            Ok::<_, DispatchError>(())
+        })?;
+
+        // Note that the portfolio was reorganized.
+        PortfolioReorganized::insert((ca_id, portfolio), true);
+        reorganization.portfolios = reorganization.portfolios.saturating_add(1);
+
+        // Emit event.
+        Self::deposit_event(Event::PortfolioReorganized(
+            agent.for_event(),
+            ca_id,
+            portfolio,
+            balance,
+            new_balance,
+            cash,
+        ));
+        Ok(())
+    }
+
+    fn base_close_reorganization(origin: T::RuntimeOrigin, ca_id: CAId) -> DispatchResult {
+        let PermissionedCallOriginData {
+            primary_did: agent,
+            secondary_key,
+            ..
+        } = <ExternalAgents<T>>::ensure_agent_asset_perms(origin, ca_id.asset_id)?;
+        let mut reorganization = Self::ensure_open_reorganization(ca_id)?;
+
+        // Unlock the remaining cash in lieu, which must be in the agent's custody.
+        let mut unlocked = Zero::zero();
+        if let Some(dist) = reorganization.cash_in_lieu.as_mut() {
+            <Portfolio<T>>::ensure_portfolio_custody_and_permission(
+                dist.from,
+                agent,
+                secondary_key.as_ref(),
+            )?;
+            <Portfolio<T>>::unlock_tokens(&dist.from, &dist.currency, dist.remaining)?;
+            unlocked = dist.remaining;
+            dist.remaining = Zero::zero();
+            dist.reclaimed = true;
+        }
+
+        reorganization.closed = true;
+        Reorganizations::insert(ca_id, reorganization);
+        <Asset<T>>::freeze_transfers(ca_id.asset_id, None);
+
+        Self::deposit_event(Event::Closed(agent.for_event(), ca_id, unlocked));
+        Ok(())
+    }
+
+    /// Kill the reorganization identified by `ca_id`.
+    ///
+    /// Unlike `close_reorganization`, this won't check permissions,
+    /// and fails if holdings have already been reorganized.
+    pub(crate) fn unverified_remove_reorganization(
+        agent: EventDid,
+        ca_id: CAId,
+        reorganization: &Reorganization,
+    ) -> DispatchResult {
+        Self::ensure_reorganization_not_started(reorganization)?;
+
+        // Unlock and remove chain data.
+        if let Some(dist) = &reorganization.cash_in_lieu {
+            if !dist.reclaimed {
+                <Portfolio<T>>::unlock_tokens(&dist.from, &dist.currency, dist.remaining)?;
+            }
+        }
+        Reorganizations::remove(ca_id);
+        // A closed reorganization no longer freezes the asset, which may be frozen by another one.
+        if !reorganization.closed {
+            <Asset<T>>::freeze_transfers(ca_id.asset_id, None);
+        }
+
+        // Emit event.
+        Self::deposit_event(Event::Removed(agent, ca_id));
+        Ok(())
+    }
+
+    /// Ensure that no holdings have been reorganized yet.
+    pub(crate) fn ensure_reorganization_not_started(
+        reorganization: &Reorganization,
+    ) -> DispatchResult {
+        ensure!(
+            reorganization.portfolios == 0,
+            Error::<T>::ReorganizationStarted
+        );
+        Ok(())
+    }
+
+    /// Move the freeze of the asset of `ca_id` to the new record date of `ca`.
+    ///
+    /// Fails if holdings have already been reorganized, the new record date is in the past,
+    /// or the deadline of `reorganization` isn't valid for the new record date.
+    pub(crate) fn change_reorganization_record_date(
+        ca_id: CAId,
+        ca: &ca::CorporateAction,
+        reorganization: &Reorganization,
+    ) -> DispatchResult {
+        Self::ensure_reorganization_not_started(reorganization)?;
+        let record_date = ca.record_date.ok_or(ca::Error::<T>::NoRecordDate)?;
+        ensure!(
+            record_date.date >= <Checkpoint<T>>::now_unix(),
+            Error::<T>::RecordDatePassed
+        );
+        Self::ensure_valid_deadline(record_date.date, reorganization.deadline)?;
+        if !reorganization.closed {
+            <Asset<T>>::freeze_transfers(
+                ca_id.asset_id,
+                Some((record_date.date, reorganization.deadline)),
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensure `deadline` is after `record_date`, by at most `MaxReorganizationPeriod`.
+    fn ensure_valid_deadline(record_date: Moment, deadline: Moment) -> DispatchResult {
+        ensure!(
+            deadline > record_date && deadline - record_date <= T::MaxReorganizationPeriod::get(),
+            Error::<T>::InvalidDeadline
+        );
+        Ok(())
+    }
+
+    fn ensure_valid_weight_meter(
+        minimum_weight: Weight,
+        weight_limit: Weight,
+    ) -> Result<WeightMeter, DispatchErrorWithPostInfo> {
+        WeightMeter::from_limit(minimum_weight, weight_limit).map_err(|_| {
+            DispatchErrorWithPostInfo {
+                post_info: Some(weight_limit).into(),
+                error: Error::<T>::InputWeightIsLessThanMinimum.into(),
+            }
+        })
+    }
+
+    /// Ensure `ca_id` has a reorganization which hasn't been closed, and return it.
+    fn ensure_open_reorganization(ca_id: CAId) -> Result<Reorganization, DispatchError> {
+        let reorganization = Reorganizations::get(ca_id).ok_or(Error::<T>::NoSuchReorganization)?;
+        ensure!(!reorganization.closed, Error::<T>::ReorganizationClosed);
+        Ok(reorganization)
+    }
+}
@@ -90,6 +90,13 @@ impl<T: Config> Module<T> {
         Ok(new_auth_id)
     }
 
+    /// Revokes the authorization `auth_id` given by `authorizer`, if it hasn't been accepted or removed yet.
+    pub fn revoke_given_auth(authorizer: &IdentityId, auth_id: u64) {
+        if let Ok(target) = <AuthorizationsGiven<T>>::try_get(authorizer, auth_id) {
+            Self::unsafe_remove_auth(&target, auth_id, authorizer, true);
+        }
+    }
+
     /// Removes an authorization.
     pub(crate) fn base_remove_authorization(
         origin: T::RuntimeOrigin,
@@ -404,4 +404,17 @@ benchmarks! {
         assert_ne!(id, Module::<T>::custom_claim_id_seq());
     }
 
+    register_custom_claim_type_with_value {
+        let n in 1 .. T::MaxLen::get() as u32;
+        let v in 1 .. T::MaxLen::get() as u32;
+
+        let id = Module::<T>::custom_claim_id_seq();
+        let caller = user::<T>("caller", 0);
+        let ty = vec![b'X'; n as usize];
+        let value_type = CustomClaimValueType::Enum(vec![vec![b'A'; v as usize]]);
+    }: _(caller.origin, ty, value_type)
+    verify {
+        assert_ne!(id, Module::<T>::custom_claim_id_seq());
+    }
+
 }
@@ -14,15 +14,15 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    Claim1stKey, Claim2ndKey, Claims, CustomClaimIdSequence, CustomClaims, CustomClaimsInverse,
-    DidRecords, Error, Event, Module, ParentDid,
+    Claim1stKey, Claim2ndKey, Claims, CustomClaimIdSequence, CustomClaimValueTypes, CustomClaims,
+    CustomClaimsInverse, DidRecords, Error, Event, Module, ParentDid,
 };
 use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure, StorageDoubleMap, StorageMap, StorageValue,
 };
 use frame_system::ensure_root;
-use pallet_base::{ensure_string_limited, try_next_pre};
+use pallet_base::{ensure_length_ok, ensure_string_limited, try_next_pre};
 
 use polymesh_common_utilities::{
     protocol_fee::ProtocolOp,
@@ -34,7 +34,7 @@ use polymesh_common_utilities::{
 };
 use polymesh_primitives::identity_claim::CustomClaimTypeId;
 use polymesh_primitives::{
-    CddId, Claim, ClaimType, IdentityClaim, IdentityId, Scope, SecondaryKey,
+    CddId, Claim, ClaimType, CustomClaimValueType, IdentityClaim, IdentityId, Scope, SecondaryKey,
 };
 use sp_runtime::traits::{CheckedAdd, SaturatedConversion, Zero};
 use sp_std::prelude::*;
@@ -274,6 +274,13 @@ impl<T: Config> Module<T> {
                 CustomClaims::contains_key(id),
                 Error::<T>::CustomClaimTypeDoesNotExist
             );
+            // Claims carry a value only if the custom claim type has a value type, and it must match.
+            let valid_value = match (CustomClaimValueTypes::get(id), claim.value()) {
+                (Some(value_type), Some(value)) => value_type.accepts(value),
+                (None, None) => true,
+                _ => false,
+            };
+            ensure!(valid_value, Error::<T>::InvalidClaimValue);
         }
         Self::unverified_add_claim_with_scope(target, claim, inner_scope, issuer, expiry);
         Ok(())
@@ -462,6 +469,34 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    pub fn base_register_custom_claim_type_with_value(
+        origin: T::RuntimeOrigin,
+        ty: Vec<u8>,
+        value_type: CustomClaimValueType,
+    ) -> DispatchResult {
+        let did = Self::ensure_perms(origin)?;
+
+        if let CustomClaimValueType::Enum(values) = &value_type {
+            ensure_length_ok::<T>(values.len())?;
+            values
+                .iter()
+                .try_for_each(|v| ensure_string_limited::<T>(v))?;
+            let mut sorted = values.iter().collect::<Vec<_>>();
+            sorted.sort();
+            sorted.dedup();
+            ensure!(
+                !values.is_empty() && sorted.len() == values.len(),
+                Error::<T>::InvalidCustomClaimValueType
+            );
+        }
+
+        let id = Self::unsafe_register_custom_claim_type(ty.clone())?;
+        CustomClaimValueTypes::insert(id, value_type.clone());
+        Self::deposit_event(Event::<T>::CustomClaimTypeAdded(did, id, ty));
+        Self::deposit_event(Event::<T>::CustomClaimValueTypeSet(did, id, value_type));
+        Ok(())
+    }
+
     fn unsafe_register_custom_claim_type(ty: Vec<u8>) -> Result<CustomClaimTypeId, DispatchError> {
         ensure_string_limited::<T>(&ty)?;
         ensure!(
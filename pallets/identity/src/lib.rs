@@ -108,14 +108,14 @@ use polymesh_common_utilities::traits::identity::{
 use polymesh_common_utilities::{SystematicIssuers, GC_DID};
 use polymesh_primitives::{
     storage_migrate_on, storage_migration_ver, AssetPermissions, Authorization, AuthorizationData,
-    AuthorizationType, CddId, Claim, ClaimType, CustomClaimTypeId, DidRecord, ExtrinsicPermissions,
-    IdentityClaim, IdentityId, KeyRecord, Permissions, PortfolioPermissions, Scope, SecondaryKey,
-    Signatory,
+    AuthorizationType, CddId, Claim, ClaimType, CustomClaimTypeId, CustomClaimValueType, DidRecord,
+    ExtrinsicPermissions, IdentityClaim, IdentityId, KeyRecord, Permissions, PortfolioPermissions,
+    Scope, SecondaryKey, Signatory,
 };
 
 pub type Event<T> = polymesh_common_utilities::traits::identity::Event<T>;
 
-storage_migration_ver!(7);
+storage_migration_ver!(8);
 
 decl_storage! {
     trait Store for Module<T: Config> as Identity {
@@ -138,6 +138,9 @@ decl_storage! {
         pub CustomClaimsInverse: map hasher(blake2_128_concat) Vec<u8> => Option<CustomClaimTypeId>;
         /// The next `CustomClaimTypeId`.
         pub CustomClaimIdSequence get(fn custom_claim_id_seq): CustomClaimTypeId;
+        /// CustomClaimTypeId -> The type of the values carried by its claims, if any.
+        pub CustomClaimValueTypes get(fn custom_claim_value_type):
+            map hasher(twox_64_concat) CustomClaimTypeId => Option<CustomClaimValueType>;
 
         /// Map from AccountId to `KeyRecord` that holds the key's type and identity.
         pub KeyRecords get(fn key_records):
@@ -178,7 +181,7 @@ decl_storage! {
         pub CddAuthForPrimaryKeyRotation get(fn cdd_auth_for_primary_key_rotation): bool;
 
         /// Storage version.
-        StorageVersion get(fn storage_version) build(|_| Version::new(7)): Version;
+        StorageVersion get(fn storage_version) build(|_| Version::new(8)): Version;
 
         /// How many "strong" references to the account key.
         ///
@@ -276,6 +279,9 @@ decl_module! {
             storage_migrate_on!(StorageVersion, 7, {
                 ticker_migrations::migrate_to_v7::<T>();
             });
+            storage_migrate_on!(StorageVersion, 8, {
+                ticker_migrations::migrate_to_v8::<T>();
+            });
             Weight::zero()
         }
 
@@ -597,6 +603,18 @@ decl_module! {
         pub fn unlink_child_identity(origin, child_did: IdentityId) {
             Self::base_unlink_child_identity(origin, child_did)?;
         }
+
+        /// Register custom claim type, whose claims carry values of `value_type`.
+        ///
+        /// # Errors
+        /// * `CustomClaimTypeAlreadyExists` The type that is being registered already exists.
+        /// * `CounterOverflow` CustomClaimTypeId has overflowed.
+        /// * `TooLong` The type being registered, or one of its enum values, is too long.
+        /// * `InvalidCustomClaimValueType` An enum value type has no values or duplicated values.
+        #[weight = <T as Config>::WeightInfo::register_custom_claim_type_with_value_full(&ty, &value_type)]
+        pub fn register_custom_claim_type_with_value(origin, ty: Vec<u8>, value_type: CustomClaimValueType) {
+            Self::base_register_custom_claim_type_with_value(origin, ty, value_type)?;
+        }
     }
 }
 
@@ -669,6 +687,10 @@ decl_error! {
         ExceptNotAllowedForExtrinsics,
         /// Maximum number of given authorizations was exceeded.
         ExceededNumberOfGivenAuths,
+        /// An enum claim value type has no values or duplicated values.
+        InvalidCustomClaimValueType,
+        /// The claim value doesn't match the value type of the custom claim type, or is missing.
+        InvalidClaimValue,
     }
 }
 
@@ -1,4 +1,5 @@
 use frame_support::storage::migration::move_prefix;
+use frame_support::storage::unhashed;
 use sp_runtime::runtime_logger::RuntimeLogger;
 
 use super::*;
@@ -145,8 +146,10 @@ impl From<v6::IdentityClaim> for IdentityClaim {
     }
 }
 
-impl<T> From<v6::AuthorizationData<T>> for AuthorizationData<T> {
+impl<T> From<v6::AuthorizationData<T>> for v7::AuthorizationData<T> {
     fn from(v6_auth_data: v6::AuthorizationData<T>) -> Self {
+        use v7::AuthorizationData;
+
         match v6_auth_data {
             v6::AuthorizationData::AttestPrimaryKeyRotation(did) => {
                 AuthorizationData::AttestPrimaryKeyRotation(did)
@@ -180,9 +183,9 @@ impl<T> From<v6::AuthorizationData<T>> for AuthorizationData<T> {
     }
 }
 
-impl<T, S> From<v6::Authorization<T, S>> for Authorization<T, S> {
+impl<T, S> From<v6::Authorization<T, S>> for v7::Authorization<T, S> {
     fn from(v6_auth: v6::Authorization<T, S>) -> Self {
-        Authorization {
+        v7::Authorization {
             authorization_data: v6_auth.authorization_data.into(),
             authorized_by: v6_auth.authorized_by,
             expiry: v6_auth.expiry,
@@ -193,6 +196,8 @@ impl<T, S> From<v6::Authorization<T, S>> for Authorization<T, S> {
 }
 
 pub(crate) fn migrate_to_v7<T: Config>() {
+    use frame_support::storage::StorageDoubleMap;
+
     RuntimeLogger::init();
 
     // Removes all elements in the old storage and inserts it in the new storage
@@ -232,11 +237,111 @@ pub(crate) fn migrate_to_v7<T: Config>() {
     });
     log::info!("Migrated {:?} Identity.KeyRecords entries.", count);
 
+    // Authorizations are stored with the v7 layout, `migrate_to_v8` then updates them.
     let mut count = 0;
     log::info!("Updating types for the Authorizations storage");
     v6::Authorizations::<T>::drain().for_each(|(acc, n, auth)| {
-        Authorizations::<T>::insert(acc, n, Authorization::from(auth));
+        let auth: v7::Authorization<T::AccountId, T::Moment> = auth.into();
+        unhashed::put(&Authorizations::<T>::hashed_key_for(acc, n), &auth);
         count += 1;
     });
     log::info!("Migrated {:?} Identity.Authorizations entries.", count);
 }
+
+mod v7 {
+    use scale_info::TypeInfo;
+
+    use super::*;
+    use polymesh_primitives::agent::AgentGroup;
+    use polymesh_primitives::asset::{AssetId, IdentityRecoveryId};
+    use polymesh_primitives::{Balance, PortfolioId, Ticker};
+
+    #[derive(Encode, Decode, TypeInfo)]
+    pub struct Authorization<AccountId, Moment> {
+        pub authorization_data: AuthorizationData<AccountId>,
+        pub authorized_by: IdentityId,
+        pub expiry: Option<Moment>,
+        pub auth_id: u64,
+        pub count: u32,
+    }
+
+    #[derive(Encode, Decode, TypeInfo)]
+    pub enum AuthorizationData<AccountId> {
+        AttestPrimaryKeyRotation(IdentityId),
+        RotatePrimaryKey,
+        TransferTicker(Ticker),
+        AddMultiSigSigner(AccountId),
+        TransferAssetOwnership(AssetId),
+        JoinIdentity(Permissions),
+        PortfolioCustody(PortfolioId),
+        BecomeAgent(AssetId, AgentGroup),
+        AddRelayerPayingKey(AccountId, AccountId, Balance),
+        RotatePrimaryKeyToSecondary(Permissions),
+        ConfirmIdentityRecovery(IdentityRecoveryId, AssetId),
+    }
+}
+
+impl<T> From<v7::AuthorizationData<T>> for AuthorizationData<T> {
+    fn from(v7_auth_data: v7::AuthorizationData<T>) -> Self {
+        match v7_auth_data {
+            v7::AuthorizationData::AttestPrimaryKeyRotation(did) => {
+                AuthorizationData::AttestPrimaryKeyRotation(did)
+            }
+            v7::AuthorizationData::RotatePrimaryKey => AuthorizationData::RotatePrimaryKey,
+            v7::AuthorizationData::TransferTicker(ticker) => {
+                AuthorizationData::TransferTicker(ticker)
+            }
+            v7::AuthorizationData::AddMultiSigSigner(acc) => {
+                AuthorizationData::AddMultiSigSigner(acc)
+            }
+            v7::AuthorizationData::TransferAssetOwnership(asset_id) => {
+                AuthorizationData::TransferAssetOwnership(asset_id)
+            }
+            v7::AuthorizationData::JoinIdentity(perms) => AuthorizationData::JoinIdentity(perms),
+            v7::AuthorizationData::PortfolioCustody(portfolio_id) => {
+                AuthorizationData::PortfolioCustody(portfolio_id)
+            }
+            v7::AuthorizationData::BecomeAgent(asset_id, ag) => {
+                AuthorizationData::BecomeAgent(asset_id, ag)
+            }
+            // Subsidies authorized before scopes existed are unscoped.
+            v7::AuthorizationData::AddRelayerPayingKey(acc1, acc2, balance) => {
+                AuthorizationData::AddRelayerPayingKey(acc1, acc2, balance, None)
+            }
+            v7::AuthorizationData::RotatePrimaryKeyToSecondary(perms) => {
+                AuthorizationData::RotatePrimaryKeyToSecondary(perms)
+            }
+            v7::AuthorizationData::ConfirmIdentityRecovery(recovery_id, asset_id) => {
+                AuthorizationData::ConfirmIdentityRecovery(recovery_id, asset_id)
+            }
+        }
+    }
+}
+
+impl<T, S> From<v7::Authorization<T, S>> for Authorization<T, S> {
+    fn from(v7_auth: v7::Authorization<T, S>) -> Self {
+        Authorization {
+            authorization_data: v7_auth.authorization_data.into(),
+            authorized_by: v7_auth.authorized_by,
+            expiry: v7_auth.expiry,
+            auth_id: v7_auth.auth_id,
+            count: v7_auth.count,
+        }
+    }
+}
+
+pub(crate) fn migrate_to_v8<T: Config>() {
+    use frame_support::storage::IterableStorageDoubleMap;
+
+    RuntimeLogger::init();
+
+    let mut count = 0;
+    log::info!("Updating types for the Authorizations storage");
+    Authorizations::<T>::translate::<v7::Authorization<T::AccountId, T::Moment>, _>(
+        |_, _, auth| {
+            count += 1;
+            Some(auth.into())
+        },
+    );
+    log::info!("Migrated {:?} Identity.Authorizations entries.", count);
+}
@@ -19,6 +19,7 @@ use frame_system::RawOrigin;
 
 use polymesh_common_utilities::benchs::{AccountIdOf, User, UserBuilder};
 use polymesh_common_utilities::TestUtilsFn;
+use polymesh_primitives::{ExtrinsicName, PalletName};
 
 use crate::*;
 
@@ -198,6 +199,50 @@ fn generate_multisig_and_create_proposal<T: Config + TestUtilsFn<AccountIdOf<T>>
     ))
 }
 
+fn generate_call_policies<T: Config>(n: u32) -> BoundedVec<CallPolicy, T::MaxCallPolicies> {
+    (0..n)
+        .map(|i| CallPolicy {
+            pallet_name: PalletName::generate(i as u64),
+            extrinsic_name: Some(ExtrinsicName::generate(i as u64)),
+            min_value: Some(1),
+            sigs_required: 2,
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+/// Creates a 2 out of 2 multisig and a proposal approved by both signers that can be executed in 1000ms.
+fn generate_scheduled_proposal<T: Config + TestUtilsFn<AccountIdOf<T>>>(
+) -> ProposalSetupResult<T, T::AccountId, <T as Config>::Proposal, T::MaxSigners> {
+    let (alice, multisig, signers, users, proposal_id, proposal, ephemeral_multisig) =
+        generate_multisig_and_proposal_for_alice::<T>(2, 2).unwrap();
+    MultiSig::<T>::create_scheduled_proposal(
+        users[0].origin().into(),
+        multisig.clone(),
+        proposal.clone(),
+        None,
+        Timestamp::<T>::get() + 1000u32.into(),
+    )
+    .unwrap();
+    MultiSig::<T>::approve(
+        users[1].origin().into(),
+        multisig.clone(),
+        proposal_id,
+        None,
+    )
+    .unwrap();
+    (
+        alice,
+        multisig,
+        signers,
+        users,
+        proposal_id,
+        proposal,
+        ephemeral_multisig,
+    )
+}
+
 macro_rules! assert_proposal_created {
     ($proposal_id:ident, $multisig:ident) => {
         assert!($proposal_id < MultiSig::<T>::next_proposal_id($multisig));
@@ -374,4 +419,100 @@ benchmarks! {
         let (alice, multisig, _, _, multisig_origin) = generate_multisig_for_alice::<T>(2, 2).unwrap();
         init_admin(&multisig, &alice);
     }: _(multisig_origin)
+
+    set_signer_weights {
+        // Number of signers
+        let n in 1 .. T::MaxSigners::get() as u32;
+
+        let (_, multisig, signers, _, multisig_origin) = generate_multisig_for_alice::<T>(n, n).unwrap();
+        let weights: BoundedVec<_, T::MaxSigners> = signers
+            .iter()
+            .map(|signer| (signer.clone(), 2))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+    }: _(multisig_origin, weights)
+    verify {
+        assert_eq!(MultiSig::<T>::total_signer_weight(&multisig), 2 * n as u64);
+    }
+
+    set_signer_weights_via_admin {
+        // Number of signers
+        let n in 1 .. T::MaxSigners::get() as u32;
+
+        let (alice, multisig, signers, _, _) = generate_multisig_for_alice::<T>(n, n).unwrap();
+        init_admin(&multisig, &alice);
+        let weights: BoundedVec<_, T::MaxSigners> = signers
+            .iter()
+            .map(|signer| (signer.clone(), 2))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        let ephemeral_multisig = multisig.clone();
+    }: _(alice.origin(), ephemeral_multisig, weights)
+    verify {
+        assert_eq!(MultiSig::<T>::total_signer_weight(&multisig), 2 * n as u64);
+    }
+
+    set_call_policies {
+        // Number of policies
+        let p in 0 .. T::MaxCallPolicies::get();
+
+        let (_, multisig, _, _, multisig_origin) = generate_multisig_for_alice::<T>(2, 1).unwrap();
+        let policies = generate_call_policies::<T>(p);
+    }: _(multisig_origin, policies)
+    verify {
+        assert_eq!(MultiSig::<T>::call_policies(&multisig).len(), p as usize);
+    }
+
+    set_call_policies_via_admin {
+        // Number of policies
+        let p in 0 .. T::MaxCallPolicies::get();
+
+        let (alice, multisig, _, _, _) = generate_multisig_for_alice::<T>(2, 1).unwrap();
+        init_admin(&multisig, &alice);
+        let policies = generate_call_policies::<T>(p);
+        let ephemeral_multisig = multisig.clone();
+    }: _(alice.origin(), ephemeral_multisig, policies)
+    verify {
+        assert_eq!(MultiSig::<T>::call_policies(&multisig).len(), p as usize);
+    }
+
+    create_scheduled_proposal {
+        let (_, multisig, _, users, proposal_id, proposal, ephemeral_multisig) = generate_multisig_and_proposal_for_alice::<T>(3, 3).unwrap();
+        let execute_after = Timestamp::<T>::get() + 1000u32.into();
+    }: _(users[0].origin(), ephemeral_multisig, proposal, Some(1337u32.into()), execute_after)
+    verify {
+        assert_proposal_created!(proposal_id, multisig);
+        assert_eq!(ProposalExecuteAfter::<T>::get(&multisig, proposal_id), Some(execute_after));
+    }
+
+    amend_proposal {
+        let (_, multisig, signers, users, proposal_id, proposal, ephemeral_multisig) = generate_multisig_and_create_proposal::<T>(3, 3).unwrap();
+        MultiSig::<T>::approve(users[1].origin().into(), multisig.clone(), proposal_id, None).unwrap();
+    }: _(users[0].origin(), ephemeral_multisig, proposal_id, proposal)
+    verify {
+        assert!(!MultiSig::<T>::votes((multisig, proposal_id), &signers[1]));
+    }
+
+    cancel_proposal {
+        let (_, multisig, _, users, proposal_id, _, ephemeral_multisig) = generate_multisig_and_create_proposal::<T>(3, 3).unwrap();
+    }: _(users[0].origin(), ephemeral_multisig, proposal_id)
+    verify {
+        assert_eq!(ProposalStates::<T>::get(&multisig, proposal_id), Some(ProposalState::Cancelled));
+    }
+
+    veto {
+        let (_, multisig, _, users, proposal_id, _, ephemeral_multisig) = generate_scheduled_proposal::<T>();
+    }: _(users[1].origin(), ephemeral_multisig, proposal_id)
+    verify {
+        assert_eq!(ProposalStates::<T>::get(&multisig, proposal_id), Some(ProposalState::Rejected));
+    }
+
+    execute_scheduled_proposal {
+        let (_, multisig, _, users, proposal_id, _, _) = generate_scheduled_proposal::<T>();
+        Timestamp::<T>::set_timestamp(Timestamp::<T>::get() + 1000u32.into());
+    }: {
+        assert!(MultiSig::<T>::base_execute_scheduled_proposal(&multisig, users[1].account(), proposal_id, Weight::MAX).is_ok());
+    }
 }
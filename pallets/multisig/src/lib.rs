@@ -32,6 +32,11 @@
 //! - **multisig**: a special type of account that can do transaction only if at least `n` of its `m`
 //! signers approve.
 //! - **proposal**: a general transaction that the multisig can vote on and accept.
+//! - **signer weight**: the number of votes counted for a signer. Defaults to one.
+//! - **call policy**: a rule matching proposals by pallet, extrinsic and value that overrides
+//! the number of signatures required to execute them.
+//! - **cooling-off period**: the time between the approval of a scheduled proposal and its
+//! `execute_after` time, during which any signer can veto the proposal.
 //!
 //! ## Interface
 //!
@@ -41,6 +46,12 @@
 //! - `create_proposal` - Creates a multisig proposal given the signer's account key.
 //! - `approve` - Approves a multisig proposal given the signer's account key.
 //! - `reject` - Rejects a multisig proposal using the caller's secondary key (`AccountId`).
+//! - `create_scheduled_proposal` - Creates a multisig proposal that can only be executed after
+//! a cooling-off period.
+//! - `amend_proposal` - Replaces the call of a proposal and resets its votes.
+//! - `cancel_proposal` - Cancels a proposal before other signers have voted on it.
+//! - `veto` - Vetoes an approved proposal during its cooling-off period.
+//! - `execute_scheduled_proposal` - Executes an approved proposal after its cooling-off period.
 //! - `accept_multisig_signer` - Accepts a multisig signer authorization given the signer's
 //! account key.
 //! - `add_multisig_signer` - Adds a signer to the multisig.
@@ -50,6 +61,9 @@
 //! - `remove_multisig_signers_via_admin` - Removes a signer from the multisig when called by the
 //! admin of the multisig.
 //! - `change_sigs_required` - Changes the number of signers required to execute a transaction.
+//! - `set_signer_weights` - Changes the number of votes counted for the approvals of a signer.
+//! - `set_call_policies` - Sets the rules overriding the number of signatures required for
+//! the proposals of a call.
 //!
 //! ### Other Public Functions
 //!
@@ -76,11 +90,14 @@ use frame_support::dispatch::{
     Weight,
 };
 use frame_support::ensure;
-use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::storage::{
+    with_transaction, IterableStorageDoubleMap, IterableStorageMap, TransactionOutcome,
+};
 use frame_support::traits::{Get, GetCallMetadata, IsSubType, UnfilteredDispatchable};
 use frame_support::BoundedVec;
 use frame_system::ensure_signed;
 use sp_runtime::traits::{Dispatchable, Hash};
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::convert::TryFrom;
 use sp_std::prelude::*;
 
@@ -88,17 +105,19 @@ use pallet_identity::PermissionedCallOriginData;
 use pallet_permissions::with_call_metadata;
 pub use polymesh_common_utilities::multisig::{MultiSigSubTrait, WeightInfo};
 use polymesh_common_utilities::traits::identity::Config as IdentityConfig;
-use polymesh_primitives::multisig::{ProposalState, ProposalVoteCount};
+use polymesh_primitives::multisig::{
+    CallPolicy, ProposalDetails, ProposalState, ProposalVoteCount,
+};
 use polymesh_primitives::{
-    extract_auth, storage_migrate_on, storage_migration_ver, AuthorizationData, IdentityId,
-    KeyRecord, Permissions, Signatory,
+    extract_auth, storage_migrate_on, storage_migration_ver, AuthorizationData, Balance,
+    IdentityId, KeyRecord, Permissions, Signatory,
 };
 //use polymesh_runtime_common::RocksDbWeight as DbWeight;
 use frame_support::weights::constants::RocksDbWeight as DbWeight;
 
 type IdentityPallet<T> = pallet_identity::Module<T>;
 
-storage_migration_ver!(3);
+storage_migration_ver!(4);
 
 fn add_base_weight(base_weight: Weight, post_info: &mut PostDispatchInfo) {
     if let Some(actual_weight) = &mut post_info.actual_weight {
@@ -124,6 +143,27 @@ fn with_base_weight(
     }
 }
 
+/// Maximum depth of nested calls inspected when matching call policies.
+pub const MAX_NESTED_CALL_DEPTH: u32 = 8;
+
+/// Returns the value moved by a multisig proposal, used to match [`CallPolicy::min_value`].
+pub trait CallValue<Call> {
+    /// Returns the value moved by `call` or `None` if the call doesn't move any value.
+    fn call_value(call: &Call) -> Option<Balance>;
+
+    /// Returns the calls dispatched by `call` (e.g. the calls of a batch), which must also be
+    /// matched against the call policies.
+    fn nested_calls(_call: &Call) -> Vec<&Call> {
+        Vec::new()
+    }
+}
+
+impl<Call> CallValue<Call> for () {
+    fn call_value(_call: &Call) -> Option<Balance> {
+        None
+    }
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -154,6 +194,13 @@ pub mod pallet {
         /// Maximum number of signers that can be added/removed in one call.
         #[pallet::constant]
         type MaxSigners: Get<u32>;
+
+        /// Maximum number of call policies of a multisig.
+        #[pallet::constant]
+        type MaxCallPolicies: Get<u32>;
+
+        /// Returns the value moved by a proposal, used to match call policies.
+        type CallValue: CallValue<<Self as Config>::Proposal>;
     }
 
     #[pallet::pallet]
@@ -187,6 +234,9 @@ pub mod pallet {
             storage_migrate_on!(StorageVersion<T>, 3, {
                 migration::migrate_to_v3::<T>(&mut weight);
             });
+            storage_migrate_on!(StorageVersion<T>, 4, {
+                migration::migrate_to_v4::<T>(&mut weight);
+            });
             weight
         }
     }
@@ -548,6 +598,204 @@ pub mod pallet {
             });
             Ok(().into())
         }
+
+        /// Sets the weight of signers of the multisig.  This must be called by the multisig itself.
+        ///
+        /// # Arguments
+        /// * `weights` - The signers and their new weights.  A weight of one removes any custom weight.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_signer_weights(weights.len() as u32))]
+        pub fn set_signer_weights(
+            origin: OriginFor<T>,
+            weights: BoundedVec<(T::AccountId, u64), T::MaxSigners>,
+        ) -> DispatchResultWithPostInfo {
+            let multisig = ensure_signed(origin)?;
+            Self::base_set_signer_weights(None, multisig, weights)?;
+            Ok(().into())
+        }
+
+        /// Sets the weight of signers of the multisig.  This must be called by the admin of the multisig.
+        ///
+        /// # Arguments
+        /// * `multisig` - Address of the multisig.
+        /// * `weights` - The signers and their new weights.  A weight of one removes any custom weight.
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_signer_weights_via_admin(weights.len() as u32))]
+        pub fn set_signer_weights_via_admin(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            weights: BoundedVec<(T::AccountId, u64), T::MaxSigners>,
+        ) -> DispatchResultWithPostInfo {
+            let caller_did = Self::ensure_ms_admin(origin, &multisig)?;
+            Self::base_set_signer_weights(Some(caller_did), multisig, weights)?;
+            Ok(().into())
+        }
+
+        /// Replaces the call policies of the multisig.  This must be called by the multisig itself.
+        ///
+        /// # Arguments
+        /// * `policies` - The new call policies.  An empty list removes all policies.
+        ///
+        /// The number of signatures required by a proposal is the highest `sigs_required` of all
+        /// policies matching the proposal, or the default number of signatures if there's no match.
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_call_policies(policies.len() as u32))]
+        pub fn set_call_policies(
+            origin: OriginFor<T>,
+            policies: BoundedVec<CallPolicy, T::MaxCallPolicies>,
+        ) -> DispatchResultWithPostInfo {
+            let multisig = ensure_signed(origin)?;
+            Self::base_set_call_policies(None, multisig, policies)?;
+            Ok(().into())
+        }
+
+        /// Replaces the call policies of the multisig.  This must be called by the admin of the multisig.
+        ///
+        /// # Arguments
+        /// * `multisig` - Address of the multisig.
+        /// * `policies` - The new call policies.  An empty list removes all policies.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_call_policies_via_admin(policies.len() as u32))]
+        pub fn set_call_policies_via_admin(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            policies: BoundedVec<CallPolicy, T::MaxCallPolicies>,
+        ) -> DispatchResultWithPostInfo {
+            let caller_did = Self::ensure_ms_admin(origin, &multisig)?;
+            Self::base_set_call_policies(Some(caller_did), multisig, policies)?;
+            Ok(().into())
+        }
+
+        /// Creates a multisig proposal that can only be executed after `execute_after`.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal` - Proposal to be voted on.
+        /// * `expiry` - Optional proposal expiry time.
+        /// * `execute_after` - The time after which the approved proposal can be executed.
+        ///
+        /// Once approved, any signer can veto the proposal until `execute_after`.
+        #[pallet::call_index(22)]
+        #[pallet::weight({
+          <T as Config>::WeightInfo::create_scheduled_proposal()
+            .saturating_add(<T as Config>::WeightInfo::execute_proposal())
+            .saturating_add(proposal.get_dispatch_info().weight)
+        })]
+        pub fn create_scheduled_proposal(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            proposal: Box<<T as Config>::Proposal>,
+            expiry: Option<T::Moment>,
+            execute_after: T::Moment,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            with_base_weight(
+                <T as Config>::WeightInfo::create_scheduled_proposal(),
+                || {
+                    Self::ensure_valid_execute_after(&execute_after)?;
+                    ProposalExecuteAfter::<T>::insert(
+                        &multisig,
+                        Self::next_proposal_id(&multisig),
+                        execute_after,
+                    );
+                    Self::base_create_proposal(&multisig, signer, &proposal, expiry)
+                },
+            )
+        }
+
+        /// Replaces the call of a proposal and resets its votes.  This must be called by the proposer.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Id of the proposal to amend.
+        /// * `proposal` - The new call of the proposal.
+        ///
+        /// The proposer's approval is cast again, so the proposal of a 1 out of `m` multisig
+        /// will be immediately executed.
+        #[pallet::call_index(23)]
+        #[pallet::weight({
+          <T as Config>::WeightInfo::amend_proposal()
+            .saturating_add(<T as Config>::WeightInfo::execute_proposal())
+            .saturating_add(proposal.get_dispatch_info().weight)
+        })]
+        pub fn amend_proposal(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+            proposal: Box<<T as Config>::Proposal>,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            with_base_weight(<T as Config>::WeightInfo::amend_proposal(), || {
+                Self::base_amend_proposal(&multisig, signer, proposal_id, &proposal)
+            })
+        }
+
+        /// Cancels a proposal.  This must be called by the proposer before any other signer has voted.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Id of the proposal to cancel.
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_proposal())]
+        pub fn cancel_proposal(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            Self::base_cancel_proposal(&multisig, signer, proposal_id)?;
+            Ok(().into())
+        }
+
+        /// Vetoes an approved proposal during its cooling-off period.  Can be called by any signer.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Id of the proposal to veto.
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::veto())]
+        pub fn veto(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            Self::base_veto(&multisig, signer, proposal_id)?;
+            Ok(().into())
+        }
+
+        /// Executes an approved proposal once its cooling-off period is over.  Can be called by any signer.
+        ///
+        /// # Arguments
+        /// * `multisig` - MultiSig address.
+        /// * `proposal_id` - Id of the proposal to execute.
+        /// * `max_weight` - The maximum weight to execute the proposal.
+        #[pallet::call_index(26)]
+        #[pallet::weight({
+          <T as Config>::WeightInfo::execute_scheduled_proposal()
+            .saturating_add(<T as Config>::WeightInfo::execute_proposal())
+            .saturating_add(<T as Config>::WeightInfo::default_max_weight(max_weight))
+        })]
+        pub fn execute_scheduled_proposal(
+            origin: OriginFor<T>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+            max_weight: Option<Weight>,
+        ) -> DispatchResultWithPostInfo {
+            let max_weight = <T as Config>::WeightInfo::default_max_weight(&max_weight);
+            let signer = ensure_signed(origin)?;
+            with_base_weight(
+                <T as Config>::WeightInfo::execute_scheduled_proposal(),
+                || {
+                    Self::base_execute_scheduled_proposal(
+                        &multisig,
+                        signer,
+                        proposal_id,
+                        max_weight,
+                    )
+                },
+            )
+        }
     }
 
     #[pallet::event]
@@ -642,6 +890,44 @@ pub mod pallet {
             multisig: T::AccountId,
             paying_did: IdentityId,
         },
+        /// A Multisig has changed the weights of its signers.
+        MultiSigSignerWeightsChanged {
+            caller_did: IdentityId,
+            multisig: T::AccountId,
+            weights: BoundedVec<(T::AccountId, u64), T::MaxSigners>,
+        },
+        /// A Multisig has replaced its call policies.
+        MultiSigCallPoliciesChanged {
+            caller_did: IdentityId,
+            multisig: T::AccountId,
+            policies: BoundedVec<CallPolicy, T::MaxCallPolicies>,
+        },
+        /// The call of a Multisig proposal has been replaced and its votes have been reset.
+        ProposalAmended {
+            caller_did: Option<IdentityId>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+        },
+        /// A Multisig proposal has been cancelled by its proposer.
+        ProposalCancelled {
+            caller_did: Option<IdentityId>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+        },
+        /// A Multisig proposal has been approved and can be executed after `execute_after`.
+        ProposalScheduled {
+            caller_did: Option<IdentityId>,
+            multisig: T::AccountId,
+            proposal_id: u64,
+            execute_after: T::Moment,
+        },
+        /// A signer has vetoed an approved Multisig proposal.
+        ProposalVetoed {
+            caller_did: Option<IdentityId>,
+            multisig: T::AccountId,
+            signer: T::AccountId,
+            proposal_id: u64,
+        },
     }
 
     /// Multisig module errors.
@@ -698,6 +984,28 @@ pub mod pallet {
         InvalidatedProposal,
         /// Multisig has no admin.
         AdminNotFound,
+        /// The weight of a signer must be greater than zero.
+        InvalidSignerWeight,
+        /// The number of signatures required by a call policy must be greater than zero.
+        InvalidCallPolicy,
+        /// Only the proposer can amend or cancel a proposal.
+        NotTheProposer,
+        /// The proposal can't be cancelled after other signers have voted on it.
+        ProposalHasVotes,
+        /// Proposal was cancelled earlier
+        ProposalAlreadyCancelled,
+        /// Proposal was approved earlier and is waiting for its cooling-off period to end.
+        ProposalAlreadyApproved,
+        /// The proposal is not waiting for its cooling-off period to end.
+        ProposalNotApproved,
+        /// The execution time of a proposal must be in the future.
+        InvalidExecuteAfter,
+        /// The cooling-off period of the proposal is not over.
+        CoolingOffPeriodNotOver,
+        /// The cooling-off period of the proposal is over.
+        CoolingOffPeriodOver,
+        /// The proposal doesn't have enough approvals to be executed.
+        NotEnoughApprovals,
     }
 
     /// Nonce to ensure unique MultiSig addresses are generated; starts from 1.
@@ -710,6 +1018,27 @@ pub mod pallet {
     pub type MultiSigSigners<T: Config> =
         StorageDoubleMap<_, Identity, T::AccountId, Twox64Concat, T::AccountId, bool, ValueQuery>;
 
+    /// Weight of the votes of a signer.  Signers without an entry have a weight of one.
+    ///
+    /// multisig -> signer => Option<weight>.
+    #[pallet::storage]
+    #[pallet::getter(fn signer_weights)]
+    pub type SignerWeights<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Twox64Concat, T::AccountId, u64, OptionQuery>;
+
+    /// Rules overriding the number of signatures required for the proposals matching them.
+    ///
+    /// multisig => call policies.
+    #[pallet::storage]
+    #[pallet::getter(fn call_policies)]
+    pub type CallPolicies<T: Config> = StorageMap<
+        _,
+        Identity,
+        T::AccountId,
+        BoundedVec<CallPolicy, T::MaxCallPolicies>,
+        ValueQuery,
+    >;
+
     /// Number of approved/accepted signers of a multisig.
     #[pallet::storage]
     #[pallet::getter(fn number_of_signers)]
@@ -787,6 +1116,22 @@ pub mod pallet {
         ProposalState<T::Moment>,
     >;
 
+    /// The account that created a multisig proposal.
+    ///
+    /// multisig -> proposal id => Option<proposer>.
+    #[pallet::storage]
+    #[pallet::getter(fn proposers)]
+    pub type Proposers<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, u64, T::AccountId>;
+
+    /// The time after which an approved multisig proposal can be executed.
+    ///
+    /// multisig -> proposal id => Option<execute after>.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_execute_after)]
+    pub type ProposalExecuteAfter<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, u64, T::Moment>;
+
     /// Proposal execution reentry guard.
     #[pallet::storage]
     #[pallet::getter(fn execution_reentry)]
@@ -826,7 +1171,7 @@ pub mod pallet {
         fn build(&self) {
             MultiSigNonce::<T>::put(1);
             TransactionVersion::<T>::put(0);
-            StorageVersion::<T>::put(Version::new(3));
+            StorageVersion::<T>::put(Version::new(4));
         }
     }
 }
@@ -892,12 +1237,93 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn ensure_sigs_in_bounds(num_signers: u64, required: u64) -> DispatchResult {
+    /// Ensures `required` is greater than zero and not greater than the total weight of the signers.
+    fn ensure_sigs_in_bounds(total_weight: u64, required: u64) -> DispatchResult {
         ensure!(required > 0, Error::<T>::RequiredSignersIsZero);
-        ensure!(num_signers >= required, Error::<T>::NotEnoughSigners);
+        ensure!(total_weight >= required, Error::<T>::NotEnoughSigners);
         Ok(())
     }
 
+    /// Returns the weight of the votes of `signer`.
+    pub fn signer_weight(multisig: &T::AccountId, signer: &T::AccountId) -> u64 {
+        SignerWeights::<T>::get(multisig, signer).unwrap_or(1)
+    }
+
+    /// Returns the sum of the weights of all signers of the multisig.
+    pub fn total_signer_weight(multisig: &T::AccountId) -> u64 {
+        SignerWeights::<T>::iter_prefix_values(multisig)
+            .fold(NumberOfSigners::<T>::get(multisig), |total, weight| {
+                total.saturating_add(weight.saturating_sub(1))
+            })
+    }
+
+    /// Returns the highest number of signatures that can be required by a proposal of the multisig.
+    fn max_sigs_required(multisig: &T::AccountId) -> u64 {
+        Self::call_policies(multisig)
+            .iter()
+            .map(|policy| policy.sigs_required)
+            .fold(Self::ms_signs_required(multisig), u64::max)
+    }
+
+    /// Returns the number of signatures required to execute `proposal`.
+    ///
+    /// The proposal and all the calls nested in it (e.g. the calls of a batch) are matched against
+    /// the call policies, and the strictest requirement is returned. A call that isn't wrapping other
+    /// calls and doesn't match any policy requires [`MultiSigSignsRequired`] signatures, as does a
+    /// proposal nesting calls deeper than [`MAX_NESTED_CALL_DEPTH`].
+    pub fn proposal_sigs_required(
+        multisig: &T::AccountId,
+        proposal: &<T as Config>::Proposal,
+    ) -> u64 {
+        let default_sigs_required = Self::ms_signs_required(multisig);
+        let policies = Self::call_policies(multisig);
+        if policies.is_empty() {
+            return default_sigs_required;
+        }
+        Self::call_sigs_required(&policies, default_sigs_required, proposal, 0)
+    }
+
+    /// Returns the strictest number of signatures required by `call` and its nested calls.
+    fn call_sigs_required(
+        policies: &[CallPolicy],
+        default_sigs_required: u64,
+        call: &<T as Config>::Proposal,
+        depth: u32,
+    ) -> u64 {
+        let nested_calls = T::CallValue::nested_calls(call);
+        if !nested_calls.is_empty() && depth >= MAX_NESTED_CALL_DEPTH {
+            return policies
+                .iter()
+                .map(|policy| policy.sigs_required)
+                .fold(default_sigs_required, u64::max);
+        }
+
+        let call_metadata = call.get_call_metadata();
+        let value = T::CallValue::call_value(call);
+        let policy_sigs_required = policies
+            .iter()
+            .filter(|policy| {
+                policy.matches(
+                    call_metadata.pallet_name,
+                    call_metadata.function_name,
+                    value,
+                )
+            })
+            .map(|policy| policy.sigs_required)
+            .max();
+
+        if nested_calls.is_empty() {
+            return policy_sigs_required.unwrap_or(default_sigs_required);
+        }
+        // Wrapping calls only add their own policies to the requirements of the wrapped calls.
+        nested_calls
+            .into_iter()
+            .map(|nested_call| {
+                Self::call_sigs_required(policies, default_sigs_required, nested_call, depth + 1)
+            })
+            .fold(policy_sigs_required.unwrap_or_default(), u64::max)
+    }
+
     fn ensure_proposal_is_active(multisig: &T::AccountId, proposal_id: u64) -> DispatchResult {
         match ProposalStates::<T>::get(multisig, proposal_id) {
             None => Err(Error::<T>::ProposalMissing.into()),
@@ -905,6 +1331,8 @@ impl<T: Config> Pallet<T> {
             Some(ProposalState::ExecutionSuccessful | ProposalState::ExecutionFailed) => {
                 Err(Error::<T>::ProposalAlreadyExecuted.into())
             }
+            Some(ProposalState::Cancelled) => Err(Error::<T>::ProposalAlreadyCancelled.into()),
+            Some(ProposalState::Approved { .. }) => Err(Error::<T>::ProposalAlreadyApproved.into()),
             Some(ProposalState::Active { until: None }) => {
                 Self::ensure_valid_proposal(multisig, proposal_id)?;
                 Ok(())
@@ -921,6 +1349,45 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns the `execute_after` time of an approved proposal waiting for its cooling-off period to end.
+    ///
+    /// Returns [`Error::ProposalExpired`] if the time limit of the proposal has passed.
+    fn ensure_proposal_is_approved(
+        multisig: &T::AccountId,
+        proposal_id: u64,
+    ) -> Result<T::Moment, DispatchError> {
+        match ProposalStates::<T>::get(multisig, proposal_id) {
+            None => Err(Error::<T>::ProposalMissing.into()),
+            Some(ProposalState::Approved {
+                execute_after,
+                until,
+            }) => {
+                Self::ensure_valid_proposal(multisig, proposal_id)?;
+                if let Some(until) = until {
+                    ensure!(
+                        until > pallet_timestamp::Pallet::<T>::get(),
+                        Error::<T>::ProposalExpired
+                    );
+                }
+                Ok(execute_after)
+            }
+            Some(_) => Err(Error::<T>::ProposalNotApproved.into()),
+        }
+    }
+
+    /// Returns `Ok` if `signer` created the proposal.
+    fn ensure_proposer(
+        multisig: &T::AccountId,
+        proposal_id: u64,
+        signer: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            Proposers::<T>::get(multisig, proposal_id).as_ref() == Some(signer),
+            Error::<T>::NotTheProposer
+        );
+        Ok(())
+    }
+
     fn base_authorize_signers(
         caller_did: IdentityId,
         multisig: &T::AccountId,
@@ -972,13 +1439,20 @@ impl<T: Config> Pallet<T> {
         let pending_num_of_signers = NumberOfSigners::<T>::get(&multisig)
             .checked_sub(signers_len)
             .ok_or(Error::<T>::TooManySigners)?;
-        let sigs_required = MultiSigSignsRequired::<T>::get(&multisig);
-        Self::ensure_sigs_in_bounds(pending_num_of_signers, sigs_required)?;
+        let removed_weight = signers
+            .iter()
+            .map(|signer| Self::signer_weight(&multisig, signer))
+            .fold(0u64, |total, weight| total.saturating_add(weight));
+        let pending_total_weight = Self::total_signer_weight(&multisig)
+            .checked_sub(removed_weight)
+            .ok_or(Error::<T>::TooManySigners)?;
+        Self::ensure_sigs_in_bounds(pending_total_weight, Self::max_sigs_required(&multisig))?;
 
         for signer in &signers {
             Self::ensure_ms_signer(&multisig, signer)?;
             IdentityPallet::<T>::remove_key_record(signer, None);
             MultiSigSigners::<T>::remove(&multisig, signer);
+            SignerWeights::<T>::remove(&multisig, signer);
         }
 
         NumberOfSigners::<T>::insert(&multisig, pending_num_of_signers);
@@ -1040,9 +1514,14 @@ impl<T: Config> Pallet<T> {
         let proposal_id = Self::next_proposal_id(multisig);
         Self::ensure_valid_expiry(&expiry)?;
 
+        let vote_count = ProposalVoteCount {
+            sigs_required: Self::proposal_sigs_required(multisig, proposal),
+            ..ProposalVoteCount::default()
+        };
         Proposals::<T>::insert(multisig, proposal_id, &*proposal);
-        ProposalVoteCounts::<T>::insert(multisig, proposal_id, ProposalVoteCount::default());
+        ProposalVoteCounts::<T>::insert(multisig, proposal_id, vote_count);
         ProposalStates::<T>::insert(multisig, proposal_id, ProposalState::new(expiry));
+        Proposers::<T>::insert(multisig, proposal_id, &signer);
 
         // Since proposal_ids are always only incremented by 1, they can not overflow.
         let next_proposal_id: u64 = proposal_id + 1u64;
@@ -1076,8 +1555,10 @@ impl<T: Config> Pallet<T> {
 
         let mut vote_count = ProposalVoteCounts::<T>::try_get(multisig, proposal_id)
             .map_err(|_| Error::<T>::ProposalMissing)?;
-        vote_count.approvals += 1u64;
-        let execute_proposal = vote_count.approvals >= Self::ms_signs_required(multisig);
+        vote_count.approvals = vote_count
+            .approvals
+            .saturating_add(Self::signer_weight(multisig, &signer));
+        let execute_proposal = vote_count.approvals >= vote_count.sigs_required;
 
         // Update storage
         Votes::<T>::insert((multisig, proposal_id), &signer, true);
@@ -1089,11 +1570,131 @@ impl<T: Config> Pallet<T> {
             signer,
             proposal_id,
         });
-        if execute_proposal {
-            Self::execute_proposal(multisig, proposal_id, caller_did, max_weight)
-        } else {
-            Ok(().into())
+        if !execute_proposal {
+            return Ok(().into());
         }
+        match ProposalExecuteAfter::<T>::get(multisig, proposal_id) {
+            Some(execute_after) if execute_after > pallet_timestamp::Pallet::<T>::get() => {
+                let until = match ProposalStates::<T>::get(multisig, proposal_id) {
+                    Some(ProposalState::Active { until }) => until,
+                    _ => None,
+                };
+                ProposalStates::<T>::insert(
+                    multisig,
+                    proposal_id,
+                    ProposalState::Approved {
+                        execute_after,
+                        until,
+                    },
+                );
+                Self::deposit_event(Event::ProposalScheduled {
+                    caller_did,
+                    multisig: multisig.clone(),
+                    proposal_id,
+                    execute_after,
+                });
+                Ok(().into())
+            }
+            _ => Self::execute_proposal(multisig, proposal_id, caller_did, max_weight),
+        }
+    }
+
+    /// Replaces the call of a proposal, resets its votes and approves it on behalf of the proposer.
+    fn base_amend_proposal(
+        multisig: &T::AccountId,
+        signer: T::AccountId,
+        proposal_id: u64,
+        proposal: &<T as Config>::Proposal,
+    ) -> DispatchResultWithPostInfo {
+        Self::ensure_proposal_is_active(multisig, proposal_id)?;
+        Self::ensure_proposer(multisig, proposal_id, &signer)?;
+        let caller_did = Self::ensure_ms_get_did(multisig)?;
+        let max_weight = proposal.get_dispatch_info().weight;
+
+        let vote_count = ProposalVoteCount {
+            sigs_required: Self::proposal_sigs_required(multisig, proposal),
+            ..ProposalVoteCount::default()
+        };
+        Proposals::<T>::insert(multisig, proposal_id, proposal);
+        ProposalVoteCounts::<T>::insert(multisig, proposal_id, vote_count);
+        let _ =
+            Votes::<T>::clear_prefix((multisig.clone(), proposal_id), T::MaxSigners::get(), None);
+        Self::deposit_event(Event::ProposalAmended {
+            caller_did,
+            multisig: multisig.clone(),
+            proposal_id,
+        });
+        Self::base_approve(multisig, signer, proposal_id, max_weight)
+    }
+
+    /// Cancels a proposal that no other signer has voted on.
+    fn base_cancel_proposal(
+        multisig: &T::AccountId,
+        signer: T::AccountId,
+        proposal_id: u64,
+    ) -> DispatchResult {
+        Self::ensure_proposal_is_active(multisig, proposal_id)?;
+        Self::ensure_proposer(multisig, proposal_id, &signer)?;
+        let caller_did = Self::ensure_ms_get_did(multisig)?;
+
+        let vote_count = ProposalVoteCounts::<T>::get(multisig, proposal_id)
+            .ok_or(Error::<T>::ProposalMissing)?;
+        ensure!(
+            vote_count.rejections == 0
+                && vote_count.approvals <= Self::signer_weight(multisig, &signer),
+            Error::<T>::ProposalHasVotes
+        );
+
+        Self::remove_proposal(multisig, proposal_id);
+        ProposalStates::<T>::insert(multisig, proposal_id, ProposalState::Cancelled);
+        Self::deposit_event(Event::ProposalCancelled {
+            caller_did,
+            multisig: multisig.clone(),
+            proposal_id,
+        });
+        Ok(())
+    }
+
+    /// Vetoes an approved proposal during its cooling-off period.
+    fn base_veto(
+        multisig: &T::AccountId,
+        signer: T::AccountId,
+        proposal_id: u64,
+    ) -> DispatchResult {
+        Self::ensure_ms_signer(multisig, &signer)?;
+        let caller_did = Self::ensure_ms_get_did(multisig)?;
+        let execute_after = Self::ensure_proposal_is_approved(multisig, proposal_id)?;
+        ensure!(
+            execute_after > pallet_timestamp::Pallet::<T>::get(),
+            Error::<T>::CoolingOffPeriodOver
+        );
+
+        Self::remove_proposal(multisig, proposal_id);
+        ProposalStates::<T>::insert(multisig, proposal_id, ProposalState::Rejected);
+        Self::deposit_event(Event::ProposalVetoed {
+            caller_did,
+            multisig: multisig.clone(),
+            signer,
+            proposal_id,
+        });
+        Ok(())
+    }
+
+    /// Executes an approved proposal whose cooling-off period is over.
+    fn base_execute_scheduled_proposal(
+        multisig: &T::AccountId,
+        signer: T::AccountId,
+        proposal_id: u64,
+        max_weight: Weight,
+    ) -> DispatchResultWithPostInfo {
+        Self::ensure_ms_signer(multisig, &signer)?;
+        let caller_did = Self::ensure_ms_get_did(multisig)?;
+        let execute_after = Self::ensure_proposal_is_approved(multisig, proposal_id)?;
+        ensure!(
+            execute_after <= pallet_timestamp::Pallet::<T>::get(),
+            Error::<T>::CoolingOffPeriodNotOver
+        );
+        Self::execute_proposal(multisig, proposal_id, caller_did, max_weight)
     }
 
     // Executes a proposal if it has enough approvals
@@ -1112,6 +1713,8 @@ impl<T: Config> Pallet<T> {
         // Take the proposal.
         let proposal = Proposals::<T>::take(multisig, proposal_id)
             .ok_or_else(|| Error::<T>::ProposalMissing)?;
+        Proposers::<T>::remove(multisig, proposal_id);
+        ProposalExecuteAfter::<T>::remove(multisig, proposal_id);
 
         // Ensure `max_weight` was enough to cover the worst-case weight.
         let proposal_weight = proposal.get_dispatch_info().weight;
@@ -1160,6 +1763,13 @@ impl<T: Config> Pallet<T> {
         Ok(Some(actual_weight).into())
     }
 
+    /// Removes the call of a proposal that won't be executed, with its proposer and schedule.
+    fn remove_proposal(multisig: &T::AccountId, proposal_id: u64) {
+        Proposals::<T>::remove(multisig, proposal_id);
+        Proposers::<T>::remove(multisig, proposal_id);
+        ProposalExecuteAfter::<T>::remove(multisig, proposal_id);
+    }
+
     /// Rejects a multisig proposal
     fn base_reject(
         multisig: &T::AccountId,
@@ -1174,9 +1784,10 @@ impl<T: Config> Pallet<T> {
             .map_err(|_| Error::<T>::ProposalMissing)?;
 
         // Only allow the original proposer to change their vote if no one else has voted
+        let signer_weight = Self::signer_weight(multisig, &signer);
         let mut proposal_owner = false;
         if Votes::<T>::get((multisig, proposal_id), &signer) {
-            if vote_count.rejections != 0 || vote_count.approvals != 1 {
+            if vote_count.rejections != 0 || vote_count.approvals != signer_weight {
                 return Err(Error::<T>::AlreadyVoted.into());
             }
             proposal_owner = true;
@@ -1191,16 +1802,17 @@ impl<T: Config> Pallet<T> {
         });
         // Record the signer's vote.
         Votes::<T>::insert((multisig, proposal_id), &signer, true);
-        vote_count.rejections += 1u64;
+        vote_count.rejections = vote_count.rejections.saturating_add(signer_weight);
 
-        let approvals_needed = Self::ms_signs_required(&multisig);
-        let ms_signers = Self::number_of_signers(&multisig);
-        if vote_count.rejections > ms_signers.saturating_sub(approvals_needed) || proposal_owner {
+        let total_weight = Self::total_signer_weight(multisig);
+        if vote_count.rejections > total_weight.saturating_sub(vote_count.sigs_required)
+            || proposal_owner
+        {
             if proposal_owner {
                 vote_count.approvals = 0;
             }
             // Remove the proposal from storage.
-            Proposals::<T>::remove(multisig, proposal_id);
+            Self::remove_proposal(multisig, proposal_id);
             ProposalStates::<T>::insert(multisig, proposal_id, ProposalState::Rejected);
             Self::deposit_event(Event::ProposalRejected {
                 caller_did,
@@ -1310,8 +1922,7 @@ impl<T: Config> Pallet<T> {
         signatures_required: u64,
     ) -> DispatchResult {
         let ms_did = Self::ensure_ms_get_did(&multisig)?;
-        let num_signers = NumberOfSigners::<T>::get(multisig);
-        Self::ensure_sigs_in_bounds(num_signers, signatures_required)?;
+        Self::ensure_sigs_in_bounds(Self::total_signer_weight(multisig), signatures_required)?;
         ensure!(
             Self::is_changing_signers_allowed(multisig),
             Error::<T>::ChangeNotAllowed
@@ -1326,6 +1937,77 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    // Sets the weights of the given signers of `multisig`.
+    fn base_set_signer_weights(
+        caller_did: Option<IdentityId>,
+        multisig: T::AccountId,
+        weights: BoundedVec<(T::AccountId, u64), T::MaxSigners>,
+    ) -> DispatchResult {
+        let ms_did = Self::ensure_ms_has_did(&multisig)?;
+        ensure!(
+            Self::is_changing_signers_allowed(&multisig),
+            Error::<T>::ChangeNotAllowed
+        );
+
+        let new_weights: BTreeMap<T::AccountId, u64> = weights.iter().cloned().collect();
+        let mut pending_total_weight = Self::total_signer_weight(&multisig);
+        for (signer, weight) in &new_weights {
+            Self::ensure_ms_signer(&multisig, signer)?;
+            ensure!(*weight > 0, Error::<T>::InvalidSignerWeight);
+            pending_total_weight = pending_total_weight
+                .saturating_sub(Self::signer_weight(&multisig, signer))
+                .saturating_add(*weight);
+        }
+        Self::ensure_sigs_in_bounds(pending_total_weight, Self::max_sigs_required(&multisig))?;
+
+        for (signer, weight) in new_weights {
+            if weight == 1 {
+                SignerWeights::<T>::remove(&multisig, signer);
+            } else {
+                SignerWeights::<T>::insert(&multisig, signer, weight);
+            }
+        }
+        Self::set_invalid_proposals(&multisig);
+        Self::deposit_event(Event::MultiSigSignerWeightsChanged {
+            caller_did: caller_did.unwrap_or(ms_did),
+            multisig,
+            weights,
+        });
+        Ok(())
+    }
+
+    // Replaces the call policies of `multisig`.
+    fn base_set_call_policies(
+        caller_did: Option<IdentityId>,
+        multisig: T::AccountId,
+        policies: BoundedVec<CallPolicy, T::MaxCallPolicies>,
+    ) -> DispatchResult {
+        let ms_did = Self::ensure_ms_has_did(&multisig)?;
+        ensure!(
+            Self::is_changing_signers_allowed(&multisig),
+            Error::<T>::ChangeNotAllowed
+        );
+
+        let total_weight = Self::total_signer_weight(&multisig);
+        for policy in &policies {
+            ensure!(policy.sigs_required > 0, Error::<T>::InvalidCallPolicy);
+            Self::ensure_sigs_in_bounds(total_weight, policy.sigs_required)?;
+        }
+
+        if policies.is_empty() {
+            CallPolicies::<T>::remove(&multisig);
+        } else {
+            CallPolicies::<T>::insert(&multisig, policies.clone());
+        }
+        Self::set_invalid_proposals(&multisig);
+        Self::deposit_event(Event::MultiSigCallPoliciesChanged {
+            caller_did: caller_did.unwrap_or(ms_did),
+            multisig,
+            policies,
+        });
+        Ok(())
+    }
+
     /// Returns `Ok` if `expiry` is in the future. Otherwise, returns [`Error::InvalidExpiryDate`].
     fn ensure_valid_expiry(expiry: &Option<T::Moment>) -> DispatchResult {
         if let Some(expiry) = expiry {
@@ -1337,6 +2019,15 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns `Ok` if `execute_after` is in the future. Otherwise, returns [`Error::InvalidExecuteAfter`].
+    fn ensure_valid_execute_after(execute_after: &T::Moment) -> DispatchResult {
+        ensure!(
+            execute_after > &pallet_timestamp::Pallet::<T>::get(),
+            Error::<T>::InvalidExecuteAfter
+        );
+        Ok(())
+    }
+
     /// Returns `Ok` if `proposal_id` is valid. Otherwise, returns [`Error::InvalidatedProposal`].
     fn ensure_valid_proposal(multisig: &T::AccountId, proposal_id: u64) -> DispatchResult {
         if let Some(last_invalid_proposal) = Self::last_invalid_proposal(multisig) {
@@ -1348,6 +2039,116 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the details of the proposals of `multisig` that can still be approved or executed,
+    /// ordered by proposal id.
+    pub fn pending_proposals(
+        multisig: &T::AccountId,
+    ) -> Vec<ProposalDetails<T::AccountId, T::Moment>> {
+        let mut proposals: Vec<_> = Proposals::<T>::iter_key_prefix(multisig)
+            .filter(|proposal_id| Self::ensure_proposal_is_pending(multisig, *proposal_id).is_ok())
+            .filter_map(|proposal_id| Self::proposal_details(multisig, proposal_id))
+            .collect();
+        proposals.sort_by_key(|details| details.proposal_id);
+        proposals
+    }
+
+    /// Returns the details of a proposal, or `None` if the proposal is no longer stored.
+    pub fn proposal_details(
+        multisig: &T::AccountId,
+        proposal_id: u64,
+    ) -> Option<ProposalDetails<T::AccountId, T::Moment>> {
+        let proposal = Proposals::<T>::get(multisig, proposal_id)?;
+        let state = ProposalStates::<T>::get(multisig, proposal_id)?;
+        let vote_count = ProposalVoteCounts::<T>::get(multisig, proposal_id).unwrap_or_default();
+        let call_metadata = proposal.get_call_metadata();
+        let (voted, not_voted) = MultiSigSigners::<T>::iter_prefix(multisig)
+            .filter(|(_, is_signer)| *is_signer)
+            .map(|(signer, _)| signer)
+            .partition(|signer| Votes::<T>::get((multisig, proposal_id), signer));
+        Some(ProposalDetails {
+            proposal_id,
+            proposer: Proposers::<T>::get(multisig, proposal_id),
+            pallet_name: call_metadata.pallet_name.into(),
+            extrinsic_name: call_metadata.function_name.into(),
+            call: proposal.encode(),
+            state,
+            vote_count,
+            voted,
+            not_voted,
+        })
+    }
+
+    /// Returns all errors that executing the proposal now would return.  An empty vec means the
+    /// proposal would be executed successfully.
+    ///
+    /// Returns [`Error::NotEnoughApprovals`] if an active proposal still needs approvals, and
+    /// [`Error::NestingNotAllowed`] if called while another proposal is being executed.
+    /// The proposed call is dispatched and its changes are always rolled back.
+    pub fn proposal_execution_report(
+        multisig: &T::AccountId,
+        proposal_id: u64,
+    ) -> Vec<DispatchError> {
+        let mut errors = Vec::new();
+        match ProposalStates::<T>::get(multisig, proposal_id) {
+            Some(ProposalState::Approved { execute_after, .. }) => {
+                if let Err(e) = Self::ensure_proposal_is_approved(multisig, proposal_id) {
+                    errors.push(e);
+                }
+                if execute_after > pallet_timestamp::Pallet::<T>::get() {
+                    errors.push(Error::<T>::CoolingOffPeriodNotOver.into());
+                }
+            }
+            _ => {
+                if let Err(e) = Self::ensure_proposal_is_active(multisig, proposal_id) {
+                    errors.push(e);
+                }
+                if let Some(vote_count) = ProposalVoteCounts::<T>::get(multisig, proposal_id) {
+                    if vote_count.approvals < vote_count.sigs_required {
+                        errors.push(Error::<T>::NotEnoughApprovals.into());
+                    }
+                }
+            }
+        }
+        if Self::execution_reentry() {
+            errors.push(Error::<T>::NestingNotAllowed.into());
+            return errors;
+        }
+
+        let proposal = match Proposals::<T>::get(multisig, proposal_id) {
+            Some(proposal) => proposal,
+            None => {
+                if errors.is_empty() {
+                    errors.push(Error::<T>::ProposalMissing.into());
+                }
+                return errors;
+            }
+        };
+        let result = with_transaction(|| {
+            let result = with_call_metadata(proposal.get_call_metadata(), || {
+                // The reentry guard is enabled as during the execution of the proposal.
+                ExecutionReentry::<T>::set(true);
+                proposal.dispatch(frame_system::RawOrigin::Signed(multisig.clone()).into())
+            });
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+        });
+        match result {
+            Ok(Err(e)) => errors.push(e.error),
+            Err(e) => errors.push(e),
+            Ok(Ok(_)) => {}
+        }
+        errors
+    }
+
+    /// Returns `Ok` if the proposal is active or approved and waiting to be executed.
+    fn ensure_proposal_is_pending(multisig: &T::AccountId, proposal_id: u64) -> DispatchResult {
+        match ProposalStates::<T>::get(multisig, proposal_id) {
+            Some(ProposalState::Approved { .. }) => {
+                Self::ensure_valid_proposal(multisig, proposal_id)
+            }
+            _ => Self::ensure_proposal_is_active(multisig, proposal_id),
+        }
+    }
+
     /// Sets [`LastInvalidProposal`] with the proposal id of the last proposal.
     fn set_invalid_proposals(multisig: &T::AccountId) {
         let next_proposal_id = Self::next_proposal_id(multisig);
@@ -1394,6 +2195,34 @@ pub mod migration {
         }
     }
 
+    mod v3 {
+        use super::*;
+
+        #[derive(Decode, Encode)]
+        pub struct ProposalVoteCount {
+            pub approvals: u64,
+            pub rejections: u64,
+        }
+    }
+
+    pub fn migrate_to_v4<T: Config>(weight: &mut Weight) {
+        RuntimeLogger::init();
+        log::info!(" >>> Migrate ProposalVoteCounts to include the required signatures");
+        let mut count = 0;
+        ProposalVoteCounts::<T>::translate::<v3::ProposalVoteCount, _>(
+            |multisig, _, vote_count| {
+                count += 1;
+                Some(ProposalVoteCount {
+                    approvals: vote_count.approvals,
+                    rejections: vote_count.rejections,
+                    sigs_required: MultiSigSignsRequired::<T>::get(&multisig),
+                })
+            },
+        );
+        weight.saturating_accrue(DbWeight::get().reads_writes(2 * count, count));
+        log::info!(" >>> {count} Multisig.ProposalVoteCounts migrated.");
+    }
+
     pub fn migrate_to_v3<T: Config>(weight: &mut Weight) {
         RuntimeLogger::init();
         // Remove old storage.
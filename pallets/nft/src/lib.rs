@@ -17,7 +17,7 @@ use pallet_base::try_next_pre;
 use pallet_portfolio::{PortfolioLockedNFT, PortfolioNFT};
 use polymesh_common_utilities::compliance_manager::ComplianceFnConfig;
 pub use polymesh_common_utilities::traits::nft::{Config, Event, NFTTrait, WeightInfo};
-use polymesh_primitives::asset::{AssetId, AssetName, AssetType, NonFungibleType};
+use polymesh_primitives::asset::{AssetId, AssetName, AssetOperation, AssetType, NonFungibleType};
 use polymesh_primitives::asset_metadata::{AssetMetadataKey, AssetMetadataValue};
 use polymesh_primitives::nft::{
     NFTCollection, NFTCollectionId, NFTCollectionKeys, NFTCount, NFTId, NFTMetadataAttribute, NFTs,
@@ -362,6 +362,7 @@ impl<T: Config> Module<T> {
             portfolio_kind,
             false,
         )?;
+        Asset::<T>::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Issue)?;
 
         Portfolio::<T>::ensure_portfolio_validity(&caller_portfolio)?;
 
@@ -435,6 +436,7 @@ impl<T: Config> Module<T> {
             portfolio_kind,
             true,
         )?;
+        Asset::<T>::ensure_asset_operation_not_paused(&asset_id, AssetOperation::Redeem)?;
 
         // Verifies if the NFT exists
         ensure!(
@@ -561,6 +563,7 @@ impl<T: Config> Module<T> {
             !Frozen::get(nfts.asset_id()),
             Error::<T>::InvalidNFTTransferFrozenAsset
         );
+        Asset::<T>::ensure_asset_operation_not_paused(nfts.asset_id(), AssetOperation::Transfer)?;
 
         // Verifies if the receiver has a valid CDD claim.
         ensure!(
@@ -691,6 +694,12 @@ impl<T: Config> Module<T> {
             nft_transfer_errors.push(Error::<T>::InvalidNFTTransferFrozenAsset.into());
         }
 
+        if let Err(e) =
+            Asset::<T>::ensure_asset_operation_not_paused(nfts.asset_id(), AssetOperation::Transfer)
+        {
+            nft_transfer_errors.push(e);
+        }
+
         if sender_portfolio.did == receiver_portfolio.did {
             nft_transfer_errors
                 .push(Error::<T>::InvalidNFTTransferSenderIdMatchesReceiverId.into());
@@ -805,6 +814,35 @@ impl<T: Config> NFTTrait<T::RuntimeOrigin> for Module<T> {
         NFTOwner::insert(asset_id, nft_id, new_owner_portfolio);
     }
 
+    fn controller_transfer_nfts(
+        asset_id: AssetId,
+        nft_ids: Vec<NFTId>,
+        source_portfolio: PortfolioId,
+        destination_portfolio: PortfolioId,
+        caller_did: IdentityId,
+    ) -> DispatchResult {
+        // Each transfer must be within the limit of nfts per leg
+        for nft_ids in nft_ids.chunks(T::MaxNumberOfNFTsCount::get() as usize) {
+            let nfts = NFTs::new_unverified(asset_id, nft_ids.to_vec());
+            Self::validate_nft_transfer(
+                &source_portfolio,
+                &destination_portfolio,
+                &nfts,
+                true,
+                None,
+            )?;
+            Self::unverified_nfts_transfer(&source_portfolio, &destination_portfolio, &nfts);
+            Self::deposit_event(Event::NFTPortfolioUpdated(
+                caller_did,
+                nfts,
+                Some(source_portfolio),
+                Some(destination_portfolio),
+                PortfolioUpdateReason::ControllerTransfer,
+            ));
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     fn create_nft_collection(
         origin: T::RuntimeOrigin,
@@ -814,4 +852,13 @@ impl<T: Config> NFTTrait<T::RuntimeOrigin> for Module<T> {
     ) -> DispatchResult {
         Module::<T>::create_nft_collection(origin, asset_id, nft_type, collection_keys)
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn issue_nft(
+        origin: T::RuntimeOrigin,
+        asset_id: AssetId,
+        portfolio_kind: PortfolioKind,
+    ) -> DispatchResult {
+        Module::<T>::issue_nft(origin, asset_id, Vec::new(), portfolio_kind)
+    }
 }
@@ -100,6 +100,47 @@ fn cast_votes<T: Config>(
     Ok(())
 }
 
+/// Makes `num_pips` community PIPs proposed by `proposer`.
+fn propose_pips<T: Config>(proposer: &User<T>, num_pips: u32) {
+    zeroize_deposit::<T>();
+    Module::<T>::set_active_pip_limit(RawOrigin::Root.into(), 0).unwrap();
+    for _ in 0..num_pips {
+        let (proposal, url, description) = make_proposal::<T>();
+        Module::<T>::propose(
+            proposer.origin().into(),
+            proposal,
+            42u32.into(),
+            Some(url),
+            Some(description),
+        )
+        .unwrap();
+    }
+}
+
+/// Delegates voting power of `num_delegators` new users to `delegate`,
+/// filling up the delegators for the category of `make_proposal` before those for all PIPs.
+fn delegate_to<T: Config + TestUtilsFn<AccountIdOf<T>>>(
+    delegate: &T::AccountId,
+    num_delegators: u32,
+) {
+    let category = Some(Module::<T>::pip_category(&make_proposal::<T>().0));
+    for i in 0..num_delegators {
+        let delegator = user::<T>("delegator", i);
+        let category = if i < T::MaxDelegators::get() {
+            category.clone()
+        } else {
+            None
+        };
+        Module::<T>::delegate(
+            delegator.origin().into(),
+            delegate.clone(),
+            category,
+            1u32.into(),
+        )
+        .unwrap();
+    }
+}
+
 /// Sets up PIPs and votes.
 fn pips_and_votes_setup<T: Config + TestUtilsFn<AccountIdOf<T>>>(
     approve_only: bool,
@@ -260,6 +301,9 @@ benchmarks! {
     }
 
     vote {
+        // The number of delegators of the voter.
+        let d in 0 .. 2 * T::MaxDelegators::get();
+
         let proposer = user::<T>("proposer", 0);
         let (proposal, url, description) = make_proposal::<T>();
         zeroize_deposit::<T>();
@@ -279,12 +323,14 @@ benchmarks! {
         // Cast an opposite vote.
         let voter = user::<T>("voter", 0);
         let voter_deposit = 43u32.into();
+        delegate_to::<T>(&voter.account(), d);
         // Cast an opposite vote.
         Module::<T>::vote(voter.origin().into(), id, false, voter_deposit).unwrap();
         let origin = voter.origin();
     }: _(origin, id, true, voter_deposit)
     verify {
         assert!(voter_deposit == Deposits::<T>::get(id, &voter.account()).expect("Deposit").amount, "incorrect voter deposit");
+        assert_eq!(DelegatedVotes::<T>::iter_prefix(id).count(), d as usize, "incorrect delegated votes");
     }
 
     approve_committee_proposal {
@@ -484,4 +530,99 @@ benchmarks! {
     verify {
         execute_verify::<T>(ProposalState::Expired, "incorrect proposal state after expiration").unwrap();
     }
+
+    set_vote_weighting {
+        let origin = RawOrigin::Root;
+    }: _(origin, VoteWeighting::Conviction)
+    verify {
+        assert_eq!(VoteWeighting::Conviction, VoteWeightingMode::get(), "incorrect VoteWeightingMode");
+    }
+
+    vote_with_conviction {
+        // The number of delegators of the voter.
+        let d in 0 .. 2 * T::MaxDelegators::get();
+
+        let proposer = user::<T>("proposer", 0);
+        let (proposal, url, description) = make_proposal::<T>();
+        zeroize_deposit::<T>();
+        Module::<T>::set_vote_weighting(RawOrigin::Root.into(), VoteWeighting::Conviction).unwrap();
+        Module::<T>::propose(
+            proposer.origin().into(),
+            proposal,
+            42u32.into(),
+            Some(url),
+            Some(description)
+        ).unwrap();
+        // Populate vote history.
+        let aye_voters = make_voters::<T>(VOTERS_A_NUM, "aye");
+        let nay_voters = make_voters::<T>(VOTERS_B_NUM, "nay");
+        let id = PipId(0);
+        cast_votes::<T>(id, aye_voters.as_slice(), true).unwrap();
+        cast_votes::<T>(id, nay_voters.as_slice(), false).unwrap();
+        // Cast an opposite vote with conviction, which stays locked when changing the vote.
+        let voter = user::<T>("voter", 0);
+        let voter_deposit = 43u32.into();
+        delegate_to::<T>(&voter.account(), d);
+        Module::<T>::vote_with_conviction(voter.origin().into(), id, false, voter_deposit, Conviction::Locked6x).unwrap();
+        let origin = voter.origin();
+    }: _(origin, id, true, voter_deposit, Conviction::Locked6x)
+    verify {
+        assert!(ConvictionLocks::<T>::contains_key(&voter.account(), id), "missing conviction lock");
+        assert_eq!(DelegatedVotes::<T>::iter_prefix(id).count(), d as usize, "incorrect delegated votes");
+    }
+
+    unlock_conviction_deposit {
+        let proposer = user::<T>("proposer", 0);
+        let (proposal, url, description) = make_proposal::<T>();
+        zeroize_deposit::<T>();
+        Module::<T>::set_vote_weighting(RawOrigin::Root.into(), VoteWeighting::Conviction).unwrap();
+        Module::<T>::propose(
+            proposer.origin().into(),
+            proposal,
+            42u32.into(),
+            Some(url),
+            Some(description)
+        ).unwrap();
+        let id = PipId(0);
+        let voter = user::<T>("voter", 0);
+        Module::<T>::vote_with_conviction(voter.origin().into(), id, true, 43u32.into(), Conviction::Locked1x).unwrap();
+        Module::<T>::vote(voter.origin().into(), id, true, 0u32.into()).unwrap();
+        let lock = ConvictionLocks::<T>::get(&voter.account(), id).unwrap();
+        System::<T>::set_block_number(lock.unlock_at);
+        let origin = voter.origin();
+    }: _(origin, id)
+    verify {
+        assert!(!ConvictionLocks::<T>::contains_key(&voter.account(), id), "conviction lock wasn't removed");
+    }
+
+    delegate {
+        // The number of pending PIPs voted on by the delegate.
+        let p in 0 .. PROPOSALS_NUM as u32;
+
+        let delegate = user::<T>("delegate", 0);
+        propose_pips::<T>(&delegate, p);
+        let delegator = user::<T>("delegator", 0);
+        // Replace an existing delegation.
+        Module::<T>::delegate(delegator.origin().into(), user::<T>("delegate", 1).account(), None, 1u32.into()).unwrap();
+        let origin = delegator.origin();
+    }: _(origin, delegate.account(), None, 43u32.into())
+    verify {
+        assert_eq!(Module::<T>::delegation(&delegator.account(), None::<PalletName>).unwrap().delegate, delegate.account(), "incorrect delegation");
+        assert_eq!(ProposalVotes::<T>::iter().filter(|(_, acc, _)| *acc == delegator.account()).count(), p as usize, "incorrect delegated votes");
+    }
+
+    revoke_delegation {
+        // The number of pending PIPs voted on by the delegate.
+        let p in 0 .. PROPOSALS_NUM as u32;
+
+        let delegate = user::<T>("delegate", 0);
+        propose_pips::<T>(&delegate, p);
+        let delegator = user::<T>("delegator", 0);
+        Module::<T>::delegate(delegator.origin().into(), delegate.account(), None, 43u32.into()).unwrap();
+        let origin = delegator.origin();
+    }: _(origin, None)
+    verify {
+        assert!(Module::<T>::delegation(&delegator.account(), None::<PalletName>).is_none(), "delegation wasn't revoked");
+        assert!(ProposalVotes::<T>::iter().all(|(_, acc, _)| acc != delegator.account()), "delegated votes weren't removed");
+    }
 }
@@ -19,9 +19,21 @@
 //! These PIPs can either be proposed by a committee, or they can be proposed by a community member,
 //! in which case they can `vote`d on by all POLYX token holders.
 //!
-//! Voting, or rather "signalling", which currently scales linearly with POLX,
+//! Voting, or rather "signalling", which scales with POLYX according to the configured
+//! `VoteWeighting` (linearly, quadratically, or by the voter's `Conviction`),
 //! in this system is used to direct the Governance Councils (GCs)
 //! attention by moving proposals up and down a review queue, specific to community proposals.
+//! With conviction weighting, the deposit of a vote cast with conviction stays locked
+//! for a number of `ConvictionLockPeriod`s after the PIP is closed,
+//! after which it can be unlocked via `unlock_conviction_deposit`.
+//!
+//! Token holders can also `delegate` voting power to a representative, either for all PIPs
+//! or for a category of PIPs, i.e., the pallet of the proposed call.
+//! Whenever the delegate votes, the delegated power is cast as a vote in the same direction,
+//! unless the delegator voted directly on the PIP. Delegations can be revoked at any time.
+//! Like the deposit of a direct vote, the delegated power is locked separately on every PIP
+//! it is cast on, and refunded once the PIP is closed or the delegated vote removed.
+//! The part of the results cast with delegated power is tracked in `DelegatedProposalResult`.
 //!
 //! From time to time, the GC will take a `snapshot` of this queue,
 //! meet and review PIPs, and reject, approve, or skip the proposal (via `enact_snapshot_results`).
@@ -63,6 +75,7 @@
 //! - `set_default_enactment_period` change the period after enactment after which the proposal is executed
 //! - `set_max_pip_skip_count` change the maximum times a PIP can be skipped
 //! - `set_active_pip_limit` change the maximum number of concurrently active PIPs
+//! - `set_vote_weighting` change how vote deposits are weighted
 //!
 //! #### Other
 //!
@@ -70,6 +83,10 @@
 //! - `amend_proposal` - allows the creator of a proposal to amend the proposal details
 //! - `cancel_proposal` - allows the creator of a proposal to cancel the proposal
 //! - `vote` - token holders, including the PIP's proposer, can vote on a PIP.
+//! - `vote_with_conviction` - vote on a PIP, locking the deposit for longer in exchange for more weight.
+//! - `unlock_conviction_deposit` - unlocks a deposit once its conviction lock period has passed.
+//! - `delegate` - delegates voting power to another account, for all PIPs or a category of PIPs.
+//! - `revoke_delegation` - revokes a delegation, unlocking the delegated power.
 //! - `approve_committee_proposal` - allows the GC to approve a committee proposal
 //! - `reject_proposal` - reject an active proposal and refund deposits
 //! - `prune_proposal` - prune all storage associated with proposal and refund deposits
@@ -98,12 +115,16 @@ use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap, Stora
 use frame_support::traits::schedule::{
     DispatchTime, Named as ScheduleNamed, Priority, HARD_DEADLINE,
 };
-use frame_support::traits::{Currency, EnsureOrigin, Get, LockIdentifier, WithdrawReasons};
+use frame_support::traits::{
+    Currency, EnsureOrigin, Get, GetCallMetadata, LockIdentifier, WithdrawReasons,
+};
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
 use frame_system::{self as system, ensure_root, ensure_signed, RawOrigin};
 use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash, One, Saturating, Zero};
+use sp_runtime::traits::{
+    BlakeTwo256, Dispatchable, Hash, IntegerSquareRoot, One, Saturating, Zero,
+};
 use sp_runtime::DispatchError;
 use sp_std::{convert::From, prelude::*};
 use sp_version::RuntimeVersion;
@@ -120,7 +141,10 @@ use polymesh_common_utilities::{
     with_transaction, CommonConfig, MaybeBlock, GC_DID, TECHNICAL_DID, UPGRADE_DID,
 };
 use polymesh_primitives::constants::{PIP_EXECUTION, PIP_EXPIRY};
-use polymesh_primitives::{impl_checked_inc, storage_migration_ver, Balance, IdentityId, Url};
+use polymesh_primitives::dry_run::{dry_run, DryRunOutcome};
+use polymesh_primitives::{
+    impl_checked_inc, storage_migration_ver, Balance, IdentityId, PalletName, Url,
+};
 use polymesh_primitives_derive::VecU8StrongTyped;
 use polymesh_runtime_common::PipsEnactSnapshotMaximumWeight;
 
@@ -146,7 +170,7 @@ pub trait WeightInfo {
     fn set_active_pip_limit() -> Weight;
     fn propose_from_community() -> Weight;
     fn propose_from_committee() -> Weight;
-    fn vote() -> Weight;
+    fn vote(d: u32) -> Weight;
     fn approve_committee_proposal() -> Weight;
     fn reject_proposal() -> Weight;
     fn prune_proposal() -> Weight;
@@ -156,6 +180,11 @@ pub trait WeightInfo {
     fn enact_snapshot_results(a: u32, r: u32, s: u32) -> Weight;
     fn execute_scheduled_pip() -> Weight;
     fn expire_scheduled_pip() -> Weight;
+    fn set_vote_weighting() -> Weight;
+    fn vote_with_conviction(d: u32) -> Weight;
+    fn unlock_conviction_deposit() -> Weight;
+    fn delegate(p: u32) -> Weight;
+    fn revoke_delegation(p: u32) -> Weight;
 }
 
 /// A wrapper for a proposal description.
@@ -204,11 +233,51 @@ pub enum VoteCount {
         ayes: Balance,
         /// Stake against
         nays: Balance,
+        /// Weighted support for
+        ayes_weight: Balance,
+        /// Weighted support against
+        nays_weight: Balance,
+        /// Stake for, cast with delegated power
+        delegated_ayes: Balance,
+        /// Stake against, cast with delegated power
+        delegated_nays: Balance,
     },
     /// Proposal was not for given index.
     ProposalNotFound,
 }
 
+/// The [`VoteCount`] as encoded before votes were weighted and could be delegated (`PipsApi` version 1).
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum VoteCountV1 {
+    /// Proposal was found and has the following votes.
+    ProposalFound {
+        /// Stake for
+        ayes: Balance,
+        /// Stake against
+        nays: Balance,
+    },
+    /// Proposal was not for given index.
+    ProposalNotFound,
+}
+
+impl From<VoteCountV1> for VoteCount {
+    fn from(vote_count: VoteCountV1) -> Self {
+        match vote_count {
+            // Votes were not weighted, so the weighted support is the stake itself.
+            VoteCountV1::ProposalFound { ayes, nays } => VoteCount::ProposalFound {
+                ayes,
+                nays,
+                ayes_weight: ayes,
+                nays_weight: nays,
+                delegated_ayes: Zero::zero(),
+                delegated_nays: Zero::zero(),
+            },
+            VoteCountV1::ProposalNotFound => VoteCount::ProposalNotFound,
+        }
+    }
+}
+
 /// Either the entire proposal encoded as a byte vector or its hash. The latter represents large
 /// proposals.
 #[derive(Encode, Decode, TypeInfo)]
@@ -286,6 +355,125 @@ pub struct Vote(
     pub Balance,
 );
 
+/// How the deposit of a vote translates into the weight used for tallying and queue ordering.
+#[derive(Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VoteWeighting {
+    /// The weight of a vote is its deposit.
+    Linear,
+    /// The weight of a vote is the square root of its deposit.
+    Quadratic,
+    /// The weight of a vote is its deposit scaled by the `Conviction` of the voter.
+    Conviction,
+}
+
+impl Default for VoteWeighting {
+    fn default() -> Self {
+        VoteWeighting::Linear
+    }
+}
+
+/// The conviction of a vote, trading a longer lock of the deposit for more weight.
+/// Only votes cast while the `VoteWeighting` is `Conviction` can have a conviction.
+#[derive(Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Conviction {
+    /// 0.1x the deposit, which is unlocked when the PIP is closed.
+    None,
+    /// 1x the deposit, which stays locked for 1 lock period.
+    Locked1x,
+    /// 2x the deposit, which stays locked for 2 lock periods.
+    Locked2x,
+    /// 3x the deposit, which stays locked for 4 lock periods.
+    Locked3x,
+    /// 4x the deposit, which stays locked for 8 lock periods.
+    Locked4x,
+    /// 5x the deposit, which stays locked for 16 lock periods.
+    Locked5x,
+    /// 6x the deposit, which stays locked for 32 lock periods.
+    Locked6x,
+}
+
+impl Default for Conviction {
+    fn default() -> Self {
+        Conviction::None
+    }
+}
+
+impl Conviction {
+    /// Returns the weight of a vote with `deposit` and this conviction.
+    pub fn weight(self, deposit: Balance) -> Balance {
+        let multiplier: Balance = match self {
+            Conviction::None => return deposit / 10,
+            Conviction::Locked1x => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 5,
+            Conviction::Locked6x => 6,
+        };
+        deposit.saturating_mul(multiplier)
+    }
+
+    /// Returns the number of lock periods the deposit stays locked for.
+    pub fn lock_periods(self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked1x => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 4,
+            Conviction::Locked4x => 8,
+            Conviction::Locked5x => 16,
+            Conviction::Locked6x => 32,
+        }
+    }
+}
+
+/// The conviction of a vote and the weight it was given when it was cast.
+#[derive(Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq, Debug)]
+pub struct WeightedVote {
+    /// The conviction of the vote.
+    pub conviction: Conviction,
+    /// The weight of the vote.
+    pub weight: Balance,
+}
+
+/// The weighted support of a proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Default, Debug)]
+pub struct WeightedVotingResult {
+    /// The total weight of the votes in favor.
+    pub ayes_weight: Balance,
+    /// The total weight of the votes against.
+    pub nays_weight: Balance,
+}
+
+/// A deposit kept locked after its vote ended due to the conviction of the vote.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct ConvictionLock<BlockNumber> {
+    /// Amount that stays locked.
+    pub amount: Balance,
+    /// The block from which the amount can be unlocked.
+    pub unlock_at: BlockNumber,
+}
+
+/// Voting power delegated to another account.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct Delegation<AccountId> {
+    /// The account voting with the delegated power.
+    pub delegate: AccountId,
+    /// The delegated power, locked on every PIP it is cast on.
+    pub amount: Balance,
+}
+
+/// A vote cast with delegated power, following the vote of the delegate.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct DelegatedVote<AccountId> {
+    /// The delegate whose vote was followed.
+    pub delegate: AccountId,
+    /// The category of the delegation, where `None` covers all PIPs.
+    pub category: Option<PalletName>,
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 pub struct VoteByPip<VoteType> {
@@ -433,6 +621,13 @@ pub trait Config:
 
     /// A call type used by the scheduler.
     type SchedulerCall: From<Call<Self>> + Into<<Self as IdentityConfig>::Proposal>;
+
+    /// The number of blocks a deposit stays locked for, per lock period of its `Conviction`,
+    /// after the vote ended.
+    type ConvictionLockPeriod: Get<Self::BlockNumber>;
+
+    /// The maximum number of delegators of an account per category of PIPs.
+    type MaxDelegators: Get<u32>;
 }
 
 storage_migration_ver!(2);
@@ -521,6 +716,40 @@ decl_storage! {
         /// proposal id -> proposalState
         pub ProposalStates get(fn proposal_state): map hasher(twox_64_concat) PipId => Option<ProposalState>;
 
+        /// How the deposits of votes are weighted when tallying and ordering PIPs.
+        pub VoteWeightingMode get(fn vote_weighting): VoteWeighting;
+
+        /// The conviction and weight of each vote.
+        /// Votes without an entry have no conviction and a weight equal to their deposit.
+        /// (proposal id, account) -> WeightedVote
+        pub WeightedVotes get(fn weighted_vote): double_map hasher(twox_64_concat) PipId, hasher(twox_64_concat) T::AccountId => Option<WeightedVote>;
+
+        /// The weighted support of a proposal, if it is ongoing.
+        /// Proposals without an entry have a weighted support equal to their stake.
+        /// proposal id -> weighted vote count
+        pub WeightedProposalResult: map hasher(twox_64_concat) PipId => Option<WeightedVotingResult>;
+
+        /// Deposits that stay locked due to the conviction of a vote.
+        /// (account, proposal id) -> ConvictionLock
+        pub ConvictionLocks get(fn conviction_lock): double_map hasher(twox_64_concat) T::AccountId, hasher(twox_64_concat) PipId => Option<ConvictionLock<T::BlockNumber>>;
+
+        /// Voting power delegated by an account, per category of PIPs.
+        /// The category of a PIP is the pallet of its proposed call, where `None` covers all PIPs.
+        /// (delegator, category) -> Delegation
+        pub Delegations get(fn delegation): double_map hasher(twox_64_concat) T::AccountId, hasher(twox_64_concat) Option<PalletName> => Option<Delegation<T::AccountId>>;
+
+        /// The accounts that delegated voting power to an account, per category of PIPs.
+        /// (delegate, category) -> delegators
+        pub Delegators get(fn delegators): double_map hasher(twox_64_concat) T::AccountId, hasher(twox_64_concat) Option<PalletName> => Vec<T::AccountId>;
+
+        /// Votes cast with delegated power.
+        /// (proposal id, delegator) -> DelegatedVote
+        pub DelegatedVotes get(fn delegated_vote): double_map hasher(twox_64_concat) PipId, hasher(twox_64_concat) T::AccountId => Option<DelegatedVote<T::AccountId>>;
+
+        /// The part of `ProposalResult` cast with delegated power.
+        /// proposal id -> vote count
+        pub DelegatedProposalResult get(fn delegated_proposal_result): map hasher(twox_64_concat) PipId => VotingResult;
+
         StorageVersion get(fn storage_version) build(|_| Version::new(2)): Version;
     }
 }
@@ -592,6 +821,30 @@ decl_event!(
         ExpirySchedulingFailed(IdentityId, PipId, BlockNumber),
         /// Cancelling the PIP execution failed in the scheduler pallet.
         ExecutionCancellingFailed(PipId),
+        /// The vote weighting was changed.
+        /// (caller DID, old value, new value)
+        VoteWeightingChanged(IdentityId, VoteWeighting, VoteWeighting),
+        /// `AccountId` voted with `Conviction` on the proposal referenced by `PipId`.
+        /// (voter DID, voter, PIP ID, conviction)
+        ConvictionVoted(IdentityId, AccountId, PipId, Conviction),
+        /// A deposit stays locked due to the conviction of the vote.
+        /// (voter, PIP ID, amount, unlock block)
+        ConvictionLocked(AccountId, PipId, Balance, BlockNumber),
+        /// A deposit locked due to the conviction of the vote was unlocked.
+        /// (voter DID, voter, PIP ID, amount)
+        ConvictionUnlocked(IdentityId, AccountId, PipId, Balance),
+        /// Voting power was delegated.
+        /// (delegator DID, delegator, delegate, category, amount)
+        Delegated(IdentityId, AccountId, AccountId, Option<PalletName>, Balance),
+        /// A delegation was revoked.
+        /// (delegator DID, delegator, delegate, category, amount)
+        DelegationRevoked(IdentityId, AccountId, AccountId, Option<PalletName>, Balance),
+        /// A vote was cast with delegated power.
+        /// (delegator, delegate, PIP ID, aye or nay, amount)
+        DelegatedVoteCast(AccountId, AccountId, PipId, bool, Balance),
+        /// A vote cast with delegated power was removed, due to a revocation or a direct vote.
+        /// (delegator, PIP ID)
+        DelegatedVoteRemoved(AccountId, PipId),
     }
 );
 
@@ -636,6 +889,18 @@ decl_error! {
         ScheduledProposalDoesntExist,
         /// A proposal that is not in a scheduled state cannot be executed.
         ProposalNotInScheduledState,
+        /// A vote with conviction can only be cast when the vote weighting is `Conviction`.
+        ConvictionVotingDisabled,
+        /// There is no deposit locked due to conviction for the given PIP.
+        NoConvictionLock,
+        /// The conviction lock period of the deposit hasn't passed yet.
+        ConvictionLockNotExpired,
+        /// An account cannot delegate voting power to itself.
+        SelfDelegation,
+        /// There is no delegation for the given category.
+        NoSuchDelegation,
+        /// The delegate has reached the maximum number of delegators for the given category.
+        TooManyDelegators,
     }
 }
 
@@ -645,6 +910,9 @@ decl_module! {
     pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
         type Error = Error<T>;
 
+        const ConvictionLockPeriod: T::BlockNumber = T::ConvictionLockPeriod::get();
+        const MaxDelegators: u32 = T::MaxDelegators::get();
+
         fn deposit_event() = default;
 
         /// Change whether completed PIPs are pruned.
@@ -798,7 +1066,7 @@ decl_module! {
 
                 // Add vote and update voting counter.
                 // INTERNAL: It is impossible to overflow counters in the first vote.
-                Self::unsafe_vote(id, proposer.clone(), Vote(true, deposit))?;
+                Self::unsafe_vote(id, proposer.clone(), Vote(true, deposit), Conviction::None)?;
 
                 // Adjust live queue.
                 Self::insert_live_queue(id);
@@ -820,72 +1088,31 @@ decl_module! {
         }
 
         /// Vote either in favor (`aye_or_nay` == true) or against a PIP with `id`.
-        /// The "convinction" or strength of the vote is given by `deposit`, which is reserved.
+        /// The strength of the vote is given by `deposit`, which is reserved,
+        /// and weighted according to the current `VoteWeighting`.
         ///
         /// Note that `vote` is *not* additive.
         /// That is, `vote(id, true, 50)` followed by `vote(id, true, 40)`
         /// will first reserve `50` and then refund `50 - 10`, ending up with `40` in deposit.
         /// To add atop of existing votes, you'll need `existing_deposit + addition`.
         ///
+        /// The delegators of `origin` follow the vote with their delegated power,
+        /// while a vote previously cast with power delegated to `origin` is replaced.
+        ///
         /// # Arguments
         /// * `id`, proposal id
         /// * `aye_or_nay`, a bool representing for or against vote
-        /// * `deposit`, the "conviction" with which the vote is made.
+        /// * `deposit`, the strength with which the vote is made.
         ///
         /// # Errors
         /// * `NoSuchProposal` if `id` doesn't reference a valid PIP.
         /// * `NotFromCommunity` if proposal was made by a committee.
         /// * `IncorrectProposalState` if PIP isn't pending.
         /// * `InsufficientDeposit` if `origin` cannot reserve `deposit - old_deposit`.
-        #[weight = <T as Config>::WeightInfo::vote()]
-        pub fn vote(origin, id: PipId, aye_or_nay: bool, deposit: Balance) {
-            let PermissionedCallOriginData {
-                sender: voter,
-                primary_did,
-                ..
-             } = Identity::<T>::ensure_origin_call_permissions(origin)?;
-
-            let pip = Self::proposals(id).ok_or(Error::<T>::NoSuchProposal)?;
-
-            // Proposal must be from the community.
-            let proposer = match pip.proposer {
-                Proposer::Committee(_) => return Err(Error::<T>::NotFromCommunity.into()),
-                Proposer::Community(p) => p,
-            };
-
-            if proposer == voter {
-                // a) Deposit must be above minimum.
-                // Note that proposer can still vote against their own PIP.
-                ensure!(deposit >= Self::min_proposal_deposit(), Error::<T>::IncorrectDeposit);
-            }
-
-            // Proposal must be pending.
-            Self::is_proposal_state(id, ProposalState::Pending)?;
-
-            let old_res = Self::aggregate_result(id);
-
-            with_transaction(|| {
-                // Reserve the deposit, or refund if needed.
-                let curr_deposit = Self::deposits(id, &voter).map(|d| d.amount).unwrap_or_default();
-                if deposit < curr_deposit {
-                    Self::reduce_lock(&voter, curr_deposit - deposit)?;
-                } else {
-                    Self::increase_lock(&voter, deposit - curr_deposit)?;
-                }
-                // Save the vote.
-                Self::unsafe_vote(id, voter.clone(), Vote(aye_or_nay, deposit))
-            })?;
-
-            // Adjust live queue.
-            Self::adjust_live_queue(id, old_res);
-
-            <Deposits<T>>::insert(id, &voter, DepositInfo {
-                owner: voter.clone(),
-                amount: deposit,
-            });
-
-            // Emit event.
-            Self::deposit_event(RawEvent::Voted(primary_did, voter, id, aye_or_nay, deposit));
+        #[weight = <T as Config>::WeightInfo::vote(2 * T::MaxDelegators::get())]
+        pub fn vote(origin, id: PipId, aye_or_nay: bool, deposit: Balance) -> DispatchResultWithPostInfo {
+            let delegators = Self::base_vote(origin, id, aye_or_nay, deposit, Conviction::None)?;
+            Ok(Some(<T as Config>::WeightInfo::vote(delegators)).into())
         }
 
         /// Approves the pending committee PIP given by the `id`.
@@ -1130,6 +1357,134 @@ decl_module! {
                 Self::maybe_prune(did, id, ProposalState::Expired)?;
             }
         }
+
+        /// Change how the deposits of votes are weighted.
+        /// Can only be called by root.
+        ///
+        /// Votes keep the weight they were given when they were cast.
+        ///
+        /// # Arguments
+        /// * `weighting` the new vote weighting.
+        #[weight = (<T as Config>::WeightInfo::set_vote_weighting(), Operational)]
+        pub fn set_vote_weighting(origin, weighting: VoteWeighting) {
+            Self::config::<VoteWeightingMode, _, _>(origin, weighting, RawEvent::VoteWeightingChanged)?;
+        }
+
+        /// Vote either in favor (`aye_or_nay` == true) or against a PIP with `id`,
+        /// weighting the `deposit` by `conviction`.
+        ///
+        /// Works like `vote`, except that the deposit stays locked for the lock period
+        /// of `conviction` after the PIP is closed or the vote is changed.
+        ///
+        /// # Arguments
+        /// * `id`, proposal id
+        /// * `aye_or_nay`, a bool representing for or against vote
+        /// * `deposit`, the amount locked for the vote.
+        /// * `conviction`, the multiplier of the deposit and the length of its lock.
+        ///
+        /// # Errors
+        /// * `ConvictionVotingDisabled` if `conviction` is given but the vote weighting isn't `Conviction`.
+        /// * `NoSuchProposal` if `id` doesn't reference a valid PIP.
+        /// * `NotFromCommunity` if proposal was made by a committee.
+        /// * `IncorrectProposalState` if PIP isn't pending.
+        /// * `InsufficientDeposit` if `origin` cannot lock `deposit`.
+        #[weight = <T as Config>::WeightInfo::vote_with_conviction(2 * T::MaxDelegators::get())]
+        pub fn vote_with_conviction(origin, id: PipId, aye_or_nay: bool, deposit: Balance, conviction: Conviction) -> DispatchResultWithPostInfo {
+            let delegators = Self::base_vote(origin, id, aye_or_nay, deposit, conviction)?;
+            Ok(Some(<T as Config>::WeightInfo::vote_with_conviction(delegators)).into())
+        }
+
+        /// Unlocks the deposit that stayed locked due to the conviction of a vote on the PIP with `id`.
+        ///
+        /// # Errors
+        /// * `NoConvictionLock` if `origin` has no deposit locked due to conviction for `id`.
+        /// * `ConvictionLockNotExpired` if the conviction lock period hasn't passed yet.
+        #[weight = <T as Config>::WeightInfo::unlock_conviction_deposit()]
+        pub fn unlock_conviction_deposit(origin, id: PipId) {
+            let PermissionedCallOriginData {
+                sender,
+                primary_did,
+                ..
+            } = Identity::<T>::ensure_origin_call_permissions(origin)?;
+
+            let lock = Self::conviction_lock(&sender, id).ok_or(Error::<T>::NoConvictionLock)?;
+            ensure!(System::<T>::block_number() >= lock.unlock_at, Error::<T>::ConvictionLockNotExpired);
+
+            Self::reduce_lock(&sender, lock.amount)?;
+            <ConvictionLocks<T>>::remove(&sender, id);
+            Self::deposit_event(RawEvent::ConvictionUnlocked(primary_did, sender, id, lock.amount));
+        }
+
+        /// Delegates `amount` of voting power to `delegate` for the PIPs of `category`,
+        /// or for all PIPs if `category` is `None`, replacing any existing delegation for `category`.
+        ///
+        /// Whenever `delegate` votes on a PIP, the delegated power is cast as a vote in the same direction,
+        /// unless `origin` voted directly on the PIP. This includes votes already cast on pending PIPs.
+        /// A delegation for a category takes precedence over a delegation for all PIPs.
+        /// Votes cast with delegated power are not delegated further.
+        ///
+        /// Like the deposit of a direct vote, `amount` is locked separately on every PIP it is cast on.
+        /// The delegated power isn't cast on PIPs for which `origin` cannot lock `amount`.
+        ///
+        /// # Arguments
+        /// * `delegate`, the account voting with the delegated power.
+        /// * `category`, the pallet of the proposed calls the delegation applies to, or `None` for all PIPs.
+        /// * `amount`, the delegated voting power.
+        ///
+        /// # Errors
+        /// * `SelfDelegation` if `origin` is `delegate`.
+        /// * `TooManyDelegators` if `delegate` reached the maximum number of delegators for `category`.
+        #[weight = <T as Config>::WeightInfo::delegate(Module::<T>::live_queue_len())]
+        pub fn delegate(origin, delegate: T::AccountId, category: Option<PalletName>, amount: Balance) {
+            let PermissionedCallOriginData {
+                sender: delegator,
+                primary_did,
+                ..
+            } = Identity::<T>::ensure_origin_call_permissions(origin)?;
+
+            ensure!(delegator != delegate, Error::<T>::SelfDelegation);
+            ensure_opt_string_limited::<T>(category.as_ref().map(|c| c.as_bytes()))?;
+
+            with_transaction(|| {
+                if let Some(delegation) = Self::delegation(&delegator, &category) {
+                    Self::remove_delegation(primary_did, &delegator, &category, delegation)?;
+                }
+                let delegators = Delegators::<T>::decode_len(&delegate, &category).unwrap_or_default();
+                ensure!(delegators < T::MaxDelegators::get() as usize, Error::<T>::TooManyDelegators);
+
+                <Delegations<T>>::insert(&delegator, &category, Delegation {
+                    delegate: delegate.clone(),
+                    amount,
+                });
+                <Delegators<T>>::append(&delegate, &category, &delegator);
+                Self::deposit_event(RawEvent::Delegated(primary_did, delegator.clone(), delegate, category, amount));
+
+                Self::sync_delegated_votes(&delegator)
+            })?;
+        }
+
+        /// Revokes the delegation of voting power for the PIPs of `category`,
+        /// or for all PIPs if `category` is `None`.
+        ///
+        /// Votes cast with the delegated power on pending PIPs are removed, unlocking their deposits,
+        /// unless they are covered by the delegation for all PIPs.
+        ///
+        /// # Errors
+        /// * `NoSuchDelegation` if there's no delegation for `category`.
+        #[weight = <T as Config>::WeightInfo::revoke_delegation(Module::<T>::live_queue_len())]
+        pub fn revoke_delegation(origin, category: Option<PalletName>) {
+            let PermissionedCallOriginData {
+                sender: delegator,
+                primary_did,
+                ..
+            } = Identity::<T>::ensure_origin_call_permissions(origin)?;
+
+            let delegation = Self::delegation(&delegator, &category).ok_or(Error::<T>::NoSuchDelegation)?;
+            with_transaction(|| {
+                Self::remove_delegation(primary_did, &delegator, &category, delegation)?;
+                Self::sync_delegated_votes(&delegator)
+            })?;
+        }
     }
 }
 
@@ -1176,6 +1531,112 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Casts a vote of `origin` on the PIP with `id`, locking `deposit` with `conviction`.
+    /// The delegators of `origin` follow the vote.
+    ///
+    /// Returns the number of delegators of `origin` for the PIP.
+    fn base_vote(
+        origin: T::RuntimeOrigin,
+        id: PipId,
+        aye_or_nay: bool,
+        deposit: Balance,
+        conviction: Conviction,
+    ) -> Result<u32, DispatchError> {
+        let PermissionedCallOriginData {
+            sender: voter,
+            primary_did,
+            ..
+        } = Identity::<T>::ensure_origin_call_permissions(origin)?;
+
+        ensure!(
+            conviction == Conviction::None || Self::vote_weighting() == VoteWeighting::Conviction,
+            Error::<T>::ConvictionVotingDisabled
+        );
+
+        let pip = Self::proposals(id).ok_or(Error::<T>::NoSuchProposal)?;
+        let category = Self::pip_category(&pip.proposal);
+
+        // Proposal must be from the community.
+        let proposer = match pip.proposer {
+            Proposer::Committee(_) => return Err(Error::<T>::NotFromCommunity.into()),
+            Proposer::Community(p) => p,
+        };
+
+        if proposer == voter {
+            // a) Deposit must be above minimum.
+            // Note that proposer can still vote against their own PIP.
+            ensure!(
+                deposit >= Self::min_proposal_deposit(),
+                Error::<T>::IncorrectDeposit
+            );
+        }
+
+        // Proposal must be pending.
+        Self::is_proposal_state(id, ProposalState::Pending)?;
+
+        let old_res = Self::aggregate_result(id);
+
+        let delegators = with_transaction(|| {
+            // A direct vote overrides the vote cast with delegated power, if any,
+            // whose deposit is then adjusted to the deposit of the direct vote.
+            if <DelegatedVotes<T>>::take(id, &voter).is_some() {
+                Self::remove_delegated_result(id, &voter);
+                Self::deposit_event(RawEvent::DelegatedVoteRemoved(voter.clone(), id));
+            }
+
+            let curr_deposit = Self::deposits(id, &voter)
+                .map(|d| d.amount)
+                .unwrap_or_default();
+            let curr_conviction = Self::weighted_vote(id, &voter)
+                .map(|v| v.conviction)
+                .unwrap_or_default();
+            match Self::conviction_unlock_at(curr_conviction) {
+                // The current deposit stays locked due to its conviction, so lock the new one in full.
+                Some(unlock_at) if !curr_deposit.is_zero() => {
+                    Self::lock_with_conviction(&voter, id, curr_deposit, unlock_at);
+                    Self::increase_lock(&voter, deposit)?;
+                }
+                // Reserve the deposit, or refund if needed.
+                _ if deposit < curr_deposit => Self::reduce_lock(&voter, curr_deposit - deposit)?,
+                _ => Self::increase_lock(&voter, deposit - curr_deposit)?,
+            }
+            // Save the vote.
+            Self::unsafe_vote(id, voter.clone(), Vote(aye_or_nay, deposit), conviction)?;
+            // The delegators of the voter follow the vote.
+            Self::sync_delegators(id, &category, &voter)
+        })?;
+
+        // Adjust live queue.
+        Self::adjust_live_queue(id, old_res);
+
+        <Deposits<T>>::insert(
+            id,
+            &voter,
+            DepositInfo {
+                owner: voter.clone(),
+                amount: deposit,
+            },
+        );
+
+        // Emit events.
+        Self::deposit_event(RawEvent::Voted(
+            primary_did,
+            voter.clone(),
+            id,
+            aye_or_nay,
+            deposit,
+        ));
+        if conviction != Conviction::None {
+            Self::deposit_event(RawEvent::ConvictionVoted(
+                primary_did,
+                voter,
+                id,
+                conviction,
+            ));
+        }
+        Ok(delegators)
+    }
+
     /// Rejects the given `id`, refunding the deposit, and possibly pruning the proposal's data.
     fn unsafe_reject_proposal(did: IdentityId, id: PipId) -> DispatchResult {
         Self::maybe_prune(did, id, ProposalState::Rejected)?;
@@ -1183,12 +1644,20 @@ impl<T: Config> Module<T> {
     }
 
     /// Refunds any tokens used to vote or bond a proposal.
+    /// Deposits of votes with conviction stay locked until their conviction lock period passed.
     ///
     /// This operation is idempotent wrt. chain state,
     /// i.e., once run, refunding again will refund nothing.
     fn refund_proposal(did: IdentityId, id: PipId) -> DispatchResult {
         let mut total_refund = 0;
-        for (_, deposit) in Deposits::<T>::drain_prefix(id) {
+        for (voter, deposit) in Deposits::<T>::drain_prefix(id) {
+            let conviction = Self::weighted_vote(id, &voter)
+                .map(|v| v.conviction)
+                .unwrap_or_default();
+            if let Some(unlock_at) = Self::conviction_unlock_at(conviction) {
+                Self::lock_with_conviction(&deposit.owner, id, deposit.amount, unlock_at);
+                continue;
+            }
             Self::reduce_lock(&deposit.owner, deposit.amount)?;
             total_refund = total_refund.saturating_add(deposit.amount);
         }
@@ -1235,8 +1704,14 @@ impl<T: Config> Module<T> {
         Self::decrement_count_if_active(state);
         if prune {
             ProposalResult::remove(id);
+            WeightedProposalResult::remove(id);
+            DelegatedProposalResult::remove(id);
             #[allow(deprecated)]
             ProposalVotes::<T>::remove_prefix(id, None);
+            #[allow(deprecated)]
+            WeightedVotes::<T>::remove_prefix(id, None);
+            #[allow(deprecated)]
+            DelegatedVotes::<T>::remove_prefix(id, None);
             <ProposalMetadata<T>>::remove(id);
             if let Some(Proposer::Committee(_)) = Self::proposals(id).map(|p| p.proposer) {
                 CommitteePips::mutate(|list| list.retain(|&i| i != id));
@@ -1391,6 +1866,40 @@ impl<T: Config> Module<T> {
         <T as Config>::Currency::reduce_lock(PIPS_LOCK_ID, acc, amount)
     }
 
+    /// Returns the block until which a deposit with `conviction` stays locked, if ending its vote now.
+    fn conviction_unlock_at(conviction: Conviction) -> Option<T::BlockNumber> {
+        let periods = conviction.lock_periods();
+        if periods == 0 {
+            return None;
+        }
+        let lock_period = T::ConvictionLockPeriod::get().saturating_mul(periods.into());
+        Some(System::<T>::block_number().saturating_add(lock_period))
+    }
+
+    /// Keeps `amount` of `acc`'s deposit on the PIP with `id` locked until `unlock_at`.
+    /// The amount is added to any existing conviction lock, which is extended if needed.
+    fn lock_with_conviction(
+        acc: &T::AccountId,
+        id: PipId,
+        amount: Balance,
+        unlock_at: T::BlockNumber,
+    ) {
+        <ConvictionLocks<T>>::mutate(acc, id, |lock| {
+            let lock = lock.get_or_insert(ConvictionLock {
+                amount: 0,
+                unlock_at,
+            });
+            lock.amount = lock.amount.saturating_add(amount);
+            lock.unlock_at = lock.unlock_at.max(unlock_at);
+        });
+        Self::deposit_event(RawEvent::ConvictionLocked(
+            acc.clone(),
+            id,
+            amount,
+            unlock_at,
+        ));
+    }
+
     /// Retrieve votes for a proposal represented by PipId `id`.
     pub fn get_votes(id: PipId) -> VoteCount
     where
@@ -1401,9 +1910,35 @@ impl<T: Config> Module<T> {
         }
 
         let voting = Self::proposal_result(id);
+        let weighted = Self::weighted_proposal_result(id);
+        let delegated = Self::delegated_proposal_result(id);
         VoteCount::ProposalFound {
             ayes: voting.ayes_stake,
             nays: voting.nays_stake,
+            ayes_weight: weighted.ayes_weight,
+            nays_weight: weighted.nays_weight,
+            delegated_ayes: delegated.ayes_stake,
+            delegated_nays: delegated.nays_stake,
+        }
+    }
+
+    /// Retrieve the weighted support of the proposal represented by PipId `id`.
+    pub fn weighted_proposal_result(id: PipId) -> WeightedVotingResult {
+        WeightedProposalResult::get(id).unwrap_or_else(|| {
+            let voting = Self::proposal_result(id);
+            WeightedVotingResult {
+                ayes_weight: voting.ayes_stake,
+                nays_weight: voting.nays_stake,
+            }
+        })
+    }
+
+    /// Returns the weight of a vote with `deposit` and `conviction` under the current vote weighting.
+    fn vote_weight(deposit: Balance, conviction: Conviction) -> Balance {
+        match Self::vote_weighting() {
+            VoteWeighting::Linear => deposit,
+            VoteWeighting::Quadratic => deposit.integer_sqrt(),
+            VoteWeighting::Conviction => conviction.weight(deposit),
         }
     }
 
@@ -1415,32 +1950,57 @@ impl<T: Config> Module<T> {
             .collect()
     }
 
-    /// Retrieve proposals `address` voted on
+    /// Retrieve proposals `address` voted on directly.
     pub fn voted_on(address: T::AccountId) -> Vec<PipId> {
         <Proposals<T>>::iter()
+            .filter(|(_, pip)| !DelegatedVotes::<T>::contains_key(pip.id, &address))
             .filter_map(|(_, pip)| Self::proposal_vote(pip.id, &address).map(|_| pip.id))
             .collect::<Vec<_>>()
     }
 
-    /// Changes the vote of `voter` to `vote`, if any.
-    fn unsafe_vote(id: PipId, voter: T::AccountId, vote: Vote) -> DispatchResult {
-        let mut stats = Self::proposal_result(id);
+    /// Retrieve proposals on which a vote was cast with the delegated power of `address`.
+    pub fn delegated_voted_on(address: T::AccountId) -> Vec<PipId> {
+        <Proposals<T>>::iter()
+            .filter(|(_, pip)| DelegatedVotes::<T>::contains_key(pip.id, &address))
+            .map(|(_, pip)| pip.id)
+            .collect::<Vec<_>>()
+    }
 
-        // Update the vote and get the old one, if any, in which case also remove it from stats.
-        if let Some(Vote(direction, deposit)) = ProposalVotes::<T>::get(id, voter.clone()) {
-            let (count, stake) = match direction {
-                true => (&mut stats.ayes_count, &mut stats.ayes_stake),
-                false => (&mut stats.nays_count, &mut stats.nays_stake),
-            };
-            *count -= 1;
-            *stake -= deposit;
-        }
+    /// Dry-runs the execution of the PIP with `id` against the current state,
+    /// regardless of its state.
+    ///
+    /// The changes are rolled back, see [`dry_run`].
+    pub fn dry_run_pip(
+        id: PipId,
+    ) -> Result<DryRunOutcome<<T as system::Config>::RuntimeEvent>, DispatchError> {
+        let pip = Self::proposals(id).ok_or(Error::<T>::NoSuchProposal)?;
+        dry_run::<T, _>(pip.proposal, system::RawOrigin::Root.into())
+    }
+
+    /// Changes the vote of `voter` to `vote` with `conviction`, if any.
+    fn unsafe_vote(
+        id: PipId,
+        voter: T::AccountId,
+        vote: Vote,
+        conviction: Conviction,
+    ) -> DispatchResult {
+        // Remove the old vote, if any, from stats.
+        let (mut stats, mut weighted) = Self::results_without_vote(id, &voter);
 
         // Add new vote to stats.
         let Vote(direction, deposit) = vote;
-        let (count, stake) = match direction {
-            true => (&mut stats.ayes_count, &mut stats.ayes_stake),
-            false => (&mut stats.nays_count, &mut stats.nays_stake),
+        let weight = Self::vote_weight(deposit, conviction);
+        let (count, stake, total_weight) = match direction {
+            true => (
+                &mut stats.ayes_count,
+                &mut stats.ayes_stake,
+                &mut weighted.ayes_weight,
+            ),
+            false => (
+                &mut stats.nays_count,
+                &mut stats.nays_stake,
+                &mut weighted.nays_weight,
+            ),
         };
         *count = count
             .checked_add(1)
@@ -1448,26 +2008,262 @@ impl<T: Config> Module<T> {
         *stake = stake
             .checked_add(deposit)
             .ok_or(Error::<T>::StakeAmountOfVotesExceeded)?;
+        *total_weight = total_weight
+            .checked_add(weight)
+            .ok_or(Error::<T>::StakeAmountOfVotesExceeded)?;
 
         // Commit all changes.
         ProposalResult::insert(id, stats);
-        ProposalVotes::<T>::insert(id, voter, vote);
+        WeightedProposalResult::insert(id, weighted);
+        ProposalVotes::<T>::insert(id, &voter, vote);
+        WeightedVotes::<T>::insert(id, voter, WeightedVote { conviction, weight });
+
+        Ok(())
+    }
+
+    /// Removes the vote of `voter`, if any.
+    fn unsafe_remove_vote(id: PipId, voter: &T::AccountId) {
+        let (stats, weighted) = Self::results_without_vote(id, voter);
+        ProposalResult::insert(id, stats);
+        WeightedProposalResult::insert(id, weighted);
+        ProposalVotes::<T>::remove(id, voter);
+        WeightedVotes::<T>::remove(id, voter);
+    }
+
+    /// Returns the raw and weighted results of the PIP with `id` without the vote of `voter`, if any.
+    fn results_without_vote(
+        id: PipId,
+        voter: &T::AccountId,
+    ) -> (VotingResult, WeightedVotingResult) {
+        let mut stats = Self::proposal_result(id);
+        let mut weighted = Self::weighted_proposal_result(id);
+        if let Some(Vote(direction, deposit)) = ProposalVotes::<T>::get(id, voter) {
+            let weight = Self::weighted_vote(id, voter).map_or(deposit, |v| v.weight);
+            let (count, stake, total_weight) = match direction {
+                true => (
+                    &mut stats.ayes_count,
+                    &mut stats.ayes_stake,
+                    &mut weighted.ayes_weight,
+                ),
+                false => (
+                    &mut stats.nays_count,
+                    &mut stats.nays_stake,
+                    &mut weighted.nays_weight,
+                ),
+            };
+            *count -= 1;
+            *stake -= deposit;
+            *total_weight -= weight;
+        }
+        (stats, weighted)
+    }
+
+    /// Removes the current vote of `delegator`, cast with delegated power, from `DelegatedProposalResult`.
+    fn remove_delegated_result(id: PipId, delegator: &T::AccountId) {
+        if let Some(Vote(direction, deposit)) = ProposalVotes::<T>::get(id, delegator) {
+            DelegatedProposalResult::mutate(id, |result| {
+                let (count, stake) = match direction {
+                    true => (&mut result.ayes_count, &mut result.ayes_stake),
+                    false => (&mut result.nays_count, &mut result.nays_stake),
+                };
+                *count = count.saturating_sub(1);
+                *stake = stake.saturating_sub(deposit);
+            });
+        }
+    }
+
+    /// Returns the category of a PIP with `proposal`, i.e., the pallet of the proposed call.
+    pub fn pip_category(proposal: &T::Proposal) -> PalletName {
+        PalletName::from(proposal.get_call_metadata().pallet_name)
+    }
+
+    /// Returns the number of PIPs in the live queue.
+    fn live_queue_len() -> u32 {
+        LiveQueue::decode_len().unwrap_or_default() as u32
+    }
+
+    /// Returns the delegation of `delegator` applying to the PIPs of `category`, if any,
+    /// along with the category of the delegation.
+    fn effective_delegation(
+        delegator: &T::AccountId,
+        category: &PalletName,
+    ) -> Option<(Option<PalletName>, Delegation<T::AccountId>)> {
+        let category = Some(category.clone());
+        match Self::delegation(delegator, &category) {
+            Some(delegation) => Some((category, delegation)),
+            None => Self::delegation(delegator, &None::<PalletName>)
+                .map(|delegation| (None, delegation)),
+        }
+    }
+
+    /// Removes the delegation of `delegator` for `category`.
+    /// The votes cast with the delegated power are left to `sync_delegated_votes`.
+    fn remove_delegation(
+        did: IdentityId,
+        delegator: &T::AccountId,
+        category: &Option<PalletName>,
+        delegation: Delegation<T::AccountId>,
+    ) -> DispatchResult {
+        <Delegations<T>>::remove(delegator, category);
+        <Delegators<T>>::mutate(&delegation.delegate, category, |delegators| {
+            delegators.retain(|d| d != delegator)
+        });
+        Self::deposit_event(RawEvent::DelegationRevoked(
+            did,
+            delegator.clone(),
+            delegation.delegate,
+            category.clone(),
+            delegation.amount,
+        ));
+        Ok(())
+    }
+
+    /// Updates the votes cast with the delegated power of `delegator` on all pending PIPs.
+    fn sync_delegated_votes(delegator: &T::AccountId) -> DispatchResult {
+        for SnapshottedPip { id, .. } in LiveQueue::get() {
+            if let Some(pip) = Self::proposals(id) {
+                let old_res = Self::aggregate_result(id);
+                Self::sync_delegated_vote(id, &Self::pip_category(&pip.proposal), delegator)?;
+                Self::adjust_live_queue(id, old_res);
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the votes cast with delegated power by the delegators of `delegate`
+    /// on the pending PIP with `id` of `category`.
+    ///
+    /// Returns the number of delegators.
+    fn sync_delegators(
+        id: PipId,
+        category: &PalletName,
+        delegate: &T::AccountId,
+    ) -> Result<u32, DispatchError> {
+        let mut count = 0;
+        let delegators = Self::delegators(delegate, Some(category.clone()))
+            .into_iter()
+            .chain(Self::delegators(delegate, None::<PalletName>));
+        for delegator in delegators {
+            Self::sync_delegated_vote(id, category, &delegator)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Updates the vote cast with the delegated power of `delegator`
+    /// on the pending PIP with `id` of `category`, following the vote of the delegate, if any.
+    ///
+    /// Direct votes of `delegator` are left untouched.
+    fn sync_delegated_vote(
+        id: PipId,
+        category: &PalletName,
+        delegator: &T::AccountId,
+    ) -> DispatchResult {
+        let delegated = Self::delegated_vote(id, delegator);
+        if delegated.is_none() && ProposalVotes::<T>::contains_key(id, delegator) {
+            return Ok(());
+        }
+
+        // Votes cast with delegated power are not delegated further.
+        let followed = Self::effective_delegation(delegator, category).and_then(
+            |(category, delegation)| match (
+                Self::proposal_vote(id, &delegation.delegate),
+                Self::delegated_vote(id, &delegation.delegate),
+            ) {
+                (Some(Vote(aye_or_nay, _)), None) => Some((category, delegation, aye_or_nay)),
+                _ => None,
+            },
+        );
+        if delegated.is_some() {
+            Self::remove_delegated_result(id, delegator);
+        }
+        // The delegated power is not cast if it cannot be locked for this PIP.
+        let followed = match followed {
+            Some((_, delegation, _))
+                if Self::bond_delegated_deposit(id, delegator, delegation.amount).is_err() =>
+            {
+                None
+            }
+            followed => followed,
+        };
+        match followed {
+            Some((category, Delegation { delegate, amount }, aye_or_nay)) => {
+                let vote = Vote(aye_or_nay, amount);
+                Self::unsafe_vote(id, delegator.clone(), vote, Conviction::None)?;
+                DelegatedProposalResult::mutate(id, |result| {
+                    let (count, stake) = match aye_or_nay {
+                        true => (&mut result.ayes_count, &mut result.ayes_stake),
+                        false => (&mut result.nays_count, &mut result.nays_stake),
+                    };
+                    *count += 1;
+                    *stake += amount;
+                });
+                <DelegatedVotes<T>>::insert(
+                    id,
+                    delegator,
+                    DelegatedVote {
+                        delegate: delegate.clone(),
+                        category,
+                    },
+                );
+                Self::deposit_event(RawEvent::DelegatedVoteCast(
+                    delegator.clone(),
+                    delegate,
+                    id,
+                    aye_or_nay,
+                    amount,
+                ));
+            }
+            None if delegated.is_some() => {
+                Self::unsafe_remove_vote(id, delegator);
+                <DelegatedVotes<T>>::remove(id, delegator);
+                if let Some(deposit) = <Deposits<T>>::take(id, delegator) {
+                    Self::reduce_lock(delegator, deposit.amount)?;
+                }
+                Self::deposit_event(RawEvent::DelegatedVoteRemoved(delegator.clone(), id));
+            }
+            None => {}
+        }
+        Ok(())
+    }
 
+    /// Locks `amount` as the deposit of the vote cast with the delegated power of `delegator`
+    /// on the PIP with `id`, adjusting the deposit already locked for the PIP, if any.
+    fn bond_delegated_deposit(
+        id: PipId,
+        delegator: &T::AccountId,
+        amount: Balance,
+    ) -> DispatchResult {
+        let curr_deposit = Self::deposits(id, delegator)
+            .map(|d| d.amount)
+            .unwrap_or_default();
+        if amount < curr_deposit {
+            Self::reduce_lock(delegator, curr_deposit - amount)?;
+        } else {
+            Self::increase_lock(delegator, amount - curr_deposit)?;
+        }
+        <Deposits<T>>::insert(
+            id,
+            delegator,
+            DepositInfo {
+                owner: delegator.clone(),
+                amount,
+            },
+        );
         Ok(())
     }
 
-    /// Construct a `SnapshottedPip` from a `PipId`.
+    /// Construct a `SnapshottedPip` from a `PipId`, using the weighted support of the PIP.
     /// `true` denotes a positive sign.
     fn aggregate_result(id: PipId) -> SnapshottedPip {
-        let VotingResult {
-            ayes_stake,
-            nays_stake,
-            ..
-        } = ProposalResult::get(id);
-        let weight = if ayes_stake >= nays_stake {
-            (true, ayes_stake - nays_stake)
+        let WeightedVotingResult {
+            ayes_weight,
+            nays_weight,
+        } = Self::weighted_proposal_result(id);
+        let weight = if ayes_weight >= nays_weight {
+            (true, ayes_weight - nays_weight)
         } else {
-            (false, nays_stake - ayes_stake)
+            (false, nays_weight - ayes_weight)
         };
         SnapshottedPip { id, weight }
     }
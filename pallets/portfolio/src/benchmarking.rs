@@ -188,4 +188,35 @@ benchmarks! {
         let portfolio_name = PortfolioName("AliceOwnsBobControls".as_bytes().to_vec());
         Module::<T>::allow_identity_to_create_portfolios(alice.clone().origin().into(), bob.did()).unwrap();
     }: _(bob.origin, alice.did(), portfolio_name)
+
+    lock_portfolio_assets {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did());
+        let asset_id = create_and_issue_sample_asset(&alice, true, None, b"MyAsset", true);
+        let release_at = Some(pallet_timestamp::Pallet::<T>::get() + 1u32.into());
+    }: _(alice.origin, alice_default_portfolio, asset_id, ONE_UNIT, PortfolioLockReason::Collateral, release_at, Some(alice.did()))
+    verify {
+        assert_eq!(PortfolioLockedByLocks::get(&alice_default_portfolio, &asset_id), ONE_UNIT);
+    }
+
+    release_portfolio_lock {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let alice_default_portfolio = PortfolioId::default_portfolio(alice.did());
+        let asset_id = create_and_issue_sample_asset(&alice, true, None, b"MyAsset", true);
+        let release_at = Some(pallet_timestamp::Pallet::<T>::get() + 1u32.into());
+        Module::<T>::lock_portfolio_assets(
+            alice.origin().into(),
+            alice_default_portfolio,
+            asset_id,
+            ONE_UNIT,
+            PortfolioLockReason::Collateral,
+            release_at,
+            Some(alice.did())
+        ).unwrap();
+        let lock_id = PortfolioLockId(NextPortfolioLockId::get().0 - 1);
+    }: _(alice.origin, alice_default_portfolio, lock_id)
+    verify {
+        assert_eq!(PortfolioLockedByLocks::get(&alice_default_portfolio, &asset_id), 0);
+        assert!(PortfolioLocks::<T>::get(&alice_default_portfolio, lock_id).is_none());
+    }
 }
@@ -29,6 +29,9 @@
 //! - `move_portfolio_funds`: Moves specified amounts of assets from one portfolio to another portfolio
 //!   of the same DID.
 //! - `rename_portfolio`: Renames a user portfolio.
+//! - `lock_portfolio_assets`: Locks an amount of an asset in a portfolio, with a reason, an
+//!   optional release time and an optional release authority.
+//! - `release_portfolio_lock`: Releases a portfolio lock.
 //!
 //! ### Public Functions
 //!
@@ -39,6 +42,7 @@
 //! - `ensure_portfolio_custody`: Makes sure that the given identity has custodian access over the portfolio.
 //! - `ensure_portfolio_transfer_validity`: Makes sure that a transfer between two portfolios is valid.
 //! - `quit_portfolio_custody`: Returns the custody of the portfolio to the owner unilaterally.
+//! - `portfolio_locks`: Returns all locks of a portfolio.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -63,8 +67,8 @@ use polymesh_common_utilities::traits::portfolio::PortfolioSubTrait;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::{
     extract_auth, identity_id::PortfolioValidityResult, storage_migrate_on, storage_migration_ver,
-    Balance, Fund, FundDescription, IdentityId, NFTId, PortfolioId, PortfolioKind, PortfolioName,
-    PortfolioNumber, SecondaryKey,
+    Balance, Fund, FundDescription, IdentityId, NFTId, PortfolioId, PortfolioKind, PortfolioLock,
+    PortfolioLockId, PortfolioLockReason, PortfolioName, PortfolioNumber, SecondaryKey,
 };
 
 type Identity<T> = pallet_identity::Module<T>;
@@ -127,6 +131,19 @@ decl_storage! {
         pub AllowedCustodians get(fn allowed_custodians):
             double_map hasher(identity) IdentityId, hasher(identity) IdentityId => bool;
 
+        /// The locks of a portfolio, created by `lock_portfolio_assets`.
+        pub PortfolioLocks get(fn portfolio_lock):
+            double_map hasher(twox_64_concat) PortfolioId, hasher(twox_64_concat) PortfolioLockId =>
+                Option<PortfolioLock<T::Moment>>;
+
+        /// Total amount of each asset held by the locks of a portfolio.
+        /// Like `PortfolioLockedAssets`, these assets show up in portfolio balance but can not be transferred away.
+        pub PortfolioLockedByLocks get(fn locked_by_locks):
+            double_map hasher(twox_64_concat) PortfolioId, hasher(blake2_128_concat) AssetId => Balance;
+
+        /// The next portfolio lock id.
+        pub NextPortfolioLockId get(fn next_portfolio_lock_id): PortfolioLockId;
+
         /// Storage version.
         StorageVersion get(fn storage_version) build(|_| Version::new(3)): Version;
     }
@@ -173,7 +190,17 @@ decl_error! {
         /// The sender identity can't be the same as the receiver identity.
         InvalidTransferSenderIdMatchesReceiverId,
         /// Adding itself as an AllowedCustodian is not permitted.
-        SelfAdditionNotAllowed
+        SelfAdditionNotAllowed,
+        /// Trying to lock an amount of zero assets.
+        EmptyPortfolioLock,
+        /// The release time of a portfolio lock must be in the future.
+        InvalidPortfolioLockReleaseTime,
+        /// The portfolio lock doesn't exist.
+        PortfolioLockNotFound,
+        /// The caller is not allowed to release the portfolio lock.
+        UnauthorizedPortfolioLockRelease,
+        /// The portfolio has more locks than the given limit.
+        TooManyPortfolioLocks
     }
 }
 
@@ -416,6 +443,62 @@ decl_module! {
         ) -> DispatchResult {
             Self::base_create_custody_portfolio(origin, portfolio_owner_id, portfolio_name)
         }
+
+        /// Locks `amount` of `asset_id` in `portfolio_id`. The locked amount still shows up in the
+        /// portfolio balance, but can't be moved away until the lock is released.
+        ///
+        /// # Arguments
+        /// * `portfolio_id` - the [`PortfolioId`] holding the assets.
+        /// * `asset_id` - the [`AssetId`] of the locked asset.
+        /// * `amount` - the amount to lock.
+        /// * `reason` - the [`PortfolioLockReason`] of the lock.
+        /// * `release_at` - optional time after which the custodian can release the lock.
+        /// * `release_authority` - optional identity that can release the lock at any time.
+        ///
+        /// If neither `release_at` nor `release_authority` are given, the custodian can release the lock at any time.
+        ///
+        /// # Errors
+        /// * `EmptyPortfolioLock` if `amount` is zero.
+        /// * `InvalidPortfolioLockReleaseTime` if `release_at` is not in the future.
+        /// * `UnauthorizedCustodian` if the caller is not the custodian of `portfolio_id`.
+        /// * `InsufficientPortfolioBalance` if the portfolio does not have enough free balance.
+        ///
+        /// # Permissions
+        /// * Portfolio
+        #[weight = <T as Config>::WeightInfo::lock_portfolio_assets()]
+        pub fn lock_portfolio_assets(
+            origin,
+            portfolio_id: PortfolioId,
+            asset_id: AssetId,
+            amount: Balance,
+            reason: PortfolioLockReason,
+            release_at: Option<T::Moment>,
+            release_authority: Option<IdentityId>
+        ) -> DispatchResult {
+            Self::base_lock_portfolio_assets(
+                origin,
+                portfolio_id,
+                asset_id,
+                amount,
+                reason,
+                release_at,
+                release_authority
+            )
+        }
+
+        /// Releases the lock `lock_id` of `portfolio_id`.
+        ///
+        /// The release authority of the lock can release it at any time. The custodian of the portfolio
+        /// can release it once its release time has passed, or at any time if the lock has neither
+        /// a release time nor a release authority.
+        ///
+        /// # Errors
+        /// * `PortfolioLockNotFound` if the lock doesn't exist.
+        /// * `UnauthorizedPortfolioLockRelease` if the caller can't release the lock now.
+        #[weight = <T as Config>::WeightInfo::release_portfolio_lock()]
+        pub fn release_portfolio_lock(origin, portfolio_id: PortfolioId, lock_id: PortfolioLockId) -> DispatchResult {
+            Self::base_release_portfolio_lock(origin, portfolio_id, lock_id)
+        }
     }
 }
 
@@ -622,7 +705,7 @@ impl<T: Config> Module<T> {
     ) -> DispatchResult {
         // Ensure portfolio has enough free balance
         let total_balance = Self::portfolio_asset_balances(&pid, asset_id);
-        let locked_balance = Self::locked_assets(&pid, asset_id);
+        let locked_balance = Self::total_locked(&pid, asset_id);
         let remaining_balance = total_balance
             .checked_sub(amount)
             .filter(|rb| rb >= &locked_balance)
@@ -654,7 +737,7 @@ impl<T: Config> Module<T> {
     ) -> DispatchResult {
         T::Asset::ensure_granular(asset_id, amount)?;
         Self::portfolio_asset_balances(portfolio, asset_id)
-            .saturating_sub(Self::locked_assets(portfolio, asset_id))
+            .saturating_sub(Self::total_locked(portfolio, asset_id))
             .checked_sub(amount)
             .ok_or_else(|| Error::<T>::InsufficientPortfolioBalance.into())
             .map(drop)
@@ -667,6 +750,83 @@ impl<T: Config> Module<T> {
         PortfolioLockedAssets::mutate(portfolio, asset_id, |l| *l = l.saturating_add(amount));
     }
 
+    /// Returns the amount of `asset_id` locked in `portfolio`, by settlements, portfolio locks and vesting.
+    pub fn total_locked(portfolio: &PortfolioId, asset_id: &AssetId) -> Balance {
+        Self::locked_assets(portfolio, asset_id)
+            .saturating_add(Self::locked_by_locks(portfolio, asset_id))
+            .saturating_add(T::Asset::unvested_balance(portfolio, asset_id))
+    }
+
+    /// Returns all locks of `portfolio_id`.
+    pub fn portfolio_locks(
+        portfolio_id: &PortfolioId,
+    ) -> Vec<(PortfolioLockId, PortfolioLock<T::Moment>)> {
+        PortfolioLocks::<T>::iter_prefix(portfolio_id).collect()
+    }
+
+    /// Moves the lock `lock_id` of the `from` portfolio to the `to` portfolio, keeping its id.
+    /// The locked units must be moved separately by the caller.
+    pub fn unchecked_move_portfolio_lock(
+        caller_did: IdentityId,
+        from: &PortfolioId,
+        to: &PortfolioId,
+        lock_id: PortfolioLockId,
+        lock: PortfolioLock<T::Moment>,
+    ) {
+        PortfolioLockedByLocks::mutate(from, &lock.asset_id, |locked| {
+            *locked = locked.saturating_sub(lock.amount)
+        });
+        PortfolioLockedByLocks::mutate(to, &lock.asset_id, |locked| {
+            *locked = locked.saturating_add(lock.amount)
+        });
+        PortfolioLocks::<T>::remove(from, lock_id);
+        PortfolioLocks::<T>::insert(to, lock_id, lock);
+        Self::deposit_event(Event::PortfolioLockMoved(caller_did, *from, *to, lock_id));
+    }
+
+    /// Rescales the amount of every `asset_id` lock of `portfolio`, keeping its id, and moves it to `target_id`.
+    /// Locks rescaled to zero are released. Fails if `portfolio` has more than `max_locks` locks.
+    /// The locked units must be rescaled separately by the caller.
+    pub fn unchecked_rescale_portfolio_locks(
+        caller_did: IdentityId,
+        portfolio: &PortfolioId,
+        asset_id: &AssetId,
+        target_id: &AssetId,
+        max_locks: u32,
+        rescale: impl Fn(Balance) -> Balance,
+    ) -> DispatchResult {
+        let locks: Vec<_> = PortfolioLocks::<T>::iter_prefix(portfolio)
+            .take(max_locks.saturating_add(1) as usize)
+            .collect();
+        ensure!(
+            locks.len() <= max_locks as usize,
+            Error::<T>::TooManyPortfolioLocks
+        );
+
+        let mut locked = 0;
+        for (lock_id, mut lock) in locks {
+            if lock.asset_id != *asset_id {
+                continue;
+            }
+            lock.asset_id = *target_id;
+            lock.amount = rescale(lock.amount);
+            if lock.amount == 0 {
+                PortfolioLocks::<T>::remove(portfolio, lock_id);
+                Self::deposit_event(Event::PortfolioLockReleased(
+                    caller_did, *portfolio, lock_id,
+                ));
+                continue;
+            }
+            locked = locked.saturating_add(lock.amount);
+            PortfolioLocks::<T>::insert(portfolio, lock_id, lock);
+        }
+        PortfolioLockedByLocks::remove(portfolio, asset_id);
+        PortfolioLockedByLocks::mutate(portfolio, target_id, |target_locked| {
+            *target_locked = target_locked.saturating_add(locked)
+        });
+        Ok(())
+    }
+
     fn base_accept_portfolio_custody(origin: T::RuntimeOrigin, auth_id: u64) -> DispatchResult {
         let to = Identity::<T>::ensure_perms(origin)?;
         Identity::<T>::accept_auth_with(&to.into(), auth_id, |data, from| {
@@ -739,6 +899,7 @@ impl<T: Config> Module<T> {
                         Error::<T>::NoDuplicateAssetsAllowed
                     );
                     Self::ensure_sufficient_balance(sender_portfolio, &asset_id, *amount)?;
+                    T::Asset::ensure_transfers_not_frozen(asset_id)?;
                 }
                 FundDescription::NonFungible(nfts) => {
                     ensure!(nfts.len() > 0, Error::<T>::EmptyTransfer);
@@ -784,6 +945,12 @@ impl<T: Config> Module<T> {
                         &asset_id,
                         amount,
                     );
+                    T::Asset::move_acquisition_lots(
+                        &asset_id,
+                        &sender_portfolio,
+                        &receiver_portfolio,
+                        amount,
+                    );
                     Self::deposit_event(Event::FundsMovedBetweenPortfolios(
                         origin_did,
                         sender_portfolio,
@@ -903,6 +1070,98 @@ impl<T: Config> Module<T> {
         ));
         Ok(())
     }
+
+    fn base_lock_portfolio_assets(
+        origin: T::RuntimeOrigin,
+        portfolio_id: PortfolioId,
+        asset_id: AssetId,
+        amount: Balance,
+        reason: PortfolioLockReason,
+        release_at: Option<T::Moment>,
+        release_authority: Option<IdentityId>,
+    ) -> DispatchResult {
+        let origin_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        ensure!(amount > 0, Error::<T>::EmptyPortfolioLock);
+        if let Some(release_at) = release_at {
+            ensure!(
+                release_at > pallet_timestamp::Pallet::<T>::get(),
+                Error::<T>::InvalidPortfolioLockReleaseTime
+            );
+        }
+        Self::ensure_portfolio_validity(&portfolio_id)?;
+        Self::ensure_portfolio_custody_and_permission(
+            portfolio_id,
+            origin_data.primary_did,
+            origin_data.secondary_key.as_ref(),
+        )?;
+        Self::ensure_sufficient_balance(&portfolio_id, &asset_id, amount)?;
+
+        let lock_id = NextPortfolioLockId::mutate(|id| {
+            mem::replace(id, PortfolioLockId(id.0.saturating_add(1)))
+        });
+        PortfolioLockedByLocks::mutate(&portfolio_id, &asset_id, |locked| {
+            *locked = locked.saturating_add(amount)
+        });
+        PortfolioLocks::<T>::insert(
+            &portfolio_id,
+            lock_id,
+            PortfolioLock {
+                asset_id,
+                amount,
+                reason,
+                locked_by: origin_data.primary_did,
+                release_at,
+                release_authority,
+            },
+        );
+        Self::deposit_event(Event::PortfolioAssetsLocked(
+            origin_data.primary_did,
+            portfolio_id,
+            lock_id,
+            asset_id,
+            amount,
+            reason,
+        ));
+        Ok(())
+    }
+
+    fn base_release_portfolio_lock(
+        origin: T::RuntimeOrigin,
+        portfolio_id: PortfolioId,
+        lock_id: PortfolioLockId,
+    ) -> DispatchResult {
+        let origin_data = Identity::<T>::ensure_origin_call_permissions(origin)?;
+        let lock = PortfolioLocks::<T>::get(&portfolio_id, lock_id)
+            .ok_or(Error::<T>::PortfolioLockNotFound)?;
+
+        let caller_did = origin_data.primary_did;
+        ensure!(
+            lock.can_be_released_by(
+                caller_did,
+                Self::custodian(&portfolio_id),
+                pallet_timestamp::Pallet::<T>::get()
+            ),
+            Error::<T>::UnauthorizedPortfolioLockRelease
+        );
+        // The release authority doesn't need access to the portfolio.
+        if lock.release_authority != Some(caller_did) {
+            Self::ensure_user_portfolio_permission(
+                origin_data.secondary_key.as_ref(),
+                portfolio_id,
+            )?;
+        }
+
+        PortfolioLockedByLocks::mutate(&portfolio_id, &lock.asset_id, |locked| {
+            *locked = locked.saturating_sub(lock.amount)
+        });
+        PortfolioLocks::<T>::remove(&portfolio_id, lock_id);
+        Self::deposit_event(Event::PortfolioLockReleased(
+            caller_did,
+            portfolio_id,
+            lock_id,
+        ));
+        Ok(())
+    }
 }
 
 impl<T: Config> PortfolioSubTrait<T::AccountId> for Module<T> {
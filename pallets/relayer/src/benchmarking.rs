@@ -20,6 +20,10 @@ use polymesh_common_utilities::{
     benchs::{user, AccountIdOf, User},
     traits::{relayer::Config, TestUtilsFn},
 };
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::{
+    AssetPermissions, ExtrinsicPermissions, PalletPermissions, SubsidyReplenishment,
+};
 
 type Relayer<T> = crate::Module<T>;
 
@@ -31,6 +35,19 @@ fn setup_users<T: Config + TestUtilsFn<AccountIdOf<T>>>() -> (User<T>, User<T>)
     (payer, user)
 }
 
+fn subsidy_scope() -> SubsidyScope {
+    SubsidyScope {
+        extrinsics: ExtrinsicPermissions::these(vec![PalletPermissions::entire_pallet(
+            "Asset".into(),
+        )]),
+        assets: AssetPermissions::elem(AssetId::new([0; 16])),
+        replenishment: Some(SubsidyReplenishment {
+            polyx_limit: 100,
+            period: 10,
+        }),
+    }
+}
+
 fn setup_paying_key<T: Config + TestUtilsFn<AccountIdOf<T>>>(limit: u128) -> (User<T>, User<T>) {
     let (payer, user) = setup_users::<T>();
     // accept paying key
@@ -40,6 +57,7 @@ fn setup_paying_key<T: Config + TestUtilsFn<AccountIdOf<T>>>(limit: u128) -> (Us
         user.account(),
         payer.account(),
         limit,
+        None,
     )
     .unwrap();
     (payer, user)
@@ -62,17 +80,18 @@ benchmarks! {
 
     set_paying_key {
         let (payer, user) = setup_users::<T>();
-    }: _(payer.origin(), user.account(), 0u128)
+    }: _(payer.origin(), user.account(), 0u128, Some(subsidy_scope()))
 
     accept_paying_key {
         let (payer, user) = setup_users::<T>();
         let limit = 100u128;
         // setup authorization
         let auth_id = <Relayer<T>>::unverified_add_auth_for_paying_key(
-            payer.did(), user.account(), payer.account(), limit
+            payer.did(), user.account(), payer.account(), limit, Some(subsidy_scope())
         ).unwrap();
     }: _(user.origin(), auth_id)
     verify {
+        assert_eq!(SubsidyScopes::<T>::get(user.account()), Some(subsidy_scope()));
         assert_subsidy(user, Some((payer, limit)));
     }
 
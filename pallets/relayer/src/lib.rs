@@ -31,7 +31,8 @@
 //! ### Dispatchable Functions
 //!
 //! - `set_paying_key` creates an authorization to allow a `user_key`
-//!   to accept a `paying_key` as their subsidiser.
+//!   to accept a `paying_key` as their subsidiser, optionally scoped to some
+//!   pallets, extrinsics and assets, and replenished every period.
 //! - `accept_paying_key` accepts a `paying_key` authorization.
 //! - `remove_paying_key` removes the `paying_key` from a `user_key`.
 //! - `update_polyx_limit` updates the available POLYX for a `user_key`.
@@ -46,23 +47,32 @@ pub mod benchmarking;
 use codec::{Decode, Encode};
 use frame_support::{
     decl_error, decl_module, decl_storage,
-    dispatch::{DispatchError, DispatchResult},
+    dispatch::{DispatchError, DispatchResult, Weight},
     ensure, fail,
+    traits::GetCallMetadata,
 };
 use frame_system::ensure_signed;
 use pallet_identity::PermissionedCallOriginData;
+use polymesh_common_utilities::traits::identity::{
+    Config as IdentityConfig, WeightInfo as IdentityWeightInfo,
+};
 pub use polymesh_common_utilities::traits::relayer::{
-    Config, Event, RawEvent, SubsidiserTrait, WeightInfo,
+    Config, Event, RawEvent, SubsidiserTrait, SubsidyCallInspector, WeightInfo,
 };
 use polymesh_primitives::{
-    extract_auth, AuthorizationData, Balance, IdentityId, Signatory, TransactionError,
+    extract_auth, AuthorizationData, Balance, BlockNumber, ExtrinsicName, IdentityId, PalletName,
+    Signatory, SubsetRestriction, SubsidyScope, TransactionError,
 };
 use scale_info::TypeInfo;
+use sp_runtime::traits::SaturatedConversion;
 use sp_runtime::transaction_validity::InvalidTransaction;
 use sp_std::vec;
 
 type Identity<T> = pallet_identity::Module<T>;
 
+/// Maximum depth of nested calls checked against the scope of a subsidy.
+pub const MAX_NESTED_CALL_DEPTH: u32 = 8;
+
 /// A Subsidy for transaction and protocol fees.
 ///
 /// This holds the subsidiser's paying key and the remaining POLYX balance
@@ -96,6 +106,16 @@ decl_storage! {
         /// before they can accept a new subsidiser.
         pub Subsidies get(fn subsidies):
             map hasher(blake2_128_concat) T::AccountId => Option<Subsidy<T::AccountId>>;
+
+        /// The scope of the subsidy for a `user_key`, if it is scoped,
+        /// as a map `user_key` => `SubsidyScope`.
+        pub SubsidyScopes get(fn subsidy_scope):
+            map hasher(blake2_128_concat) T::AccountId => Option<SubsidyScope>;
+
+        /// The last period in which the subsidy of a `user_key` was replenished,
+        /// as a map `user_key` => period index.
+        pub SubsidyPeriods get(fn subsidy_period):
+            map hasher(blake2_128_concat) T::AccountId => BlockNumber;
     }
 }
 
@@ -110,12 +130,16 @@ decl_module! {
         /// # Arguments
         /// - `user_key` the user key to subsidise.
         /// - `polyx_limit` the initial POLYX limit for this subsidy.
+        /// - `scope` the pallets, extrinsics and assets the subsidy pays for, and its replenishment.
+        ///    An unscoped subsidy pays for all subsidised pallets and is never replenished.
         ///
         /// # Errors
         /// - `UnauthorizedCaller` if `origin` is not authorized to call this extrinsic.
-        #[weight = <T as Config>::WeightInfo::set_paying_key()]
-        pub fn set_paying_key(origin, user_key: T::AccountId, polyx_limit: Balance) -> DispatchResult {
-            Self::base_set_paying_key(origin, user_key, polyx_limit)
+        /// - `InvalidReplenishmentPeriod` if the replenishment period of `scope` is zero.
+        /// - `ExceptNotAllowedForExtrinsics` if `scope` uses the `Except` variant.
+        #[weight = <T as Config>::WeightInfo::set_paying_key().saturating_add(Module::<T>::scope_cost(&scope))]
+        pub fn set_paying_key(origin, user_key: T::AccountId, polyx_limit: Balance, scope: Option<SubsidyScope>) -> DispatchResult {
+            Self::base_set_paying_key(origin, user_key, polyx_limit, scope)
         }
 
         /// Accepts a `paying_key` authorization.
@@ -131,6 +155,7 @@ decl_module! {
         /// - `NotAuthorizedForPayingKey` if the authorization was created an identity different from the `paying_key`'s identity.
         /// - `UserKeyCddMissing` if the `user_key` is not attached to a CDD'd identity.
         /// - `PayingKeyCddMissing` if the `paying_key` is not attached to a CDD'd identity.
+        /// - `InvalidReplenishmentPeriod` if the replenishment period of the scope is zero.
         /// - `UnauthorizedCaller` if `origin` is not authorized to call this extrinsic.
         #[weight = <T as Config>::WeightInfo::accept_paying_key()]
         pub fn accept_paying_key(origin, auth_id: u64) -> DispatchResult {
@@ -218,6 +243,8 @@ decl_error! {
         NotAuthorizedForUserKey,
         /// The remaining POLYX for `user_key` overflowed.
         Overflow,
+        /// The replenishment period of a subsidy scope must be at least one block.
+        InvalidReplenishmentPeriod,
     }
 }
 
@@ -226,6 +253,7 @@ impl<T: Config> Module<T> {
         origin: T::RuntimeOrigin,
         user_key: T::AccountId,
         polyx_limit: Balance,
+        scope: Option<SubsidyScope>,
     ) -> DispatchResult {
         let PermissionedCallOriginData {
             sender: paying_key,
@@ -233,8 +261,16 @@ impl<T: Config> Module<T> {
             ..
         } = <Identity<T>>::ensure_origin_call_permissions(origin)?;
 
+        Self::ensure_valid_scope(&scope)?;
+
         // Create authorization for `paying_key` to subsidise the `user_key`, with `polyx_limit` POLYX.
-        Self::unverified_add_auth_for_paying_key(paying_did, user_key, paying_key, polyx_limit)?;
+        Self::unverified_add_auth_for_paying_key(
+            paying_did,
+            user_key,
+            paying_key,
+            polyx_limit,
+            scope,
+        )?;
         Ok(())
     }
 
@@ -245,8 +281,10 @@ impl<T: Config> Module<T> {
         let signer = Signatory::Account(caller_key.clone());
 
         <Identity<T>>::accept_auth_with(&signer, auth_id, |data, auth_by| -> DispatchResult {
-            let (user_key, paying_key, polyx_limit) =
-                extract_auth!(data, AddRelayerPayingKey(user_key, paying_key, polyx_limit));
+            let (user_key, paying_key, polyx_limit, scope) = extract_auth!(
+                data,
+                AddRelayerPayingKey(user_key, paying_key, polyx_limit, scope)
+            );
 
             // Allow: `origin == user_key`.
             ensure!(user_key == caller_key, Error::<T>::NotAuthorizedForUserKey);
@@ -257,6 +295,7 @@ impl<T: Config> Module<T> {
                 user_key.clone(),
                 paying_key.clone(),
                 polyx_limit,
+                scope,
             )?;
 
             Self::deposit_event(RawEvent::AcceptedPayingKey(
@@ -299,6 +338,8 @@ impl<T: Config> Module<T> {
 
         // Remove paying key for user key.
         <Subsidies<T>>::remove(&user_key);
+        <SubsidyScopes<T>>::remove(&user_key);
+        <SubsidyPeriods<T>>::remove(&user_key);
 
         Self::deposit_event(RawEvent::RemovedPayingKey(
             sender_did.for_event(),
@@ -323,6 +364,12 @@ impl<T: Config> Module<T> {
         // Check if the current paying key matches.
         let mut subsidy = Self::ensure_is_paying_key(&user_key, &paying_key)?;
 
+        // Replenish the subsidy first, if a new period started.
+        if let Some((period, polyx_limit)) = Self::pending_replenishment(&user_key) {
+            subsidy.remaining = polyx_limit;
+            <SubsidyPeriods<T>>::insert(&user_key, period);
+        }
+
         // Update polyx limit.
         let old_remaining = subsidy.remaining;
         let new_remaining = match action {
@@ -353,6 +400,7 @@ impl<T: Config> Module<T> {
         user_key: T::AccountId,
         paying_key: T::AccountId,
         polyx_limit: Balance,
+        scope: Option<SubsidyScope>,
     ) -> Result<u64, DispatchError> {
         let auth_id = <Identity<T>>::add_auth(
             from,
@@ -361,6 +409,7 @@ impl<T: Config> Module<T> {
                 user_key.clone(),
                 paying_key.clone(),
                 polyx_limit,
+                scope,
             ),
             None,
         )?;
@@ -374,6 +423,97 @@ impl<T: Config> Module<T> {
         Ok(auth_id)
     }
 
+    /// Ensures that `scope` is replenished at most once per block
+    /// and that its extrinsic permissions are limited like the ones of secondary keys.
+    fn ensure_valid_scope(scope: &Option<SubsidyScope>) -> DispatchResult {
+        if let Some(scope) = scope {
+            if let Some(replenishment) = &scope.replenishment {
+                ensure!(
+                    replenishment.period > 0,
+                    Error::<T>::InvalidReplenishmentPeriod
+                );
+            }
+            <Identity<T>>::ensure_perms_length_limited(&scope.permissions())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the complexity cost of the extrinsic and asset permissions of `scope`.
+    fn scope_cost(scope: &Option<SubsidyScope>) -> Weight {
+        scope.as_ref().map_or(Weight::zero(), |scope| {
+            <T as IdentityConfig>::WeightInfo::permissions_cost_perms(&scope.permissions())
+        })
+    }
+
+    /// Ensures that `call`, and all the calls nested in it, are covered by the scope of the subsidy of `user_key`.
+    ///
+    /// Unscoped subsidies, and calls to this pallet, which the user key pays itself, are always allowed.
+    pub fn ensure_call_in_subsidy_scope(
+        user_key: &T::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+    ) -> Result<(), InvalidTransaction>
+    where
+        <T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+    {
+        match <SubsidyScopes<T>>::get(user_key) {
+            Some(scope) => Self::ensure_call_in_scope(&scope, call, 0),
+            None => Ok(()),
+        }
+    }
+
+    /// Ensures that `call`, found at `depth` in the calls nested in the subsidised call, is covered by `scope`.
+    ///
+    /// Calls that dispatch other calls must be covered themselves, but only their nested calls operate on assets.
+    fn ensure_call_in_scope(
+        scope: &SubsidyScope,
+        call: &<T as frame_system::Config>::RuntimeCall,
+        depth: u32,
+    ) -> Result<(), InvalidTransaction>
+    where
+        <T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+    {
+        let not_subsidised = InvalidTransaction::Custom(TransactionError::CallNotSubsidised as u8);
+        let metadata = call.get_call_metadata();
+        if metadata.pallet_name == "Relayer" {
+            return Ok(());
+        }
+        ensure!(
+            scope.extrinsics.sufficient_for(
+                &PalletName::from(metadata.pallet_name),
+                &ExtrinsicName::from(metadata.function_name),
+            ),
+            not_subsidised
+        );
+
+        let nested_calls = T::CallInspector::nested_calls(call);
+        if nested_calls.is_empty() {
+            if scope.assets == SubsetRestriction::Whole {
+                return Ok(());
+            }
+            let assets = T::CallInspector::call_assets(call).ok_or(not_subsidised)?;
+            ensure!(
+                assets
+                    .into_iter()
+                    .all(|asset_id| scope.assets.ge(&SubsetRestriction::elem(asset_id))),
+                not_subsidised
+            );
+            return Ok(());
+        }
+        ensure!(depth < MAX_NESTED_CALL_DEPTH, not_subsidised);
+        nested_calls
+            .into_iter()
+            .try_for_each(|nested_call| Self::ensure_call_in_scope(scope, nested_call, depth + 1))
+    }
+
+    /// Returns the current period of the subsidy of `user_key` and the POLYX limit to restore,
+    /// if the subsidy is replenished and was last replenished in an earlier period.
+    fn pending_replenishment(user_key: &T::AccountId) -> Option<(BlockNumber, Balance)> {
+        let replenishment = <SubsidyScopes<T>>::get(user_key)?.replenishment?;
+        let now = frame_system::Pallet::<T>::block_number().saturated_into::<BlockNumber>();
+        let period = replenishment.period_of(now);
+        (period > <SubsidyPeriods<T>>::get(user_key)).then_some((period, replenishment.polyx_limit))
+    }
+
     /// Check if the `key` has a valid CDD.
     fn key_has_valid_cdd(key: &T::AccountId) -> bool {
         if let Some(did) = <Identity<T>>::get_identity(key) {
@@ -405,7 +545,11 @@ impl<T: Config> Module<T> {
         user_key: T::AccountId,
         paying_key: T::AccountId,
         polyx_limit: Balance,
+        scope: Option<SubsidyScope>,
     ) -> DispatchResult {
+        // The authorization might not have been created through `set_paying_key`.
+        Self::ensure_valid_scope(&scope)?;
+
         // Ensure that the authorization came from the DID of the paying_key.
         ensure!(
             <Identity<T>>::get_identity(&paying_key) == Some(from),
@@ -442,13 +586,33 @@ impl<T: Config> Module<T> {
 
         // All checks passed.
         <Subsidies<T>>::insert(
-            user_key,
+            &user_key,
             Subsidy {
-                paying_key,
+                paying_key: paying_key.clone(),
                 remaining: polyx_limit,
             },
         );
 
+        // Replace the scope of any previous subsidy.
+        <SubsidyPeriods<T>>::remove(&user_key);
+        match scope {
+            Some(scope) => {
+                // The initial POLYX limit covers the current period.
+                if let Some(replenishment) = &scope.replenishment {
+                    let now = frame_system::Pallet::<T>::block_number().saturated_into();
+                    <SubsidyPeriods<T>>::insert(&user_key, replenishment.period_of(now));
+                }
+                <SubsidyScopes<T>>::insert(&user_key, scope.clone());
+                Self::deposit_event(RawEvent::SubsidyScoped(
+                    user_did.for_event(),
+                    user_key,
+                    paying_key,
+                    scope,
+                ));
+            }
+            None => <SubsidyScopes<T>>::remove(&user_key),
+        }
+
         Ok(())
     }
 
@@ -471,8 +635,14 @@ impl<T: Config> Module<T> {
         user_key: &T::AccountId,
         fee: Balance,
     ) -> Result<Option<Subsidy<T::AccountId>>, InvalidTransaction> {
-        // Get the Subsidy for `user_key`.
-        match <Subsidies<T>>::get(user_key) {
+        // Get the Subsidy for `user_key`, replenished if a new period started.
+        let subsidy = <Subsidies<T>>::get(user_key).map(|mut s| {
+            if let Some((_, polyx_limit)) = Self::pending_replenishment(user_key) {
+                s.remaining = polyx_limit;
+            }
+            s
+        });
+        match subsidy {
             // There was no subsidy.
             None => Ok(None),
             // Has subsidy, but not enough remaining POLYX.
@@ -508,6 +678,10 @@ impl<T: Config> SubsidiserTrait<T::AccountId> for Module<T> {
     ) -> Result<Option<T::AccountId>, InvalidTransaction> {
         if let Some(mut subsidy) = Self::get_subsidy(user_key, fee)? {
             let paying_key = subsidy.paying_key.clone();
+            // Record the replenishment applied by `get_subsidy`, if any.
+            if let Some((period, _)) = Self::pending_replenishment(user_key) {
+                <SubsidyPeriods<T>>::insert(user_key, period);
+            }
             // Debit the fee from the remaining POLYX of subsidy.
             subsidy.remaining = subsidy.remaining.saturating_sub(fee);
             <Subsidies<T>>::insert(user_key, subsidy);
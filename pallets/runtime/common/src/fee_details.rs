@@ -1,10 +1,13 @@
 use codec::{Decode, Encode};
 use core::convert::{TryFrom, TryInto};
 use core::marker::PhantomData;
+use frame_support::traits::{GetCallMetadata, IsType};
 use pallet_identity::Module;
 use polymesh_common_utilities::traits::identity::Config;
 use polymesh_common_utilities::{traits::transaction_payment::CddAndFeeDetails, Context};
-use polymesh_primitives::{AccountId, AuthorizationData, IdentityId, Signatory, TransactionError};
+use polymesh_primitives::{
+    AccountId, AuthorizationData, Balance, IdentityId, Signatory, TransactionError,
+};
 use sp_runtime::transaction_validity::InvalidTransaction;
 
 /// A `CddHandler` that considers `TestUtils`.
@@ -46,11 +49,12 @@ impl<C> GetValidPayerHook<C> for Noop {
 /// The set of `Call`s from pallets that `CddHandler` recognizes specially.
 pub enum Call<'a, R>
 where
-    R: Config + pallet_multisig::Config + pallet_relayer::Config,
+    R: Config + pallet_multisig::Config + pallet_relayer::Config + pallet_utility::Config,
 {
     MultiSig(&'a pallet_multisig::Call<R>),
     Identity(&'a pallet_identity::Call<R>),
     Relayer(&'a pallet_relayer::Call<R>),
+    Utility(&'a pallet_utility::Call<R>),
 }
 
 /// The implementation of `CddAndFeeDetails` for the chain.
@@ -59,9 +63,14 @@ pub struct CddHandler<A, H>(PhantomData<(A, H)>);
 
 impl<C, A, H> CddAndFeeDetails<AccountId, C> for CddHandler<A, H>
 where
+    C: IsType<<A as frame_system::Config>::RuntimeCall>,
+    <A as frame_system::Config>::RuntimeCall: GetCallMetadata,
     H: GetValidPayerHook<C>,
     for<'a> Call<'a, A>: TryFrom<&'a C>,
-    A: Config<AccountId = AccountId> + pallet_multisig::Config + pallet_relayer::Config,
+    A: Config<AccountId = AccountId>
+        + pallet_multisig::Config
+        + pallet_relayer::Config
+        + pallet_utility::Config,
 {
     /// Check if there's an eligible payer with valid CDD.
     /// Return the payer if found or else an error.
@@ -157,7 +166,7 @@ where
 
         // The CDD check and fee payer varies depending on the transaction.
         // This match covers all possible scenarios.
-        match call.try_into() {
+        let payer = match call.try_into() {
             // Call made by a key to accept invitation to become a signing key
             // of a multisig that has a valid CDD. The auth should be valid.
             Ok(Call::MultiSig(pallet_multisig::Call::accept_multisig_signer { auth_id })) => {
@@ -206,11 +215,33 @@ where
                 | pallet_multisig::Call::approve { multisig, .. }
                 | pallet_multisig::Call::reject { multisig, .. },
             )) => handle_multisig(multisig, caller),
+            // Call made by any key with a valid envelope from a sponsor, who pays the fee.
+            // The sponsor must have a valid CDD, the caller doesn't need an identity.
+            Ok(Call::Utility(pallet_utility::Call::sponsored_call {
+                envelope,
+                signature,
+                call,
+            })) => {
+                if pallet_utility::Pallet::<A>::ensure_valid_sponsorship(
+                    caller, envelope, signature, call,
+                )
+                .is_err()
+                {
+                    return INVALID_SPONSORSHIP;
+                }
+                caller_pays(&envelope.sponsor)
+            }
             // All other calls.
             //
             // The external account must directly be linked to an identity with valid CDD.
             _ => caller_pays(caller),
+        }?;
+
+        // The call, and the calls nested in it, must be covered by the scope of the payer's relayer subsidy, if any.
+        if let Some(payer) = &payer {
+            pallet_relayer::Module::<A>::ensure_call_in_subsidy_scope(payer, call.into_ref())?;
         }
+        Ok(payer)
     }
 
     /// Clears context. Should be called in post_dispatch
@@ -227,6 +258,16 @@ where
     fn get_payer_from_context() -> Option<AccountId> {
         Context::current_payer::<pallet_identity::Module<A>>()
     }
+
+    /// Sponsored calls are capped to the `max_fee` of their envelope.
+    fn get_max_fee(call: &C) -> Option<Balance> {
+        match call.try_into() {
+            Ok(Call::Utility(pallet_utility::Call::sponsored_call { envelope, .. })) => {
+                Some(envelope.max_fee)
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Encode, Decode)]
@@ -254,3 +295,7 @@ const MISSING_ID: ValidPayerResult = Err(InvalidTransaction::Custom(
 const INVALID_AUTH: ValidPayerResult = Err(InvalidTransaction::Custom(
     TransactionError::InvalidAuthorization as u8,
 ));
+
+const INVALID_SPONSORSHIP: ValidPayerResult = Err(InvalidTransaction::Custom(
+    TransactionError::InvalidSponsorship as u8,
+));
@@ -147,6 +147,7 @@ macro_rules! misc_pallet_impls {
                     RuntimeCall::Identity(x) => Identity(x),
                     RuntimeCall::MultiSig(x) => MultiSig(x),
                     RuntimeCall::Relayer(x) => Relayer(x),
+                    RuntimeCall::Utility(x) => Utility(x),
                     _ => return Err(()),
                 })
             }
@@ -174,6 +175,7 @@ macro_rules! misc_pallet_impls {
             type GovernanceCommittee = PolymeshCommittee;
             type CddProviders = CddServiceProviders;
             type Identity = Identity;
+            type AssetFeeHandler = Asset;
         }
 
         impl polymesh_common_utilities::traits::CommonConfig for Runtime {
@@ -285,6 +287,83 @@ macro_rules! misc_pallet_impls {
             type Proposal = RuntimeCall;
             type WeightInfo = polymesh_weights::pallet_multisig::SubstrateWeight;
             type MaxSigners = MaxMultiSigSigners;
+            type MaxCallPolicies = MaxMultiSigCallPolicies;
+            type CallValue = MultiSigCallValue;
+        }
+
+        /// Extracts the value moved by a multisig proposal and the calls nested in it.
+        ///
+        /// The value of asset and settlement transfers is the sum of their fungible amounts.
+        pub struct MultiSigCallValue;
+
+        impl pallet_multisig::CallValue<RuntimeCall> for MultiSigCallValue {
+            fn call_value(call: &RuntimeCall) -> Option<polymesh_primitives::Balance> {
+                use polymesh_primitives::portfolio::FundDescription;
+                use polymesh_primitives::settlement::Leg;
+
+                let legs_value = |legs: &[Leg]| {
+                    legs.iter()
+                        .filter_map(|leg| match leg {
+                            Leg::Fungible { amount, .. } => Some(*amount),
+                            _ => None,
+                        })
+                        .fold(0, |total: polymesh_primitives::Balance, amount| {
+                            total.saturating_add(amount)
+                        })
+                };
+                match call {
+                    RuntimeCall::Balances(
+                        pallet_balances::Call::transfer { value, .. }
+                        | pallet_balances::Call::transfer_with_memo { value, .. },
+                    ) => Some(*value),
+                    RuntimeCall::Asset(
+                        pallet_asset::Call::transfer_from { amount: value, .. }
+                        | pallet_asset::Call::controller_transfer { value, .. },
+                    ) => Some(*value),
+                    RuntimeCall::Portfolio(pallet_portfolio::Call::move_portfolio_funds {
+                        funds,
+                        ..
+                    }) => Some(
+                        funds
+                            .iter()
+                            .filter_map(|fund| match &fund.description {
+                                FundDescription::Fungible { amount, .. } => Some(*amount),
+                                FundDescription::NonFungible(_) => None,
+                            })
+                            .fold(0, |total: polymesh_primitives::Balance, amount| {
+                                total.saturating_add(amount)
+                            }),
+                    ),
+                    RuntimeCall::Settlement(
+                        pallet_settlement::Call::add_instruction { legs, .. }
+                        | pallet_settlement::Call::add_and_affirm_instruction { legs, .. }
+                        | pallet_settlement::Call::add_instruction_with_mediators { legs, .. }
+                        | pallet_settlement::Call::add_and_affirm_with_mediators { legs, .. }
+                        | pallet_settlement::Call::add_instruction_with_hash_lock { legs, .. },
+                    ) => Some(legs_value(legs)),
+                    _ => None,
+                }
+            }
+
+            fn nested_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+                match call {
+                    RuntimeCall::Utility(
+                        pallet_utility::Call::batch { calls }
+                        | pallet_utility::Call::batch_all { calls }
+                        | pallet_utility::Call::force_batch { calls },
+                    ) => calls.iter().collect(),
+                    RuntimeCall::Utility(pallet_utility::Call::relay_tx { call, .. }) => {
+                        vec![call.call()]
+                    }
+                    RuntimeCall::Utility(
+                        pallet_utility::Call::as_derivative { call, .. }
+                        | pallet_utility::Call::dispatch_as { call, .. }
+                        | pallet_utility::Call::with_weight { call, .. }
+                        | pallet_utility::Call::sponsored_call { call, .. },
+                    ) => vec![call.as_ref()],
+                    _ => Vec::new(),
+                }
+            }
         }
 
         impl pallet_bridge::Config for Runtime {}
@@ -306,6 +385,65 @@ macro_rules! misc_pallet_impls {
         impl pallet_relayer::Config for Runtime {
             type RuntimeEvent = RuntimeEvent;
             type WeightInfo = polymesh_weights::pallet_relayer::SubstrateWeight;
+            type CallInspector = RelayerCallInspector;
+        }
+
+        /// Extracts the assets a subsidised call operates on and the calls nested in it.
+        ///
+        /// Only asset, NFT, portfolio and settlement calls moving or issuing assets operate on assets.
+        pub struct RelayerCallInspector;
+
+        impl pallet_relayer::SubsidyCallInspector<RuntimeCall> for RelayerCallInspector {
+            fn call_assets(call: &RuntimeCall) -> Option<Vec<polymesh_primitives::asset::AssetId>> {
+                use polymesh_primitives::portfolio::FundDescription;
+
+                match call {
+                    RuntimeCall::Asset(
+                        pallet_asset::Call::issue { asset_id, .. }
+                        | pallet_asset::Call::redeem { asset_id, .. }
+                        | pallet_asset::Call::controller_transfer { asset_id, .. }
+                        | pallet_asset::Call::transfer_from { asset_id, .. }
+                        | pallet_asset::Call::approve { asset_id, .. }
+                        | pallet_asset::Call::add_vesting_schedule { asset_id, .. }
+                        | pallet_asset::Call::claim_vested_assets { asset_id, .. }
+                        | pallet_asset::Call::pre_approve_asset { asset_id }
+                        | pallet_asset::Call::remove_asset_pre_approval { asset_id },
+                    ) => Some(vec![*asset_id]),
+                    RuntimeCall::Nft(
+                        pallet_nft::Call::issue_nft { asset_id, .. }
+                        | pallet_nft::Call::redeem_nft { asset_id, .. },
+                    ) => Some(vec![*asset_id]),
+                    RuntimeCall::Portfolio(
+                        pallet_portfolio::Call::lock_portfolio_assets { asset_id, .. }
+                        | pallet_portfolio::Call::pre_approve_portfolio { asset_id, .. }
+                        | pallet_portfolio::Call::remove_portfolio_pre_approval { asset_id, .. },
+                    ) => Some(vec![*asset_id]),
+                    RuntimeCall::Portfolio(pallet_portfolio::Call::move_portfolio_funds {
+                        funds,
+                        ..
+                    }) => Some(
+                        funds
+                            .iter()
+                            .map(|fund| match &fund.description {
+                                FundDescription::Fungible { asset_id, .. } => *asset_id,
+                                FundDescription::NonFungible(nfts) => *nfts.asset_id(),
+                            })
+                            .collect(),
+                    ),
+                    RuntimeCall::Settlement(
+                        pallet_settlement::Call::add_instruction { legs, .. }
+                        | pallet_settlement::Call::add_and_affirm_instruction { legs, .. }
+                        | pallet_settlement::Call::add_instruction_with_mediators { legs, .. }
+                        | pallet_settlement::Call::add_and_affirm_with_mediators { legs, .. }
+                        | pallet_settlement::Call::add_instruction_with_hash_lock { legs, .. },
+                    ) => Some(legs.iter().filter_map(|leg| leg.asset_id().copied()).collect()),
+                    _ => None,
+                }
+            }
+
+            fn nested_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+                <MultiSigCallValue as pallet_multisig::CallValue<RuntimeCall>>::nested_calls(call)
+            }
         }
 
         impl pallet_asset::Config for Runtime {
@@ -323,6 +461,9 @@ macro_rules! misc_pallet_impls {
             type CPWeightInfo = polymesh_weights::pallet_checkpoint::SubstrateWeight;
             type NFTFn = pallet_nft::Module<Runtime>;
             type MaxAssetMediators = MaxAssetMediators;
+            type IdentityRecoveryVetoPeriod = IdentityRecoveryVetoPeriod;
+            type MaxIdentityRecoveryAssets = MaxIdentityRecoveryAssets;
+            type MaxVestingSchedules = MaxVestingSchedules;
         }
 
         impl polymesh_contracts::Config for Runtime {
@@ -374,9 +515,13 @@ macro_rules! misc_pallet_impls {
             type RuntimeEvent = RuntimeEvent;
             type MaxTargetIds = MaxTargetIds;
             type MaxDidWhts = MaxDidWhts;
+            type MaxReorganizationPeriod = MaxReorganizationPeriod;
+            type MaxReorganizationBatch = MaxReorganizationBatch;
             type WeightInfo = polymesh_weights::pallet_corporate_actions::SubstrateWeight;
             type BallotWeightInfo = polymesh_weights::pallet_corporate_ballot::SubstrateWeight;
             type DistWeightInfo = polymesh_weights::pallet_capital_distribution::SubstrateWeight;
+            type ReorgWeightInfo = polymesh_weights::pallet_corporate_reorganization::SubstrateWeight;
+            type FixedIncomeWeightInfo = polymesh_weights::pallet_fixed_income::SubstrateWeight;
         }
 
         impl pallet_statistics::Config for Runtime {
@@ -384,6 +529,9 @@ macro_rules! misc_pallet_impls {
             type Asset = Asset;
             type MaxStatsPerAsset = MaxStatsPerAsset;
             type MaxTransferConditionsPerAsset = MaxTransferConditionsPerAsset;
+            type VolumePeriodLength = VolumePeriodLength;
+            type MaxVolumePeriods = MaxVolumePeriods;
+            type MaxAcquisitionLots = MaxAcquisitionLots;
             type WeightInfo = polymesh_weights::pallet_statistics::SubstrateWeight;
         }
 
@@ -392,6 +540,8 @@ macro_rules! misc_pallet_impls {
             type RuntimeCall = RuntimeCall;
             type PalletsOrigin = OriginCaller;
             type WeightInfo = polymesh_weights::pallet_utility::SubstrateWeight;
+            type MaxEnvelopeLifetime = MaxEnvelopeLifetime;
+            type MaxExpiredEnvelopesPerBlock = MaxExpiredEnvelopesPerBlock;
         }
 
         impl pallet_scheduler::Config for Runtime {
@@ -485,6 +635,7 @@ macro_rules! misc_pallet_impls {
             type MaxNumberOfPortfolios = MaxNumberOfPortfolios;
             type MaxNumberOfVenueSigners = MaxNumberOfVenueSigners;
             type MaxInstructionMediators = MaxInstructionMediators;
+            type MaxNumberOfNettedInstructions = MaxNumberOfNettedInstructions;
         }
 
         impl pallet_sto::Config for Runtime {
@@ -667,13 +818,17 @@ macro_rules! runtime_apis {
         use pallet_identity::types::{AssetDidResult, CddStatus, RpcDidRecords, DidStatus, KeyIdentityData};
         use pallet_pips::{Vote, VoteCount};
         use pallet_protocol_fee_rpc_runtime_api::CappedFee;
-        use polymesh_primitives::asset::AssetId;
-        use polymesh_primitives::settlement::{InstructionId, ExecuteInstructionInfo, AffirmationCount};
+        use polymesh_primitives::asset::{AssetId, AssetOperation};
+        use polymesh_primitives::asset_metadata::AssetMetadataKey;
+        use polymesh_primitives::settlement::{InstructionId, ExecuteInstructionInfo, AffirmationCount, LegTransferFee, VenueId};
         use polymesh_primitives::transfer_compliance::TransferCondition;
         use polymesh_primitives::compliance_manager::{AssetComplianceResult, ComplianceReport};
+        use polymesh_primitives::multisig::ProposalDetails;
+        use polymesh_primitives::committee::CommitteeKind;
+        use polymesh_primitives::dry_run::DryRunOutcome;
         use polymesh_primitives::{
-            asset::CheckpointId, IdentityId, Index, NFTs,PortfolioId, Signatory, Ticker,
-            WeightMeter, IdentityClaim
+            asset::CheckpointId, IdentityId, Index, NFTs,PortfolioId, PortfolioLock, PortfolioLockId,
+            Signatory, Ticker, WeightMeter, IdentityClaim
         };
 
         /// The address format for describing accounts.
@@ -716,6 +871,13 @@ macro_rules! runtime_apis {
             )
         >;
 
+        /// Returns the asset, and its rate, in which the payer of the signed extrinsic `uxt` pays fees.
+        fn fee_asset_of(uxt: &UncheckedExtrinsic) -> Option<(AssetId, Balance)> {
+            let (address, _, _) = uxt.signature.as_ref()?;
+            let who = <Indices as StaticLookup>::lookup(address.clone()).ok()?;
+            TransactionPayment::fee_asset_of(&who, &uxt.function)
+        }
+
         sp_api::impl_runtime_apis! {
             impl sp_api::Core<Block> for Runtime {
                 fn version() -> RuntimeVersion {
@@ -922,12 +1084,24 @@ macro_rules! runtime_apis {
             > for Runtime {
                 fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
                     let actual = uxt.function.get_actual_weight();
-                    TransactionPayment::query_info(uxt, len, actual)
+                    let fee_asset = fee_asset_of(&uxt);
+                    let info = TransactionPayment::query_info(uxt, len, actual);
+                    match fee_asset {
+                        // Report the fee in the asset chosen by the payer.
+                        Some((_, rate)) => TransactionPayment::dispatch_info_in_asset(info, rate),
+                        None => info,
+                    }
                 }
 
                 fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> pallet_transaction_payment::FeeDetails<Balance> {
                     let actual = uxt.function.get_actual_weight();
-                    TransactionPayment::query_fee_details(uxt, len, actual)
+                    let fee_asset = fee_asset_of(&uxt);
+                    let details = TransactionPayment::query_fee_details(uxt, len, actual);
+                    match fee_asset {
+                        // Report the fee in the asset chosen by the payer.
+                        Some((_, rate)) => TransactionPayment::fee_details_in_asset(details, rate),
+                        None => details,
+                    }
                 }
             }
 
@@ -979,6 +1153,41 @@ macro_rules! runtime_apis {
                 fn voted_on(address: polymesh_primitives::AccountId) -> Vec<pallet_pips::PipId> {
                     Pips::voted_on(address)
                 }
+
+                /// PIPs with a vote cast with the delegated power of `address`.
+                fn delegated_voted_on(address: polymesh_primitives::AccountId) -> Vec<pallet_pips::PipId> {
+                    Pips::delegated_voted_on(address)
+                }
+            }
+
+            impl node_rpc_runtime_api::governance::GovernanceApi<
+                Block,
+                RuntimeEvent,
+                polymesh_primitives::Hash
+            > for Runtime
+            {
+                fn dry_run_pip(
+                    id: pallet_pips::PipId
+                ) -> Result<DryRunOutcome<RuntimeEvent>, DispatchError> {
+                    Pips::dry_run_pip(id)
+                }
+
+                fn dry_run_committee_proposal(
+                    committee: CommitteeKind,
+                    proposal: polymesh_primitives::Hash
+                ) -> Result<DryRunOutcome<RuntimeEvent>, DispatchError> {
+                    match committee {
+                        CommitteeKind::Governance => {
+                            PolymeshCommittee::dry_run_proposal(proposal)
+                        }
+                        CommitteeKind::Technical => {
+                            TechnicalCommittee::dry_run_proposal(proposal)
+                        }
+                        CommitteeKind::Upgrade => {
+                            UpgradeCommittee::dry_run_proposal(proposal)
+                        }
+                    }
+                }
             }
 
             impl pallet_protocol_fee_rpc_runtime_api::ProtocolFeeApi<
@@ -1051,6 +1260,18 @@ macro_rules! runtime_apis {
                         &mut weight_meter
                     )
                 }
+
+                fn paused_operations(asset_id: AssetId) -> Vec<AssetOperation> {
+                    Asset::paused_operations(asset_id).operations()
+                }
+
+                fn asset_metadata_json(asset_id: AssetId, key: AssetMetadataKey) -> Option<Vec<u8>> {
+                    Asset::asset_metadata_json(&asset_id, &key)
+                }
+
+                fn paused_settlement_venues(asset_id: AssetId) -> Vec<VenueId> {
+                    Asset::paused_settlement_venues(&asset_id)
+                }
             }
 
             impl pallet_group_rpc_runtime_api::GroupApi<Block> for Runtime {
@@ -1086,6 +1307,45 @@ macro_rules! runtime_apis {
                 }
             }
 
+            impl node_rpc_runtime_api::portfolio::PortfolioApi<Block, Moment> for Runtime {
+                #[inline]
+                fn portfolio_locks(
+                    portfolio_id: PortfolioId
+                ) -> Vec<(PortfolioLockId, PortfolioLock<Moment>)> {
+                    Portfolio::portfolio_locks(&portfolio_id)
+                }
+            }
+
+            impl node_rpc_runtime_api::multisig::MultisigApi<
+                Block,
+                polymesh_primitives::AccountId,
+                Moment
+            > for Runtime
+            {
+                #[inline]
+                fn pending_proposals(
+                    multisig: polymesh_primitives::AccountId
+                ) -> Vec<ProposalDetails<polymesh_primitives::AccountId, Moment>> {
+                    MultiSig::pending_proposals(&multisig)
+                }
+
+                #[inline]
+                fn proposal_details(
+                    multisig: polymesh_primitives::AccountId,
+                    proposal_id: u64
+                ) -> Option<ProposalDetails<polymesh_primitives::AccountId, Moment>> {
+                    MultiSig::proposal_details(&multisig, proposal_id)
+                }
+
+                #[inline]
+                fn execution_report(
+                    multisig: polymesh_primitives::AccountId,
+                    proposal_id: u64
+                ) -> Vec<DispatchError> {
+                    MultiSig::proposal_execution_report(&multisig, proposal_id)
+                }
+            }
+
             impl node_rpc_runtime_api::settlement::SettlementApi<Block> for Runtime {
                 #[inline]
                 fn get_execute_instruction_info(
@@ -1114,6 +1374,11 @@ macro_rules! runtime_apis {
                     Settlement::execute_instruction_report(&instruction_id, &mut weight_meter)
                 }
 
+                #[inline]
+                fn get_instruction_transfer_fees(instruction_id: InstructionId) -> Vec<LegTransferFee> {
+                    Settlement::instruction_transfer_fees(&instruction_id)
+                }
+
             }
 
             impl node_rpc_runtime_api::compliance::ComplianceApi<Block> for Runtime {
@@ -1137,14 +1402,14 @@ macro_rules! runtime_apis {
                 #[inline]
                 fn transfer_restrictions_report(
                     asset_id: AssetId,
-                    sender_did: &IdentityId,
+                    sender_portfolio: &PortfolioId,
                     receiver_did: &IdentityId,
                     transfer_amount: Balance,
                 ) -> FrameResult<Vec<TransferCondition>, DispatchError> {
                     let mut weight_meter = WeightMeter::max_limit_no_minimum();
                     Statistics::transfer_restrictions_report(
                         asset_id,
-                        sender_did,
+                        sender_portfolio,
                         receiver_did,
                         transfer_amount,
                         &mut weight_meter
@@ -28,6 +28,8 @@ use pallet_asset::checkpoint as pallet_checkpoint;
 pub use pallet_balances::Call as BalancesCall;
 use pallet_corporate_actions::ballot as pallet_corporate_ballot;
 use pallet_corporate_actions::distribution as pallet_capital_distribution;
+use pallet_corporate_actions::fixed_income as pallet_fixed_income;
+use pallet_corporate_actions::reorganization as pallet_corporate_reorganization;
 use pallet_session::historical as pallet_session_historical;
 pub use pallet_staking::StakerStatus;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -58,7 +60,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_version: 7_000_003,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 7,
+    transaction_version: 8,
     state_version: 1,
 };
 
@@ -95,9 +97,15 @@ parameter_types! {
     pub const MaxNumberOfPortfolios: u32 = (10 + 100) * 2;
     pub const MaxNumberOfVenueSigners: u32 = 50;
     pub const MaxInstructionMediators: u32 = 4;
+    pub const MaxNumberOfNettedInstructions: u32 = 100;
 
     // Multisig
     pub const MaxMultiSigSigners: u32 = 50;
+    pub const MaxMultiSigCallPolicies: u32 = 16;
+
+    // Pips
+    pub const PipConvictionLockPeriod: BlockNumber = 7 * DAYS;
+    pub const PipMaxDelegators: u32 = 50;
 
     // I'm online:
     pub const ImOnlineUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
@@ -115,6 +123,10 @@ parameter_types! {
     pub const AssetMetadataValueMaxLength: u32 = 8 * 1024;
     pub const AssetMetadataTypeDefMaxLength: u32 = 8 * 1024;
     pub const MaxAssetMediators: u32 = 4;
+    /// Seven days, in milliseconds.
+    pub const IdentityRecoveryVetoPeriod: u64 = 7 * 24 * 60 * 60 * 1000;
+    pub const MaxIdentityRecoveryAssets: u32 = 100;
+    pub const MaxVestingSchedules: u32 = 20;
 
     // Compliance manager:
     pub const MaxConditionComplexity: u32 = 50;
@@ -122,10 +134,17 @@ parameter_types! {
     // Corporate Actions:
     pub const MaxTargetIds: u32 = 1000;
     pub const MaxDidWhts: u32 = 1000;
+    /// Thirty days, in milliseconds.
+    pub const MaxReorganizationPeriod: Moment = 30 * 24 * 60 * 60 * 1000;
+    pub const MaxReorganizationBatch: u32 = 50;
 
     // Statistics:
     pub const MaxStatsPerAsset: u32 = 10 + BENCHMARK_MAX_INCREASE;
     pub const MaxTransferConditionsPerAsset: u32 = 4 + BENCHMARK_MAX_INCREASE;
+    /// One day, in milliseconds.
+    pub const VolumePeriodLength: u64 = 24 * 60 * 60 * 1000;
+    pub const MaxVolumePeriods: u32 = 365;
+    pub const MaxAcquisitionLots: u32 = 100;
 
     // Scheduler:
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
@@ -148,6 +167,10 @@ parameter_types! {
     pub const MaxNumberOfFungibleMoves: u32 = 10;
     pub const MaxNumberOfNFTsMoves: u32 = 100;
 
+    // Utility:
+    pub const MaxEnvelopeLifetime: BlockNumber = 7 * DAYS;
+    pub const MaxExpiredEnvelopesPerBlock: u32 = 100;
+
     // State trie Migration
     pub const MigrationSignedDepositPerItem: Balance = 0;
     pub const MigrationSignedDepositBase: Balance = 0;
@@ -289,6 +312,8 @@ impl pallet_pips::Config for Runtime {
     type WeightInfo = polymesh_weights::pallet_pips::SubstrateWeight;
     type Scheduler = Scheduler;
     type SchedulerCall = RuntimeCall;
+    type ConvictionLockPeriod = PipConvictionLockPeriod;
+    type MaxDelegators = PipMaxDelegators;
 }
 
 /// CddProviders instance of group
@@ -406,6 +431,9 @@ construct_runtime!(
 
         ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 50,
 
+        CorporateReorganization: pallet_corporate_reorganization::{Pallet, Call, Storage, Event} = 51,
+        FixedIncome: pallet_fixed_income::{Pallet, Call, Storage, Event} = 52,
+
         TestUtils: pallet_test_utils::{Pallet, Call, Storage, Event<T> } = 200,
     }
 );
@@ -5,11 +5,12 @@ use super::{
     storage::{TestStorage, User},
     ExtBuilder,
 };
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::asset_metadata::{
-    AssetMetadataKey, AssetMetadataLockStatus, AssetMetadataName, AssetMetadataSpec,
-    AssetMetadataValue, AssetMetadataValueDetail,
+    AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataLockStatus, AssetMetadataName,
+    AssetMetadataSpec, AssetMetadataTypeDef, AssetMetadataValue, AssetMetadataValueDetail,
 };
 use sp_keyring::AccountKeyring;
 
@@ -607,3 +608,81 @@ fn check_locked_until() {
         ));
     });
 }
+
+#[test]
+fn set_asset_metadata_typed_value() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = User::new(AccountKeyring::Dave);
+
+        // Create asset.
+        let asset_id = create_and_issue_sample_asset(&owner);
+
+        // Register a local metadata type for a coupon rate and maturity date.
+        let (name, mut spec) = make_metadata_type("COUPON");
+        spec.set_type_def(AssetMetadataTypeDef::new_from_type::<(u32, Moment)>());
+        exec_ok!(Asset::register_asset_metadata_local_type(
+            owner.origin(),
+            asset_id,
+            name.clone(),
+            spec.clone(),
+        ));
+        let typed_key = Asset::asset_metadata_local_name_to_key(asset_id, name)
+            .map(AssetMetadataKey::from)
+            .expect("Failed to register metadata");
+        let untyped_key = register_metadata_type(owner, Some(asset_id), "UNTYPED");
+
+        // Values that don't conform to the type definition are rejected.
+        let invalid_values = [b"cow".to_vec(), (500u32, 1_700_000_000u64, 0u8).encode()];
+        for value in invalid_values {
+            exec_noop!(
+                Asset::set_asset_metadata(
+                    owner.origin(),
+                    asset_id,
+                    typed_key,
+                    AssetMetadataValue(value),
+                    None
+                ),
+                AssetError::AssetMetadataValueInvalid
+            );
+        }
+        exec_noop!(
+            Asset::register_and_set_local_asset_metadata(
+                owner.origin(),
+                asset_id,
+                "COUPON2".as_bytes().into(),
+                spec,
+                AssetMetadataValue(b"cow".to_vec()),
+                None
+            ),
+            AssetError::AssetMetadataValueInvalid
+        );
+
+        // Conforming values and values of untyped keys are accepted.
+        exec_ok!(Asset::set_asset_metadata(
+            owner.origin(),
+            asset_id,
+            typed_key,
+            AssetMetadataValue((500u32, 1_700_000_000u64).encode()),
+            None
+        ));
+        exec_ok!(Asset::set_asset_metadata(
+            owner.origin(),
+            asset_id,
+            untyped_key,
+            AssetMetadataValue(b"cow".to_vec()),
+            None
+        ));
+
+        // Values are returned as JSON.
+        assert_eq!(
+            Asset::asset_metadata_json(&asset_id, &typed_key),
+            Some(b"[500,1700000000]".to_vec())
+        );
+        assert_eq!(
+            Asset::asset_metadata_json(&asset_id, &untyped_key),
+            Some(br#""0x636f77""#.to_vec())
+        );
+        let missing_key = AssetMetadataKey::Local(AssetMetadataLocalKey(100));
+        assert_eq!(Asset::asset_metadata_json(&asset_id, &missing_key), None);
+    });
+}
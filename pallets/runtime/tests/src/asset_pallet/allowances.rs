@@ -0,0 +1,271 @@
+use frame_support::weights::Weight;
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok, assert_storage_noop};
+use sp_keyring::AccountKeyring;
+
+use pallet_asset::Allowances;
+use polymesh_common_utilities::traits::asset::RawEvent as AssetEvent;
+use polymesh_primitives::asset::{AssetAllowance, TransferFee, TransferFeeAmount};
+use polymesh_primitives::{
+    Claim, ClaimType, Condition, ConditionType, CountryCode, PortfolioId, PortfolioKind, Scope,
+    TrustedFor, TrustedIssuer,
+};
+
+use super::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
+use crate::asset_test::set_timestamp;
+use crate::storage::{EventTest, User};
+use crate::{ExtBuilder, TestStorage};
+
+type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
+type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
+type System = frame_system::Pallet<TestStorage>;
+
+#[test]
+fn approve_and_transfer_from() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::transfer_from(
+                bob.origin(),
+                asset_id,
+                alice_portfolio,
+                PortfolioKind::Default,
+                100,
+                None
+            ),
+            AssetError::InsufficientAllowance
+        ));
+
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob.did,
+            1_000,
+            None
+        ));
+        assert_eq!(
+            Allowances::<TestStorage>::get((alice_portfolio, asset_id), bob.did),
+            Some(AssetAllowance {
+                amount: 1_000,
+                expiry: None
+            })
+        );
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::transfer_from(
+                bob.origin(),
+                asset_id,
+                alice_portfolio,
+                PortfolioKind::Default,
+                1_001,
+                None
+            ),
+            AssetError::InsufficientAllowance
+        ));
+
+        assert_ok!(Asset::transfer_from(
+            bob.origin(),
+            asset_id,
+            alice_portfolio,
+            PortfolioKind::Default,
+            400,
+            None
+        ));
+        assert_eq!(Asset::balance_of(&asset_id, bob.did), 400);
+        assert_eq!(Asset::balance_of(&asset_id, alice.did), ISSUE_AMOUNT - 400);
+        assert_eq!(
+            Asset::allowance((alice_portfolio, asset_id), bob.did).map(|a| a.amount),
+            Some(600)
+        );
+
+        // Using the whole allowance removes it
+        assert_ok!(Asset::transfer_from(
+            bob.origin(),
+            asset_id,
+            alice_portfolio,
+            PortfolioKind::Default,
+            600,
+            None
+        ));
+        assert_eq!(Asset::allowance((alice_portfolio, asset_id), bob.did), None);
+    });
+}
+
+#[test]
+fn expired_allowance() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(100);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+
+        assert_noop!(
+            Asset::approve(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                bob.did,
+                1_000,
+                Some(100)
+            ),
+            AssetError::InvalidAllowanceExpiry
+        );
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob.did,
+            1_000,
+            Some(200)
+        ));
+
+        set_timestamp(200);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::transfer_from(
+                bob.origin(),
+                asset_id,
+                alice_portfolio,
+                PortfolioKind::Default,
+                100,
+                None
+            ),
+            AssetError::AllowanceExpired
+        ));
+
+        // An allowance of zero removes it
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob.did,
+            0,
+            None
+        ));
+        assert_eq!(Asset::allowance((alice_portfolio, asset_id), bob.did), None);
+    });
+}
+
+#[test]
+fn transfer_from_respects_compliance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let dave = User::new(AccountKeyring::Dave);
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::add_compliance_requirement(
+            alice.origin(),
+            asset_id,
+            Vec::new(),
+            vec![Condition {
+                condition_type: ConditionType::IsPresent(Claim::Jurisdiction(
+                    CountryCode::BR,
+                    Scope::Identity(alice.did)
+                )),
+                issuers: vec![TrustedIssuer {
+                    issuer: dave.did,
+                    trusted_for: TrustedFor::Specific(vec![ClaimType::Jurisdiction])
+                }]
+            }],
+        ));
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob.did,
+            1_000,
+            None
+        ));
+
+        // Bob doesn't have the required claim
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::transfer_from(
+                bob.origin(),
+                asset_id,
+                alice_portfolio,
+                PortfolioKind::Default,
+                100,
+                None
+            ),
+            AssetError::InvalidTransferComplianceFailure
+        ));
+    });
+}
+
+#[test]
+fn transfer_from_charges_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let charlie_portfolio = PortfolioId::default_portfolio(charlie.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+        assert_ok!(Asset::set_transfer_fee(
+            alice.origin(),
+            asset_id,
+            TransferFee {
+                amount: TransferFeeAmount::BasisPoints(1_000),
+                currency: None,
+                beneficiary: charlie_portfolio,
+            }
+        ));
+        assert_ok!(Asset::approve(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob.did,
+            1_000,
+            None
+        ));
+
+        // The weight limit must cover the minimum weight of the call
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::transfer_from(
+                bob.origin(),
+                asset_id,
+                alice_portfolio,
+                PortfolioKind::Default,
+                1_000,
+                Some(Weight::zero())
+            ),
+            AssetError::InputWeightIsLessThanMinimum
+        ));
+
+        // Bob pays the fee from the portfolio receiving the units
+        assert_ok!(Asset::transfer_from(
+            bob.origin(),
+            asset_id,
+            alice_portfolio,
+            PortfolioKind::Default,
+            1_000,
+            None
+        ));
+        assert_eq!(
+            Asset::balance_of(&asset_id, alice.did),
+            ISSUE_AMOUNT - 1_000
+        );
+        assert_eq!(Asset::balance_of(&asset_id, bob.did), 900);
+        assert_eq!(Asset::balance_of(&asset_id, charlie.did), 100);
+        assert!(System::events().iter().any(|record| record.event
+            == EventTest::Asset(AssetEvent::TransferFromFeeCharged(
+                bob.did,
+                asset_id,
+                bob_portfolio,
+                charlie_portfolio,
+                asset_id,
+                100
+            ))));
+    });
+}
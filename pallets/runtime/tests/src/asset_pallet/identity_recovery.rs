@@ -0,0 +1,262 @@
+use frame_support::{assert_noop, assert_ok, StorageDoubleMap, StorageMap, StorageValue};
+use sp_keyring::AccountKeyring;
+
+use pallet_asset::{IdentityRecoveries, IdentityRecoveryCounter};
+use pallet_portfolio::PortfolioAssetBalances;
+use polymesh_primitives::asset::IdentityRecoveryId;
+use polymesh_primitives::{
+    AssetId, AuthorizationData, PortfolioId, PortfolioLockId, PortfolioLockReason, PortfolioName,
+    PortfolioNumber, Signatory, WeightMeter,
+};
+
+use super::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
+use crate::asset_test::set_timestamp;
+use crate::storage::{get_last_auth_id, IdentityRecoveryVetoPeriod, User};
+use crate::{ExtBuilder, TestStorage};
+
+type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
+type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
+type Identity = pallet_identity::Module<TestStorage>;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
+type Timestamp = pallet_timestamp::Pallet<TestStorage>;
+
+/// Issues an asset owned by `issuer` and moves half of it to the default portfolio of `investor`
+/// and the other half to a new user portfolio of `investor`.
+fn setup_investor_balances(issuer: &User, investor: &User) -> AssetId {
+    let asset_id = create_and_issue_sample_asset(issuer);
+    assert_ok!(ComplianceManager::pause_asset_compliance(
+        issuer.origin(),
+        asset_id
+    ));
+    assert_ok!(Portfolio::create_portfolio(
+        investor.origin(),
+        PortfolioName(b"InvestorPortfolio".to_vec())
+    ));
+
+    let issuer_portfolio = PortfolioId::default_portfolio(issuer.did);
+    let investor_portfolios = [
+        PortfolioId::default_portfolio(investor.did),
+        PortfolioId::user_portfolio(investor.did, PortfolioNumber(1)),
+    ];
+    for investor_portfolio in investor_portfolios {
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+        assert_ok!(Asset::base_transfer(
+            issuer_portfolio,
+            investor_portfolio,
+            asset_id,
+            ISSUE_AMOUNT / 2,
+            None,
+            None,
+            issuer.did,
+            &mut weight_meter
+        ));
+    }
+    asset_id
+}
+
+#[test]
+fn identity_recovery() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.to_account_id()])
+        .build()
+        .execute_with(|| {
+            set_timestamp(1);
+            let eve = User::existing(AccountKeyring::Eve);
+            let alice = User::new(AccountKeyring::Alice);
+            let bob = User::new(AccountKeyring::Bob);
+            let charlie = User::new(AccountKeyring::Charlie);
+            let asset_id = setup_investor_balances(&alice, &bob);
+
+            assert_ok!(Asset::initiate_identity_recovery(
+                eve.origin(),
+                bob.did,
+                charlie.did,
+                [asset_id].into()
+            ));
+            let recovery_id = IdentityRecoveryCounter::get();
+            assert_eq!(recovery_id, IdentityRecoveryId(1));
+
+            // The recovery can't be executed before all assets have been confirmed
+            assert_noop!(
+                Asset::execute_identity_recovery(charlie.origin(), recovery_id, 2, 1, 0),
+                AssetError::IdentityRecoveryNotConfirmed
+            );
+            let auth_id = get_last_auth_id(&Signatory::Identity(alice.did));
+            assert_ok!(Asset::confirm_identity_recovery(alice.origin(), auth_id));
+            let recovery = IdentityRecoveries::<TestStorage>::get(recovery_id).unwrap();
+            assert!(recovery.pending_assets.is_empty());
+            assert!(recovery.confirmed_assets.contains(&asset_id));
+
+            // The recovery can't be executed during the veto period
+            assert_noop!(
+                Asset::execute_identity_recovery(charlie.origin(), recovery_id, 2, 1, 0),
+                AssetError::IdentityRecoveryVetoPeriodNotOver
+            );
+            set_timestamp(recovery.execute_after);
+            assert_noop!(
+                Asset::veto_identity_recovery(bob.origin(), recovery_id),
+                AssetError::IdentityRecoveryVetoPeriodOver
+            );
+
+            // The lock is moved together with the locked units
+            let bob_default_portfolio = PortfolioId::default_portfolio(bob.did);
+            let charlie_default_portfolio = PortfolioId::default_portfolio(charlie.did);
+            assert_ok!(Portfolio::lock_portfolio_assets(
+                bob.origin(),
+                bob_default_portfolio,
+                asset_id,
+                100,
+                PortfolioLockReason::Collateral,
+                None,
+                None
+            ));
+            let lock_id = PortfolioLockId(0);
+            assert_noop!(
+                Asset::execute_identity_recovery(charlie.origin(), recovery_id, 1, 1, 0),
+                AssetError::IdentityRecoveryLimitsExceeded
+            );
+            assert_noop!(
+                Asset::execute_identity_recovery(charlie.origin(), recovery_id, 2, 0, 0),
+                AssetError::IdentityRecoveryLimitsExceeded
+            );
+            assert_ok!(Asset::execute_identity_recovery(
+                charlie.origin(),
+                recovery_id,
+                2,
+                1,
+                0
+            ));
+            assert_eq!(
+                Portfolio::portfolio_lock(bob_default_portfolio, lock_id),
+                None
+            );
+            assert_eq!(
+                Portfolio::portfolio_lock(charlie_default_portfolio, lock_id)
+                    .map(|lock| lock.amount),
+                Some(100)
+            );
+            assert_eq!(
+                Portfolio::locked_by_locks(bob_default_portfolio, asset_id),
+                0
+            );
+            assert_eq!(
+                Portfolio::locked_by_locks(charlie_default_portfolio, asset_id),
+                100
+            );
+
+            assert_eq!(
+                PortfolioAssetBalances::get(PortfolioId::default_portfolio(bob.did), asset_id),
+                0
+            );
+            assert_eq!(
+                PortfolioAssetBalances::get(
+                    PortfolioId::user_portfolio(bob.did, PortfolioNumber(1)),
+                    asset_id
+                ),
+                0
+            );
+            assert_eq!(
+                PortfolioAssetBalances::get(PortfolioId::default_portfolio(charlie.did), asset_id),
+                ISSUE_AMOUNT
+            );
+            assert_eq!(IdentityRecoveries::<TestStorage>::get(recovery_id), None);
+        });
+}
+
+#[test]
+fn veto_identity_recovery() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let asset_id = setup_investor_balances(&alice, &bob);
+
+        assert_ok!(Asset::initiate_identity_recovery(
+            frame_system::RawOrigin::Root.into(),
+            bob.did,
+            charlie.did,
+            [asset_id].into()
+        ));
+        let recovery_id = IdentityRecoveryCounter::get();
+        assert_eq!(
+            IdentityRecoveries::<TestStorage>::get(recovery_id)
+                .unwrap()
+                .execute_after,
+            Timestamp::get() + IdentityRecoveryVetoPeriod::get()
+        );
+
+        assert_noop!(
+            Asset::veto_identity_recovery(charlie.origin(), recovery_id),
+            AssetError::NotPrimaryKeyOfRecoveredIdentity
+        );
+        let auth_id = get_last_auth_id(&Signatory::Identity(alice.did));
+        assert_ok!(Asset::veto_identity_recovery(bob.origin(), recovery_id));
+        assert_eq!(IdentityRecoveries::<TestStorage>::get(recovery_id), None);
+
+        // The authorization to confirm the vetoed recovery has been revoked
+        assert_eq!(
+            Identity::authorizations(Signatory::Identity(alice.did), auth_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn initiate_identity_recovery_unauthorized() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let asset_id = setup_investor_balances(&alice, &bob);
+
+        assert_noop!(
+            Asset::initiate_identity_recovery(
+                charlie.origin(),
+                bob.did,
+                charlie.did,
+                [asset_id].into()
+            ),
+            AssetError::NotAuthorizedToInitiateIdentityRecovery
+        );
+        assert_noop!(
+            Asset::initiate_identity_recovery(
+                frame_system::RawOrigin::Root.into(),
+                bob.did,
+                bob.did,
+                [asset_id].into()
+            ),
+            AssetError::InvalidIdentityRecovery
+        );
+    });
+}
+
+#[test]
+fn confirm_identity_recovery_not_agent() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let asset_id = setup_investor_balances(&alice, &bob);
+
+        assert_ok!(Asset::initiate_identity_recovery(
+            frame_system::RawOrigin::Root.into(),
+            bob.did,
+            charlie.did,
+            [asset_id].into()
+        ));
+        let recovery_id = IdentityRecoveryCounter::get();
+        let auth_id = pallet_identity::Module::<TestStorage>::add_auth(
+            alice.did,
+            Signatory::Identity(charlie.did),
+            AuthorizationData::ConfirmIdentityRecovery(recovery_id, asset_id),
+            None,
+        )
+        .unwrap();
+        assert_noop!(
+            Asset::confirm_identity_recovery(charlie.origin(), auth_id),
+            pallet_external_agents::Error::<TestStorage>::UnauthorizedAgent
+        );
+    });
+}
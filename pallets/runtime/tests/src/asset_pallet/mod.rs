@@ -1,10 +1,15 @@
 mod accept_ticker_transfer;
+mod allowances;
 mod base_transfer;
 mod controller_transfer;
+mod identity_recovery;
 mod issue;
 mod link_ticker_to_asset;
+mod pause_asset_operations;
 mod register_metadata;
 mod register_ticker;
+mod transfer_fee;
 mod unlink_ticker_from_asset;
+mod vesting;
 
 pub(crate) mod setup;
@@ -0,0 +1,179 @@
+use frame_support::{assert_noop, assert_ok, StorageMap};
+use sp_keyring::AccountKeyring;
+
+use pallet_asset::PausedOperations;
+use polymesh_primitives::asset::{AssetOperation, AssetOperations};
+use polymesh_primitives::{PortfolioId, PortfolioKind, WeightMeter};
+
+use super::setup::{create_and_issue_sample_asset, create_and_issue_sample_nft};
+use crate::storage::User;
+use crate::{ExtBuilder, TestStorage};
+
+type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
+type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
+type ExternalAgentsError = pallet_external_agents::Error<TestStorage>;
+type Nft = pallet_nft::Module<TestStorage>;
+
+#[test]
+fn pause_and_unpause_asset_operations() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+
+        let issue_and_redeem =
+            AssetOperations::from_iter([AssetOperation::Issue, AssetOperation::Redeem]);
+        assert_ok!(Asset::pause_asset_operations(
+            alice.origin(),
+            asset_id,
+            issue_and_redeem
+        ));
+        assert_eq!(PausedOperations::get(asset_id), issue_and_redeem);
+        assert_noop!(
+            Asset::issue(alice.origin(), asset_id, 1_000, PortfolioKind::Default),
+            AssetError::AssetOperationPaused
+        );
+        assert_noop!(
+            Asset::redeem(alice.origin(), asset_id, 1_000, PortfolioKind::Default),
+            AssetError::AssetOperationPaused
+        );
+
+        // Transfers are still allowed
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+        assert_ok!(Asset::base_transfer(
+            PortfolioId::default_portfolio(alice.did),
+            PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            1_000,
+            None,
+            None,
+            alice.did,
+            &mut weight_meter
+        ));
+
+        assert_ok!(Asset::unpause_asset_operations(
+            alice.origin(),
+            asset_id,
+            AssetOperations::from_iter([AssetOperation::Issue])
+        ));
+        assert_eq!(
+            Asset::paused_operations(asset_id).operations(),
+            vec![AssetOperation::Redeem]
+        );
+        assert_ok!(Asset::issue(
+            alice.origin(),
+            asset_id,
+            1_000,
+            PortfolioKind::Default
+        ));
+
+        // The storage is cleared once all operations have been unpaused
+        assert_ok!(Asset::unpause_asset_operations(
+            alice.origin(),
+            asset_id,
+            AssetOperations::from_iter([AssetOperation::Redeem])
+        ));
+        assert!(!PausedOperations::contains_key(asset_id));
+    });
+}
+
+#[test]
+fn pause_transfers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+
+        assert_ok!(Asset::pause_asset_operations(
+            alice.origin(),
+            asset_id,
+            AssetOperations::from_iter([AssetOperation::Transfer])
+        ));
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+        assert_noop!(
+            Asset::base_transfer(
+                PortfolioId::default_portfolio(alice.did),
+                PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                1_000,
+                None,
+                None,
+                alice.did,
+                &mut weight_meter
+            ),
+            AssetError::AssetOperationPaused
+        );
+        assert_eq!(
+            Asset::asset_transfer_report(
+                &PortfolioId::default_portfolio(alice.did),
+                &PortfolioId::default_portfolio(bob.did),
+                &asset_id,
+                1_000,
+                false,
+                &mut weight_meter
+            ),
+            vec![AssetError::AssetOperationPaused.into()]
+        );
+
+        // Issuance is still allowed
+        assert_ok!(Asset::issue(
+            alice.origin(),
+            asset_id,
+            1_000,
+            PortfolioKind::Default
+        ));
+    });
+}
+
+#[test]
+fn pause_nft_issuance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let asset_id = create_and_issue_sample_nft(&alice);
+
+        assert_ok!(Asset::pause_asset_operations(
+            alice.origin(),
+            asset_id,
+            AssetOperations::from_iter([AssetOperation::Issue])
+        ));
+        assert_noop!(
+            Nft::issue_nft(alice.origin(), asset_id, Vec::new(), PortfolioKind::Default),
+            AssetError::AssetOperationPaused
+        );
+    });
+}
+
+#[test]
+fn pause_asset_operations_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let asset_id = create_and_issue_sample_asset(&alice);
+
+        assert_noop!(
+            Asset::pause_asset_operations(alice.origin(), asset_id, AssetOperations::default()),
+            AssetError::InvalidAssetOperations
+        );
+        assert_noop!(
+            Asset::pause_asset_operations(alice.origin(), asset_id, AssetOperations(1 << 7)),
+            AssetError::InvalidAssetOperations
+        );
+        assert_noop!(
+            Asset::pause_asset_operations(
+                bob.origin(),
+                asset_id,
+                AssetOperations::from_iter([AssetOperation::Issue])
+            ),
+            ExternalAgentsError::UnauthorizedAgent
+        );
+    });
+}
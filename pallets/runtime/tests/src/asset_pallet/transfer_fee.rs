@@ -0,0 +1,129 @@
+use frame_support::{assert_noop, assert_ok, StorageMap};
+use sp_keyring::AccountKeyring;
+
+use pallet_asset::TransferFees;
+use polymesh_primitives::asset::{TransferFee, TransferFeeAmount};
+use polymesh_primitives::{PortfolioId, PortfolioNumber};
+
+use super::setup::{create_and_issue_sample_asset, create_and_issue_sample_nft};
+use crate::storage::User;
+use crate::{ExtBuilder, TestStorage};
+
+type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
+type ExternalAgentsError = pallet_external_agents::Error<TestStorage>;
+type PortfolioError = pallet_portfolio::Error<TestStorage>;
+
+fn sample_fee(amount: TransferFeeAmount, beneficiary: &User) -> TransferFee {
+    TransferFee {
+        amount,
+        currency: None,
+        beneficiary: PortfolioId::default_portfolio(beneficiary.did),
+    }
+}
+
+#[test]
+fn set_and_remove_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let asset_id = create_and_issue_sample_asset(&alice);
+
+        let fee = sample_fee(TransferFeeAmount::BasisPoints(25), &alice);
+        assert_ok!(Asset::set_transfer_fee(
+            alice.origin(),
+            asset_id,
+            fee.clone()
+        ));
+        assert_eq!(TransferFees::get(asset_id), Some(fee));
+
+        assert_ok!(Asset::remove_transfer_fee(alice.origin(), asset_id));
+        assert_eq!(TransferFees::get(asset_id), None);
+        assert_noop!(
+            Asset::remove_transfer_fee(alice.origin(), asset_id),
+            AssetError::TransferFeeNotFound
+        );
+    });
+}
+
+#[test]
+fn set_transfer_fee_unauthorized_agent() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let asset_id = create_and_issue_sample_asset(&alice);
+
+        assert_noop!(
+            Asset::set_transfer_fee(
+                bob.origin(),
+                asset_id,
+                sample_fee(TransferFeeAmount::Flat(10), &bob)
+            ),
+            ExternalAgentsError::UnauthorizedAgent
+        );
+    });
+}
+
+#[test]
+fn set_invalid_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        let currency = create_and_issue_sample_asset(&alice);
+        let nft_asset_id = create_and_issue_sample_nft(&alice);
+
+        let invalid_fees = [
+            (asset_id, sample_fee(TransferFeeAmount::Flat(0), &alice)),
+            (
+                asset_id,
+                sample_fee(TransferFeeAmount::BasisPoints(0), &alice),
+            ),
+            (
+                asset_id,
+                sample_fee(TransferFeeAmount::BasisPoints(10_001), &alice),
+            ),
+            (
+                asset_id,
+                TransferFee {
+                    currency: Some(currency),
+                    ..sample_fee(TransferFeeAmount::BasisPoints(100), &alice)
+                },
+            ),
+            (
+                asset_id,
+                TransferFee {
+                    currency: Some(nft_asset_id),
+                    ..sample_fee(TransferFeeAmount::Flat(10), &alice)
+                },
+            ),
+            (
+                nft_asset_id,
+                sample_fee(TransferFeeAmount::Flat(10), &alice),
+            ),
+        ];
+        for (asset_id, fee) in invalid_fees {
+            assert_noop!(
+                Asset::set_transfer_fee(alice.origin(), asset_id, fee),
+                AssetError::InvalidTransferFee
+            );
+        }
+
+        let unknown_portfolio = TransferFee {
+            beneficiary: PortfolioId::user_portfolio(alice.did, PortfolioNumber(10)),
+            ..sample_fee(TransferFeeAmount::Flat(10), &alice)
+        };
+        assert_noop!(
+            Asset::set_transfer_fee(alice.origin(), asset_id, unknown_portfolio),
+            PortfolioError::PortfolioDoesNotExist
+        );
+
+        // NFT collections can charge a flat fee in a fungible currency
+        assert_ok!(Asset::set_transfer_fee(
+            alice.origin(),
+            nft_asset_id,
+            TransferFee {
+                currency: Some(currency),
+                ..sample_fee(TransferFeeAmount::Flat(10), &alice)
+            }
+        ));
+    });
+}
@@ -0,0 +1,271 @@
+use frame_support::dispatch::DispatchResult;
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok, assert_storage_noop};
+use sp_keyring::AccountKeyring;
+
+use polymesh_primitives::asset::{AssetId, VestingRelease, VestingScheduleId};
+use polymesh_primitives::{Balance, PortfolioId, PortfolioKind, WeightMeter};
+
+use super::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
+use crate::asset_test::set_timestamp;
+use crate::storage::User;
+use crate::{ExtBuilder, TestStorage};
+
+type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
+type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
+
+const VESTED_AMOUNT: Balance = 1_000;
+
+fn transfer(from: &User, to: &User, asset_id: AssetId, amount: Balance) -> DispatchResult {
+    let mut weight_meter = WeightMeter::max_limit_no_minimum();
+    Asset::base_transfer(
+        PortfolioId::default_portfolio(from.did),
+        PortfolioId::default_portfolio(to.did),
+        asset_id,
+        amount,
+        None,
+        None,
+        from.did,
+        &mut weight_meter,
+    )
+}
+
+#[test]
+fn automatic_vesting_schedule() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(0);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::add_vesting_schedule(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                bob_portfolio,
+                VESTED_AMOUNT,
+                100,
+                50,
+                1_000,
+                VestingRelease::Automatic,
+                None
+            ),
+            AssetError::InvalidVestingSchedule
+        ));
+        assert_ok!(Asset::add_vesting_schedule(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob_portfolio,
+            VESTED_AMOUNT,
+            0,
+            100,
+            1_000,
+            VestingRelease::Automatic,
+            None
+        ));
+        assert_eq!(Asset::balance_of(&asset_id, bob.did), VESTED_AMOUNT);
+        assert_eq!(
+            Asset::unvested_balance(&bob_portfolio, &asset_id),
+            VESTED_AMOUNT
+        );
+        assert_eq!(
+            Portfolio::total_locked(&bob_portfolio, &asset_id),
+            VESTED_AMOUNT
+        );
+        assert_noop!(
+            transfer(&bob, &alice, asset_id, 1),
+            AssetError::InsufficientVestedBalance
+        );
+
+        // Half of the schedule has elapsed
+        set_timestamp(500);
+        assert_eq!(Asset::unvested_balance(&bob_portfolio, &asset_id), 500);
+        assert_noop!(
+            transfer(&bob, &alice, asset_id, 501),
+            AssetError::InsufficientVestedBalance
+        );
+        assert_ok!(transfer(&bob, &alice, asset_id, 500));
+
+        // The schedule has ended
+        set_timestamp(1_000);
+        assert_eq!(Asset::unvested_balance(&bob_portfolio, &asset_id), 0);
+        assert_ok!(transfer(&bob, &alice, asset_id, 500));
+        assert_eq!(Asset::balance_of(&asset_id, alice.did), ISSUE_AMOUNT);
+    });
+}
+
+#[test]
+fn claim_vested_assets() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(0);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+        assert_ok!(Asset::add_vesting_schedule(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob_portfolio,
+            VESTED_AMOUNT,
+            0,
+            0,
+            1_000,
+            VestingRelease::OnClaim,
+            None
+        ));
+
+        // Vested units stay locked until they are claimed
+        set_timestamp(250);
+        assert_eq!(
+            Asset::unvested_balance(&bob_portfolio, &asset_id),
+            VESTED_AMOUNT
+        );
+        assert_noop!(
+            Asset::claim_vested_assets(alice.origin(), asset_id, bob_portfolio),
+            pallet_portfolio::Error::<TestStorage>::UnauthorizedCustodian
+        );
+        assert_ok!(Asset::claim_vested_assets(
+            bob.origin(),
+            asset_id,
+            bob_portfolio
+        ));
+        assert_eq!(Asset::unvested_balance(&bob_portfolio, &asset_id), 750);
+        assert_ok!(transfer(&bob, &alice, asset_id, 250));
+
+        // Claiming the whole schedule removes it
+        set_timestamp(1_000);
+        assert_ok!(Asset::claim_vested_assets(
+            bob.origin(),
+            asset_id,
+            bob_portfolio
+        ));
+        assert!(Asset::vesting_schedules(bob_portfolio, asset_id).is_empty());
+        assert_noop!(
+            Asset::claim_vested_assets(bob.origin(), asset_id, bob_portfolio),
+            AssetError::VestingScheduleNotFound
+        );
+    });
+}
+
+#[test]
+fn revoke_vesting_schedule() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(0);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+        assert_ok!(Asset::add_vesting_schedule(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob_portfolio,
+            VESTED_AMOUNT,
+            0,
+            0,
+            1_000,
+            VestingRelease::Automatic,
+            None
+        ));
+        let schedule_id = Asset::vesting_schedule_counter();
+
+        set_timestamp(400);
+        assert_noop!(
+            Asset::revoke_vesting_schedule(
+                alice.origin(),
+                asset_id,
+                bob_portfolio,
+                VestingScheduleId(schedule_id.0 + 1),
+                PortfolioKind::Default
+            ),
+            AssetError::VestingScheduleNotFound
+        );
+        assert_ok!(Asset::revoke_vesting_schedule(
+            alice.origin(),
+            asset_id,
+            bob_portfolio,
+            schedule_id,
+            PortfolioKind::Default
+        ));
+
+        // The unvested units went back to the treasury and the vested units are free
+        assert_eq!(Asset::balance_of(&asset_id, bob.did), 400);
+        assert_eq!(Asset::balance_of(&asset_id, alice.did), ISSUE_AMOUNT - 400);
+        assert_eq!(Asset::unvested_balance(&bob_portfolio, &asset_id), 0);
+        assert_ok!(transfer(&bob, &alice, asset_id, 400));
+    });
+}
+
+#[test]
+fn vesting_schedule_only_locks_transferred_units() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(0);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did);
+        let asset_id = create_and_issue_sample_asset(&alice);
+        assert_ok!(ComplianceManager::pause_asset_compliance(
+            alice.origin(),
+            asset_id
+        ));
+        assert_ok!(transfer(&alice, &bob, asset_id, 500));
+
+        // The agent can't vest more than its funding portfolio holds
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Asset::add_vesting_schedule(
+                alice.origin(),
+                asset_id,
+                PortfolioKind::Default,
+                bob_portfolio,
+                ISSUE_AMOUNT,
+                0,
+                0,
+                1_000,
+                VestingRelease::Automatic,
+                None
+            ),
+            AssetError::InsufficientBalance
+        ));
+        assert_ok!(Asset::add_vesting_schedule(
+            alice.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            bob_portfolio,
+            VESTED_AMOUNT,
+            0,
+            0,
+            1_000,
+            VestingRelease::Automatic,
+            None
+        ));
+
+        // The units Bob already held stay transferable
+        assert_eq!(Asset::balance_of(&asset_id, bob.did), 500 + VESTED_AMOUNT);
+        assert_eq!(
+            Asset::unvested_balance(&bob_portfolio, &asset_id),
+            VESTED_AMOUNT
+        );
+        assert_noop!(
+            transfer(&bob, &alice, asset_id, 501),
+            AssetError::InsufficientVestedBalance
+        );
+        assert_ok!(transfer(&bob, &alice, asset_id, 500));
+    });
+}
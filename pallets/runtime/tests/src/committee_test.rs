@@ -13,7 +13,7 @@ use frame_system::{EventRecord, Phase};
 use pallet_committee::{self as committee, PolymeshVotes, RawEvent as CommitteeRawEvent};
 use pallet_group as group;
 use pallet_identity as identity;
-use pallet_pips::{PipId, ProposalState, SnapshotResult};
+use pallet_pips::{PipId, ProposalState, RawEvent as PipsRawEvent, SnapshotResult};
 use polymesh_common_utilities::MaybeBlock;
 use polymesh_primitives::IdentityId;
 use sp_core::H256;
@@ -657,3 +657,48 @@ fn expiry_works() {
         );
     });
 }
+
+#[test]
+fn dry_run_proposal() {
+    let committee = vec![
+        AccountKeyring::Alice.to_account_id(),
+        AccountKeyring::Bob.to_account_id(),
+        AccountKeyring::Charlie.to_account_id(),
+    ];
+    ExtBuilder::default()
+        .governance_committee(committee)
+        .build()
+        .execute_with(dry_run_proposal_we);
+}
+
+fn dry_run_proposal_we() {
+    System::set_block_number(1);
+
+    let alice = AccountKeyring::Alice;
+    let alice_signer = Origin::signed(alice.to_account_id());
+    let _ = register_keyring_account(alice);
+    prepare_proposal(alice);
+    assert_ok!(Pips::snapshot(alice_signer.clone()));
+
+    // The proposal is pending, as only Alice voted for it.
+    assert_ok!(vote(&alice_signer, true));
+    let hash = hash_enact_snapshot_results();
+    let outcome = Committee::dry_run_proposal(hash).unwrap();
+    assert_ok!(outcome.result);
+    assert!(outcome.events.iter().any(|e| matches!(
+        e,
+        EventTest::Pips(PipsRawEvent::ExecutionScheduled(_, PipId(0), _))
+    )));
+    assert!(!outcome.storage_changes.is_empty());
+
+    // All changes were rolled back.
+    assert_eq!(
+        Pips::proposal_state(PipId(0)).unwrap(),
+        ProposalState::Pending
+    );
+    assert_eq!(Committee::proposals(), vec![hash]);
+    assert_noop!(
+        Committee::dry_run_proposal(H256::zero()),
+        committee::Error::<TestStorage, committee::Instance1>::NoSuchProposal
+    );
+}
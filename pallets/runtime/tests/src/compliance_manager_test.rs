@@ -8,11 +8,12 @@ use polymesh_common_utilities::compliance_manager::ComplianceFnConfig;
 use polymesh_primitives::agent::AgentGroup;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::compliance_manager::{
-    ComplianceReport, ComplianceRequirement, ComplianceRequirementResult,
+    ComplianceReport, ComplianceRequirement, ComplianceRequirementResult, ExpressionReport,
 };
 use polymesh_primitives::{
-    AuthorizationData, Claim, ClaimType, Condition, ConditionType, CountryCode, IdentityId,
-    PortfolioId, Scope, Signatory, TargetIdentity, TrustedFor, WeightMeter,
+    AuthorizationData, Claim, ClaimType, ClaimValue, Condition, ConditionExpression, ConditionType,
+    CountryCode, CustomClaimTypeId, CustomClaimValueType, IdentityId, PortfolioId, Scope,
+    Signatory, TargetIdentity, TrustedFor, WeightMeter,
 };
 use sp_keyring::AccountKeyring;
 
@@ -950,6 +951,208 @@ fn jurisdiction_asset_compliance_we() {
     assert_invalid_transfer!(asset_id, owner.did, user.did, 10);
 }
 
+#[test]
+fn expression_asset_compliance() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.to_account_id()])
+        .build()
+        .execute_with(expression_asset_compliance_we);
+}
+fn expression_asset_compliance_we() {
+    use ConditionExpression::{And, Not, Or};
+
+    let owner = User::new(AccountKeyring::Alice);
+    let cdd = User::new(AccountKeyring::Bob);
+    let us_investor = User::new(AccountKeyring::Charlie);
+    let other_investor = User::new(AccountKeyring::Dave);
+
+    let asset_id = create_and_issue_sample_asset(&owner);
+
+    // (Accredited AND US) OR (NOT US AND KYC), where KYC is issued by the owner.
+    let scope = Scope::from(IdentityId::from(0));
+    let us = Claim::Jurisdiction(CountryCode::US, scope.clone());
+    let kyc = Claim::KnowYourCustomer(scope.clone());
+    let leaf = |claim: Claim, issuer: IdentityId| {
+        ConditionExpression::Condition(Box::new(Condition::from_dids(
+            ConditionType::IsPresent(claim),
+            &[issuer],
+        )))
+    };
+    let expression = Or(vec![
+        And(vec![
+            leaf(Claim::Accredited(scope.clone()), cdd.did),
+            leaf(us.clone(), cdd.did),
+        ]),
+        And(vec![
+            Not(Box::new(leaf(us.clone(), cdd.did))),
+            leaf(kyc.clone(), owner.did),
+        ]),
+    ]);
+
+    // The condition holding the expression can't have issuers of its own.
+    assert_noop!(
+        ComplianceManager::add_compliance_requirement(
+            owner.origin(),
+            asset_id,
+            vec![],
+            vec![Condition::from_dids(
+                ConditionType::IsSatisfied(expression.clone()),
+                &[cdd.did]
+            )]
+        ),
+        CMError::<TestStorage>::ExpressionWithTrustedIssuers
+    );
+    let condition = Condition::from(ConditionType::IsSatisfied(expression));
+    // Complexity = 4 conditions + 4 nodes.
+    assert_eq!(condition.complexity(0), 8);
+    assert_ok!(ComplianceManager::add_compliance_requirement(
+        owner.origin(),
+        asset_id,
+        vec![],
+        vec![condition]
+    ));
+
+    // US investors must be accredited.
+    assert_add_claim!(cdd.origin(), us_investor.did, us.clone(), None);
+    assert_invalid_transfer!(asset_id, owner.did, us_investor.did, 10);
+    assert_add_claim!(cdd.origin(), us_investor.did, kyc.clone(), None);
+    assert_invalid_transfer!(asset_id, owner.did, us_investor.did, 10);
+    assert_add_claim!(
+        cdd.origin(),
+        us_investor.did,
+        Claim::Accredited(scope.clone()),
+        None
+    );
+    assert_valid_transfer!(asset_id, owner.did, us_investor.did, 10);
+
+    // Other investors need a KYC claim issued by the owner.
+    assert_add_claim!(cdd.origin(), other_investor.did, kyc.clone(), None);
+    assert_invalid_transfer!(asset_id, owner.did, other_investor.did, 10);
+    assert_add_claim!(owner.origin(), other_investor.did, kyc, None);
+    assert_valid_transfer!(asset_id, owner.did, other_investor.did, 10);
+
+    // The report includes the result of each node.
+    let report = ComplianceManager::compliance_report(
+        &asset_id,
+        &owner.did,
+        &other_investor.did,
+        &mut WeightMeter::max_limit_no_minimum(),
+    )
+    .unwrap();
+    assert!(report.is_any_requirement_satisfied());
+    let condition_report = report
+        .get_requirement(0)
+        .unwrap()
+        .get_receiver_condition(0)
+        .unwrap();
+    assert!(condition_report.is_condition_satisfied());
+    match condition_report.expression().unwrap() {
+        ExpressionReport::Or(true, branches) => {
+            assert!(matches!(&branches[0], ExpressionReport::And(false, _)));
+            match &branches[1] {
+                ExpressionReport::And(true, nodes) => {
+                    assert!(matches!(&nodes[0], ExpressionReport::Not(true, _)));
+                    assert!(nodes[1].is_satisfied());
+                }
+                _ => panic!("Unexpected report for the second branch"),
+            }
+        }
+        _ => panic!("Unexpected report for the expression"),
+    }
+}
+
+#[test]
+fn claim_value_asset_compliance() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Eve.to_account_id()])
+        .build()
+        .execute_with(claim_value_asset_compliance_we);
+}
+fn claim_value_asset_compliance_we() {
+    let owner = User::new(AccountKeyring::Alice);
+    let issuer = User::new(AccountKeyring::Bob);
+    let user = User::new(AccountKeyring::Charlie);
+
+    let asset_id = create_and_issue_sample_asset(&owner);
+
+    // Register the "net worth" and "tier" custom claim types.
+    let tier = |tier: &str| ClaimValue::Enum(tier.as_bytes().to_vec());
+    assert_ok!(Identity::register_custom_claim_type_with_value(
+        issuer.origin(),
+        "net worth".into(),
+        CustomClaimValueType::Decimal
+    ));
+    assert_ok!(Identity::register_custom_claim_type_with_value(
+        issuer.origin(),
+        "tier".into(),
+        CustomClaimValueType::Enum(vec![b"A".to_vec(), b"B".to_vec(), b"C".to_vec()])
+    ));
+    let (net_worth_id, tier_id) = (CustomClaimTypeId(1), CustomClaimTypeId(2));
+    let scope = Some(Scope::Asset(asset_id));
+
+    // Net worth > 1_000 AND tier in {A, B}.
+    let receiver_conditions = vec![
+        Condition::from_dids(
+            ConditionType::ClaimValueGreaterThan(
+                net_worth_id,
+                scope.clone(),
+                ClaimValue::Decimal(1_000_000_000),
+            ),
+            &[issuer.did],
+        ),
+        Condition::from_dids(
+            ConditionType::ClaimValueInSet(tier_id, scope.clone(), vec![tier("A"), tier("B")]),
+            &[issuer.did],
+        ),
+    ];
+    assert_ok!(ComplianceManager::add_compliance_requirement(
+        owner.origin(),
+        asset_id,
+        vec![],
+        receiver_conditions
+    ));
+
+    let add_claim = |id, value| {
+        assert_add_claim!(
+            issuer.origin(),
+            user.did,
+            Claim::CustomValue(id, scope.clone(), value),
+            None
+        );
+    };
+    add_claim(net_worth_id, ClaimValue::Decimal(1_000_000_000));
+    add_claim(tier_id, tier("A"));
+    assert_invalid_transfer!(asset_id, owner.did, user.did, 10);
+    add_claim(net_worth_id, ClaimValue::Decimal(1_000_000_001));
+    assert_valid_transfer!(asset_id, owner.did, user.did, 10);
+    add_claim(tier_id, tier("C"));
+    assert_invalid_transfer!(asset_id, owner.did, user.did, 10);
+
+    // Claims without a value never satisfy a claim value condition.
+    assert_add_claim!(
+        issuer.origin(),
+        user.did,
+        Claim::Custom(tier_id, scope.clone()),
+        None
+    );
+    assert_invalid_transfer!(asset_id, owner.did, user.did, 10);
+
+    // Claim value conditions have their custom scopes limited.
+    assert_noop!(
+        ComplianceManager::add_compliance_requirement(
+            owner.origin(),
+            asset_id,
+            vec![],
+            vec![Condition::from(ConditionType::ClaimValueLessThan(
+                net_worth_id,
+                Some(Scope::Custom(vec![0; 33])),
+                ClaimValue::Decimal(0),
+            ))]
+        ),
+        IdError::CustomScopeTooLong
+    );
+}
+
 #[test]
 fn scope_asset_compliance() {
     ExtBuilder::default()
@@ -1,20 +1,27 @@
 use super::{
     asset_test::max_len_bytes,
-    storage::{root, Balance, Checkpoint, MaxDidWhts, MaxTargetIds, TestStorage, User},
+    storage::{
+        root, Balance, Checkpoint, MaxDidWhts, MaxReorganizationBatch, MaxReorganizationPeriod,
+        MaxTargetIds, TestStorage, User,
+    },
     ExtBuilder,
 };
-use crate::asset_pallet::setup::create_and_issue_sample_asset;
+use crate::asset_pallet::setup::{create_and_issue_sample_asset, create_asset, ISSUE_AMOUNT};
 use crate::asset_test::{check_schedules, next_schedule_id, set_timestamp};
 use core::iter;
 use frame_support::{
-    assert_noop, assert_ok,
-    dispatch::{DispatchError, DispatchResult},
+    assert_err_ignore_postinfo, assert_noop, assert_ok, assert_storage_noop,
+    dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
     IterableStorageDoubleMap, StorageDoubleMap, StorageMap,
 };
 use pallet_asset::Assets;
 use pallet_corporate_actions::{
     ballot::{BallotMeta, BallotTimeRange, BallotVote, Motion, Votes},
     distribution::{self, Distribution, PER_SHARE_PRECISION},
+    fixed_income::{self, Coupons, FixedIncomeTerms, FixedIncomes},
+    reorganization::{
+        self, RemainderPolicy, ReorganizationKind, ReorganizationRatio, Reorganizations,
+    },
     CACheckpoint, CADetails, CAId, CAIdSequence, CAKind, CorporateAction, CorporateActions,
     Details, LocalCAId, RecordDate, RecordDateSpec, TargetIdentities, TargetTreatment,
     TargetTreatment::{Exclude, Include},
@@ -24,11 +31,14 @@ use polymesh_common_utilities::{
     constants::currency::ONE_UNIT,
     traits::checkpoint::{ScheduleCheckpoints, ScheduleId},
 };
-use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::asset::{AssetId, AssetType, VestingRelease};
+use polymesh_primitives::calendar::{CalendarPeriod, CalendarUnit, CheckpointSchedule};
+use polymesh_primitives::statistics::{StatOpType, StatType};
 use polymesh_primitives::{
     agent::AgentGroup, asset::CheckpointId, AuthorizationData, Claim, ClaimType, Condition,
-    ConditionType, CountryCode, Document, DocumentId, IdentityId, Moment, PortfolioId,
-    PortfolioNumber, Scope, Signatory, TrustedFor, TrustedIssuer,
+    ConditionType, CountryCode, Document, DocumentId, Fund, FundDescription, IdentityId, Moment,
+    PortfolioId, PortfolioKind, PortfolioLockReason, PortfolioName, PortfolioNumber, Scope,
+    Signatory, TrustedFor, TrustedIssuer,
 };
 use sp_arithmetic::Permill;
 use sp_keyring::AccountKeyring;
@@ -46,11 +56,16 @@ type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
 type CA = pallet_corporate_actions::Module<TestStorage>;
 type Ballot = pallet_corporate_actions::ballot::Module<TestStorage>;
 type Dist = distribution::Module<TestStorage>;
+type Reorg = reorganization::Module<TestStorage>;
+type FixedIncome = fixed_income::Module<TestStorage>;
 type Portfolio = pallet_portfolio::Module<TestStorage>;
+type Statistics = pallet_statistics::Module<TestStorage>;
 type BaseError = pallet_base::Error<TestStorage>;
 type Error = pallet_corporate_actions::Error<TestStorage>;
 type BallotError = pallet_corporate_actions::ballot::Error<TestStorage>;
 type DistError = distribution::Error<TestStorage>;
+type ReorgError = reorganization::Error<TestStorage>;
+type FIError = fixed_income::Error<TestStorage>;
 type PError = pallet_portfolio::Error<TestStorage>;
 type CPError = pallet_asset::checkpoint::Error<TestStorage>;
 type EAError = pallet_external_agents::Error<TestStorage>;
@@ -2308,3 +2323,849 @@ fn dist_claim_existing_checkpoint() {
 fn dist_claim_scheduled_checkpoint() {
     dist_claim_cp_test(|asset_id, owner| dist_ca(owner, asset_id, Some(2000)).unwrap());
 }
+
+fn reorg_ca(owner: User, asset_id: AssetId) -> CAId {
+    let id = next_ca_id(asset_id);
+    assert_ok!(moment_ca(owner, asset_id, CAKind::Reorganization, Some(1)));
+    id
+}
+
+/// The deadline of the reorganizations, after the record date of `reorg_ca`.
+const REORG_DEADLINE: Moment = 1_000;
+
+fn split(new: u32, old: u32) -> ReorganizationKind {
+    ReorganizationKind::Split(ReorganizationRatio { new, old })
+}
+
+fn apply_reorganization(owner: User, id: CAId, holder: User) -> DispatchResultWithPostInfo {
+    let portfolio = PortfolioId::default_portfolio(holder.did);
+    Reorg::apply_reorganization(owner.origin(), id, vec![portfolio], 0, None)
+}
+
+#[test]
+fn reorganize_bad() {
+    currency_test(|asset_id, currency, [owner, other, _]| {
+        let reorganize = |id, kind| {
+            Reorg::reorganize(
+                owner.origin(),
+                id,
+                kind,
+                RemainderPolicy::RoundDown,
+                REORG_DEADLINE,
+            )
+        };
+
+        // Not an agent.
+        let id = reorg_ca(owner, asset_id);
+        assert_noop!(
+            Reorg::reorganize(
+                other.origin(),
+                id,
+                split(2, 1),
+                RemainderPolicy::RoundDown,
+                REORG_DEADLINE
+            ),
+            EAError::UnauthorizedAgent
+        );
+
+        // Wrong CA kind.
+        let other_id = next_ca_id(asset_id);
+        assert_ok!(moment_ca(owner, asset_id, CAKind::Other, Some(1)));
+        assert_noop!(
+            reorganize(other_id, split(2, 1)),
+            ReorgError::CANotReorganization
+        );
+
+        // Nonsensical ratios.
+        assert_noop!(reorganize(id, split(0, 1)), ReorgError::InvalidRatio);
+        assert_noop!(reorganize(id, split(2, 0)), ReorgError::InvalidRatio);
+        assert_noop!(reorganize(id, split(3, 3)), ReorgError::InvalidRatio);
+
+        // An asset can't be converted into itself.
+        let ratio = ReorganizationRatio { new: 1, old: 1 };
+        assert_noop!(
+            reorganize(id, ReorganizationKind::Conversion(asset_id, ratio)),
+            ReorgError::InvalidConversion
+        );
+
+        // Cash in lieu must be non-zero.
+        let cash = |per_share, amount| RemainderPolicy::CashInLieu {
+            portfolio: None,
+            currency,
+            per_share,
+            amount,
+        };
+        assert_noop!(
+            Reorg::reorganize(owner.origin(), id, split(2, 1), cash(0, 1), REORG_DEADLINE),
+            ReorgError::InvalidCashInLieu
+        );
+        assert_noop!(
+            Reorg::reorganize(owner.origin(), id, split(2, 1), cash(1, 0), REORG_DEADLINE),
+            ReorgError::InvalidCashInLieu
+        );
+
+        // The asset is frozen, so it can't be paid in lieu of fractions.
+        assert_noop!(
+            Reorg::reorganize(
+                owner.origin(),
+                id,
+                split(2, 1),
+                RemainderPolicy::CashInLieu {
+                    portfolio: None,
+                    currency: asset_id,
+                    per_share: 1,
+                    amount: 1,
+                },
+                REORG_DEADLINE
+            ),
+            ReorgError::InvalidCashInLieu
+        );
+
+        // Only one reorganization per CA.
+        assert_ok!(reorganize(id, split(2, 1)));
+        assert_noop!(reorganize(id, split(3, 1)), ReorgError::AlreadyExists);
+
+        // The asset is already frozen by the first reorganization.
+        let frozen_id = reorg_ca(owner, asset_id);
+        assert_noop!(
+            reorganize(frozen_id, split(2, 1)),
+            ReorgError::TransfersAlreadyFrozen
+        );
+
+        // The record date can't be in the past.
+        assert_ok!(CA::remove_ca(owner.origin(), id));
+        set_timestamp(2);
+        assert_noop!(
+            reorganize(frozen_id, split(2, 1)),
+            ReorgError::RecordDatePassed
+        );
+    });
+}
+
+#[test]
+fn apply_reorganization_split_works() {
+    test(|asset_id, [owner, foo, bar]| {
+        set_schedule_complexity();
+        transfer(&asset_id, owner, foo);
+        transfer_amount(&asset_id, owner, bar, AMOUNT * 2);
+
+        let id = reorg_ca(owner, asset_id);
+        assert_ok!(Reorg::reorganize(
+            owner.origin(),
+            id,
+            split(3, 2),
+            RemainderPolicy::RoundDown,
+            REORG_DEADLINE
+        ));
+        let apply = |holder: User| apply_reorganization(owner, id, holder);
+
+        // Holdings can't move from the record date on.
+        set_timestamp(2);
+        assert_noop!(
+            crate::asset_test::transfer(asset_id, foo, bar, 1),
+            AssetError::TransfersFrozen
+        );
+
+        // Forward split of `foo`, which can't be applied twice.
+        assert_ok!(apply(foo));
+        assert_eq!(Asset::balance_of(&asset_id, foo.did), AMOUNT * 3 / 2);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            apply(foo),
+            ReorgError::PortfolioAlreadyReorganized
+        ));
+        assert_eq!(Reorg::reorganizations(id).unwrap().portfolios, 1);
+
+        // Once started, neither the record date nor the CA can change.
+        assert_noop!(
+            CA::change_record_date(owner.origin(), id, None),
+            ReorgError::ReorganizationStarted
+        );
+        assert_noop!(
+            CA::remove_ca(owner.origin(), id),
+            ReorgError::ReorganizationStarted
+        );
+
+        // Holdings of `bar` are reorganized too, and the supply follows.
+        assert_ok!(apply(bar));
+        assert_eq!(Asset::balance_of(&asset_id, bar.did), AMOUNT * 3);
+        assert_eq!(
+            Assets::get(&asset_id).unwrap().total_supply,
+            ISSUE_AMOUNT + AMOUNT * 3 / 2
+        );
+
+        // No more holdings are reorganized after closing, which unfreezes the asset.
+        assert_ok!(Reorg::close_reorganization(owner.origin(), id));
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            apply(owner),
+            ReorgError::ReorganizationClosed
+        ));
+        transfer_amount(&asset_id, foo, bar, 1);
+    });
+}
+
+#[test]
+fn apply_reorganization_batches_until_deadline() {
+    test(|asset_id, [owner, foo, bar]| {
+        set_schedule_complexity();
+        transfer(&asset_id, owner, foo);
+        transfer(&asset_id, owner, bar);
+
+        // The deadline must be after the record date, by at most `MaxReorganizationPeriod`.
+        let id = reorg_ca(owner, asset_id);
+        let reorganize = |deadline| {
+            Reorg::reorganize(
+                owner.origin(),
+                id,
+                split(2, 1),
+                RemainderPolicy::RoundDown,
+                deadline,
+            )
+        };
+        assert_noop!(reorganize(1), ReorgError::InvalidDeadline);
+        assert_noop!(
+            reorganize(2 + MaxReorganizationPeriod::get()),
+            ReorgError::InvalidDeadline
+        );
+        assert_ok!(reorganize(REORG_DEADLINE));
+
+        // Portfolios are reorganized in bounded batches.
+        set_timestamp(2);
+        let portfolio = |user: User| PortfolioId::default_portfolio(user.did);
+        let batch = vec![portfolio(foo); MaxReorganizationBatch::get() as usize + 1];
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Reorg::apply_reorganization(owner.origin(), id, batch, 0, None),
+            ReorgError::TooManyPortfolios
+        ));
+        assert_ok!(Reorg::apply_reorganization(
+            owner.origin(),
+            id,
+            vec![portfolio(foo), portfolio(bar)],
+            0,
+            None
+        ));
+        assert_eq!(Asset::balance_of(&asset_id, foo.did), AMOUNT * 2);
+        assert_eq!(Asset::balance_of(&asset_id, bar.did), AMOUNT * 2);
+        assert_eq!(Reorg::reorganizations(id).unwrap().portfolios, 2);
+
+        // The asset is unfrozen at the deadline, after which holdings are left as they are.
+        set_timestamp(REORG_DEADLINE);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            apply_reorganization(owner, id, owner),
+            ReorgError::DeadlinePassed
+        ));
+        transfer_amount(&asset_id, foo, bar, 1);
+    });
+}
+
+#[test]
+fn apply_reorganization_rescales_portfolio() {
+    test(|asset_id, [owner, foo, _]| {
+        set_schedule_complexity();
+        let lots_stat = StatType {
+            operation_type: StatOpType::AcquisitionLots,
+            claim_issuer: None,
+        };
+        assert_ok!(Statistics::set_active_asset_stats(
+            owner.origin(),
+            asset_id,
+            [lots_stat].into_iter().collect()
+        ));
+
+        // `foo` holds 1_200 units, 300 of them locked and 200 vesting, and moves 100 units to another portfolio.
+        let portfolio = PortfolioId::default_portfolio(foo.did);
+        let user_portfolio = PortfolioId::user_portfolio(foo.did, PortfolioNumber(1));
+        transfer_amount(&asset_id, owner, foo, 1_000);
+        assert_ok!(Portfolio::lock_portfolio_assets(
+            foo.origin(),
+            portfolio,
+            asset_id,
+            300,
+            PortfolioLockReason::Collateral,
+            None,
+            None
+        ));
+        assert_ok!(Asset::add_vesting_schedule(
+            owner.origin(),
+            asset_id,
+            PortfolioKind::Default,
+            portfolio,
+            200,
+            0,
+            0,
+            1_000_000,
+            VestingRelease::Automatic,
+            None
+        ));
+        assert_ok!(Portfolio::create_portfolio(
+            foo.origin(),
+            PortfolioName(b"Reorg".to_vec())
+        ));
+        let funds = vec![Fund {
+            description: FundDescription::Fungible {
+                asset_id,
+                amount: 100,
+            },
+            memo: None,
+        }];
+        assert_ok!(Portfolio::move_portfolio_funds(
+            foo.origin(),
+            portfolio,
+            user_portfolio,
+            funds.clone()
+        ));
+
+        let id = reorg_ca(owner, asset_id);
+        assert_ok!(Reorg::reorganize(
+            owner.origin(),
+            id,
+            split(3, 2),
+            RemainderPolicy::RoundDown,
+            REORG_DEADLINE
+        ));
+        set_timestamp(2);
+
+        // Units can't be moved between portfolios while the reorganization is applied.
+        assert_noop!(
+            Portfolio::move_portfolio_funds(foo.origin(), portfolio, user_portfolio, funds),
+            AssetError::TransfersFrozen
+        );
+
+        // Every portfolio lock must be accounted for.
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Reorg::apply_reorganization(owner.origin(), id, vec![portfolio], 0, None),
+            PError::TooManyPortfolioLocks
+        ));
+        assert_ok!(Reorg::apply_reorganization(
+            owner.origin(),
+            id,
+            vec![portfolio],
+            1,
+            None
+        ));
+
+        // The lock, the vesting schedule and the acquisition lots are rescaled in place.
+        assert_eq!(
+            Portfolio::portfolio_asset_balances(portfolio, asset_id),
+            1_650
+        );
+        assert_eq!(Portfolio::locked_by_locks(portfolio, asset_id), 450);
+        let locks = Portfolio::portfolio_locks(&portfolio);
+        assert_eq!(locks.len(), 1);
+        assert_eq!(locks[0].1.amount, 450);
+        assert_eq!(Asset::vesting_schedules(portfolio, asset_id)[0].amount, 300);
+        assert_eq!(
+            Statistics::acquisition_lots(asset_id, &portfolio).lots(),
+            &[(1, 1_650)]
+        );
+
+        // The other portfolio is reorganized separately.
+        assert_eq!(Asset::balance_of(&asset_id, foo.did), 1_750);
+        assert_ok!(Reorg::apply_reorganization(
+            owner.origin(),
+            id,
+            vec![user_portfolio],
+            0,
+            None
+        ));
+        assert_eq!(
+            Portfolio::portfolio_asset_balances(user_portfolio, asset_id),
+            150
+        );
+        assert_eq!(Asset::balance_of(&asset_id, foo.did), 1_800);
+    });
+}
+
+#[test]
+fn apply_reorganization_reverse_split_cash_in_lieu() {
+    currency_test(|_, currency, [owner, foo, _]| {
+        // An indivisible asset, where fractions of a unit must be paid in cash.
+        let asset_id = create_asset(&owner, None, Some(false), None, None, None, true, None);
+        transfer_amount(&asset_id, owner, foo, 5 * ONE_UNIT);
+
+        let id = reorg_ca(owner, asset_id);
+        let owner_portfolio = PortfolioId::default_portfolio(owner.did);
+        let amount = 10 * ONE_UNIT;
+        assert_ok!(Reorg::reorganize(
+            owner.origin(),
+            id,
+            split(1, 3),
+            RemainderPolicy::CashInLieu {
+                portfolio: None,
+                currency,
+                per_share: 3 * PER_SHARE_PRECISION,
+                amount,
+            },
+            REORG_DEADLINE
+        ));
+        assert_eq!(Portfolio::locked_assets(owner_portfolio, currency), amount);
+
+        set_timestamp(2);
+        assert_ok!(apply_reorganization(owner, id, foo));
+
+        // 5 units become 1 unit, with 2/3 of a unit paid at a price of 3.
+        let cash = (5 * ONE_UNIT / 3 - ONE_UNIT) * 3;
+        assert_eq!(Asset::balance_of(&asset_id, foo.did), ONE_UNIT);
+        assert_eq!(Asset::balance_of(&currency, foo.did), cash);
+        let reorg = Reorg::reorganizations(id).unwrap();
+        assert_eq!(reorg.cash_in_lieu.unwrap().remaining, amount - cash);
+        assert_eq!(
+            Portfolio::locked_assets(owner_portfolio, currency),
+            amount - cash
+        );
+
+        // Closing unlocks the remaining cash.
+        assert_ok!(Reorg::close_reorganization(owner.origin(), id));
+        assert_eq!(Portfolio::locked_assets(owner_portfolio, currency), 0);
+        assert_noop!(
+            Reorg::close_reorganization(owner.origin(), id),
+            ReorgError::ReorganizationClosed
+        );
+    });
+}
+
+#[test]
+fn apply_reorganization_conversion_works() {
+    currency_test(|asset_id, currency, [owner, foo, _]| {
+        transfer(&asset_id, owner, foo);
+
+        let id = reorg_ca(owner, asset_id);
+        let ratio = ReorganizationRatio { new: 1, old: 2 };
+        assert_ok!(Reorg::reorganize(
+            owner.origin(),
+            id,
+            ReorganizationKind::Conversion(currency, ratio),
+            RemainderPolicy::RoundDown,
+            REORG_DEADLINE
+        ));
+
+        set_timestamp(2);
+
+        // The converted units must comply with the rules of the other asset.
+        assert_ok!(Asset::freeze(owner.origin(), currency));
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            apply_reorganization(owner, id, foo),
+            AssetError::InvalidTransferFrozenAsset
+        ));
+        assert_ok!(Asset::unfreeze(owner.origin(), currency));
+        assert_ok!(apply_reorganization(owner, id, foo));
+
+        // The holdings of `foo` were redeemed and replaced by the other asset.
+        assert_eq!(Asset::balance_of(&asset_id, foo.did), 0);
+        assert_eq!(Asset::balance_of(&currency, foo.did), AMOUNT / 2);
+        assert_eq!(
+            Assets::get(&currency).unwrap().total_supply,
+            ISSUE_AMOUNT + AMOUNT / 2
+        );
+
+        // Removing the CA is no longer possible.
+        assert_noop!(
+            CA::remove_ca(owner.origin(), id),
+            ReorgError::ReorganizationStarted
+        );
+    });
+}
+
+#[test]
+fn remove_ca_with_reorganization_unlocks_cash() {
+    currency_test(|asset_id, currency, [owner, ..]| {
+        let id = reorg_ca(owner, asset_id);
+        let owner_portfolio = PortfolioId::default_portfolio(owner.did);
+        assert_ok!(Reorg::reorganize(
+            owner.origin(),
+            id,
+            split(2, 1),
+            RemainderPolicy::CashInLieu {
+                portfolio: None,
+                currency,
+                per_share: PER_SHARE_PRECISION,
+                amount: ONE_UNIT,
+            },
+            REORG_DEADLINE
+        ));
+        assert_eq!(
+            Portfolio::locked_assets(owner_portfolio, currency),
+            ONE_UNIT
+        );
+
+        assert_eq!(Asset::transfers_frozen(asset_id), Some((1, REORG_DEADLINE)));
+
+        assert_ok!(CA::remove_ca(owner.origin(), id));
+        assert_eq!(Reorganizations::get(id), None);
+        assert_eq!(Portfolio::locked_assets(owner_portfolio, currency), 0);
+        assert_eq!(Asset::transfers_frozen(asset_id), None);
+    });
+}
+
+fn bond(owner: User) -> AssetId {
+    create_asset(
+        &owner,
+        Some(b"Bond"),
+        None,
+        Some(AssetType::FixedIncome),
+        None,
+        None,
+        true,
+        None,
+    )
+}
+
+/// Terms with a coupon every second from 1s on, paid half a second after each record date.
+fn bond_terms(currency: AssetId, coupons: u32, maturity: Moment) -> FixedIncomeTerms {
+    FixedIncomeTerms {
+        portfolio: None,
+        currency,
+        coupon_schedule: CheckpointSchedule {
+            start: 1000,
+            period: CalendarPeriod {
+                unit: CalendarUnit::Second,
+                amount: 1,
+            },
+        },
+        coupons,
+        coupon_per_share: PER_SHARE_PRECISION / 10,
+        coupon_payment_delay: 500,
+        coupon_claim_period: None,
+        maturity,
+        principal_per_share: PER_SHARE_PRECISION,
+    }
+}
+
+#[test]
+fn create_fixed_income_bad() {
+    currency_test(|asset_id, currency, [owner, other, _]| {
+        let bond = bond(owner);
+        let create = |terms| FixedIncome::create_fixed_income(owner.origin(), bond, terms);
+        let terms = bond_terms(currency, 2, 3000);
+
+        // Not an agent.
+        assert_noop!(
+            FixedIncome::create_fixed_income(other.origin(), bond, terms),
+            EAError::UnauthorizedAgent
+        );
+
+        // Only fixed income assets.
+        assert_noop!(
+            FixedIncome::create_fixed_income(owner.origin(), asset_id, terms),
+            FIError::NotFixedIncomeAsset
+        );
+
+        // Nonsensical terms.
+        assert_noop!(
+            create(FixedIncomeTerms {
+                maturity: 1,
+                ..terms
+            }),
+            FIError::MaturityInPast
+        );
+        assert_noop!(
+            create(FixedIncomeTerms {
+                principal_per_share: 0,
+                ..terms
+            }),
+            FIError::InvalidPrincipal
+        );
+        assert_noop!(
+            create(FixedIncomeTerms {
+                coupon_per_share: 0,
+                ..terms
+            }),
+            DistError::DistributionPerShareIsZero
+        );
+
+        // Coupons must fall before maturity, and a one-shot schedule yields a single coupon.
+        assert_noop!(
+            create(bond_terms(currency, 3, 2500)),
+            FIError::InvalidCouponSchedule
+        );
+        let mut one_shot = terms;
+        one_shot.coupon_schedule.period = CalendarPeriod::default();
+        assert_noop!(create(one_shot), FIError::InvalidCouponSchedule);
+        assert_noop!(
+            create(FixedIncomeTerms {
+                coupons: fixed_income::MAX_COUPONS + 1,
+                ..terms
+            }),
+            FIError::TooManyCoupons
+        );
+
+        // Only one set of terms per asset.
+        assert_ok!(create(terms));
+        assert_eq!(
+            Checkpoint::schedule_ref_count(
+                bond,
+                FixedIncome::fixed_incomes(bond)
+                    .unwrap()
+                    .schedule_id
+                    .unwrap()
+            ),
+            1
+        );
+        assert_noop!(create(terms), FIError::AlreadyExists);
+    });
+}
+
+#[test]
+fn fixed_income_lifecycle_works() {
+    currency_test(|_, currency, [owner, foo, _]| {
+        let bond = bond(owner);
+        transfer(&bond, owner, foo);
+
+        // Enough currency for coupons and principal.
+        assert_ok!(Asset::issue(
+            owner.origin(),
+            currency,
+            ISSUE_AMOUNT,
+            PortfolioKind::Default
+        ));
+        let terms = bond_terms(currency, 2, 3000);
+        assert_ok!(FixedIncome::create_fixed_income(
+            owner.origin(),
+            bond,
+            terms
+        ));
+
+        // Coupons are distributed by anyone, once due.
+        assert_noop!(
+            FixedIncome::distribute_coupon(foo.origin(), bond),
+            FIError::CouponNotDue
+        );
+        set_timestamp(1500);
+        transfer(&bond, owner, foo);
+        assert_ok!(FixedIncome::distribute_coupon(foo.origin(), bond));
+        let coupon = Coupons::get(bond, 0).unwrap();
+        let dist = Dist::distributions(coupon).unwrap();
+        assert_eq!(dist.amount, ISSUE_AMOUNT / 10);
+        assert_eq!(dist.payment_at, 1500);
+
+        // `foo` is paid for the units held at the first record date only.
+        assert_ok!(Dist::claim(foo.origin(), coupon));
+        assert_eq!(Asset::balance_of(&currency, foo.did), AMOUNT / 10);
+
+        // Maturity requires the date to pass and all coupons to be distributed.
+        assert_noop!(FixedIncome::mature(foo.origin(), bond), FIError::NotMatured);
+        set_timestamp(3000);
+        assert_noop!(
+            FixedIncome::mature(foo.origin(), bond),
+            FIError::CouponsPending
+        );
+        assert_noop!(
+            FixedIncome::redeem(foo.origin(), bond, PortfolioKind::Default),
+            FIError::NotMatured
+        );
+        assert_ok!(FixedIncome::distribute_coupon(foo.origin(), bond));
+        assert!(Coupons::get(bond, 1).is_some());
+        assert_noop!(
+            FixedIncome::distribute_coupon(foo.origin(), bond),
+            FIError::AllCouponsDistributed
+        );
+
+        // Maturing locks the principal for all outstanding units.
+        let owner_portfolio = PortfolioId::default_portfolio(owner.did);
+        let locked = Portfolio::locked_assets(owner_portfolio, currency);
+        assert_ok!(FixedIncome::mature(foo.origin(), bond));
+        assert_eq!(
+            Portfolio::locked_assets(owner_portfolio, currency),
+            locked + ISSUE_AMOUNT
+        );
+        assert_noop!(
+            FixedIncome::mature(foo.origin(), bond),
+            FIError::AlreadyMatured
+        );
+
+        // `foo` redeems its units for their principal.
+        assert_ok!(FixedIncome::redeem(
+            foo.origin(),
+            bond,
+            PortfolioKind::Default
+        ));
+        assert_eq!(Asset::balance_of(&bond, foo.did), 0);
+        assert_eq!(
+            Asset::balance_of(&currency, foo.did),
+            AMOUNT / 10 + AMOUNT * 2
+        );
+        assert_noop!(
+            FixedIncome::redeem(foo.origin(), bond, PortfolioKind::Default),
+            FIError::NothingToRedeem
+        );
+
+        // Units must all be redeemed before closing.
+        assert_noop!(
+            FixedIncome::close_fixed_income(owner.origin(), bond),
+            FIError::UnitsOutstanding
+        );
+        assert_noop!(
+            FixedIncome::push_redemption(foo.origin(), bond, owner_portfolio, 0),
+            EAError::UnauthorizedAgent
+        );
+        assert_ok!(FixedIncome::push_redemption(
+            owner.origin(),
+            bond,
+            owner_portfolio,
+            0
+        ));
+        assert_eq!(Asset::total_supply(&bond), 0);
+
+        // Closing unlocks what remains of the principal.
+        assert_ok!(FixedIncome::close_fixed_income(owner.origin(), bond));
+        assert_eq!(Portfolio::locked_assets(owner_portfolio, currency), locked);
+        let redemption = FixedIncomes::get(bond).unwrap().redemption.unwrap();
+        assert_eq!(redemption.remaining, 0);
+        assert_noop!(
+            FixedIncome::close_fixed_income(owner.origin(), bond),
+            FIError::FixedIncomeClosed
+        );
+    });
+}
+
+#[test]
+fn fixed_income_redeems_locked_units_in_any_portfolio() {
+    currency_test(|_, currency, [owner, foo, _]| {
+        let bond = bond(owner);
+        assert_ok!(Asset::issue(
+            owner.origin(),
+            currency,
+            ISSUE_AMOUNT,
+            PortfolioKind::Default
+        ));
+        assert_ok!(FixedIncome::create_fixed_income(
+            owner.origin(),
+            bond,
+            bond_terms(currency, 0, 3000)
+        ));
+
+        // `foo` holds 500 units and moves 300 of them to another portfolio,
+        // where 100 of them are locked, and which receives 100 more vesting units.
+        transfer(&bond, owner, foo);
+        let kind = PortfolioKind::User(PortfolioNumber(1));
+        let user_portfolio = PortfolioId::user_portfolio(foo.did, PortfolioNumber(1));
+        assert_ok!(Portfolio::create_portfolio(
+            foo.origin(),
+            PortfolioName(b"Bonds".to_vec())
+        ));
+        assert_ok!(Portfolio::move_portfolio_funds(
+            foo.origin(),
+            PortfolioId::default_portfolio(foo.did),
+            user_portfolio,
+            vec![Fund {
+                description: FundDescription::Fungible {
+                    asset_id: bond,
+                    amount: 300,
+                },
+                memo: None,
+            }]
+        ));
+        assert_ok!(Portfolio::lock_portfolio_assets(
+            foo.origin(),
+            user_portfolio,
+            bond,
+            100,
+            PortfolioLockReason::Collateral,
+            None,
+            None
+        ));
+        assert_ok!(Asset::add_vesting_schedule(
+            owner.origin(),
+            bond,
+            PortfolioKind::Default,
+            user_portfolio,
+            100,
+            0,
+            0,
+            1_000_000,
+            VestingRelease::Automatic,
+            None
+        ));
+        set_timestamp(3000);
+        assert_ok!(FixedIncome::mature(foo.origin(), bond));
+
+        // `foo` redeems the free units of each of its portfolios.
+        assert_ok!(FixedIncome::redeem(
+            foo.origin(),
+            bond,
+            PortfolioKind::Default
+        ));
+        assert_ok!(FixedIncome::redeem(foo.origin(), bond, kind));
+        assert_eq!(
+            Portfolio::portfolio_asset_balances(user_portfolio, bond),
+            200
+        );
+        assert_eq!(Asset::balance_of(&currency, foo.did), AMOUNT - 100);
+        assert_noop!(
+            FixedIncome::redeem(foo.origin(), bond, kind),
+            FIError::NothingToRedeem
+        );
+
+        // The agent redeems the locked and vesting units, releasing the lock and the schedule.
+        assert_noop!(
+            FixedIncome::push_redemption(owner.origin(), bond, user_portfolio, 0),
+            PError::TooManyPortfolioLocks
+        );
+        assert_ok!(FixedIncome::push_redemption(
+            owner.origin(),
+            bond,
+            user_portfolio,
+            1
+        ));
+        assert_eq!(Portfolio::portfolio_asset_balances(user_portfolio, bond), 0);
+        assert_eq!(Portfolio::locked_by_locks(user_portfolio, bond), 0);
+        assert!(Portfolio::portfolio_locks(&user_portfolio).is_empty());
+        assert_eq!(Asset::unvested_balance(&user_portfolio, &bond), 0);
+        assert_eq!(Asset::balance_of(&currency, foo.did), AMOUNT + 100);
+
+        // Once the issuer's units are redeemed too, the asset can be closed.
+        assert_noop!(
+            FixedIncome::close_fixed_income(owner.origin(), bond),
+            FIError::UnitsOutstanding
+        );
+        assert_ok!(FixedIncome::push_redemption(
+            owner.origin(),
+            bond,
+            PortfolioId::default_portfolio(owner.did),
+            0
+        ));
+        assert_ok!(FixedIncome::close_fixed_income(owner.origin(), bond));
+    });
+}
+
+#[test]
+fn fixed_income_default_coupon_works() {
+    currency_test(|_, currency, [owner, foo, _]| {
+        let bond = bond(owner);
+        let terms = bond_terms(currency, 1, 3000);
+        assert_ok!(FixedIncome::create_fixed_income(
+            owner.origin(),
+            bond,
+            terms
+        ));
+        let default = || FixedIncome::default_coupon(foo.origin(), bond);
+
+        // Coupons can only be defaulted after their payment date.
+        assert_noop!(default(), FIError::CouponNotDue);
+        set_timestamp(1200);
+        assert_noop!(default(), FIError::CouponPaymentNotDue);
+
+        // Nor while the funding portfolio can cover them.
+        set_timestamp(1500);
+        assert_noop!(default(), FIError::CouponFunded);
+
+        // Once it can't, the coupon is defaulted, which lets the asset mature.
+        transfer_amount(&currency, owner, foo, ISSUE_AMOUNT - 1);
+        assert_ok!(default());
+        assert_eq!(
+            FixedIncome::defaulted_coupons(bond, 0),
+            Some(ISSUE_AMOUNT / 10)
+        );
+        assert_eq!(Coupons::get(bond, 0), None);
+        assert_noop!(default(), FIError::AllCouponsDistributed);
+
+        // Maturing closes the issuance, as the principal of new units wouldn't be locked.
+        transfer_amount(&currency, foo, owner, ISSUE_AMOUNT - 1);
+        set_timestamp(3000);
+        assert_ok!(FixedIncome::mature(foo.origin(), bond));
+        assert!(Asset::issuance_closed(bond));
+        assert_noop!(
+            Asset::issue(owner.origin(), bond, ISSUE_AMOUNT, PortfolioKind::Default),
+            AssetError::IssuanceClosed
+        );
+    });
+}
@@ -3,14 +3,17 @@ use super::{
     storage::{get_last_auth_id, make_account_without_cdd, register_keyring_account, TestStorage},
     ExtBuilder,
 };
+use codec::Encode;
 use frame_support::assert_noop;
 use pallet_balances as balances;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
 use pallet_test_utils as test_utils;
+use pallet_utility::{self as utility, SponsorEnvelope};
 use polymesh_common_utilities::traits::transaction_payment::CddAndFeeDetails;
-use polymesh_primitives::{Signatory, TransactionError};
+use polymesh_primitives::{AccountId, Signatory, TransactionError};
 use polymesh_runtime_develop::runtime::{CddHandler, RuntimeCall};
+use sp_io::hashing::blake2_256;
 use sp_keyring::AccountKeyring;
 use sp_runtime::transaction_validity::InvalidTransaction;
 
@@ -210,3 +213,66 @@ fn cdd_checks() {
             );
         });
 }
+
+fn sponsored_call(
+    sponsor: AccountKeyring,
+    signer: AccountKeyring,
+    user: &AccountId,
+) -> RuntimeCall {
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    let envelope = SponsorEnvelope {
+        sponsor: sponsor.to_account_id(),
+        user: user.clone(),
+        call_hash: blake2_256(&call.encode()),
+        max_fee: 1_000,
+        expiry: 10,
+    };
+    RuntimeCall::Utility(utility::Call::sponsored_call {
+        signature: signer.sign(&envelope.encode()).into(),
+        envelope,
+        call: Box::new(call),
+    })
+}
+
+#[test]
+fn sponsored_call_payer() {
+    ExtBuilder::default()
+        .cdd_providers(vec![AccountKeyring::Bob.to_account_id()])
+        .monied(true)
+        .build()
+        .execute_with(|| {
+            // alice does not have an identity, charlie has valid cdd.
+            let alice_account = AccountKeyring::Alice.to_account_id();
+            let _ = register_keyring_account(AccountKeyring::Charlie).unwrap();
+
+            // The sponsor pays, up to the max fee of its envelope.
+            let call = sponsored_call(
+                AccountKeyring::Charlie,
+                AccountKeyring::Charlie,
+                &alice_account,
+            );
+            assert_eq!(
+                CddHandler::get_valid_payer(&call, &alice_account),
+                Ok(Some(AccountKeyring::Charlie.to_account_id()))
+            );
+            assert_eq!(CddHandler::get_max_fee(&call), Some(1_000));
+
+            // The envelope must be signed by the sponsor.
+            let call = sponsored_call(
+                AccountKeyring::Charlie,
+                AccountKeyring::Alice,
+                &alice_account,
+            );
+            assert_noop!(
+                CddHandler::get_valid_payer(&call, &alice_account),
+                InvalidTransaction::Custom(TransactionError::InvalidSponsorship as u8)
+            );
+
+            // The sponsor must have an identity.
+            let call = sponsored_call(AccountKeyring::Dave, AccountKeyring::Dave, &alice_account);
+            assert_noop!(
+                CddHandler::get_valid_payer(&call, &alice_account),
+                InvalidTransaction::Custom(TransactionError::MissingIdentity as u8)
+            );
+        });
+}
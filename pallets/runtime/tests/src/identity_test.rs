@@ -18,7 +18,9 @@ use frame_support::{
     StorageMap, StorageValue,
 };
 use pallet_balances as balances;
-use pallet_identity::{ChildDid, CustomClaimIdSequence, CustomClaims, CustomClaimsInverse};
+use pallet_identity::{
+    ChildDid, CustomClaimIdSequence, CustomClaimValueTypes, CustomClaims, CustomClaimsInverse,
+};
 use polymesh_common_utilities::{
     constants::currency::POLY,
     traits::{
@@ -34,9 +36,9 @@ use polymesh_common_utilities::{
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::{
     AccountId, AssetPermissions, AuthorizationData, AuthorizationType, Claim, ClaimType,
-    CustomClaimTypeId, ExtrinsicName, ExtrinsicPermissions, IdentityClaim, IdentityId, KeyRecord,
-    PalletName, PalletPermissions, Permissions, PortfolioId, PortfolioNumber, Scope, SecondaryKey,
-    Signatory, SubsetRestriction, Ticker, TransactionError,
+    ClaimValue, CustomClaimTypeId, CustomClaimValueType, ExtrinsicName, ExtrinsicPermissions,
+    IdentityClaim, IdentityId, KeyRecord, PalletName, PalletPermissions, Permissions, PortfolioId,
+    PortfolioNumber, Scope, SecondaryKey, Signatory, SubsetRestriction, Ticker, TransactionError,
 };
 use polymesh_runtime_develop::runtime::{CddHandler, RuntimeCall};
 use sp_core::H512;
@@ -1969,6 +1971,86 @@ fn invalid_custom_claim_type() {
     });
 }
 
+#[test]
+fn custom_claim_value_type_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let register = |ty: &str, value_type| {
+            Identity::register_custom_claim_type_with_value(alice.origin(), ty.into(), value_type)
+        };
+        let tier = |tier: &str| tier.as_bytes().to_vec();
+
+        // Enum value types need distinct values.
+        assert_noop!(
+            register("tier", CustomClaimValueType::Enum(vec![])),
+            Error::InvalidCustomClaimValueType
+        );
+        assert_noop!(
+            register(
+                "tier",
+                CustomClaimValueType::Enum(vec![tier("A"), tier("A")])
+            ),
+            Error::InvalidCustomClaimValueType
+        );
+        let tiers = CustomClaimValueType::Enum(vec![tier("A"), tier("B")]);
+        assert_ok!(register("tier", tiers.clone()));
+        assert_ok!(register("net worth", CustomClaimValueType::Decimal));
+        assert_ok!(Identity::register_custom_claim_type(
+            alice.origin(),
+            "plain".into()
+        ));
+        let (tier_id, net_worth_id, plain_id) = (
+            CustomClaimTypeId(1),
+            CustomClaimTypeId(2),
+            CustomClaimTypeId(3),
+        );
+        assert_eq!(CustomClaimValueTypes::get(tier_id), Some(tiers));
+        assert_eq!(CustomClaimValueTypes::get(plain_id), None);
+
+        // Values must match the value type of the claim type.
+        let add = |id, value| {
+            Identity::base_add_claim(
+                alice.did,
+                Claim::CustomValue(id, None, value),
+                alice.did,
+                None,
+            )
+        };
+        assert_noop!(
+            add(tier_id, ClaimValue::Enum(tier("C"))),
+            Error::InvalidClaimValue
+        );
+        assert_noop!(
+            add(net_worth_id, ClaimValue::Integer(1)),
+            Error::InvalidClaimValue
+        );
+        assert_noop!(
+            add(plain_id, ClaimValue::Integer(1)),
+            Error::InvalidClaimValue
+        );
+        assert_ok!(add(tier_id, ClaimValue::Enum(tier("B"))));
+        assert_ok!(add(net_worth_id, ClaimValue::Decimal(1_500_000)));
+
+        // Claims of a claim type with a value type must carry a value.
+        assert_noop!(
+            Identity::base_add_claim(alice.did, Claim::Custom(tier_id, None), alice.did, None),
+            Error::InvalidClaimValue
+        );
+        assert_ok!(Identity::base_add_claim(
+            alice.did,
+            Claim::Custom(plain_id, None),
+            alice.did,
+            None
+        ));
+
+        // The stored claim carries its value.
+        let claim = Identity::fetch_claim(alice.did, ClaimType::Custom(tier_id), alice.did, None)
+            .unwrap()
+            .claim;
+        assert_eq!(claim.value(), Some(&ClaimValue::Enum(tier("B"))));
+    });
+}
+
 #[test]
 fn cdd_register_did_events() {
     ExtBuilder::default()
@@ -1,14 +1,18 @@
+use codec::Encode;
 use frame_support::{
     assert_err, assert_err_ignore_postinfo, assert_noop, assert_ok, assert_storage_noop,
     dispatch::DispatchResult, BoundedVec,
 };
 
 use pallet_multisig::{
-    self as multisig, AdminDid, LastInvalidProposal, ProposalStates, ProposalVoteCounts, Votes,
+    self as multisig, AdminDid, LastInvalidProposal, ProposalStates, ProposalVoteCounts,
+    SignerWeights, Votes,
 };
 use polymesh_common_utilities::constants::currency::POLY;
-use polymesh_primitives::multisig::ProposalState;
-use polymesh_primitives::{AccountId, AuthorizationData, Permissions, SecondaryKey, Signatory};
+use polymesh_primitives::multisig::{CallPolicy, ProposalState};
+use polymesh_primitives::{
+    AccountId, AuthorizationData, ExtrinsicName, PalletName, Permissions, SecondaryKey, Signatory,
+};
 use sp_keyring::AccountKeyring;
 
 use super::asset_test::set_timestamp;
@@ -1722,3 +1726,642 @@ fn setup_multisig(
     }
     ms_address
 }
+
+#[test]
+fn weighted_signers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_signer = AccountKeyring::Bob.to_account_id();
+        let charlie = Origin::signed(AccountKeyring::Charlie.to_account_id());
+        let charlie_signer = AccountKeyring::Charlie.to_account_id();
+        let dave = Origin::signed(AccountKeyring::Dave.to_account_id());
+        let dave_signer = AccountKeyring::Dave.to_account_id();
+        let eve_signer = AccountKeyring::Eve.to_account_id();
+
+        let ms_address = setup_multisig(
+            alice.acc(),
+            3,
+            create_signers(vec![
+                charlie_signer.clone(),
+                dave_signer.clone(),
+                eve_signer.clone(),
+            ]),
+        );
+        let ms_origin = Origin::signed(ms_address.clone());
+
+        assert_noop!(
+            MultiSig::set_signer_weights(
+                ms_origin.clone(),
+                vec![(charlie_signer.clone(), 0)].try_into().unwrap()
+            ),
+            Error::InvalidSignerWeight
+        );
+        assert_noop!(
+            MultiSig::set_signer_weights(
+                ms_origin.clone(),
+                vec![(bob_signer, 2)].try_into().unwrap()
+            ),
+            Error::NotASigner
+        );
+        assert_ok!(MultiSig::set_signer_weights(
+            ms_origin.clone(),
+            vec![(charlie_signer.clone(), 2)].try_into().unwrap()
+        ));
+        assert_eq!(
+            SignerWeights::<TestStorage>::get(&ms_address, &charlie_signer),
+            Some(2)
+        );
+        assert_eq!(MultiSig::total_signer_weight(&ms_address), 4);
+
+        // Removing charlie would leave a total weight of 2, which is less than the 3 required signatures.
+        assert_noop!(
+            MultiSig::remove_multisig_signers(
+                ms_origin.clone(),
+                create_signers(vec![charlie_signer.clone()])
+            ),
+            Error::NotEnoughSigners
+        );
+
+        // Charlie's vote counts twice.
+        let call = Box::new(RuntimeCall::System(frame_system::Call::remark {
+            remark: vec![],
+        }));
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            charlie.clone(),
+            ms_address.clone(),
+            call,
+            None,
+        ));
+        let vote_count = ProposalVoteCounts::<TestStorage>::get(&ms_address, proposal_id).unwrap();
+        assert_eq!(vote_count.approvals, 2);
+        assert_eq!(vote_count.sigs_required, 3);
+        assert_ok!(MultiSig::approve(
+            dave.clone(),
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::ExecutionSuccessful)
+        );
+
+        // Setting the weight back to one removes the entry.
+        assert_ok!(MultiSig::set_signer_weights(
+            ms_origin,
+            vec![(charlie_signer.clone(), 1)].try_into().unwrap()
+        ));
+        assert_eq!(
+            SignerWeights::<TestStorage>::get(&ms_address, &charlie_signer),
+            None
+        );
+        assert_eq!(MultiSig::total_signer_weight(&ms_address), 3);
+    });
+}
+
+#[test]
+fn call_policies() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = Origin::signed(AccountKeyring::Charlie.to_account_id());
+        let charlie_signer = AccountKeyring::Charlie.to_account_id();
+        let dave = Origin::signed(AccountKeyring::Dave.to_account_id());
+        let dave_signer = AccountKeyring::Dave.to_account_id();
+
+        let ms_address = setup_multisig(
+            alice.acc(),
+            1,
+            create_signers(vec![charlie_signer.clone(), dave_signer.clone()]),
+        );
+        assert_ok!(Balances::transfer(
+            bob.origin(),
+            ms_address.clone().into(),
+            20 * POLY
+        ));
+
+        let transfer_policy = |sigs_required| CallPolicy {
+            pallet_name: PalletName::from("Balances"),
+            extrinsic_name: Some(ExtrinsicName::from("transfer")),
+            min_value: Some(10 * POLY),
+            sigs_required,
+        };
+        assert_noop!(
+            MultiSig::set_call_policies_via_admin(
+                alice.origin(),
+                ms_address.clone(),
+                vec![transfer_policy(0)].try_into().unwrap()
+            ),
+            Error::InvalidCallPolicy
+        );
+        assert_noop!(
+            MultiSig::set_call_policies_via_admin(
+                alice.origin(),
+                ms_address.clone(),
+                vec![transfer_policy(3)].try_into().unwrap()
+            ),
+            Error::NotEnoughSigners
+        );
+        assert_ok!(MultiSig::set_call_policies_via_admin(
+            alice.origin(),
+            ms_address.clone(),
+            vec![transfer_policy(2)].try_into().unwrap()
+        ));
+
+        // Small transfers only need the default number of signatures.
+        let transfer = |value| {
+            Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer {
+                dest: bob.acc().into(),
+                value,
+            }))
+        };
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            charlie.clone(),
+            ms_address.clone(),
+            transfer(POLY),
+            None,
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::ExecutionSuccessful)
+        );
+
+        // Large transfers need the signatures of the matching policy.
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            charlie.clone(),
+            ms_address.clone(),
+            transfer(10 * POLY),
+            None,
+        ));
+        let vote_count = ProposalVoteCounts::<TestStorage>::get(&ms_address, proposal_id).unwrap();
+        assert_eq!(vote_count.sigs_required, 2);
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::Active { until: None })
+        );
+
+        // Signers can't be removed if the remaining weight can't satisfy the policy.
+        assert_noop!(
+            MultiSig::remove_multisig_signers_via_admin(
+                alice.origin(),
+                ms_address.clone(),
+                create_signers(vec![dave_signer.clone()])
+            ),
+            Error::NotEnoughSigners
+        );
+
+        assert_ok!(MultiSig::approve(
+            dave.clone(),
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::ExecutionSuccessful)
+        );
+
+        // Large transfers nested in a batch also need the signatures of the matching policy.
+        let batch = Box::new(RuntimeCall::Utility(pallet_utility::Call::batch {
+            calls: vec![*transfer(POLY), *transfer(10 * POLY)],
+        }));
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            charlie.clone(),
+            ms_address.clone(),
+            batch,
+            None,
+        ));
+        let vote_count = ProposalVoteCounts::<TestStorage>::get(&ms_address, proposal_id).unwrap();
+        assert_eq!(vote_count.sigs_required, 2);
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::Active { until: None })
+        );
+    });
+}
+
+#[test]
+fn cancel_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_key = AccountKeyring::Bob.to_account_id();
+        let bob = Origin::signed(bob_key.clone());
+        let charlie_key = AccountKeyring::Charlie.to_account_id();
+        let charlie = Origin::signed(charlie_key.clone());
+
+        let dave_key = AccountKeyring::Dave.to_account_id();
+
+        let ms_address = setup_multisig(
+            alice.acc(),
+            2,
+            create_signers(vec![bob_key, charlie_key, dave_key]),
+        );
+        let call = Box::new(RuntimeCall::MultiSig(
+            multisig::Call::change_sigs_required { sigs_required: 1 },
+        ));
+
+        // A proposal can't be cancelled once another signer has voted on it.
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            call.clone(),
+            None,
+        ));
+        assert_noop!(
+            MultiSig::cancel_proposal(charlie.clone(), ms_address.clone(), proposal_id),
+            Error::NotTheProposer
+        );
+        assert_ok!(MultiSig::reject(
+            charlie.clone(),
+            ms_address.clone(),
+            proposal_id
+        ));
+        assert_noop!(
+            MultiSig::cancel_proposal(bob.clone(), ms_address.clone(), proposal_id),
+            Error::ProposalHasVotes
+        );
+
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            call,
+            None,
+        ));
+        assert_ok!(MultiSig::cancel_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            proposal_id
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::Cancelled)
+        );
+        assert_eq!(MultiSig::proposals(&ms_address, proposal_id), None);
+        assert_noop!(
+            MultiSig::approve(charlie, ms_address.clone(), proposal_id, None),
+            Error::ProposalAlreadyCancelled
+        );
+    });
+}
+
+#[test]
+fn amend_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_key = AccountKeyring::Bob.to_account_id();
+        let bob = Origin::signed(bob_key.clone());
+        let charlie_key = AccountKeyring::Charlie.to_account_id();
+        let charlie = Origin::signed(charlie_key.clone());
+        let dave_key = AccountKeyring::Dave.to_account_id();
+        let dave = Origin::signed(dave_key.clone());
+
+        let ms_address = setup_multisig(
+            alice.acc(),
+            3,
+            create_signers(vec![bob_key.clone(), charlie_key.clone(), dave_key]),
+        );
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            Box::new(RuntimeCall::MultiSig(
+                multisig::Call::change_sigs_required { sigs_required: 1 },
+            )),
+            None,
+        ));
+        assert_ok!(MultiSig::approve(
+            charlie.clone(),
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+
+        let new_call = Box::new(RuntimeCall::MultiSig(
+            multisig::Call::change_sigs_required { sigs_required: 2 },
+        ));
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            MultiSig::amend_proposal(
+                charlie.clone(),
+                ms_address.clone(),
+                proposal_id,
+                new_call.clone()
+            ),
+            Error::NotTheProposer
+        ));
+        assert_ok!(MultiSig::amend_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            proposal_id,
+            new_call.clone()
+        ));
+
+        // The votes are reset and only the proposer's approval is counted.
+        assert_eq!(
+            MultiSig::proposals(&ms_address, proposal_id),
+            Some(*new_call)
+        );
+        let vote_count = ProposalVoteCounts::<TestStorage>::get(&ms_address, proposal_id).unwrap();
+        assert_eq!(vote_count.approvals, 1);
+        assert!(Votes::<TestStorage>::get(
+            (&ms_address, proposal_id),
+            &bob_key
+        ));
+        assert!(!Votes::<TestStorage>::get(
+            (&ms_address, proposal_id),
+            &charlie_key
+        ));
+
+        assert_ok!(MultiSig::approve(
+            charlie,
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_ok!(MultiSig::approve(
+            dave,
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::ExecutionSuccessful)
+        );
+        assert_eq!(MultiSig::ms_signs_required(&ms_address), 2);
+    });
+}
+
+#[test]
+fn scheduled_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_key = AccountKeyring::Bob.to_account_id();
+        let bob = Origin::signed(bob_key.clone());
+        let charlie_key = AccountKeyring::Charlie.to_account_id();
+        let charlie = Origin::signed(charlie_key.clone());
+
+        let ms_address = setup_multisig(alice.acc(), 2, create_signers(vec![bob_key, charlie_key]));
+        let call = Box::new(RuntimeCall::MultiSig(
+            multisig::Call::change_sigs_required { sigs_required: 1 },
+        ));
+
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            MultiSig::create_scheduled_proposal(
+                bob.clone(),
+                ms_address.clone(),
+                call.clone(),
+                None,
+                1
+            ),
+            Error::InvalidExecuteAfter
+        ));
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_scheduled_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            call,
+            None,
+            100
+        ));
+        assert_noop!(
+            MultiSig::veto(charlie.clone(), ms_address.clone(), proposal_id),
+            Error::ProposalNotApproved
+        );
+
+        // The approved proposal waits for the cooling-off period.
+        assert_ok!(MultiSig::approve(
+            charlie.clone(),
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::Approved {
+                execute_after: 100,
+                until: None
+            })
+        );
+        assert_eq!(MultiSig::ms_signs_required(&ms_address), 2);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            MultiSig::execute_scheduled_proposal(
+                bob.clone(),
+                ms_address.clone(),
+                proposal_id,
+                None
+            ),
+            Error::CoolingOffPeriodNotOver
+        ));
+
+        set_timestamp(100);
+        assert_noop!(
+            MultiSig::veto(charlie.clone(), ms_address.clone(), proposal_id),
+            Error::CoolingOffPeriodOver
+        );
+        assert_ok!(MultiSig::execute_scheduled_proposal(
+            bob,
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::ExecutionSuccessful)
+        );
+        assert_eq!(MultiSig::ms_signs_required(&ms_address), 1);
+        assert_eq!(MultiSig::proposers(&ms_address, proposal_id), None);
+        assert_eq!(
+            MultiSig::proposal_execute_after(&ms_address, proposal_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn expired_scheduled_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_key = AccountKeyring::Bob.to_account_id();
+        let bob = Origin::signed(bob_key.clone());
+        let charlie_key = AccountKeyring::Charlie.to_account_id();
+        let charlie = Origin::signed(charlie_key.clone());
+
+        let ms_address = setup_multisig(alice.acc(), 2, create_signers(vec![bob_key, charlie_key]));
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_scheduled_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            Box::new(RuntimeCall::MultiSig(
+                multisig::Call::change_sigs_required { sigs_required: 1 },
+            )),
+            Some(50),
+            100
+        ));
+        assert_ok!(MultiSig::approve(
+            charlie,
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::Approved {
+                execute_after: 100,
+                until: Some(50)
+            })
+        );
+
+        // The time limit of the proposal still applies once it's approved.
+        set_timestamp(100);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            MultiSig::execute_scheduled_proposal(bob, ms_address.clone(), proposal_id, None),
+            Error::ProposalExpired
+        ));
+        assert_eq!(MultiSig::ms_signs_required(&ms_address), 2);
+    });
+}
+
+#[test]
+fn veto_scheduled_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_key = AccountKeyring::Bob.to_account_id();
+        let bob = Origin::signed(bob_key.clone());
+        let charlie_key = AccountKeyring::Charlie.to_account_id();
+        let charlie = Origin::signed(charlie_key.clone());
+        let dave_key = AccountKeyring::Dave.to_account_id();
+        let dave = Origin::signed(dave_key.clone());
+
+        let ms_address = setup_multisig(
+            alice.acc(),
+            2,
+            create_signers(vec![bob_key, charlie_key, dave_key]),
+        );
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_scheduled_proposal(
+            bob,
+            ms_address.clone(),
+            Box::new(RuntimeCall::MultiSig(
+                multisig::Call::change_sigs_required { sigs_required: 1 },
+            )),
+            None,
+            100
+        ));
+        assert_ok!(MultiSig::approve(
+            charlie.clone(),
+            ms_address.clone(),
+            proposal_id,
+            None
+        ));
+
+        // Any signer can veto the proposal, even after approving it.
+        assert_ok!(MultiSig::veto(dave, ms_address.clone(), proposal_id));
+        assert_eq!(
+            ProposalStates::<TestStorage>::get(&ms_address, proposal_id),
+            Some(ProposalState::Rejected)
+        );
+        set_timestamp(100);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            MultiSig::execute_scheduled_proposal(charlie, ms_address.clone(), proposal_id, None),
+            Error::ProposalNotApproved
+        ));
+        assert_eq!(MultiSig::ms_signs_required(&ms_address), 2);
+    });
+}
+
+#[test]
+fn pending_proposals_and_execution_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let alice = User::new(AccountKeyring::Alice);
+        let bob_key = AccountKeyring::Bob.to_account_id();
+        let bob = Origin::signed(bob_key.clone());
+        let charlie_key = AccountKeyring::Charlie.to_account_id();
+        let dave_key = AccountKeyring::Dave.to_account_id();
+
+        let ms_address = setup_multisig(
+            alice.acc(),
+            3,
+            create_signers(vec![bob_key.clone(), charlie_key.clone(), dave_key.clone()]),
+        );
+        let call = RuntimeCall::MultiSig(multisig::Call::change_sigs_required { sigs_required: 2 });
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            Box::new(call.clone()),
+            Some(100),
+        ));
+        let expired_proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            bob.clone(),
+            ms_address.clone(),
+            Box::new(call.clone()),
+            Some(10),
+        ));
+        set_timestamp(10);
+
+        // Only the proposal that hasn't expired is pending.
+        let pending_proposals = MultiSig::pending_proposals(&ms_address);
+        assert_eq!(pending_proposals.len(), 1);
+        let details = &pending_proposals[0];
+        assert_eq!(details.proposal_id, proposal_id);
+        assert_eq!(details.proposer, Some(bob_key.clone()));
+        assert_eq!(details.pallet_name, PalletName::from("MultiSig"));
+        assert_eq!(
+            details.extrinsic_name,
+            ExtrinsicName::from("change_sigs_required")
+        );
+        assert_eq!(details.call, call.encode());
+        assert_eq!(details.state, ProposalState::Active { until: Some(100) });
+        assert_eq!(details.vote_count.approvals, 1);
+        assert_eq!(details.vote_count.sigs_required, 3);
+        assert_eq!(details.voted, vec![bob_key]);
+        assert_eq!(details.not_voted.len(), 2);
+        assert!(details.not_voted.contains(&charlie_key));
+        assert!(details.not_voted.contains(&dave_key));
+
+        // The proposal would succeed once approved, but the expired proposal can't be executed.
+        assert_eq!(
+            MultiSig::proposal_execution_report(&ms_address, proposal_id),
+            vec![Error::NotEnoughApprovals.into()]
+        );
+        assert_eq!(
+            MultiSig::proposal_execution_report(&ms_address, expired_proposal_id),
+            vec![
+                Error::ProposalExpired.into(),
+                Error::NotEnoughApprovals.into()
+            ]
+        );
+        // The report doesn't change the state of the multisig.
+        assert_eq!(MultiSig::ms_signs_required(&ms_address), 3);
+
+        // A proposal whose call would fail.
+        let proposal_id = MultiSig::next_proposal_id(ms_address.clone());
+        assert_ok!(MultiSig::create_proposal(
+            bob,
+            ms_address.clone(),
+            Box::new(RuntimeCall::MultiSig(
+                multisig::Call::change_sigs_required { sigs_required: 4 }
+            )),
+            None,
+        ));
+        assert_eq!(
+            MultiSig::proposal_execution_report(&ms_address, proposal_id),
+            vec![
+                Error::NotEnoughApprovals.into(),
+                Error::NotEnoughSigners.into()
+            ]
+        );
+        assert_eq!(MultiSig::pending_proposals(&ms_address).len(), 2);
+    });
+}
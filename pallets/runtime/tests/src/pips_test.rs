@@ -3,7 +3,8 @@ use super::{
     asset_test::max_len_bytes,
     committee_test::{gc_vmo, set_members},
     storage::{
-        fast_forward_blocks, make_remark_proposal, root, EventTest, RuntimeCall, TestStorage, User,
+        fast_forward_blocks, make_remark_proposal, root, EventTest, PipConvictionLockPeriod,
+        RuntimeCall, TestStorage, User,
     },
     ExtBuilder,
 };
@@ -15,13 +16,14 @@ use frame_support::{
 };
 use frame_system::{self, EventRecord};
 use pallet_pips::{
-    DepositInfo, LiveQueue, Pip, PipDescription, PipId, PipsMetadata, ProposalState, Proposer,
-    RawEvent as Event, SnapshotId, SnapshotMetadata, SnapshotResult, SnapshottedPip, Vote,
-    VoteCount, VotingResult,
+    Conviction, ConvictionLock, DelegatedVote, DepositInfo, LiveQueue, MinimumProposalDeposit, Pip,
+    PipDescription, PipId, PipsMetadata, ProposalState, Proposer, RawEvent as Event, SnapshotId,
+    SnapshotMetadata, SnapshotResult, SnapshottedPip, Vote, VoteCount, VoteWeighting, VotingResult,
 };
 use pallet_treasury as treasury;
 use polymesh_common_utilities::{MaybeBlock, GC_DID};
-use polymesh_primitives::{AccountId, BlockNumber, Url};
+use polymesh_primitives::dry_run::StorageChange;
+use polymesh_primitives::{AccountId, BlockNumber, PalletName, Url};
 use sp_keyring::AccountKeyring;
 use std::ops::Deref;
 
@@ -207,6 +209,15 @@ fn updating_pips_variables_works() {
         assert_ok!(Pips::set_active_pip_limit(root(), 42));
         assert_last_event!(Event::ActivePipLimitChanged(_, 5, 42));
         assert_eq!(Pips::active_pip_limit(), 42);
+
+        assert_eq!(Pips::vote_weighting(), VoteWeighting::Linear);
+        assert_ok!(Pips::set_vote_weighting(root(), VoteWeighting::Quadratic));
+        assert_last_event!(Event::VoteWeightingChanged(
+            _,
+            VoteWeighting::Linear,
+            VoteWeighting::Quadratic
+        ));
+        assert_eq!(Pips::vote_weighting(), VoteWeighting::Quadratic);
     });
 }
 
@@ -237,6 +248,10 @@ fn updating_pips_variables_only_root() {
             Pips::set_active_pip_limit(signer.clone(), 0),
             DispatchError::BadOrigin,
         );
+        assert_noop!(
+            Pips::set_vote_weighting(signer.clone(), VoteWeighting::Quadratic),
+            DispatchError::BadOrigin,
+        );
 
         assert_eq!(System::events(), vec![])
     });
@@ -489,7 +504,7 @@ fn proposal_details_are_correct() {
             created_at: 42,
             url: Some(proposal_url),
             description: Some(proposal_desc),
-            transaction_version: 7,
+            transaction_version: 8,
             expiry: <_>::default(),
         };
         assert_eq!(Pips::proposal_metadata(PipId(0)).unwrap(), expected);
@@ -1962,6 +1977,10 @@ fn pips_rpcs() {
             VoteCount::ProposalFound {
                 ayes: charlie_vote_deposit,
                 nays: bob_vote_deposit,
+                ayes_weight: charlie_vote_deposit,
+                nays_weight: bob_vote_deposit,
+                delegated_ayes: 0,
+                delegated_nays: 0,
             }
         );
         assert_eq!(
@@ -1971,3 +1990,451 @@ fn pips_rpcs() {
         assert_eq!(Pips::voted_on(bob.acc()), vec![pip_id1, pip_id0]);
     });
 }
+
+#[test]
+fn quadratic_vote_weighting() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        System::set_block_number(1);
+        let proposer = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        set_members(vec![bob.did]);
+        assert_ok!(Pips::set_min_proposal_deposit(root(), 0));
+
+        // Votes cast before changing the weighting keep their weight.
+        assert_ok!(community_proposal(proposer, 0));
+        assert_ok!(Pips::vote(bob.origin(), PipId(0), true, 100));
+        assert_ok!(Pips::set_vote_weighting(root(), VoteWeighting::Quadratic));
+
+        assert_ok!(community_proposal(proposer, 0));
+        assert_ok!(Pips::vote(bob.origin(), PipId(1), true, 400));
+        assert_ok!(Pips::vote(charlie.origin(), PipId(1), false, 100));
+        assert_eq!(
+            Pips::get_votes(PipId(0)),
+            VoteCount::ProposalFound {
+                ayes: 100,
+                nays: 0,
+                ayes_weight: 100,
+                nays_weight: 0,
+                delegated_ayes: 0,
+                delegated_nays: 0,
+            }
+        );
+        assert_eq!(
+            Pips::get_votes(PipId(1)),
+            VoteCount::ProposalFound {
+                ayes: 400,
+                nays: 100,
+                ayes_weight: 20,
+                nays_weight: 10,
+                delegated_ayes: 0,
+                delegated_nays: 0,
+            }
+        );
+
+        // Changing a vote replaces the weight it was cast with.
+        assert_ok!(Pips::vote(bob.origin(), PipId(0), true, 900));
+        assert_eq!(
+            Pips::get_votes(PipId(0)),
+            VoteCount::ProposalFound {
+                ayes: 900,
+                nays: 0,
+                ayes_weight: 30,
+                nays_weight: 0,
+                delegated_ayes: 0,
+                delegated_nays: 0,
+            }
+        );
+
+        // The snapshot is ordered by weighted support.
+        assert_ok!(Pips::snapshot(bob.origin()));
+        assert_eq!(
+            Pips::snapshot_queue(),
+            vec![spip(1, true, 10), spip(0, true, 30)]
+        );
+    });
+}
+
+#[test]
+fn conviction_vote_weighting() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        System::set_block_number(1);
+        let proposer = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let bob_free = Balances::free_balance(&bob.acc());
+        let charlie_free = Balances::free_balance(&charlie.acc());
+        let lock_period = PipConvictionLockPeriod::get();
+        assert_ok!(Pips::set_min_proposal_deposit(root(), 0));
+        assert_ok!(community_proposal(proposer, 0));
+        let id = PipId(0);
+
+        // Conviction requires conviction weighting.
+        assert_noop!(
+            Pips::vote_with_conviction(bob.origin(), id, true, 100, Conviction::Locked2x),
+            Error::ConvictionVotingDisabled
+        );
+
+        assert_ok!(Pips::set_vote_weighting(root(), VoteWeighting::Conviction));
+        assert_ok!(Pips::vote_with_conviction(
+            bob.origin(),
+            id,
+            true,
+            100,
+            Conviction::Locked2x
+        ));
+        assert_ok!(Pips::vote(charlie.origin(), id, false, 1000));
+        assert_eq!(
+            Pips::get_votes(id),
+            VoteCount::ProposalFound {
+                ayes: 100,
+                nays: 1000,
+                ayes_weight: 200,
+                nays_weight: 100,
+                delegated_ayes: 0,
+                delegated_nays: 0,
+            }
+        );
+        assert_eq!(Pips::live_queue(), vec![spip(0, true, 100)]);
+
+        // Changing a vote with conviction keeps the previous deposit locked.
+        assert_ok!(Pips::vote_with_conviction(
+            bob.origin(),
+            id,
+            true,
+            50,
+            Conviction::Locked1x
+        ));
+        assert_eq!(
+            Pips::conviction_lock(bob.acc(), id),
+            Some(ConvictionLock {
+                amount: 100,
+                unlock_at: 1 + 2 * lock_period,
+            })
+        );
+        assert_balance(bob.acc(), bob_free, 150);
+
+        // Deposits with conviction stay locked after the PIP is closed, others are refunded.
+        assert_ok!(Pips::reject_proposal(gc_vmo(), id));
+        assert_eq!(
+            Pips::conviction_lock(bob.acc(), id),
+            Some(ConvictionLock {
+                amount: 150,
+                unlock_at: 1 + 2 * lock_period,
+            })
+        );
+        assert_balance(bob.acc(), bob_free, 150);
+        assert_balance(charlie.acc(), charlie_free, 0);
+
+        System::set_block_number(2 * lock_period);
+        assert_noop!(
+            Pips::unlock_conviction_deposit(bob.origin(), id),
+            Error::ConvictionLockNotExpired
+        );
+        System::set_block_number(1 + 2 * lock_period);
+        assert_ok!(Pips::unlock_conviction_deposit(bob.origin(), id));
+        assert_last_event!(Event::ConvictionUnlocked(_, _, _, 150));
+        assert_balance(bob.acc(), bob_free, 0);
+        assert_noop!(
+            Pips::unlock_conviction_deposit(bob.origin(), id),
+            Error::NoConvictionLock
+        );
+    });
+}
+
+#[test]
+fn vote_delegation() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        System::set_block_number(1);
+        let proposer = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let dave = User::new(AccountKeyring::Dave);
+        let charlie_free = Balances::free_balance(&charlie.acc());
+        let dave_free = Balances::free_balance(&dave.acc());
+        let system = Some(PalletName::from("System"));
+        assert_ok!(Pips::set_min_proposal_deposit(root(), 0));
+        assert_ok!(community_proposal(proposer, 0));
+        assert_ok!(remark_proposal(proposer, 0));
+        let (pips_id, system_id) = (PipId(0), PipId(1));
+
+        assert_noop!(
+            Pips::delegate(bob.origin(), bob.acc(), None, 100),
+            Error::SelfDelegation
+        );
+        assert_ok!(Pips::delegate(charlie.origin(), bob.acc(), None, 100));
+        assert_last_event!(Event::Delegated(_, _, _, None, 100));
+        assert_ok!(Pips::delegate(
+            dave.origin(),
+            bob.acc(),
+            system.clone(),
+            200
+        ));
+        // The delegated power is only locked once cast on a PIP.
+        assert_balance(charlie.acc(), charlie_free, 0);
+        assert_balance(dave.acc(), dave_free, 0);
+        assert_eq!(
+            Pips::delegators(bob.acc(), None::<PalletName>),
+            vec![charlie.acc()]
+        );
+
+        // Delegated power follows the vote of the delegate within the category of the delegation.
+        assert_ok!(Pips::vote(bob.origin(), pips_id, true, 10));
+        assert_last_event!(Event::Voted(..));
+        assert_event_exists!(EventTest::Pips(Event::DelegatedVoteCast(
+            _,
+            _,
+            PipId(0),
+            true,
+            100
+        )));
+        assert_eq!(
+            Pips::delegated_vote(pips_id, charlie.acc()),
+            Some(DelegatedVote {
+                delegate: bob.acc(),
+                category: None,
+            })
+        );
+        assert_eq!(Pips::delegated_vote(pips_id, dave.acc()), None);
+        assert_balance(charlie.acc(), charlie_free, 100);
+        assert_eq!(
+            Pips::proposal_result(pips_id),
+            VotingResult {
+                ayes_count: 3,
+                ayes_stake: 110,
+                ..VotingResult::default()
+            }
+        );
+        assert_ok!(Pips::vote(bob.origin(), system_id, false, 10));
+        assert_eq!(
+            Pips::get_votes(system_id),
+            VoteCount::ProposalFound {
+                ayes: 0,
+                nays: 310,
+                ayes_weight: 0,
+                nays_weight: 310,
+                delegated_ayes: 0,
+                delegated_nays: 300,
+            }
+        );
+        // The delegated power is locked separately on every PIP it is cast on.
+        assert_balance(charlie.acc(), charlie_free, 200);
+        assert_balance(dave.acc(), dave_free, 200);
+        assert_eq!(Pips::voted_on(charlie.acc()), Vec::<PipId>::new());
+        assert_eq!(
+            Pips::delegated_voted_on(charlie.acc()),
+            vec![system_id, pips_id]
+        );
+
+        // A direct vote overrides the delegated vote.
+        assert_ok!(Pips::vote(charlie.origin(), system_id, true, 5));
+        assert_event_exists!(EventTest::Pips(Event::DelegatedVoteRemoved(_, PipId(1))));
+        assert_eq!(Pips::delegated_vote(system_id, charlie.acc()), None);
+        assert_balance(charlie.acc(), charlie_free, 105);
+        assert_ok!(Pips::vote(bob.origin(), system_id, true, 10));
+        assert_eq!(
+            Pips::proposal_vote(system_id, charlie.acc()),
+            Some(Vote(true, 5))
+        );
+        assert_eq!(
+            Pips::get_votes(system_id),
+            VoteCount::ProposalFound {
+                ayes: 215,
+                nays: 0,
+                ayes_weight: 215,
+                nays_weight: 0,
+                delegated_ayes: 200,
+                delegated_nays: 0,
+            }
+        );
+
+        // Revoking removes the delegated votes and unlocks the delegated power.
+        assert_ok!(Pips::revoke_delegation(dave.origin(), system.clone()));
+        assert_event_exists!(EventTest::Pips(Event::DelegationRevoked(
+            _,
+            _,
+            _,
+            Some(_),
+            200
+        )));
+        assert_eq!(Pips::proposal_vote(system_id, dave.acc()), None);
+        assert_eq!(
+            Pips::get_votes(system_id),
+            VoteCount::ProposalFound {
+                ayes: 15,
+                nays: 0,
+                ayes_weight: 15,
+                nays_weight: 0,
+                delegated_ayes: 0,
+                delegated_nays: 0,
+            }
+        );
+        assert_balance(dave.acc(), dave_free, 0);
+        assert_noop!(
+            Pips::revoke_delegation(dave.origin(), system),
+            Error::NoSuchDelegation
+        );
+    });
+}
+
+#[test]
+fn vote_delegation_category_precedence() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        System::set_block_number(1);
+        let proposer = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let dave = User::new(AccountKeyring::Dave);
+        let system = Some(PalletName::from("System"));
+        assert_ok!(Pips::set_min_proposal_deposit(root(), 0));
+        assert_ok!(community_proposal(proposer, 0));
+        assert_ok!(remark_proposal(proposer, 0));
+        let (pips_id, system_id) = (PipId(0), PipId(1));
+        assert_ok!(Pips::vote(bob.origin(), pips_id, true, 10));
+        assert_ok!(Pips::vote(bob.origin(), system_id, true, 10));
+        assert_ok!(Pips::vote(charlie.origin(), system_id, false, 10));
+
+        // Delegating follows the votes already cast by the delegate.
+        assert_ok!(Pips::delegate(dave.origin(), bob.acc(), None, 50));
+        assert_eq!(
+            Pips::proposal_vote(pips_id, dave.acc()),
+            Some(Vote(true, 50))
+        );
+        assert_eq!(
+            Pips::proposal_vote(system_id, dave.acc()),
+            Some(Vote(true, 50))
+        );
+
+        // A delegation for a category takes precedence over a delegation for all PIPs.
+        assert_ok!(Pips::delegate(
+            dave.origin(),
+            charlie.acc(),
+            system.clone(),
+            70
+        ));
+        assert_eq!(
+            Pips::proposal_vote(pips_id, dave.acc()),
+            Some(Vote(true, 50))
+        );
+        assert_eq!(
+            Pips::proposal_vote(system_id, dave.acc()),
+            Some(Vote(false, 70))
+        );
+        assert_eq!(
+            Pips::delegated_vote(system_id, dave.acc()),
+            Some(DelegatedVote {
+                delegate: charlie.acc(),
+                category: system.clone(),
+            })
+        );
+
+        // Votes cast with delegated power are not delegated further.
+        let eve = User::new(AccountKeyring::Eve);
+        assert_ok!(Pips::delegate(eve.origin(), dave.acc(), None, 30));
+        assert_eq!(Pips::proposal_vote(pips_id, eve.acc()), None);
+        assert_eq!(Pips::delegated_vote(pips_id, eve.acc()), None);
+
+        // Revoking the category delegation falls back to the delegation for all PIPs.
+        assert_ok!(Pips::revoke_delegation(dave.origin(), system));
+        assert_eq!(
+            Pips::proposal_vote(system_id, dave.acc()),
+            Some(Vote(true, 50))
+        );
+        assert_eq!(
+            Pips::get_votes(system_id),
+            VoteCount::ProposalFound {
+                ayes: 60,
+                nays: 10,
+                ayes_weight: 60,
+                nays_weight: 10,
+                delegated_ayes: 50,
+                delegated_nays: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn vote_delegation_insufficient_balance() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        System::set_block_number(1);
+        let proposer = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let charlie = User::new(AccountKeyring::Charlie);
+        let charlie_free = Balances::free_balance(&charlie.acc());
+        assert_ok!(Pips::set_min_proposal_deposit(root(), 0));
+        assert_ok!(community_proposal(proposer, 0));
+        let id = PipId(0);
+
+        // Delegated power which cannot be locked for a PIP is not cast on it.
+        assert_ok!(Pips::delegate(
+            charlie.origin(),
+            bob.acc(),
+            None,
+            charlie_free + 1
+        ));
+        assert_ok!(Pips::vote(bob.origin(), id, true, 10));
+        assert_eq!(Pips::proposal_vote(id, charlie.acc()), None);
+        assert_eq!(Pips::delegated_vote(id, charlie.acc()), None);
+        assert_balance(charlie.acc(), charlie_free, 0);
+    });
+}
+
+#[test]
+fn vote_delegation_too_many_delegators() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        let delegate = User::new(AccountKeyring::Alice);
+        let delegators = [
+            AccountKeyring::Bob,
+            AccountKeyring::Charlie,
+            AccountKeyring::Dave,
+        ];
+        for delegator in delegators {
+            let delegator = User::new(delegator);
+            assert_ok!(Pips::delegate(delegator.origin(), delegate.acc(), None, 1));
+        }
+        let eve = User::new(AccountKeyring::Eve);
+        assert_noop!(
+            Pips::delegate(eve.origin(), delegate.acc(), None, 1),
+            Error::TooManyDelegators
+        );
+        // Other categories have their own limit.
+        assert_ok!(Pips::delegate(
+            eve.origin(),
+            delegate.acc(),
+            Some(PalletName::from("System")),
+            1
+        ));
+    });
+}
+
+#[test]
+fn dry_run_pip() {
+    ExtBuilder::default().monied(true).build().execute_with(|| {
+        System::set_block_number(1);
+        let proposer = User::new(AccountKeyring::Alice);
+        assert_ok!(Pips::set_min_proposal_deposit(root(), 0));
+        assert_ok!(community_proposal(proposer, 0));
+        let events = System::events();
+
+        let outcome = Pips::dry_run_pip(PipId(0)).unwrap();
+        assert_ok!(outcome.result);
+        assert_eq!(
+            outcome.events,
+            vec![EventTest::Pips(Event::MinimumProposalDepositChanged(
+                GC_DID, 0, 42
+            ))]
+        );
+        assert!(outcome.storage_changes.contains(&StorageChange::Modified(
+            MinimumProposalDeposit::hashed_key().to_vec()
+        )));
+
+        // All changes were rolled back.
+        assert_eq!(Pips::min_proposal_deposit(), 0);
+        assert_eq!(System::events(), events);
+        assert_eq!(
+            Pips::dry_run_pip(PipId(1)),
+            Err(Error::NoSuchProposal.into())
+        );
+    });
+}
@@ -1,10 +1,11 @@
+use frame_support::dispatch::DispatchResult;
 use frame_support::storage::StorageDoubleMap;
 use frame_support::{assert_noop, assert_ok, StorageMap};
 
 use pallet_nft::NFTOwner;
 use pallet_portfolio::{
     AllowedCustodians, Event, NameToNumber, PortfolioAssetBalances, PortfolioCustodian,
-    PortfolioNFT, Portfolios, PreApprovedPortfolios,
+    PortfolioLockedByLocks, PortfolioNFT, Portfolios, PreApprovedPortfolios,
 };
 use polymesh_common_utilities::portfolio::PortfolioSubTrait;
 use polymesh_primitives::asset::{AssetId, AssetType, NonFungibleType};
@@ -14,13 +15,13 @@ use polymesh_primitives::asset_metadata::{
 use polymesh_primitives::settlement::{Leg, SettlementType};
 use polymesh_primitives::{
     AuthorizationData, AuthorizationError, Fund, FundDescription, Memo, NFTCollectionKeys, NFTId,
-    NFTMetadataAttribute, NFTs, PortfolioId, PortfolioKind, PortfolioName, PortfolioNumber,
-    Signatory,
+    NFTMetadataAttribute, NFTs, PortfolioId, PortfolioKind, PortfolioLock, PortfolioLockId,
+    PortfolioLockReason, PortfolioName, PortfolioNumber, Signatory,
 };
 use sp_keyring::AccountKeyring;
 
 use super::asset_pallet::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
-use super::asset_test::max_len_bytes;
+use super::asset_test::{max_len_bytes, set_timestamp};
 use super::nft::{create_nft_collection, mint_nft};
 use super::storage::{user_portfolio_btreeset, EventTest, System, TestStorage, User};
 use super::ExtBuilder;
@@ -1130,3 +1131,205 @@ fn allow_identity_to_create_portfolios_not_allowed() {
         );
     });
 }
+
+fn move_funds(
+    owner: &User,
+    from: PortfolioId,
+    to: PortfolioId,
+    asset_id: AssetId,
+    amount: u128,
+) -> DispatchResult {
+    Portfolio::move_portfolio_funds(
+        owner.origin(),
+        from,
+        to,
+        vec![Fund {
+            description: FundDescription::Fungible { asset_id, amount },
+            memo: None,
+        }],
+    )
+}
+
+#[test]
+fn lock_portfolio_assets() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let (owner, num) = create_portfolio();
+        let lender = User::new(AccountKeyring::Bob);
+        let asset_id = create_and_issue_sample_asset(&owner);
+        let owner_default_portfolio = PortfolioId::default_portfolio(owner.did);
+        let owner_user_portfolio = PortfolioId::user_portfolio(owner.did, num);
+
+        // Pledge half of the tokens to the lender until time 100.
+        let lock_amount = ISSUE_AMOUNT / 2;
+        assert_ok!(Portfolio::lock_portfolio_assets(
+            owner.origin(),
+            owner_default_portfolio,
+            asset_id,
+            lock_amount,
+            PortfolioLockReason::Collateral,
+            Some(100),
+            Some(lender.did)
+        ));
+        let lock_id = PortfolioLockId(0);
+        assert_eq!(
+            Portfolio::portfolio_locks(&owner_default_portfolio),
+            vec![(
+                lock_id,
+                PortfolioLock {
+                    asset_id,
+                    amount: lock_amount,
+                    reason: PortfolioLockReason::Collateral,
+                    locked_by: owner.did,
+                    release_at: Some(100),
+                    release_authority: Some(lender.did),
+                }
+            )]
+        );
+        assert_eq!(
+            PortfolioLockedByLocks::get(owner_default_portfolio, asset_id),
+            lock_amount
+        );
+        assert_eq!(
+            Portfolio::default_portfolio_balance(owner.did, &asset_id),
+            ISSUE_AMOUNT
+        );
+
+        // The locked tokens can't be moved away, nor locked again.
+        assert_noop!(
+            move_funds(
+                &owner,
+                owner_default_portfolio,
+                owner_user_portfolio,
+                asset_id,
+                ISSUE_AMOUNT
+            ),
+            Error::InsufficientPortfolioBalance
+        );
+        assert_noop!(
+            Portfolio::lock_portfolio_assets(
+                owner.origin(),
+                owner_default_portfolio,
+                asset_id,
+                lock_amount + 1,
+                PortfolioLockReason::LegalHold,
+                None,
+                None
+            ),
+            Error::InsufficientPortfolioBalance
+        );
+        assert_ok!(move_funds(
+            &owner,
+            owner_default_portfolio,
+            owner_user_portfolio,
+            asset_id,
+            ISSUE_AMOUNT - lock_amount
+        ));
+
+        // Only the lender can release the lock before its release time.
+        assert_noop!(
+            Portfolio::release_portfolio_lock(owner.origin(), owner_default_portfolio, lock_id),
+            Error::UnauthorizedPortfolioLockRelease
+        );
+        assert_ok!(Portfolio::release_portfolio_lock(
+            lender.origin(),
+            owner_default_portfolio,
+            lock_id
+        ));
+        assert!(Portfolio::portfolio_locks(&owner_default_portfolio).is_empty());
+        assert_eq!(
+            PortfolioLockedByLocks::get(owner_default_portfolio, asset_id),
+            0
+        );
+        assert_ok!(move_funds(
+            &owner,
+            owner_default_portfolio,
+            owner_user_portfolio,
+            asset_id,
+            lock_amount
+        ));
+        assert_noop!(
+            Portfolio::release_portfolio_lock(lender.origin(), owner_default_portfolio, lock_id),
+            Error::PortfolioLockNotFound
+        );
+    });
+}
+
+#[test]
+fn release_expired_portfolio_lock() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_timestamp(1);
+        let owner = User::new(AccountKeyring::Alice);
+        let bob = User::new(AccountKeyring::Bob);
+        let asset_id = create_and_issue_sample_asset(&owner);
+        let owner_default_portfolio = PortfolioId::default_portfolio(owner.did);
+
+        assert_noop!(
+            Portfolio::lock_portfolio_assets(
+                owner.origin(),
+                owner_default_portfolio,
+                asset_id,
+                0,
+                PortfolioLockReason::Escrow,
+                None,
+                None
+            ),
+            Error::EmptyPortfolioLock
+        );
+        assert_noop!(
+            Portfolio::lock_portfolio_assets(
+                owner.origin(),
+                owner_default_portfolio,
+                asset_id,
+                1,
+                PortfolioLockReason::Escrow,
+                Some(1),
+                None
+            ),
+            Error::InvalidPortfolioLockReleaseTime
+        );
+        assert_noop!(
+            Portfolio::lock_portfolio_assets(
+                bob.origin(),
+                owner_default_portfolio,
+                asset_id,
+                1,
+                PortfolioLockReason::Escrow,
+                None,
+                None
+            ),
+            Error::UnauthorizedCustodian
+        );
+
+        assert_ok!(Portfolio::lock_portfolio_assets(
+            owner.origin(),
+            owner_default_portfolio,
+            asset_id,
+            ISSUE_AMOUNT,
+            PortfolioLockReason::Vesting,
+            Some(100),
+            None
+        ));
+        let lock_id = PortfolioLockId(0);
+        assert_noop!(
+            Portfolio::release_portfolio_lock(owner.origin(), owner_default_portfolio, lock_id),
+            Error::UnauthorizedPortfolioLockRelease
+        );
+
+        // Once the release time has passed, only the custodian can release the lock.
+        set_timestamp(100);
+        assert_noop!(
+            Portfolio::release_portfolio_lock(bob.origin(), owner_default_portfolio, lock_id),
+            Error::UnauthorizedPortfolioLockRelease
+        );
+        assert_ok!(Portfolio::release_portfolio_lock(
+            owner.origin(),
+            owner_default_portfolio,
+            lock_id
+        ));
+        assert_eq!(
+            PortfolioLockedByLocks::get(owner_default_portfolio, asset_id),
+            0
+        );
+    });
+}
@@ -10,10 +10,15 @@ use frame_support::{
 use frame_system;
 use pallet_relayer::Subsidy;
 use polymesh_common_utilities::{
-    constants::currency::POLY, protocol_fee::ProtocolOp,
-    traits::transaction_payment::CddAndFeeDetails,
+    constants::currency::POLY,
+    protocol_fee::ProtocolOp,
+    traits::{relayer::SubsidiserTrait, transaction_payment::CddAndFeeDetails},
+};
+use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::{
+    AccountId, Balance, ExtrinsicPermissions, PalletPermissions, PortfolioKind, PortfolioName,
+    Signatory, SubsetRestriction, SubsidyReplenishment, SubsidyScope, Ticker, TransactionError,
 };
-use polymesh_primitives::{AccountId, Balance, Signatory, Ticker, TransactionError};
 use polymesh_runtime_develop::runtime::{CddHandler, RuntimeCall as DevRuntimeCall};
 use sp_keyring::AccountKeyring;
 use sp_runtime::{
@@ -99,7 +104,12 @@ fn assert_subsidy(user: User, subsidy: Option<(User, Balance)>) {
 #[track_caller]
 fn setup_subsidy(user: User, payer: User, limit: Balance) {
     // Add authorization for using `payer` as the paying key for `user`.
-    assert_ok!(Relayer::set_paying_key(payer.origin(), user.acc(), limit));
+    assert_ok!(Relayer::set_paying_key(
+        payer.origin(),
+        user.acc(),
+        limit,
+        None
+    ));
 
     // No subsidy yet.
     assert_subsidy(user, None);
@@ -125,7 +135,12 @@ fn do_basic_relayer_paying_key_test() {
     let dave = User::new(AccountKeyring::Dave);
 
     // Add authorization for using Alice as the paying key for Bob.
-    assert_ok!(Relayer::set_paying_key(alice.origin(), bob.acc(), 10u128));
+    assert_ok!(Relayer::set_paying_key(
+        alice.origin(),
+        bob.acc(),
+        10u128,
+        None
+    ));
 
     // The keys are not used yet.
     assert_key_usage(alice, 0);
@@ -284,7 +299,12 @@ fn do_accept_new_paying_key_test() {
     assert_subsidy(bob, Some((alice, 10u128)));
 
     // Add authorization for using Dave as the paying key for Bob.
-    assert_ok!(Relayer::set_paying_key(dave.origin(), bob.acc(), 200u128));
+    assert_ok!(Relayer::set_paying_key(
+        dave.origin(),
+        bob.acc(),
+        200u128,
+        None
+    ));
 
     // Bob accepts Dave as his new subsidiser replacing Alice as the subsidiser.
     let auth_id = get_last_auth_id(&Signatory::Account(bob.acc()));
@@ -346,7 +366,8 @@ fn do_relayer_user_key_missing_cdd_test() {
     assert_ok!(Relayer::set_paying_key(
         alice.origin(),
         bob_acc.clone(),
-        10u128
+        10u128,
+        None
     ));
 
     // Bob tries to accept the paying key, without having a CDD.
@@ -370,7 +391,7 @@ fn do_relayer_paying_key_missing_cdd_test() {
     let (bob_sign, _) = make_account_without_cdd(bob_acc.clone()).unwrap();
 
     // Add authorization for using Bob as the paying key for Alice.
-    assert_ok!(Relayer::set_paying_key(bob_sign, alice.acc(), 10u128));
+    assert_ok!(Relayer::set_paying_key(bob_sign, alice.acc(), 10u128, None));
 
     // Alice tries to accept the paying key, but the paying key
     // is without a CDD.
@@ -543,7 +564,12 @@ fn do_relayer_accept_cdd_and_fees_test() {
     let bob_sign = Signatory::Account(bob.acc());
 
     // Alice creates authoration to subsidise for Bob.
-    assert_ok!(Relayer::set_paying_key(alice.origin(), bob.acc(), 0u128));
+    assert_ok!(Relayer::set_paying_key(
+        alice.origin(),
+        bob.acc(),
+        0u128,
+        None
+    ));
     let auth_id = get_last_auth_id(&bob_sign);
 
     // Check that Bob can accept the subsidy with Alice paying for the transaction.
@@ -555,3 +581,225 @@ fn do_relayer_accept_cdd_and_fees_test() {
         Ok(Some(alice.acc()))
     );
 }
+
+/// Setup a subsidy of `limit` POLYX with the `payer` paying for the `user`, within `scope`.
+#[track_caller]
+fn setup_scoped_subsidy(user: User, payer: User, limit: Balance, scope: SubsidyScope) {
+    assert_ok!(Relayer::set_paying_key(
+        payer.origin(),
+        user.acc(),
+        limit,
+        Some(scope.clone())
+    ));
+    let auth_id = get_last_auth_id(&Signatory::Account(user.acc()));
+    assert_ok!(Relayer::accept_paying_key(user.origin(), auth_id));
+    assert_subsidy(user, Some((payer, limit)));
+    assert_eq!(Relayer::subsidy_scope(user.acc()), Some(scope));
+}
+
+#[test]
+fn relayer_scoped_subsidy_test() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(&do_relayer_scoped_subsidy_test);
+}
+fn do_relayer_scoped_subsidy_test() {
+    let alice = User::new(AccountKeyring::Alice);
+    let bob = User::new(AccountKeyring::Bob);
+
+    // The subsidy only covers registering tickers.
+    let extrinsics = ExtrinsicPermissions::these(vec![PalletPermissions::new(
+        "Asset".into(),
+        SubsetRestriction::elem("register_unique_ticker".into()),
+    )]);
+    let scope = SubsidyScope {
+        extrinsics,
+        assets: SubsetRestriction::Whole,
+        replenishment: None,
+    };
+    setup_scoped_subsidy(bob, alice, 1_000, scope);
+
+    let get_valid_payer = |call: DevRuntimeCall| CddHandler::get_valid_payer(&call, &bob.acc());
+    let ticker = Ticker::from_slice_truncated(b"A");
+    assert_eq!(
+        get_valid_payer(DevRuntimeCall::Asset(
+            pallet_asset::Call::register_unique_ticker { ticker }
+        )),
+        Ok(Some(bob.acc()))
+    );
+    let create_portfolio = || {
+        DevRuntimeCall::Portfolio(pallet_portfolio::Call::create_portfolio {
+            name: PortfolioName(b"P".to_vec()),
+        })
+    };
+    assert_eq!(
+        get_valid_payer(create_portfolio()),
+        Err(InvalidTransaction::Custom(
+            TransactionError::CallNotSubsidised as u8
+        ))
+    );
+
+    // Calls nested in a batch are covered only if the batch and all its calls are.
+    let batch = |calls| DevRuntimeCall::Utility(pallet_utility::Call::batch { calls });
+    let register_ticker = || {
+        DevRuntimeCall::Asset(pallet_asset::Call::register_unique_ticker {
+            ticker: Ticker::from_slice_truncated(b"B"),
+        })
+    };
+    assert_eq!(
+        get_valid_payer(batch(vec![register_ticker()])),
+        Err(InvalidTransaction::Custom(
+            TransactionError::CallNotSubsidised as u8
+        ))
+    );
+    let extrinsics = ExtrinsicPermissions::these(vec![
+        PalletPermissions::new(
+            "Asset".into(),
+            SubsetRestriction::elem("register_unique_ticker".into()),
+        ),
+        PalletPermissions::new("Utility".into(), SubsetRestriction::elem("batch".into())),
+    ]);
+    assert_ok!(Relayer::remove_paying_key(
+        bob.origin(),
+        bob.acc(),
+        alice.acc()
+    ));
+    let scope = SubsidyScope {
+        extrinsics,
+        assets: SubsetRestriction::Whole,
+        replenishment: None,
+    };
+    setup_scoped_subsidy(bob, alice, 1_000, scope);
+    assert_eq!(
+        get_valid_payer(batch(vec![register_ticker()])),
+        Ok(Some(bob.acc()))
+    );
+    assert_eq!(
+        get_valid_payer(batch(vec![register_ticker(), create_portfolio()])),
+        Err(InvalidTransaction::Custom(
+            TransactionError::CallNotSubsidised as u8
+        ))
+    );
+
+    // The user key can always remove the subsidy.
+    assert_eq!(
+        get_valid_payer(DevRuntimeCall::Relayer(
+            pallet_relayer::Call::remove_paying_key {
+                user_key: bob.acc(),
+                paying_key: alice.acc(),
+            }
+        )),
+        Ok(Some(bob.acc()))
+    );
+    assert_ok!(Relayer::remove_paying_key(
+        bob.origin(),
+        bob.acc(),
+        alice.acc()
+    ));
+    assert_eq!(Relayer::subsidy_scope(bob.acc()), None);
+    assert_eq!(get_valid_payer(create_portfolio()), Ok(Some(bob.acc())));
+}
+
+#[test]
+fn relayer_asset_scoped_subsidy_test() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(&do_relayer_asset_scoped_subsidy_test);
+}
+fn do_relayer_asset_scoped_subsidy_test() {
+    let alice = User::new(AccountKeyring::Alice);
+    let bob = User::new(AccountKeyring::Bob);
+    let asset_a = AssetId::new([1; 16]);
+    let asset_b = AssetId::new([2; 16]);
+
+    // The subsidy only covers calls operating on `asset_a`.
+    let scope = SubsidyScope {
+        extrinsics: ExtrinsicPermissions::Whole,
+        assets: SubsetRestriction::elem(asset_a),
+        replenishment: None,
+    };
+    setup_scoped_subsidy(bob, alice, 1_000, scope);
+
+    let get_valid_payer = |call: DevRuntimeCall| CddHandler::get_valid_payer(&call, &bob.acc());
+    let not_subsidised = Err(InvalidTransaction::Custom(
+        TransactionError::CallNotSubsidised as u8,
+    ));
+    let issue = |asset_id| {
+        DevRuntimeCall::Asset(pallet_asset::Call::issue {
+            asset_id,
+            amount: 1_000,
+            portfolio_kind: PortfolioKind::Default,
+        })
+    };
+    let batch = |calls| DevRuntimeCall::Utility(pallet_utility::Call::batch { calls });
+    assert_eq!(get_valid_payer(issue(asset_a)), Ok(Some(bob.acc())));
+    assert_eq!(get_valid_payer(issue(asset_b)), not_subsidised);
+    assert_eq!(
+        get_valid_payer(batch(vec![issue(asset_a)])),
+        Ok(Some(bob.acc()))
+    );
+    assert_eq!(
+        get_valid_payer(batch(vec![issue(asset_a), issue(asset_b)])),
+        not_subsidised
+    );
+
+    // Calls that don't operate on assets aren't covered.
+    let create_portfolio = DevRuntimeCall::Portfolio(pallet_portfolio::Call::create_portfolio {
+        name: PortfolioName(b"P".to_vec()),
+    });
+    assert_eq!(get_valid_payer(create_portfolio), not_subsidised);
+}
+
+#[test]
+fn relayer_subsidy_replenishment_test() {
+    ExtBuilder::default()
+        .monied(true)
+        .build()
+        .execute_with(&do_relayer_subsidy_replenishment_test);
+}
+fn do_relayer_subsidy_replenishment_test() {
+    let alice = User::new(AccountKeyring::Alice);
+    let bob = User::new(AccountKeyring::Bob);
+    frame_system::Pallet::<TestStorage>::set_block_number(5);
+
+    // A replenishment period must be at least one block.
+    let scope = |period| SubsidyScope {
+        extrinsics: ExtrinsicPermissions::Whole,
+        assets: SubsetRestriction::Whole,
+        replenishment: Some(SubsidyReplenishment {
+            polyx_limit: 100,
+            period,
+        }),
+    };
+    assert_noop!(
+        Relayer::set_paying_key(alice.origin(), bob.acc(), 50, Some(scope(0))),
+        Error::InvalidReplenishmentPeriod
+    );
+
+    // The initial limit covers the current period.
+    setup_scoped_subsidy(bob, alice, 50, scope(10));
+    assert_ok!(Relayer::debit_subsidy(&bob.acc(), 30));
+    assert_subsidy(bob, Some((alice, 20)));
+    assert_eq!(
+        Relayer::check_subsidy(&bob.acc(), 30, None),
+        Err(InvalidTransaction::Payment)
+    );
+
+    // The limit is restored once the next period starts.
+    frame_system::Pallet::<TestStorage>::set_block_number(10);
+    assert_eq!(
+        Relayer::check_subsidy(&bob.acc(), 90, None),
+        Ok(Some(alice.acc()))
+    );
+    assert_ok!(Relayer::debit_subsidy(&bob.acc(), 90));
+    assert_subsidy(bob, Some((alice, 10)));
+
+    // But only once per period.
+    frame_system::Pallet::<TestStorage>::set_block_number(19);
+    assert_eq!(
+        Relayer::check_subsidy(&bob.acc(), 20, None),
+        Err(InvalidTransaction::Payment)
+    );
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
 use std::ops::Deref;
 
@@ -9,7 +9,7 @@ use frame_support::{
     BoundedBTreeSet, IterableStorageDoubleMap, StorageDoubleMap, StorageMap,
 };
 use rand::{prelude::*, thread_rng};
-use sp_runtime::{AccountId32, AnySignature};
+use sp_runtime::{AccountId32, AnySignature, Permill};
 use sp_std::collections::btree_set::BTreeSet;
 
 use pallet_asset::BalanceOf;
@@ -17,21 +17,27 @@ use pallet_nft::NumberOfNFTs;
 use pallet_portfolio::{PortfolioLockedNFT, PortfolioNFT};
 use pallet_scheduler as scheduler;
 use pallet_settlement::{
-    AffirmsReceived, InstructionAffirmsPending, InstructionLegs, InstructionMediatorsAffirmations,
-    InstructionMemos, NumberOfVenueSigners, OffChainAffirmations, RawEvent, UserAffirmations,
-    UserVenues, VenueInstructions,
+    AffirmsReceived, InstructionAffirmsPending, InstructionHashLocks, InstructionLegs,
+    InstructionMediatorsAffirmations, InstructionMemos, NumberOfVenueSigners, OffChainAffirmations,
+    RawEvent, UserAffirmations, UserVenues, VenueInstructions,
 };
 use polymesh_common_utilities::constants::currency::ONE_UNIT;
-use polymesh_primitives::asset::{AssetId, AssetType, NonFungibleType};
+use polymesh_primitives::asset::{
+    AssetId, AssetOperation, AssetOperations, AssetType, NonFungibleType, TransferFee,
+    TransferFeeAmount,
+};
 use polymesh_primitives::asset_metadata::{
     AssetMetadataKey, AssetMetadataLocalKey, AssetMetadataValue,
 };
 use polymesh_primitives::checked_inc::CheckedInc;
 use polymesh_primitives::settlement::{
-    AffirmationCount, AffirmationStatus, AssetCount, Instruction, InstructionId, InstructionStatus,
-    Leg, LegId, LegStatus, MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType,
-    VenueDetails, VenueId, VenueType,
+    AffirmationCount, AffirmationStatus, AssetCount, HashLock, HashLockAlgorithm, Instruction,
+    InstructionId, InstructionStatus, Leg, LegId, LegStatus, LegTransferFee,
+    MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType, VenueDetails, VenueId,
+    VenueType,
 };
+use polymesh_primitives::statistics::{StatOpType, StatType};
+use polymesh_primitives::transfer_compliance::TransferCondition;
 use polymesh_primitives::{
     AccountId, AuthorizationData, Balance, Claim, ClaimType, Condition, ConditionType, CountryCode,
     Fund, FundDescription, IdentityId, Memo, NFTCollectionKeys, NFTId, NFTMetadataAttribute, NFTs,
@@ -45,8 +51,8 @@ use super::asset_test::max_len_bytes;
 use super::nft::{create_nft_collection, mint_nft};
 use super::settlement_pallet::setup::create_and_issue_sample_asset_with_venue;
 use super::storage::{
-    default_portfolio_btreeset, make_account_with_balance, user_portfolio_btreeset,
-    vec_to_btreeset, TestStorage, User,
+    default_portfolio_btreeset, fast_forward_to_block, make_account_with_balance,
+    user_portfolio_btreeset, vec_to_btreeset, TestStorage, User,
 };
 use super::{next_block, ExtBuilder};
 
@@ -67,6 +73,8 @@ type System = frame_system::Pallet<TestStorage>;
 type Error = pallet_settlement::Error<TestStorage>;
 type Scheduler = pallet_scheduler::Pallet<TestStorage>;
 type NFTError = pallet_nft::Error<TestStorage>;
+type Statistics = pallet_statistics::Module<TestStorage>;
+type StatisticsError = pallet_statistics::Error<TestStorage>;
 
 macro_rules! assert_add_claim {
     ($signer:expr, $target:expr, $claim:expr) => {
@@ -4154,134 +4162,1191 @@ fn missing_venue_for_offchain_asset() {
     });
 }
 
-/// Asserts the storage has been updated after adding an instruction.
-/// While each portfolio in `portfolios_pending_approval` must have a pending `AffirmationStatus`, each portfolio in `portfolios_pre_approved`
-/// must have an affirmed status. The number of pending affirmations must be equal to the number of portfolios in `portfolios_pending_approval` + the number of offchain legs,
-/// all legs must have been included in `InstructionLegs` and `InstructionMemos` must be equal to `instruction_memo`.
-fn assert_add_instruction_storage(
-    instruction_id: &InstructionId,
-    portfolios_pending_approval: &BTreeSet<PortfolioId>,
-    portfolios_pre_approved: &BTreeSet<PortfolioId>,
-    offchain_legs: &BTreeSet<LegId>,
-    instruction_memo: Option<Memo>,
-    legs: &[Leg],
-    mediators_pending_approval: &BTreeSet<IdentityId>,
-    mediators_affirmed: &BTreeSet<IdentityId>,
-) {
-    portfolios_pending_approval.iter().for_each(|portfolio_id| {
-        assert_eq!(
-            UserAffirmations::get(portfolio_id, instruction_id),
-            AffirmationStatus::Pending
-        )
-    });
-    portfolios_pre_approved.iter().for_each(|portfolio_id| {
-        assert_eq!(
-            UserAffirmations::get(portfolio_id, instruction_id),
-            AffirmationStatus::Affirmed
+#[test]
+fn add_instruction_with_invalid_hash_lock() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let legs = vec![Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            amount: 1_000,
+        }];
+        let hash_lock = sample_hash_lock(b"secret", System::block_number());
+
+        assert_noop!(
+            Settlement::add_instruction_with_hash_lock(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                legs.clone(),
+                None,
+                hash_lock,
+                [LegId(0)].into(),
+            ),
+            Error::InvalidHashLockExpiry
         );
-        assert_eq!(
-            AffirmsReceived::get(instruction_id, portfolio_id),
-            AffirmationStatus::Affirmed
-        )
-    });
-    offchain_legs.iter().for_each(|leg_id| {
-        assert_eq!(
-            OffChainAffirmations::get(instruction_id, leg_id),
-            AffirmationStatus::Pending
+        let hash_lock = sample_hash_lock(b"secret", System::block_number() + 10);
+        assert_noop!(
+            Settlement::add_instruction_with_hash_lock(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleOnBlock(System::block_number() + 5),
+                None,
+                None,
+                legs.clone(),
+                None,
+                hash_lock.clone(),
+                [LegId(0)].into(),
+            ),
+            Error::InvalidSettlementTypeForHashLock
+        );
+        assert_noop!(
+            Settlement::add_instruction_with_hash_lock(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleOnAffirmation,
+                None,
+                None,
+                legs,
+                None,
+                hash_lock,
+                [LegId(1)].into(),
+            ),
+            Error::InvalidHashLockedLegs
         );
     });
-    assert_eq!(
-        InstructionAffirmsPending::get(instruction_id),
-        portfolios_pending_approval.len() as u64
-            + offchain_legs.len() as u64
-            + mediators_pending_approval.len() as u64
-    );
+}
 
-    assert_eq!(InstructionMemos::get(instruction_id), instruction_memo);
+#[test]
+fn execute_hash_locked_instruction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
 
-    (0..legs.len()).for_each(|i| {
-        assert_eq!(
-            InstructionLegs::get(instruction_id, LegId(i as u64)),
-            Some(legs[i].clone())
-        )
-    });
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let instruction_id = Settlement::instruction_counter();
+        let legs = vec![Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            amount: 1_000,
+        }];
+        let hash_lock = sample_hash_lock(b"secret", System::block_number() + 10);
 
-    mediators_pending_approval.iter().for_each(|identity_id| {
+        assert_ok!(Settlement::add_instruction_with_hash_lock(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+            hash_lock.clone(),
+            [LegId(0)].into(),
+        ));
         assert_eq!(
-            InstructionMediatorsAffirmations::<TestStorage>::get(instruction_id, identity_id),
-            MediatorAffirmationStatus::Pending
-        )
-    });
-    mediators_affirmed.iter().for_each(|identity_id| {
-        match InstructionMediatorsAffirmations::<TestStorage>::get(instruction_id, identity_id) {
-            MediatorAffirmationStatus::Pending | MediatorAffirmationStatus::Unknown => {
-                panic!("unexpected mediator affirmation status")
-            }
-            MediatorAffirmationStatus::Affirmed { .. } => {}
-        }
-    });
-}
+            InstructionHashLocks::<TestStorage>::get(instruction_id),
+            Some(hash_lock)
+        );
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
 
-#[track_caller]
-fn assert_instruction_details(
-    instruction_id: InstructionId,
-    details: Instruction<Moment, BlockNumber>,
-) {
-    assert_eq!(Settlement::instruction_details(instruction_id), details);
-}
+        // The instruction must not be executed without revealing the preimage
+        next_block();
+        assert_instruction_status(instruction_id, InstructionStatus::Pending);
+        assert_noop!(
+            Settlement::execute_manual_instruction(
+                alice.origin(),
+                instruction_id,
+                None,
+                1,
+                0,
+                0,
+                None
+            ),
+            Error::InstructionIsHashLocked
+        );
+        assert_noop!(
+            Settlement::execute_with_preimage(
+                bob.origin(),
+                instruction_id,
+                b"wrong".to_vec(),
+                1,
+                0,
+                0,
+                None
+            ),
+            Error::InvalidPreimage
+        );
 
-#[track_caller]
-fn assert_instruction_status(
-    instruction_id: InstructionId,
-    status: InstructionStatus<BlockNumber>,
-) {
-    assert_eq!(Settlement::instruction_status(instruction_id), status);
+        assert_ok!(Settlement::execute_with_preimage(
+            bob.origin(),
+            instruction_id,
+            b"secret".to_vec(),
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_eq!(
+            InstructionHashLocks::<TestStorage>::get(instruction_id),
+            None
+        );
+        assert_instruction_status(
+            instruction_id,
+            InstructionStatus::Success(System::block_number()),
+        );
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 1_000);
+        assert_balance(&asset_id, &bob, 1_000);
+    });
 }
 
-#[track_caller]
-fn assert_balance(asset_id: &AssetId, user: &User, balance: Balance) {
-    assert_eq!(Asset::balance_of(asset_id, user.did), balance);
-}
+#[test]
+fn execute_hash_locked_legs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
 
-#[track_caller]
-fn assert_user_affirms(instruction_id: InstructionId, user: &User, status: AffirmationStatus) {
-    assert_eq!(
-        Settlement::user_affirmations(PortfolioId::default_portfolio(user.did), instruction_id),
-        status
-    );
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let instruction_id = Settlement::instruction_counter();
+        let legs = vec![
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 1_000,
+            },
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 500,
+            },
+        ];
 
-    let affirms_received_status = match status {
-        AffirmationStatus::Pending => AffirmationStatus::Unknown,
-        AffirmationStatus::Affirmed => AffirmationStatus::Affirmed,
-        _ => return,
-    };
+        assert_ok!(Settlement::add_instruction_with_hash_lock(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+            sample_hash_lock(b"secret", System::block_number() + 10),
+            [LegId(0)].into(),
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        next_block();
+        assert_instruction_status(instruction_id, InstructionStatus::Pending);
 
-    assert_eq!(
-        Settlement::affirms_received(instruction_id, PortfolioId::default_portfolio(user.did)),
-        affirms_received_status
-    );
-}
+        // Only the hash locked leg is executed when the preimage is revealed
+        assert_ok!(Settlement::execute_with_preimage(
+            bob.origin(),
+            instruction_id,
+            b"secret".to_vec(),
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_instruction_status(instruction_id, InstructionStatus::Pending);
+        assert_eq!(InstructionLegs::get(instruction_id, LegId(0)), None);
+        assert_balance(&asset_id, &bob, 1_000);
+        assert_locked_assets(&asset_id, &alice, 500);
 
-#[track_caller]
-fn assert_leg_status(instruction_id: InstructionId, leg: LegId, status: LegStatus<AccountId>) {
-    assert_eq!(
-        Settlement::instruction_leg_status(instruction_id, leg),
-        status
-    );
+        // The remaining leg is settled according to the settlement type
+        next_block();
+        assert_instruction_status(
+            instruction_id,
+            InstructionStatus::Success(System::block_number()),
+        );
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 1_500);
+        assert_balance(&asset_id, &bob, 1_500);
+        assert_locked_assets(&asset_id, &alice, 0);
+    });
 }
 
-#[track_caller]
-fn assert_affirms_pending(instruction_id: InstructionId, pending: u64) {
-    assert_eq!(
-        Settlement::instruction_affirms_pending(instruction_id),
-        pending
-    );
-}
+#[test]
+fn expired_hash_lock_releases_assets() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
 
-#[track_caller]
-fn assert_locked_assets(asset_id: &AssetId, user: &User, num_of_assets: Balance) {
-    assert_eq!(
-        Portfolio::locked_assets(PortfolioId::default_portfolio(user.did), asset_id),
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let instruction_id = Settlement::instruction_counter();
+        let legs = vec![Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            amount: 1_000,
+        }];
+        let expiry = System::block_number() + 10;
+
+        assert_ok!(Settlement::add_instruction_with_hash_lock(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleOnAffirmation,
+            None,
+            None,
+            legs,
+            None,
+            sample_hash_lock(b"secret", expiry),
+            [LegId(0)].into(),
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_locked_assets(&asset_id, &alice, 1_000);
+
+        fast_forward_to_block(expiry);
+        assert_eq!(
+            InstructionHashLocks::<TestStorage>::get(instruction_id),
+            None
+        );
+        assert_instruction_status(instruction_id, InstructionStatus::Rejected(expiry));
+        assert_locked_assets(&asset_id, &alice, 0);
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT);
+        assert_noop!(
+            Settlement::execute_with_preimage(
+                bob.origin(),
+                instruction_id,
+                b"secret".to_vec(),
+                1,
+                0,
+                0,
+                None
+            ),
+            Error::InstructionNotHashLocked
+        );
+    });
+}
+
+#[test]
+fn amend_instruction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let dave = User::new(AccountKeyring::Dave);
+        let alice = User::new(AccountKeyring::Alice);
+        let charlie = User::new(AccountKeyring::Charlie);
+
+        let (alice_asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let charlie_asset_id = create_and_issue_sample_asset(&charlie);
+        let instruction_id = Settlement::instruction_counter();
+        let legs = vec![
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id: alice_asset_id,
+                amount: 1_000,
+            },
+            Leg::Fungible {
+                sender: PortfolioId::default_portfolio(charlie.did),
+                receiver: PortfolioId::default_portfolio(dave.did),
+                asset_id: charlie_asset_id,
+                amount: 1_000,
+            },
+        ];
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            legs,
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        assert_affirm_instruction!(charlie.origin(), instruction_id, charlie.did);
+        assert_affirm_instruction!(dave.origin(), instruction_id, dave.did);
+
+        assert_noop!(
+            Settlement::amend_instruction(
+                bob.origin(),
+                instruction_id,
+                BTreeMap::from([(LegId(0), 500)]),
+                None
+            ),
+            Error::Unauthorized
+        );
+        assert_noop!(
+            Settlement::amend_instruction(
+                alice.origin(),
+                instruction_id,
+                BTreeMap::from([(LegId(0), 1_000)]),
+                None
+            ),
+            Error::InvalidLegAmendment
+        );
+        assert_ok!(Settlement::amend_instruction(
+            alice.origin(),
+            instruction_id,
+            BTreeMap::from([(LegId(0), 500)]),
+            None
+        ));
+
+        // Only the parties of the amended leg must affirm again
+        assert_eq!(Settlement::instruction_revision(instruction_id), 1);
+        assert_eq!(
+            Settlement::instruction_legs(instruction_id, LegId(0)),
+            Some(Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id: alice_asset_id,
+                amount: 500,
+            })
+        );
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Pending);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Pending);
+        assert_user_affirms(instruction_id, &charlie, AffirmationStatus::Affirmed);
+        assert_user_affirms(instruction_id, &dave, AffirmationStatus::Affirmed);
+        assert_affirms_pending(instruction_id, 2);
+        assert_locked_assets(&alice_asset_id, &alice, 0);
+        assert_locked_assets(&charlie_asset_id, &charlie, 1_000);
+
+        // Affirmation counts computed before the amendment are no longer valid
+        let stale_count = AffirmationCount::new(AssetCount::new(1, 0, 0), AssetCount::default(), 0);
+        assert_noop!(
+            Settlement::affirm_instruction_with_count(
+                alice.origin(),
+                instruction_id,
+                default_portfolio_btreeset(alice.did),
+                Some(stale_count)
+            ),
+            Error::InstructionRevisionMismatch
+        );
+        let affirmation_count = Settlement::affirmation_count(
+            instruction_id,
+            vec![PortfolioId::default_portfolio(alice.did)],
+        );
+        assert_eq!(affirmation_count.revision(), 1);
+        assert_ok!(Settlement::affirm_instruction_with_count(
+            alice.origin(),
+            instruction_id,
+            default_portfolio_btreeset(alice.did),
+            Some(affirmation_count)
+        ));
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        assert_locked_assets(&alice_asset_id, &alice, 500);
+
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            2,
+            0,
+            0,
+            None
+        ));
+        assert_balance(&alice_asset_id, &bob, 500);
+        assert_balance(&charlie_asset_id, &dave, 1_000);
+    });
+}
+
+#[test]
+fn partially_execute_instruction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let instruction_id = Settlement::instruction_counter();
+        let legs = vec![Leg::Fungible {
+            sender: PortfolioId::default_portfolio(alice.did),
+            receiver: PortfolioId::default_portfolio(bob.did),
+            asset_id,
+            amount: 1_000,
+        }];
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            legs,
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+
+        assert_noop!(
+            Settlement::partially_execute_instruction(
+                alice.origin(),
+                instruction_id,
+                BTreeMap::from([(LegId(0), 400)]),
+                None
+            ),
+            Error::NotAllAffirmationsHaveBeenReceived
+        );
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        assert_noop!(
+            Settlement::partially_execute_instruction(
+                alice.origin(),
+                instruction_id,
+                BTreeMap::from([(LegId(0), 1_000)]),
+                None
+            ),
+            Error::InvalidPartialExecution
+        );
+        assert_ok!(Settlement::partially_execute_instruction(
+            alice.origin(),
+            instruction_id,
+            BTreeMap::from([(LegId(0), 400)]),
+            None
+        ));
+
+        // The remaining amount is still locked and pending
+        assert_instruction_status(instruction_id, InstructionStatus::Pending);
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 400);
+        assert_balance(&asset_id, &bob, 400);
+        assert_locked_assets(&asset_id, &alice, 600);
+        assert_user_affirms(instruction_id, &alice, AffirmationStatus::Affirmed);
+        assert_user_affirms(instruction_id, &bob, AffirmationStatus::Affirmed);
+
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_instruction_status(
+            instruction_id,
+            InstructionStatus::Success(System::block_number()),
+        );
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 1_000);
+        assert_balance(&asset_id, &bob, 1_000);
+        assert_locked_assets(&asset_id, &alice, 0);
+    });
+}
+
+#[test]
+fn execute_netted_instructions() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let charlie = User::new(AccountKeyring::Charlie);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let add_and_affirm = |sender: &User, receiver: &User, amount: Balance| {
+            let instruction_id = Settlement::instruction_counter();
+            assert_ok!(Settlement::add_instruction(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleManual(System::block_number()),
+                None,
+                None,
+                vec![Leg::Fungible {
+                    sender: PortfolioId::default_portfolio(sender.did),
+                    receiver: PortfolioId::default_portfolio(receiver.did),
+                    asset_id,
+                    amount,
+                }],
+                None,
+            ));
+            assert_affirm_instruction!(sender.origin(), instruction_id, sender.did);
+            assert_affirm_instruction!(receiver.origin(), instruction_id, receiver.did);
+            instruction_id
+        };
+
+        // Bob needs some tokens to send back to alice
+        let first_id = add_and_affirm(&alice, &bob, 500);
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            first_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+
+        let alice_to_bob = add_and_affirm(&alice, &bob, 1_000);
+        let bob_to_alice = add_and_affirm(&bob, &alice, 400);
+        let instruction_ids: BoundedBTreeSet<_, _> = [alice_to_bob, bob_to_alice]
+            .into_iter()
+            .try_collect()
+            .unwrap();
+
+        assert_noop!(
+            Settlement::execute_netted_instructions(
+                bob.origin(),
+                venue_id.unwrap(),
+                instruction_ids.clone(),
+                AssetCount::new(2, 0, 0),
+                None
+            ),
+            Error::Unauthorized
+        );
+        assert_noop!(
+            Settlement::execute_netted_instructions(
+                alice.origin(),
+                venue_id.unwrap(),
+                instruction_ids.clone(),
+                AssetCount::new(1, 0, 0),
+                None
+            ),
+            Error::NumberOfFungibleTransfersUnderestimated
+        );
+
+        // An instruction from another venue can't be netted
+        let other_venue = Settlement::venue_counter();
+        assert_ok!(Settlement::create_venue(
+            charlie.origin(),
+            VenueDetails::default(),
+            vec![],
+            VenueType::Other
+        ));
+        let other_instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            charlie.origin(),
+            Some(other_venue),
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            vec![Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 1,
+            }],
+            None,
+        ));
+        let mixed_ids: BoundedBTreeSet<_, _> = [alice_to_bob, other_instruction_id]
+            .into_iter()
+            .try_collect()
+            .unwrap();
+        assert_noop!(
+            Settlement::execute_netted_instructions(
+                alice.origin(),
+                venue_id.unwrap(),
+                mixed_ids,
+                AssetCount::new(2, 0, 0),
+                None
+            ),
+            Error::InstructionNotInVenue
+        );
+
+        assert_ok!(Settlement::execute_netted_instructions(
+            alice.origin(),
+            venue_id.unwrap(),
+            instruction_ids,
+            AssetCount::new(2, 0, 0),
+            None
+        ));
+        for instruction_id in [alice_to_bob, bob_to_alice] {
+            assert_instruction_status(
+                instruction_id,
+                InstructionStatus::Success(System::block_number()),
+            );
+        }
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 1_100);
+        assert_balance(&asset_id, &bob, 1_100);
+        assert_locked_assets(&asset_id, &alice, 0);
+        assert_locked_assets(&asset_id, &bob, 0);
+
+        // Each netted leg is identified by its own event
+        let system_events = System::events();
+        for instruction_id in [alice_to_bob, bob_to_alice] {
+            assert!(system_events.iter().any(|record| record.event
+                == super::storage::EventTest::Settlement(RawEvent::FungibleLegNetted(
+                    alice.did,
+                    instruction_id,
+                    LegId(0)
+                ))));
+        }
+    });
+}
+
+#[test]
+fn netted_instructions_enforce_statistics_restrictions() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        assert_ok!(Statistics::set_active_asset_stats(
+            alice.origin(),
+            asset_id,
+            [StatType {
+                operation_type: StatOpType::Balance,
+                claim_issuer: None,
+            }]
+            .into(),
+        ));
+        // Bob can't hold more than 10% of the supply
+        assert_ok!(Statistics::set_asset_transfer_compliance(
+            alice.origin(),
+            asset_id,
+            [TransferCondition::MaxInvestorOwnership(
+                Permill::from_percent(10)
+            )]
+            .into(),
+        ));
+
+        // Each instruction respects the restriction on its own, but not once the other one is settled
+        let mut instruction_ids = BTreeSet::new();
+        for _ in 0..2 {
+            let instruction_id = Settlement::instruction_counter();
+            assert_ok!(Settlement::add_instruction(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleManual(System::block_number()),
+                None,
+                None,
+                vec![Leg::Fungible {
+                    sender: PortfolioId::default_portfolio(alice.did),
+                    receiver: PortfolioId::default_portfolio(bob.did),
+                    asset_id,
+                    amount: ISSUE_AMOUNT / 100 * 8,
+                }],
+                None,
+            ));
+            assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+            assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+            instruction_ids.insert(instruction_id);
+        }
+
+        assert_noop!(
+            Settlement::execute_netted_instructions(
+                alice.origin(),
+                venue_id.unwrap(),
+                instruction_ids.try_into().unwrap(),
+                AssetCount::new(2, 0, 0),
+                None
+            ),
+            StatisticsError::InvalidTransferStatisticsFailure
+        );
+    });
+}
+
+/// Asserts the storage has been updated after adding an instruction.
+/// While each portfolio in `portfolios_pending_approval` must have a pending `AffirmationStatus`, each portfolio in `portfolios_pre_approved`
+/// must have an affirmed status. The number of pending affirmations must be equal to the number of portfolios in `portfolios_pending_approval` + the number of offchain legs,
+/// all legs must have been included in `InstructionLegs` and `InstructionMemos` must be equal to `instruction_memo`.
+#[test]
+fn execute_instruction_with_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let charlie = User::new(AccountKeyring::Charlie);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let beneficiary = PortfolioId::default_portfolio(charlie.did);
+        assert_ok!(Asset::set_transfer_fee(
+            alice.origin(),
+            asset_id,
+            TransferFee {
+                amount: TransferFeeAmount::BasisPoints(150),
+                currency: None,
+                beneficiary,
+            }
+        ));
+
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            vec![Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 1_000,
+            }],
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+
+        // 1.5% of 1_000 rounded down
+        let expected_fee = LegTransferFee {
+            leg_id: LegId(0),
+            payer: PortfolioId::default_portfolio(alice.did),
+            beneficiary,
+            asset_id,
+            amount: 15,
+        };
+        assert_eq!(
+            Settlement::instruction_transfer_fees(&instruction_id),
+            vec![expected_fee]
+        );
+        assert!(Settlement::execute_instruction_report(
+            &instruction_id,
+            &mut WeightMeter::max_limit_no_minimum()
+        )
+        .is_empty());
+
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_instruction_status(
+            instruction_id,
+            InstructionStatus::Success(System::block_number()),
+        );
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 1_015);
+        assert_balance(&asset_id, &bob, 1_000);
+        assert_balance(&asset_id, &charlie, 15);
+    });
+}
+
+#[test]
+fn execute_instruction_with_unpaid_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let currency = create_and_issue_sample_asset(&alice);
+        assert_ok!(Asset::set_transfer_fee(
+            alice.origin(),
+            asset_id,
+            TransferFee {
+                amount: TransferFeeAmount::Flat(50),
+                currency: Some(currency),
+                beneficiary: PortfolioId::default_portfolio(alice.did),
+            }
+        ));
+
+        let add_and_affirm = |sender: &User, receiver: &User| {
+            let instruction_id = Settlement::instruction_counter();
+            assert_ok!(Settlement::add_instruction(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleManual(System::block_number()),
+                None,
+                None,
+                vec![Leg::Fungible {
+                    sender: PortfolioId::default_portfolio(sender.did),
+                    receiver: PortfolioId::default_portfolio(receiver.did),
+                    asset_id,
+                    amount: 100,
+                }],
+                None,
+            ));
+            assert_affirm_instruction!(sender.origin(), instruction_id, sender.did);
+            assert_affirm_instruction!(receiver.origin(), instruction_id, receiver.did);
+            instruction_id
+        };
+
+        // No fee is charged when the sender is the beneficiary
+        let first_id = add_and_affirm(&alice, &bob);
+        assert!(Settlement::instruction_transfer_fees(&first_id).is_empty());
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            first_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_balance(&currency, &alice, ISSUE_AMOUNT);
+
+        // Bob doesn't hold the fee currency, so the fee can't be reserved when affirming
+        let second_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            vec![Leg::Fungible {
+                sender: PortfolioId::default_portfolio(bob.did),
+                receiver: PortfolioId::default_portfolio(alice.did),
+                asset_id,
+                amount: 100,
+            }],
+            None,
+        ));
+        assert_noop!(
+            Settlement::affirm_instruction(
+                bob.origin(),
+                second_id,
+                default_portfolio_btreeset(bob.did)
+            ),
+            PortfolioError::InsufficientPortfolioBalance
+        );
+        assert!(Settlement::instruction_transfer_fees(&second_id).is_empty());
+        assert_balance(&asset_id, &bob, 100);
+    });
+}
+
+#[test]
+fn partially_execute_instruction_with_flat_transfer_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+        let charlie = User::new(AccountKeyring::Charlie);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        assert_ok!(Asset::set_transfer_fee(
+            alice.origin(),
+            asset_id,
+            TransferFee {
+                amount: TransferFeeAmount::Flat(50),
+                currency: None,
+                beneficiary: PortfolioId::default_portfolio(charlie.did),
+            }
+        ));
+
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            vec![Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 1_000,
+            }],
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+        // The fee is reserved when the sender affirms
+        assert_locked_assets(&asset_id, &alice, 1_050);
+
+        // The flat fee is charged on the first partial execution only
+        assert_ok!(Settlement::partially_execute_instruction(
+            alice.origin(),
+            instruction_id,
+            BTreeMap::from([(LegId(0), 400)]),
+            None
+        ));
+        assert_balance(&asset_id, &charlie, 50);
+        assert_locked_assets(&asset_id, &alice, 600);
+        assert!(Settlement::instruction_transfer_fees(&instruction_id).is_empty());
+
+        assert_ok!(Settlement::partially_execute_instruction(
+            alice.origin(),
+            instruction_id,
+            BTreeMap::from([(LegId(0), 300)]),
+            None
+        ));
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_instruction_status(
+            instruction_id,
+            InstructionStatus::Success(System::block_number()),
+        );
+        assert_balance(&asset_id, &alice, ISSUE_AMOUNT - 1_050);
+        assert_balance(&asset_id, &bob, 1_000);
+        assert_balance(&asset_id, &charlie, 50);
+        assert_locked_assets(&asset_id, &alice, 0);
+    });
+}
+
+#[test]
+fn execute_instruction_with_paused_settlement() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let instruction_id = Settlement::instruction_counter();
+        assert_ok!(Settlement::add_instruction(
+            alice.origin(),
+            venue_id,
+            SettlementType::SettleManual(System::block_number()),
+            None,
+            None,
+            vec![Leg::Fungible {
+                sender: PortfolioId::default_portfolio(alice.did),
+                receiver: PortfolioId::default_portfolio(bob.did),
+                asset_id,
+                amount: 1_000,
+            }],
+            None,
+        ));
+        assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+        assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+
+        let settlement = AssetOperations::from_iter([AssetOperation::Settlement]);
+        assert_ok!(Asset::pause_asset_operations(
+            alice.origin(),
+            asset_id,
+            settlement
+        ));
+        assert_eq!(
+            Settlement::execute_instruction_report(
+                &instruction_id,
+                &mut WeightMeter::max_limit_no_minimum()
+            ),
+            vec![AssetError::AssetOperationPaused.into()]
+        );
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Settlement::execute_manual_instruction(
+                alice.origin(),
+                instruction_id,
+                None,
+                1,
+                0,
+                0,
+                None
+            ),
+            AssetError::AssetOperationPaused
+        ));
+
+        assert_ok!(Asset::unpause_asset_operations(
+            alice.origin(),
+            asset_id,
+            settlement
+        ));
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_balance(&asset_id, &bob, 1_000);
+    });
+}
+
+#[test]
+fn execute_instruction_with_paused_venue_settlement() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bob = User::new(AccountKeyring::Bob);
+        let alice = User::new(AccountKeyring::Alice);
+
+        let (asset_id, venue_id) = create_and_issue_sample_asset_with_venue(&alice);
+        let add_and_affirm = |venue_id: Option<VenueId>| {
+            let instruction_id = Settlement::instruction_counter();
+            assert_ok!(Settlement::add_instruction(
+                alice.origin(),
+                venue_id,
+                SettlementType::SettleManual(System::block_number()),
+                None,
+                None,
+                vec![Leg::Fungible {
+                    sender: PortfolioId::default_portfolio(alice.did),
+                    receiver: PortfolioId::default_portfolio(bob.did),
+                    asset_id,
+                    amount: 1_000,
+                }],
+                None,
+            ));
+            assert_affirm_instruction!(alice.origin(), instruction_id, alice.did);
+            assert_affirm_instruction!(bob.origin(), instruction_id, bob.did);
+            instruction_id
+        };
+        let venue_instruction_id = add_and_affirm(venue_id);
+        let instruction_id = add_and_affirm(None);
+
+        let venue_id = venue_id.unwrap();
+        assert_ok!(Asset::pause_venue_settlement(
+            alice.origin(),
+            asset_id,
+            venue_id
+        ));
+        assert_eq!(Asset::paused_settlement_venues(&asset_id), vec![venue_id]);
+        assert_storage_noop!(assert_err_ignore_postinfo!(
+            Settlement::execute_manual_instruction(
+                alice.origin(),
+                venue_instruction_id,
+                None,
+                1,
+                0,
+                0,
+                None
+            ),
+            AssetError::AssetOperationPaused
+        ));
+
+        // Instructions that are not settled through the paused venue are not affected
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            instruction_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_balance(&asset_id, &bob, 1_000);
+
+        assert_ok!(Asset::unpause_venue_settlement(
+            alice.origin(),
+            asset_id,
+            venue_id
+        ));
+        assert!(Asset::paused_settlement_venues(&asset_id).is_empty());
+        assert_noop!(
+            Asset::unpause_venue_settlement(alice.origin(), asset_id, venue_id),
+            AssetError::VenueSettlementNotPaused
+        );
+        assert_ok!(Settlement::execute_manual_instruction(
+            alice.origin(),
+            venue_instruction_id,
+            None,
+            1,
+            0,
+            0,
+            None
+        ));
+        assert_balance(&asset_id, &bob, 2_000);
+    });
+}
+
+fn assert_add_instruction_storage(
+    instruction_id: &InstructionId,
+    portfolios_pending_approval: &BTreeSet<PortfolioId>,
+    portfolios_pre_approved: &BTreeSet<PortfolioId>,
+    offchain_legs: &BTreeSet<LegId>,
+    instruction_memo: Option<Memo>,
+    legs: &[Leg],
+    mediators_pending_approval: &BTreeSet<IdentityId>,
+    mediators_affirmed: &BTreeSet<IdentityId>,
+) {
+    portfolios_pending_approval.iter().for_each(|portfolio_id| {
+        assert_eq!(
+            UserAffirmations::get(portfolio_id, instruction_id),
+            AffirmationStatus::Pending
+        )
+    });
+    portfolios_pre_approved.iter().for_each(|portfolio_id| {
+        assert_eq!(
+            UserAffirmations::get(portfolio_id, instruction_id),
+            AffirmationStatus::Affirmed
+        );
+        assert_eq!(
+            AffirmsReceived::get(instruction_id, portfolio_id),
+            AffirmationStatus::Affirmed
+        )
+    });
+    offchain_legs.iter().for_each(|leg_id| {
+        assert_eq!(
+            OffChainAffirmations::get(instruction_id, leg_id),
+            AffirmationStatus::Pending
+        );
+    });
+    assert_eq!(
+        InstructionAffirmsPending::get(instruction_id),
+        portfolios_pending_approval.len() as u64
+            + offchain_legs.len() as u64
+            + mediators_pending_approval.len() as u64
+    );
+
+    assert_eq!(InstructionMemos::get(instruction_id), instruction_memo);
+
+    (0..legs.len()).for_each(|i| {
+        assert_eq!(
+            InstructionLegs::get(instruction_id, LegId(i as u64)),
+            Some(legs[i].clone())
+        )
+    });
+
+    mediators_pending_approval.iter().for_each(|identity_id| {
+        assert_eq!(
+            InstructionMediatorsAffirmations::<TestStorage>::get(instruction_id, identity_id),
+            MediatorAffirmationStatus::Pending
+        )
+    });
+    mediators_affirmed.iter().for_each(|identity_id| {
+        match InstructionMediatorsAffirmations::<TestStorage>::get(instruction_id, identity_id) {
+            MediatorAffirmationStatus::Pending | MediatorAffirmationStatus::Unknown => {
+                panic!("unexpected mediator affirmation status")
+            }
+            MediatorAffirmationStatus::Affirmed { .. } => {}
+        }
+    });
+}
+
+#[track_caller]
+fn assert_instruction_details(
+    instruction_id: InstructionId,
+    details: Instruction<Moment, BlockNumber>,
+) {
+    assert_eq!(Settlement::instruction_details(instruction_id), details);
+}
+
+#[track_caller]
+fn assert_instruction_status(
+    instruction_id: InstructionId,
+    status: InstructionStatus<BlockNumber>,
+) {
+    assert_eq!(Settlement::instruction_status(instruction_id), status);
+}
+
+#[track_caller]
+fn assert_balance(asset_id: &AssetId, user: &User, balance: Balance) {
+    assert_eq!(Asset::balance_of(asset_id, user.did), balance);
+}
+
+#[track_caller]
+fn assert_user_affirms(instruction_id: InstructionId, user: &User, status: AffirmationStatus) {
+    assert_eq!(
+        Settlement::user_affirmations(PortfolioId::default_portfolio(user.did), instruction_id),
+        status
+    );
+
+    let affirms_received_status = match status {
+        AffirmationStatus::Pending => AffirmationStatus::Unknown,
+        AffirmationStatus::Affirmed => AffirmationStatus::Affirmed,
+        _ => return,
+    };
+
+    assert_eq!(
+        Settlement::affirms_received(instruction_id, PortfolioId::default_portfolio(user.did)),
+        affirms_received_status
+    );
+}
+
+#[track_caller]
+fn assert_leg_status(instruction_id: InstructionId, leg: LegId, status: LegStatus<AccountId>) {
+    assert_eq!(
+        Settlement::instruction_leg_status(instruction_id, leg),
+        status
+    );
+}
+
+#[track_caller]
+fn assert_affirms_pending(instruction_id: InstructionId, pending: u64) {
+    assert_eq!(
+        Settlement::instruction_affirms_pending(instruction_id),
+        pending
+    );
+}
+
+#[track_caller]
+fn assert_locked_assets(asset_id: &AssetId, user: &User, num_of_assets: Balance) {
+    assert_eq!(
+        Portfolio::locked_assets(PortfolioId::default_portfolio(user.did), asset_id),
         num_of_assets
     );
 }
+
+fn sample_hash_lock(preimage: &[u8], expiry: BlockNumber) -> HashLock<BlockNumber> {
+    HashLock {
+        algorithm: HashLockAlgorithm::Sha256,
+        hash: HashLockAlgorithm::Sha256.hash(preimage),
+        expiry,
+    }
+}
@@ -151,6 +151,8 @@ parameter_types! {
     pub static Offset: BlockNumber = 0;
 
     pub const MaxLen: u32 = 256;
+    pub const PipConvictionLockPeriod: BlockNumber = 10;
+    pub const PipMaxDelegators: u32 = 3;
     pub const MaxLocks: u32 = 1024;
     pub const BlockHashCount: u64 = 250;
     pub const MaximumBlockWeight: Weight = Weight::from_ref_time(1024);
@@ -249,6 +251,8 @@ impl pallet_pips::Config for Test {
     type WeightInfo = polymesh_weights::pallet_pips::SubstrateWeight;
     type Scheduler = Scheduler;
     type SchedulerCall = RuntimeCall;
+    type ConvictionLockPeriod = PipConvictionLockPeriod;
+    type MaxDelegators = PipMaxDelegators;
 }
 
 impl pallet_treasury::Config for Test {
@@ -365,6 +369,9 @@ impl polymesh_common_utilities::transaction_payment::CddAndFeeDetails<AccountId,
     fn get_payer_from_context() -> Option<AccountId> {
         None
     }
+    fn get_max_fee(_: &Call) -> Option<Balance> {
+        None
+    }
 }
 
 impl SubsidiserTrait<AccountId> for Test {
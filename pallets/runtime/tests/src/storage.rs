@@ -41,6 +41,8 @@ use pallet_committee as committee;
 use pallet_corporate_actions as corporate_actions;
 use pallet_corporate_actions::ballot as corporate_ballots;
 use pallet_corporate_actions::distribution as capital_distributions;
+use pallet_corporate_actions::fixed_income as fixed_incomes;
+use pallet_corporate_actions::reorganization as corporate_reorganizations;
 use pallet_group as group;
 use pallet_identity as identity;
 use pallet_multisig as multisig;
@@ -114,7 +116,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_version: 1,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 7,
+    transaction_version: 8,
     state_version: 1,
 };
 
@@ -223,6 +225,11 @@ parameter_types! {
 
     // Multisig
     pub const MaxMultiSigSigners: u32 = 50;
+    pub const MaxMultiSigCallPolicies: u32 = 16;
+
+    // Pips
+    pub const PipConvictionLockPeriod: BlockNumber = 10;
+    pub const PipMaxDelegators: u32 = 3;
 
     pub const ImOnlineUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
     pub const MaxSetIdSessionEntries: u32 = BondingDuration::get() * SessionsPerEra::get();
@@ -234,12 +241,18 @@ parameter_types! {
         BondingDuration::get() as u64 * SessionsPerEra::get() as u64 * EpochDuration::get();
     pub const MaxNumberOfCollectionKeys: u8 = u8::MAX;
     pub const MaxNumberOfFungibleMoves: u32 = 10;
+    pub const MaxEnvelopeLifetime: BlockNumber = 1000;
+    pub const MaxExpiredEnvelopesPerBlock: u32 = 100;
     pub const MaxNumberOfNFTsMoves: u32 = 100;
     pub const MaxNumberOfOffChainAssets: u32 = 10;
     pub const MaxNumberOfPortfolios: u32 = (10 + 100) * 2;
     pub const MaxNumberOfVenueSigners: u32 = 50;
     pub const MaxInstructionMediators: u32 = 4;
+    pub const MaxNumberOfNettedInstructions: u32 = 100;
     pub const MaxAssetMediators: u32 = 4;
+    pub const IdentityRecoveryVetoPeriod: u64 = 7 * 24 * 60 * 60 * 1000;
+    pub const MaxIdentityRecoveryAssets: u32 = 10;
+    pub const MaxVestingSchedules: u32 = 4;
     pub const MaxGivenAuths: u32 = 1024;
     pub const MigrationSignedDepositPerItem: Balance = 0;
     pub const MigrationSignedDepositBase: Balance = 0;
@@ -335,6 +348,9 @@ frame_support::construct_runtime!(
 
         Nft: pallet_nft::{Pallet, Call, Storage, Event} = 49,
 
+        CorporateReorganization: corporate_reorganizations::{Pallet, Call, Storage, Event} = 51,
+        FixedIncome: fixed_incomes::{Pallet, Call, Storage, Event} = 52,
+
         TestUtils: pallet_test_utils::{Pallet, Call, Storage, Event<T> } = 200,
 
         // Testing only.
@@ -466,10 +482,17 @@ parameter_types! {
     pub const BlockRangeForTimelock: BlockNumber = 1000;
     pub const MaxTargetIds: u32 = 10;
     pub const MaxDidWhts: u32 = 10;
+    /// Thirty days, in milliseconds.
+    pub const MaxReorganizationPeriod: u64 = 30 * 24 * 60 * 60 * 1000;
+    pub const MaxReorganizationBatch: u32 = 10;
     pub const MinimumPeriod: u64 = 3;
 
     pub const MaxStatsPerAsset: u32 = 10 + BENCHMARK_MAX_INCREASE;
     pub const MaxTransferConditionsPerAsset: u32 = 4 + BENCHMARK_MAX_INCREASE;
+    /// One day, in milliseconds.
+    pub const VolumePeriodLength: u64 = 24 * 60 * 60 * 1000;
+    pub const MaxVolumePeriods: u32 = 365;
+    pub const MaxAcquisitionLots: u32 = 100;
 
     pub const MaxConditionComplexity: u32 = 50;
     pub const MaxDefaultTrustedClaimIssuers: usize = 10;
@@ -526,6 +549,14 @@ impl CddAndFeeDetails<AccountId, RuntimeCall> for TestStorage {
     fn get_payer_from_context() -> Option<AccountId> {
         Context::current_payer::<Identity>()
     }
+    fn get_max_fee(call: &RuntimeCall) -> Option<Balance> {
+        match call {
+            RuntimeCall::Utility(pallet_utility::Call::sponsored_call { envelope, .. }) => {
+                Some(envelope.max_fee)
+            }
+            _ => None,
+        }
+    }
 }
 
 pub struct WeightToFee;
@@ -704,6 +735,8 @@ impl pips::Config for TestStorage {
     type WeightInfo = polymesh_weights::pallet_pips::SubstrateWeight;
     type Scheduler = Scheduler;
     type SchedulerCall = RuntimeCall;
+    type ConvictionLockPeriod = PipConvictionLockPeriod;
+    type MaxDelegators = PipMaxDelegators;
 }
 
 impl pallet_test_utils::Config for TestStorage {
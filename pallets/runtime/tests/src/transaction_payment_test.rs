@@ -1,16 +1,23 @@
+use super::asset_pallet::setup::{create_and_issue_sample_asset, ISSUE_AMOUNT};
 use super::ext_builder::ExtBuilder;
-use super::storage::{RuntimeCall, TestStorage};
+use super::storage::{root, RuntimeCall, TestStorage, User};
 use codec::Encode;
 use frame_support::{
-    dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, Weight},
+    assert_noop, assert_ok,
+    dispatch::{
+        DispatchClass, DispatchError, DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo, Weight,
+    },
     traits::Currency,
     weights::WeightToFee,
 };
 use pallet_balances::Call as BalancesCall;
 use pallet_transaction_payment::{ChargeTransactionPayment, Multiplier, RuntimeDispatchInfo};
-use polymesh_primitives::AccountId;
-use polymesh_primitives::TransactionError;
+use pallet_utility::SponsorEnvelope;
+use polymesh_common_utilities::constants::currency::ONE_UNIT;
+use polymesh_primitives::{AccountId, PortfolioId, TransactionError};
 use sp_arithmetic::traits::One;
+use sp_core::sr25519::Signature;
+use sp_io::hashing::blake2_256;
 use sp_keyring::AccountKeyring;
 use sp_runtime::{
     testing::TestXt,
@@ -26,7 +33,11 @@ fn call() -> <TestStorage as frame_system::Config>::RuntimeCall {
     })
 }
 
+type Asset = pallet_asset::Module<TestStorage>;
+type AssetError = pallet_asset::Error<TestStorage>;
 type Balances = pallet_balances::Module<TestStorage>;
+type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
 type System = frame_system::Pallet<TestStorage>;
 type TransactionPayment = pallet_transaction_payment::Module<TestStorage>;
 
@@ -552,3 +563,190 @@ fn operational_tx_with_tip_ext(cdd: AccountId, gc: AccountId) {
         .pre_dispatch(&cdd, &call, &operational_info, len)
         .is_ok());
 }
+
+#[test]
+fn signed_extension_transaction_payment_in_asset() {
+    ExtBuilder::default()
+        .monied(true)
+        .transaction_fees(5, 1, 1)
+        .build()
+        .execute_with(|| {
+            let alice = User::new(AccountKeyring::Alice);
+            let bob = User::new(AccountKeyring::Bob);
+            let asset_id = create_and_issue_sample_asset(&alice);
+            // Two units of the asset are worth one POLYX.
+            let rate = 2 * ONE_UNIT;
+
+            assert_noop!(
+                Asset::set_fee_asset_rate(alice.origin(), asset_id, Some(rate)),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Asset::set_fee_asset(alice.origin(), Some(asset_id)),
+                AssetError::FeeAssetNotApproved
+            );
+            assert_ok!(Asset::set_fee_asset_rate(root(), asset_id, Some(rate)));
+            assert_ok!(Asset::set_fee_asset(alice.origin(), Some(asset_id)));
+
+            // Fees are paid in POLYX until a collector is set.
+            assert_eq!(
+                TransactionPayment::fee_asset_of(&alice.acc(), &call()),
+                None
+            );
+            let collector = PortfolioId::default_portfolio(bob.did);
+            assert_ok!(Asset::set_fee_collector(root(), collector));
+            assert_eq!(
+                TransactionPayment::fee_asset_of(&alice.acc(), &call()),
+                Some((asset_id, rate))
+            );
+
+            let len = 10;
+            let polyx_balance = Balances::free_balance(&alice.acc());
+            let collector_polyx_balance = Balances::free_balance(&bob.acc());
+            let fee = TransactionPayment::compute_fee(len as u32, &info_from_weight(100), 0);
+            let pre = ChargeTransactionPayment::<TestStorage>::from(0)
+                .pre_dispatch(&alice.acc(), &call(), &info_from_weight(100), len)
+                .unwrap();
+            // The fee is paid in POLYX by the collector, and locked in its portfolio until corrected.
+            assert_eq!(Asset::balance_of(&asset_id, bob.did), fee * 2);
+            assert_eq!(Portfolio::locked_assets(collector, asset_id), fee * 2);
+            assert_eq!(
+                Balances::free_balance(&bob.acc()),
+                collector_polyx_balance - fee
+            );
+
+            // The overpaid part of the fee is refunded in the asset, and in POLYX to the collector.
+            assert!(ChargeTransactionPayment::<TestStorage>::post_dispatch(
+                Some(pre),
+                &info_from_weight(100),
+                &post_info_from_weight(50),
+                len,
+                &Ok(())
+            )
+            .is_ok());
+            let actual_fee = TransactionPayment::compute_actual_fee(
+                len as u32,
+                &info_from_weight(100),
+                &post_info_from_weight(50),
+                0,
+            );
+            assert!(actual_fee < fee);
+            assert_eq!(Asset::balance_of(&asset_id, bob.did), actual_fee * 2);
+            assert_eq!(
+                Asset::balance_of(&asset_id, alice.did),
+                ISSUE_AMOUNT - actual_fee * 2
+            );
+            assert_eq!(Balances::free_balance(&alice.acc()), polyx_balance);
+            assert_eq!(Portfolio::locked_assets(collector, asset_id), 0);
+            assert_eq!(
+                Balances::free_balance(&bob.acc()),
+                collector_polyx_balance - actual_fee
+            );
+
+            // Opting out goes back to POLYX.
+            assert_ok!(Asset::set_fee_asset(alice.origin(), None));
+            assert_eq!(
+                TransactionPayment::fee_asset_of(&alice.acc(), &call()),
+                None
+            );
+        });
+}
+
+#[test]
+fn restricted_fee_asset() {
+    ExtBuilder::default()
+        .monied(true)
+        .transaction_fees(5, 1, 1)
+        .build()
+        .execute_with(|| {
+            let alice = User::new(AccountKeyring::Alice);
+            let bob = User::new(AccountKeyring::Bob);
+            let asset_id = create_and_issue_sample_asset(&alice);
+            let rate = 2 * ONE_UNIT;
+            assert_ok!(Asset::set_fee_collector(
+                root(),
+                PortfolioId::default_portfolio(bob.did)
+            ));
+
+            // Assets with compliance requirements can't be approved to pay fees.
+            assert_ok!(ComplianceManager::add_compliance_requirement(
+                alice.origin(),
+                asset_id,
+                vec![],
+                vec![]
+            ));
+            assert_noop!(
+                Asset::set_fee_asset_rate(root(), asset_id, Some(rate)),
+                AssetError::RestrictedFeeAsset
+            );
+
+            // Paused requirements are not evaluated.
+            assert_ok!(ComplianceManager::pause_asset_compliance(
+                alice.origin(),
+                asset_id
+            ));
+            assert_ok!(Asset::set_fee_asset_rate(root(), asset_id, Some(rate)));
+            assert_ok!(Asset::set_fee_asset(alice.origin(), Some(asset_id)));
+            assert_eq!(
+                TransactionPayment::fee_asset_of(&alice.acc(), &call()),
+                Some((asset_id, rate))
+            );
+
+            // Once the requirements are resumed, fees are paid in POLYX and the asset can't be opted in to.
+            assert_ok!(ComplianceManager::resume_asset_compliance(
+                alice.origin(),
+                asset_id
+            ));
+            assert_eq!(
+                TransactionPayment::fee_asset_of(&alice.acc(), &call()),
+                None
+            );
+            assert_noop!(
+                Asset::set_fee_asset(alice.origin(), Some(asset_id)),
+                AssetError::RestrictedFeeAsset
+            );
+        });
+}
+
+#[test]
+fn signed_extension_sponsored_fee_cap() {
+    ExtBuilder::default()
+        .monied(true)
+        .transaction_fees(5, 1, 1)
+        .build()
+        .execute_with(|| {
+            let alice = AccountKeyring::Alice.to_account_id();
+            let len = 10;
+            let fee = TransactionPayment::compute_fee(len as u32, &info_from_weight(100), 0);
+            let sponsored_call = |max_fee| {
+                RuntimeCall::Utility(pallet_utility::Call::sponsored_call {
+                    envelope: SponsorEnvelope {
+                        sponsor: AccountKeyring::Bob.to_account_id(),
+                        user: alice.clone(),
+                        call_hash: blake2_256(&call().encode()),
+                        max_fee,
+                        expiry: 10,
+                    },
+                    signature: Signature([0; 64]).into(),
+                    call: Box::new(call()),
+                })
+            };
+
+            assert_eq!(
+                ChargeTransactionPayment::<TestStorage>::from(0)
+                    .pre_dispatch(
+                        &alice,
+                        &sponsored_call(fee - 1),
+                        &info_from_weight(100),
+                        len
+                    )
+                    .map(|_| ()),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::Custom(TransactionError::SponsoredFeeTooHigh as u8)
+                ))
+            );
+            assert!(ChargeTransactionPayment::<TestStorage>::from(0)
+                .pre_dispatch(&alice, &sponsored_call(fee), &info_from_weight(100), len)
+                .is_ok());
+        });
+}
@@ -1,4 +1,5 @@
 use super::{
+    asset_test::set_timestamp,
     storage::{account_from, make_account, TestStorage, User},
     ExtBuilder,
 };
@@ -10,7 +11,8 @@ use pallet_external_agents::Event;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::{
     asset::AssetType, jurisdiction::CountryCode, statistics::*, transfer_compliance::*, AccountId,
-    Balance, Claim, ClaimType, IdentityId, PortfolioId, PortfolioKind, Scope, WeightMeter,
+    Balance, Claim, ClaimType, Fund, FundDescription, IdentityId, PortfolioId, PortfolioKind,
+    PortfolioName, PortfolioNumber, Scope, WeightMeter,
 };
 use sp_arithmetic::Permill;
 use sp_keyring::AccountKeyring;
@@ -19,6 +21,7 @@ use std::collections::{HashMap, HashSet};
 type Origin = <TestStorage as frame_system::Config>::RuntimeOrigin;
 type Identity = pallet_identity::Module<TestStorage>;
 type Asset = pallet_asset::Module<TestStorage>;
+type Portfolio = pallet_portfolio::Module<TestStorage>;
 type Statistics = pallet_statistics::Module<TestStorage>;
 type ComplianceManager = pallet_compliance_manager::Module<TestStorage>;
 type Error = pallet_statistics::Error<TestStorage>;
@@ -521,6 +524,9 @@ impl AssetTracker {
                 let cal_value = match operation_type {
                     StatOpType::Count => self.calculate_stat_count(claim_issuer, &key2) as u128,
                     StatOpType::Balance => self.calculate_stat_balance(claim_issuer, &key2),
+                    StatOpType::InvestorVolume
+                    | StatOpType::AssetVolume
+                    | StatOpType::AcquisitionLots => 0,
                 };
                 // Get stat from pallet.
                 let value = Statistics::asset_stats(key1, key2.clone());
@@ -584,6 +590,12 @@ impl AssetTracker {
                     println!("Balance[{:?}]: cal={:?}, stat={:?}", key2, cal_value, value);
                     assert_eq!(value, cal_value as u128);
                 }
+                (StatOpType::InvestorVolume | StatOpType::AssetVolume, _) => {
+                    // Volumes are stored per period in `AssetVolumeStats`.
+                }
+                (StatOpType::AcquisitionLots, _) => {
+                    // Lots are stored per portfolio in `PortfolioAcquisitionLots`.
+                }
             }
         }
     }
@@ -811,6 +823,213 @@ fn max_investor_ownership_rule_with_ext() {
     tracker.ensure_asset_stats();
 }
 
+#[test]
+fn max_investor_volume_rule() {
+    ExtBuilder::default()
+        .cdd_providers(vec![CDD_PROVIDER.to_account_id()])
+        .build()
+        .execute_with(max_investor_volume_rule_with_ext);
+}
+
+fn max_investor_volume_rule_with_ext() {
+    const DAY: u64 = 24 * 60 * 60 * 1000;
+    set_timestamp(10 * DAY);
+
+    // Create an asset.
+    let mut tracker = AssetTracker::new();
+    tracker.set_active_stats(vec![StatType {
+        operation_type: StatOpType::InvestorVolume,
+        claim_issuer: None,
+    }]);
+    tracker.mint(100_000);
+    let id = tracker.new_investor();
+    tracker.do_valid_transfer(tracker.owner_id, id, 50_000);
+
+    // Each investor can send at most 10_000 within a 3 days window.
+    let condition = TransferCondition::MaxInvestorVolume(3, 10_000);
+    tracker.set_transfer_conditions(vec![condition.clone()]);
+
+    let other_id = tracker.new_investor();
+    tracker.do_valid_transfer(id, other_id, 6_000);
+    set_timestamp(11 * DAY);
+    tracker.do_valid_transfer(id, other_id, 4_000);
+    tracker.ensure_invalid_transfer(id, other_id, 1);
+    // The volume of other investors is not affected.
+    tracker.do_valid_transfer(other_id, id, 10_000);
+
+    let mut weight_meter = WeightMeter::max_limit_no_minimum();
+    assert_eq!(
+        Statistics::transfer_restrictions_report(
+            tracker.asset_id,
+            &PortfolioId::default_portfolio(tracker.investor(id).did),
+            &tracker.investor(other_id).did,
+            1,
+            &mut weight_meter
+        )
+        .unwrap(),
+        vec![condition]
+    );
+
+    // The transfers of the first day leave the window.
+    set_timestamp(13 * DAY);
+    tracker.do_valid_transfer(id, other_id, 6_000);
+    tracker.ensure_invalid_transfer(id, other_id, 1);
+
+    // The window can't be empty or larger than `MaxVolumePeriods`.
+    for periods in [0, 366] {
+        assert_noop!(
+            Statistics::set_asset_transfer_compliance(
+                tracker.owner_origin(),
+                tracker.asset_id,
+                [TransferCondition::MaxInvestorVolume(periods, 10_000)].into(),
+            ),
+            Error::InvalidVolumeWindow
+        );
+    }
+}
+
+#[test]
+fn max_asset_volume_rule() {
+    ExtBuilder::default()
+        .cdd_providers(vec![CDD_PROVIDER.to_account_id()])
+        .build()
+        .execute_with(max_asset_volume_rule_with_ext);
+}
+
+fn max_asset_volume_rule_with_ext() {
+    const DAY: u64 = 24 * 60 * 60 * 1000;
+    set_timestamp(10 * DAY);
+
+    // Create an asset.
+    let mut tracker = AssetTracker::new();
+    tracker.set_active_stats(vec![StatType {
+        operation_type: StatOpType::AssetVolume,
+        claim_issuer: None,
+    }]);
+    tracker.set_transfer_conditions(vec![TransferCondition::MaxAssetVolume(1, 20_000)]);
+
+    // Mint is not restricted by transfer rules.
+    tracker.mint(100_000);
+
+    let first_id = tracker.new_investor();
+    let second_id = tracker.new_investor();
+    tracker.do_valid_transfer(tracker.owner_id, first_id, 15_000);
+    tracker.do_valid_transfer(first_id, second_id, 5_000);
+    tracker.ensure_invalid_transfer(tracker.owner_id, second_id, 1);
+
+    // Exempt senders are not restricted.
+    tracker.set_investors_exempt(&[tracker.owner_id], true);
+    tracker.do_valid_transfer(tracker.owner_id, second_id, 1);
+
+    // A new period starts a new window.
+    set_timestamp(11 * DAY);
+    tracker.do_valid_transfer(first_id, second_id, 10_000);
+}
+
+#[test]
+fn min_holding_period_rule() {
+    ExtBuilder::default()
+        .cdd_providers(vec![CDD_PROVIDER.to_account_id()])
+        .build()
+        .execute_with(min_holding_period_rule_with_ext);
+}
+
+fn min_holding_period_rule_with_ext() {
+    const DAY: u64 = 24 * 60 * 60 * 1000;
+    let start = 1_000;
+    set_timestamp(start);
+
+    // Create an asset.
+    let mut tracker = AssetTracker::new();
+    tracker.set_active_stats(vec![StatType {
+        operation_type: StatOpType::AcquisitionLots,
+        claim_issuer: None,
+    }]);
+    tracker.set_transfer_conditions(vec![TransferCondition::MinHoldingPeriod(DAY)]);
+    // The issuer is not restricted by the holding period.
+    tracker.set_investors_exempt(&[tracker.owner_id], true);
+    tracker.mint(100_000);
+
+    // Two lots are acquired half a day apart.
+    let id = tracker.new_investor();
+    let other_id = tracker.new_investor();
+    tracker.do_valid_transfer(tracker.owner_id, id, 1_000);
+    set_timestamp(start + DAY / 2);
+    tracker.do_valid_transfer(tracker.owner_id, id, 500);
+    tracker.ensure_invalid_transfer(id, other_id, 1);
+
+    // Only the first lot has been held for the minimum holding period.
+    set_timestamp(start + DAY);
+    tracker.ensure_invalid_transfer(id, other_id, 1_001);
+    tracker.do_valid_transfer(id, other_id, 1_000);
+    tracker.ensure_invalid_transfer(id, other_id, 1);
+
+    set_timestamp(start + DAY / 2 + DAY);
+    tracker.do_valid_transfer(id, other_id, 500);
+    let key1 = Stat1stKey {
+        asset_id: tracker.asset_id,
+        stat_type: TransferCondition::MinHoldingPeriod(DAY).get_stat_type(),
+    };
+    assert_eq!(
+        Statistics::portfolio_acquisition_lots(
+            key1,
+            PortfolioId::default_portfolio(tracker.investor(id).did)
+        ),
+        AcquisitionLots::default()
+    );
+
+    // The units received by the other investor must be held for another day.
+    tracker.ensure_invalid_transfer(other_id, id, 1);
+
+    // Lots are kept per portfolio and keep their acquisition time when moved between portfolios.
+    let other_did = tracker.investor(other_id).did;
+    let other_origin = tracker.investor(other_id).origin();
+    let default_portfolio = PortfolioId::default_portfolio(other_did);
+    let user_portfolio = PortfolioId::user_portfolio(other_did, PortfolioNumber(1));
+    assert_ok!(Portfolio::create_portfolio(
+        other_origin.clone(),
+        PortfolioName(b"Lots".to_vec())
+    ));
+    assert_ok!(Portfolio::move_portfolio_funds(
+        other_origin,
+        default_portfolio,
+        user_portfolio,
+        vec![Fund {
+            description: FundDescription::Fungible {
+                asset_id: tracker.asset_id,
+                amount: 1_200,
+            },
+            memo: None,
+        }]
+    ));
+    assert_eq!(
+        Statistics::portfolio_acquisition_lots(key1, user_portfolio).lots(),
+        &[(start + DAY, 1_000), (start + DAY / 2 + DAY, 200)]
+    );
+    assert_eq!(
+        Statistics::portfolio_acquisition_lots(key1, default_portfolio).lots(),
+        &[(start + DAY / 2 + DAY, 300)]
+    );
+
+    // The report checks the holding period of the sender's actual portfolio.
+    set_timestamp(start + 2 * DAY);
+    let report = |sender_portfolio: PortfolioId| {
+        Statistics::transfer_restrictions_report(
+            tracker.asset_id,
+            &sender_portfolio,
+            &tracker.investor(id).did,
+            1_000,
+            &mut WeightMeter::max_limit_no_minimum(),
+        )
+        .unwrap()
+    };
+    assert_eq!(report(user_portfolio), vec![]);
+    assert_eq!(
+        report(default_portfolio),
+        vec![TransferCondition::MinHoldingPeriod(DAY)]
+    );
+}
+
 #[test]
 fn claim_count_rule() {
     ExtBuilder::default()
@@ -1100,6 +1319,26 @@ fn ensure_invalid_set_active_stats_ext() {
         ),
         Error::StatTypeLimitReached
     );
+
+    // Volume and lot stats can't be scoped to a claim
+    for operation_type in [
+        StatOpType::InvestorVolume,
+        StatOpType::AssetVolume,
+        StatOpType::AcquisitionLots,
+    ] {
+        assert_noop!(
+            Statistics::set_active_asset_stats(
+                tracker.owner_origin(),
+                tracker.asset_id,
+                [StatType {
+                    operation_type,
+                    claim_issuer: Some((claim_type, IdentityId::from(1))),
+                }]
+                .into(),
+            ),
+            Error::StatTypeClaimNotSupported
+        );
+    }
 }
 
 #[test]
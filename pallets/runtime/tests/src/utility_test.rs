@@ -4,7 +4,7 @@ use frame_support::dispatch::{
     Pays, PostDispatchInfo, Weight,
 };
 use frame_support::error::BadOrigin;
-use frame_support::traits::Contains;
+use frame_support::traits::{Contains, Get, OnInitialize};
 use frame_support::{
     assert_err_ignore_postinfo, assert_noop, assert_ok, assert_storage_noop, storage, StorageMap,
 };
@@ -16,14 +16,16 @@ use pallet_balances::Call as BalancesCall;
 use pallet_pips::{ProposalState, SnapshotResult};
 use pallet_portfolio::Call as PortfolioCall;
 use pallet_utility::{
-    self as utility, Call as UtilityCall, Config as UtilityConfig, Event, UniqueCall, WeightInfo,
+    self as utility, Call as UtilityCall, Config as UtilityConfig, Event, SponsorEnvelope,
+    UniqueCall, WeightInfo,
 };
 use polymesh_common_utilities::traits::transaction_payment::CddAndFeeDetails;
 use polymesh_primitives::{
-    AccountId, Balance, ExtrinsicPermissions, PalletPermissions, Permissions, PortfolioName,
-    PortfolioNumber, SubsetRestriction, Ticker,
+    AccountId, Balance, BlockNumber, ExtrinsicPermissions, PalletPermissions, Permissions,
+    PortfolioName, PortfolioNumber, SubsetRestriction, Ticker,
 };
 use sp_core::sr25519::Signature;
+use sp_io::hashing::blake2_256;
 use sp_keyring::AccountKeyring;
 
 use super::committee_test::set_members;
@@ -310,6 +312,123 @@ fn _relay_unhappy_cases() {
     );
 }
 
+fn sponsor_envelope(
+    sponsor: AccountKeyring,
+    user: &AccountId,
+    call: &RuntimeCall,
+    expiry: BlockNumber,
+) -> SponsorEnvelope<AccountId, BlockNumber> {
+    SponsorEnvelope {
+        sponsor: sponsor.to_account_id(),
+        user: user.clone(),
+        call_hash: blake2_256(&call.encode()),
+        max_fee: 1_000,
+        expiry,
+    }
+}
+
+#[test]
+fn sponsored_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let alice = User::new(AccountKeyring::Alice).balance(1_000);
+        let bob = User::new(AccountKeyring::Bob).balance(1_000);
+        let charlie = User::new(AccountKeyring::Charlie).balance(1_000);
+
+        let call = transfer(charlie.acc(), 50);
+        let envelope = sponsor_envelope(AccountKeyring::Bob, &alice.acc(), &call, 10);
+        let signature = AccountKeyring::Bob.sign(&envelope.encode());
+
+        assert_ok!(Utility::sponsored_call(
+            alice.origin(),
+            envelope.clone(),
+            signature.into(),
+            Box::new(call.clone())
+        ));
+        assert_event(Event::SponsoredCall {
+            sponsor: bob.acc(),
+            user: alice.acc(),
+            result: Ok(()),
+        });
+        assert_balance(alice.acc(), 950, 0);
+        assert_balance(bob.acc(), 1_000, 0);
+        assert_balance(charlie.acc(), 1_050, 0);
+
+        // Envelopes are one-shot.
+        assert_noop!(
+            Utility::sponsored_call(
+                alice.origin(),
+                envelope.clone(),
+                signature.into(),
+                Box::new(call)
+            ),
+            Error::SponsorEnvelopeUsed
+        );
+
+        // Used envelopes are cleared once expired, even if the expiry block was skipped.
+        let hash = blake2_256(&envelope.encode());
+        <Utility as OnInitialize<BlockNumber>>::on_initialize(9);
+        assert!(Utility::used_sponsor_envelope(10, hash));
+        assert_eq!(Utility::expired_envelopes_cursor(), Some((10, None)));
+        <Utility as OnInitialize<BlockNumber>>::on_initialize(11);
+        assert!(!Utility::used_sponsor_envelope(10, hash));
+        assert_eq!(Utility::expired_envelopes_cursor(), Some((12, None)));
+    });
+}
+
+#[test]
+fn sponsored_call_unhappy_cases() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(5);
+        let alice = User::new(AccountKeyring::Alice).balance(1_000);
+        let charlie = AccountKeyring::Charlie.to_account_id();
+        let call = transfer(charlie.clone(), 50);
+        let sponsored_call = |envelope: &SponsorEnvelope<AccountId, BlockNumber>,
+                              signer: AccountKeyring,
+                              call: RuntimeCall| {
+            Utility::sponsored_call(
+                alice.origin(),
+                envelope.clone(),
+                signer.sign(&envelope.encode()).into(),
+                Box::new(call),
+            )
+        };
+
+        // The envelope must be issued for the caller and the call.
+        let envelope = sponsor_envelope(AccountKeyring::Bob, &charlie, &call, 10);
+        assert_noop!(
+            sponsored_call(&envelope, AccountKeyring::Bob, call.clone()),
+            Error::SponsorEnvelopeMismatch
+        );
+        let envelope = sponsor_envelope(AccountKeyring::Bob, &alice.acc(), &call, 10);
+        assert_noop!(
+            sponsored_call(&envelope, AccountKeyring::Bob, transfer(charlie, 60)),
+            Error::SponsorEnvelopeMismatch
+        );
+
+        // The envelope must be used before its expiry.
+        let expired = sponsor_envelope(AccountKeyring::Bob, &alice.acc(), &call, 5);
+        assert_noop!(
+            sponsored_call(&expired, AccountKeyring::Bob, call.clone()),
+            Error::SponsorEnvelopeExpired
+        );
+
+        // The envelope can't be valid for more than `MaxEnvelopeLifetime` blocks.
+        let lifetime = <TestStorage as UtilityConfig>::MaxEnvelopeLifetime::get();
+        let too_long = sponsor_envelope(AccountKeyring::Bob, &alice.acc(), &call, 5 + lifetime + 1);
+        assert_noop!(
+            sponsored_call(&too_long, AccountKeyring::Bob, call.clone()),
+            Error::SponsorEnvelopeLifetimeTooLong
+        );
+
+        // The envelope must be signed by the sponsor.
+        assert_noop!(
+            sponsored_call(&envelope, AccountKeyring::Alice, call),
+            Error::InvalidSignature
+        );
+    });
+}
+
 #[test]
 fn batch_secondary_with_permissions_works() {
     ExtBuilder::default()
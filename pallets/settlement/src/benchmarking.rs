@@ -30,7 +30,7 @@ use polymesh_common_utilities::constants::currency::ONE_UNIT;
 use polymesh_common_utilities::constants::ENSURED_MAX_LEN;
 use polymesh_common_utilities::TestUtilsFn;
 use polymesh_primitives::checked_inc::CheckedInc;
-use polymesh_primitives::settlement::ReceiptMetadata;
+use polymesh_primitives::settlement::{HashLockAlgorithm, ReceiptMetadata};
 use polymesh_primitives::{IdentityId, Memo, NFTId, NFTs, PortfolioId, Ticker};
 
 use crate::*;
@@ -327,6 +327,20 @@ where
     (m_user, m_identity)
 }
 
+/// Returns a [`HashLock`] expiring at block `100` for the given `preimage`.
+fn setup_hash_lock<T: Config>(preimage: &[u8]) -> HashLock<T::BlockNumber> {
+    HashLock {
+        algorithm: HashLockAlgorithm::Sha256,
+        hash: HashLockAlgorithm::Sha256.hash(preimage),
+        expiry: 100u32.into(),
+    }
+}
+
+/// Returns the [`LegId`] of all legs in the instruction.
+fn instruction_leg_ids(id: InstructionId) -> BTreeSet<LegId> {
+    InstructionLegs::iter_key_prefix(id).collect()
+}
+
 benchmarks! {
     where_clause { where T: TestUtilsFn<AccountIdOf<T>>, T: pallet_scheduler::Config }
 
@@ -798,4 +812,130 @@ benchmarks! {
 
         let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
     }: _(parameters.asset_mediators[0].origin.clone(), InstructionId(1), None)
+
+    add_instruction_with_hash_lock {
+        // Number of fungible, non-fungible and offchain LEGS in the instruction
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+        let n in 0..T::MaxNumberOfNFTs::get();
+        let o in 0..T::MaxNumberOfOffChainAssets::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let memo = Some(Memo::default());
+        let settlement_type = SettlementType::SettleManual(0u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account()]);
+        let hash_lock = setup_hash_lock::<T>(&[0; ENSURED_MAX_LEN as usize]);
+
+        let parameters = setup_legs::<T>(&alice, &bob, f, n, o, false, false);
+        let hash_locked_legs = (0..parameters.legs.len() as u64).map(LegId).collect::<BTreeSet<_>>();
+    }: _(alice.origin, Some(venue_id), settlement_type, None, None, parameters.legs, memo, hash_lock, hash_locked_legs)
+
+    execute_with_preimage {
+        // Number of fungible, non-fungible and offchain assets in the instruction
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+        let n in 0..T::MaxNumberOfNFTs::get();
+        let o in 0..T::MaxNumberOfOffChainAssets::get();
+
+        let m = T::MaxInstructionMediators::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let settlement_type = SettlementType::SettleManual(0u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
+        let preimage = vec![0; ENSURED_MAX_LEN as usize];
+
+        setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        Module::<T>::set_hash_lock(InstructionId(1), setup_hash_lock::<T>(&preimage), instruction_leg_ids(InstructionId(1))).unwrap();
+    }: _(bob.origin, InstructionId(1), preimage, f, n, o, Some(Weight::MAX))
+
+    expire_hash_lock {
+        // Number of fungible, non-fungible and offchain assets in the instruction
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+        let n in 0..T::MaxNumberOfNFTs::get();
+        let o in 0..T::MaxNumberOfOffChainAssets::get();
+
+        let m = T::MaxInstructionMediators::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let settlement_type = SettlementType::SettleManual(0u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
+
+        setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        Module::<T>::set_hash_lock(InstructionId(1), setup_hash_lock::<T>(&[]), instruction_leg_ids(InstructionId(1))).unwrap();
+    }: _(RawOrigin::Root, InstructionId(1), Weight::MAX)
+
+    amend_instruction {
+        // Number of fungible, non-fungible and offchain LEGS in the instruction
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+        let n in 0..T::MaxNumberOfNFTs::get();
+        let o in 0..T::MaxNumberOfOffChainAssets::get();
+
+        let m = T::MaxInstructionMediators::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let settlement_type = SettlementType::SettleManual(0u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
+
+        setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, n, o, m, false, false);
+        // All off-chain and fungible legs are amended
+        let amendments: BTreeMap<LegId, Balance> = (0..o + f)
+            .map(|leg_id| (LegId(leg_id as u64), 2 * ONE_UNIT))
+            .collect();
+    }: _(alice.origin, InstructionId(1), amendments, Some(AssetCount::new(f, n, o)))
+
+    partially_execute_instruction {
+        // Number of fungible legs being partially executed
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+
+        let m = T::MaxInstructionMediators::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let settlement_type = SettlementType::SettleManual(0u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
+
+        setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, 0, 0, m, false, false);
+        let partial_amounts: BTreeMap<LegId, Balance> = (0..f)
+            .map(|leg_id| (LegId(leg_id as u64), ONE_UNIT / 2))
+            .collect();
+    }: _(alice.origin, InstructionId(1), partial_amounts, Some(Weight::MAX))
+
+    execute_netted_instructions {
+        // Number of instructions being netted
+        let i in 1..T::MaxNumberOfNettedInstructions::get();
+        // Number of fungible legs in the first instruction
+        let f in 1..T::MaxNumberOfFungibleAssets::get();
+
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let settlement_type = SettlementType::SettleManual(0u32.into());
+        let venue_id = create_venue_::<T>(alice.did(), vec![alice.account(), bob.account()]);
+
+        let parameters = setup_execute_instruction::<T>(&alice, &bob, settlement_type, venue_id, f, 0, 0, 0, false, false);
+        // Every other instruction repeats the first leg, so that all instructions are netted into the same positions
+        for _ in 1..i {
+            let instruction_id = Module::<T>::instruction_counter();
+            Module::<T>::add_instruction(
+                alice.origin.clone().into(),
+                Some(venue_id),
+                settlement_type,
+                None,
+                None,
+                vec![parameters.legs[0].clone()],
+                None,
+            )
+            .unwrap();
+            let portfolios = [parameters.portfolios.sdr_portfolios[0]].into_iter().try_collect().unwrap();
+            Module::<T>::affirm_instruction(alice.origin.clone().into(), instruction_id, portfolios).unwrap();
+            let portfolios = [parameters.portfolios.rcv_portfolios[0]].into_iter().try_collect().unwrap();
+            Module::<T>::affirm_instruction(bob.origin.clone().into(), instruction_id, portfolios).unwrap();
+            parameters.asset_mediators.iter().for_each(|u| {
+                let _ = Module::<T>::affirm_instruction_as_mediator(u.origin.clone().into(), instruction_id, None);
+            });
+        }
+        let instruction_ids = (1..=i as u64).map(InstructionId).try_collect().unwrap();
+        let number_of_assets = AssetCount::new(f + i - 1, 0, 0);
+    }: _(alice.origin, venue_id, instruction_ids, number_of_assets, Some(Weight::MAX))
 }
@@ -42,6 +42,8 @@
 //! - `set_venue_filtering` - Enables or disabled venue filtering for a token.
 //! - `allow_venues` - Allows additional venues to create instructions involving an asset.
 //! - `disallow_venues` - Revokes permission given to venues for creating instructions involving a particular asset.
+//! - `add_instruction_with_hash_lock` - Adds a new instruction that can only be executed by revealing the preimage of its hash lock.
+//! - `execute_with_preimage` - Reveals the preimage of an instruction's hash lock and executes the instruction.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -63,6 +65,7 @@ use frame_support::{
 };
 use frame_system::{ensure_root, RawOrigin};
 use sp_runtime::traits::{One, Verify};
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryFrom;
 use sp_std::prelude::*;
@@ -70,7 +73,9 @@ use sp_std::vec;
 
 use pallet_asset::MandatoryMediators;
 use pallet_base::{ensure_string_limited, try_next_post};
-use polymesh_common_utilities::constants::queue_priority::SETTLEMENT_INSTRUCTION_EXECUTION_PRIORITY;
+use polymesh_common_utilities::constants::queue_priority::{
+    SETTLEMENT_HASH_LOCK_EXPIRY_PRIORITY, SETTLEMENT_INSTRUCTION_EXECUTION_PRIORITY,
+};
 use polymesh_common_utilities::traits::portfolio::PortfolioSubTrait;
 pub use polymesh_common_utilities::traits::settlement::{Event, RawEvent, WeightInfo};
 use polymesh_common_utilities::traits::{asset, compliance_manager, identity, nft, CommonConfig};
@@ -79,9 +84,9 @@ use polymesh_common_utilities::SystematicIssuers::Settlement as SettlementDID;
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::settlement::{
     AffirmationCount, AffirmationStatus, AssetCount, ExecuteInstructionInfo, FilteredLegs,
-    Instruction, InstructionId, InstructionInfo, InstructionStatus, Leg, LegId, LegStatus,
-    MediatorAffirmationStatus, Receipt, ReceiptDetails, SettlementType, Venue, VenueDetails,
-    VenueId, VenueType,
+    HashLock, Instruction, InstructionId, InstructionInfo, InstructionStatus, Leg, LegId,
+    LegStatus, LegTransferFee, MediatorAffirmationStatus, NetPositions, Receipt, ReceiptDetails,
+    SettlementType, Venue, VenueDetails, VenueId, VenueType,
 };
 use polymesh_primitives::{
     storage_migrate_on, storage_migration_ver, Balance, IdentityId, Memo, NFTs, PortfolioId,
@@ -143,6 +148,9 @@ pub trait Config:
 
     /// Maximum number mediators in the instruction level (this does not include asset mediators).
     type MaxInstructionMediators: Get<u32>;
+
+    /// Maximum number of instructions that can be netted in a single call.
+    type MaxNumberOfNettedInstructions: Get<u32>;
 }
 
 decl_error! {
@@ -236,6 +244,38 @@ decl_error! {
         MediatorAffirmationExpired,
         /// Offchain assets must have a venue.
         OffChainAssetsMustHaveAVenue,
+        /// The hash lock expiry must be in the future and after the manual settlement block.
+        InvalidHashLockExpiry,
+        /// Hash locked instructions can't be settled on a specific block.
+        InvalidSettlementTypeForHashLock,
+        /// The instruction can only be executed by revealing the preimage of its hash lock.
+        InstructionIsHashLocked,
+        /// The instruction doesn't have a hash lock.
+        InstructionNotHashLocked,
+        /// The hash lock of the instruction has expired.
+        HashLockExpired,
+        /// The given preimage doesn't match the hash lock of the instruction.
+        InvalidPreimage,
+        /// Only instructions with a venue can be amended or partially executed.
+        InstructionHasNoVenue,
+        /// The instruction must be pending.
+        InstructionNotPending,
+        /// Only the amount of fungible and off-chain legs can be amended and it must differ from the current amount.
+        InvalidLegAmendment,
+        /// The instruction has been amended after the affirmation count was computed.
+        InstructionRevisionMismatch,
+        /// Only affirmed fungible legs can be partially executed, for an amount less than the leg's amount.
+        InvalidPartialExecution,
+        /// At least one instruction must be given for netting.
+        NoInstructionsToNet,
+        /// The instruction doesn't belong to the given venue.
+        InstructionNotInVenue,
+        /// The total amount sent or received by a portfolio overflows.
+        NetPositionOverflow,
+        /// The release of the hash locked assets couldn't be scheduled at the hash lock expiry.
+        HashLockSchedulingFailed,
+        /// The hash locked legs must be a non-empty set of the instruction's legs.
+        InvalidHashLockedLegs,
     }
 }
 
@@ -308,6 +348,19 @@ decl_storage! {
         /// The status for the mediators affirmation.
         pub InstructionMediatorsAffirmations get(fn venue_mediators_affirmations):
             double_map hasher(twox_64_concat) InstructionId, hasher(identity) IdentityId => MediatorAffirmationStatus<T::Moment>;
+        /// The hash lock of an instruction. Only pending instructions that are waiting for their preimage are stored.
+        pub InstructionHashLocks get(fn instruction_hash_lock):
+            map hasher(twox_64_concat) InstructionId => Option<HashLock<T::BlockNumber>>;
+        /// Legs that can only be executed by revealing the preimage of the instruction's hash lock. (instruction_id, leg_id) -> bool
+        pub HashLockedLegs get(fn hash_locked_legs):
+            double_map hasher(twox_64_concat) InstructionId, hasher(twox_64_concat) LegId => bool;
+        /// The number of times a pending instruction has been amended. instruction_id -> revision
+        pub InstructionRevisions get(fn instruction_revision):
+            map hasher(twox_64_concat) InstructionId => u32;
+        /// The transfer fee locked in the sender's portfolio when the leg was affirmed, which is charged when the leg is executed.
+        /// (instruction_id, leg_id) -> LegTransferFee
+        pub ReservedTransferFees get(fn reserved_transfer_fee):
+            double_map hasher(twox_64_concat) InstructionId, hasher(twox_64_concat) LegId => Option<LegTransferFee>;
         /// Storage version.
         StorageVersion get(fn storage_version) build(|_| Version::new(3)): Version;
     }
@@ -879,44 +932,244 @@ decl_module! {
         ) -> DispatchResultWithPostInfo {
             Self::base_reject_instruction(origin, instruction_id, None, number_of_assets)
         }
+
+        /// Adds a new instruction where the legs in `hash_locked_legs` can only be executed by revealing the
+        /// preimage of `hash_lock` before its expiry block. Once the preimage is revealed, the remaining legs are
+        /// settled according to `settlement_type`. If the expiry block is reached first, the instruction is rejected
+        /// and all locked assets are released.
+        ///
+        /// # Arguments
+        /// * `venue_id`: The optional [`VenueId`] of the venue this instruction belongs to.
+        /// * `settlement_type`: The [`SettlementType`] specifying when the instruction can be settled. [`SettlementType::SettleOnBlock`] is not allowed.
+        /// * `trade_date`: Optional date from which people can interact with this instruction.
+        /// * `value_date`: Optional date after which the instruction should be settled (not enforced).
+        /// * `legs`: A vector of all [`Leg`] included in this instruction.
+        /// * `instruction_memo`: An optional [`Memo`] field for this instruction.
+        /// * `hash_lock`: The [`HashLock`] that must be unlocked for executing the hash locked legs.
+        /// * `hash_locked_legs`: The [`LegId`] of each leg that is only executed by revealing the preimage.
+        #[weight = <T as Config>::WeightInfo::add_instruction_with_hash_lock_legs(legs)]
+        pub fn add_instruction_with_hash_lock(
+            origin,
+            venue_id: Option<VenueId>,
+            settlement_type: SettlementType<T::BlockNumber>,
+            trade_date: Option<T::Moment>,
+            value_date: Option<T::Moment>,
+            legs: Vec<Leg>,
+            instruction_memo: Option<Memo>,
+            hash_lock: HashLock<T::BlockNumber>,
+            hash_locked_legs: BTreeSet<LegId>,
+        ) {
+            let did = Identity::<T>::ensure_perms(origin)?;
+            Self::ensure_valid_hash_lock(&hash_lock, &settlement_type)?;
+            ensure!(
+                !hash_locked_legs.is_empty()
+                    && hash_locked_legs.iter().all(|leg_id| (leg_id.0 as usize) < legs.len()),
+                Error::<T>::InvalidHashLockedLegs
+            );
+            let instruction_id = Self::base_add_instruction(
+                did,
+                venue_id,
+                settlement_type,
+                trade_date,
+                value_date,
+                legs,
+                instruction_memo,
+                None
+            )?;
+            Self::set_hash_lock(instruction_id, hash_lock, hash_locked_legs)?;
+        }
+
+        /// Reveals the preimage of the instruction's hash lock and executes its hash locked legs.
+        /// If all legs are hash locked the whole instruction is executed, otherwise the remaining legs
+        /// are settled according to the instruction's settlement type.
+        /// If the execution fails, the preimage is not revealed.
+        ///
+        /// # Arguments
+        /// * `id`: The [`InstructionId`] of the instruction to be executed.
+        /// * `preimage`: The preimage of the instruction's [`HashLock`].
+        /// * `fungible_transfers`: The number of fungible hash locked legs in the instruction.
+        /// * `nfts_transfers`: The number of nfts being transferred in the hash locked legs.
+        /// * `offchain_transfers`: The number of offchain hash locked legs in the instruction.
+        /// * `weight_limit`: An optional maximum [`Weight`] value to be charged for executing the instruction.
+        /// If the `weight_limit` is less than the required amount, the instruction will fail execution.
+        #[weight = <T as Config>::WeightInfo::execute_with_preimage_weight_limit(weight_limit, fungible_transfers, nfts_transfers, offchain_transfers)]
+        pub fn execute_with_preimage(
+            origin,
+            id: InstructionId,
+            preimage: Vec<u8>,
+            fungible_transfers: u32,
+            nfts_transfers: u32,
+            offchain_transfers: u32,
+            weight_limit: Option<Weight>
+        ) -> DispatchResultWithPostInfo {
+            let mut weight_meter = Self::ensure_valid_weight_meter(
+                Self::execute_with_preimage_minimum_weight(),
+                weight_limit.unwrap_or(<T as Config>::WeightInfo::execute_with_preimage(
+                    fungible_transfers,
+                    nfts_transfers,
+                    offchain_transfers,
+                )),
+            )?;
+            let input_cost = AssetCount::new(fungible_transfers, nfts_transfers, offchain_transfers);
+            Self::base_execute_with_preimage(origin, id, preimage, &input_cost, &mut weight_meter)
+                .map_err(|e| DispatchErrorWithPostInfo {
+                    post_info: Some(weight_meter.consumed()).into(),
+                    error: e.error,
+                })
+        }
+
+        /// Root callable extrinsic, used as an internal call to release the assets of an expired hash locked instruction.
+        #[weight = (*weight_limit).max(<T as Config>::WeightInfo::expire_hash_lock(0, 0, 0))]
+        fn expire_hash_lock(origin, id: InstructionId, weight_limit: Weight) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let actual_weight = Self::base_expire_hash_lock(id)?;
+            Ok(PostDispatchInfo::from(Some(actual_weight.min(weight_limit))))
+        }
+
+        /// Amends the amount of fungible and off-chain legs of a pending instruction. The revision of the instruction
+        /// is incremented and only the parties of the amended legs have to affirm the instruction again.
+        ///
+        /// # Arguments
+        /// * `id`: The [`InstructionId`] of the instruction being amended.
+        /// * `amendments`: The new amount for each [`LegId`] being amended.
+        /// * `number_of_assets`: An optional [`AssetCount`] that will be used for a precise fee estimation before executing the extrinsic.
+        ///
+        /// Note: calling the rpc method `get_affirmation_count` returns the current revision of the instruction.
+        ///
+        /// # Permissions
+        /// * Venue creator
+        #[weight = <T as Config>::WeightInfo::amend_instruction_input(*number_of_assets)]
+        pub fn amend_instruction(
+            origin,
+            id: InstructionId,
+            amendments: BTreeMap<LegId, Balance>,
+            number_of_assets: Option<AssetCount>
+        ) -> DispatchResultWithPostInfo {
+            Self::base_amend_instruction(origin, id, amendments, number_of_assets)
+        }
+
+        /// Transfers part of the amount of affirmed fungible legs. The remaining amount of each leg stays
+        /// locked and pending until the instruction is executed, partially executed again or rejected.
+        ///
+        /// # Arguments
+        /// * `id`: The [`InstructionId`] of the instruction being partially executed.
+        /// * `partial_amounts`: The amount being transferred for each [`LegId`]. It must be less than the leg's amount.
+        /// * `weight_limit`: An optional maximum [`Weight`] value to be charged for the partial execution.
+        ///
+        /// # Permissions
+        /// * Venue creator
+        #[weight = <T as Config>::WeightInfo::partially_execute_instruction_weight_limit(weight_limit, partial_amounts.len() as u32)]
+        pub fn partially_execute_instruction(
+            origin,
+            id: InstructionId,
+            partial_amounts: BTreeMap<LegId, Balance>,
+            weight_limit: Option<Weight>
+        ) -> DispatchResultWithPostInfo {
+            let mut weight_meter = Self::ensure_valid_weight_meter(
+                <T as Config>::WeightInfo::partially_execute_instruction(0),
+                weight_limit.unwrap_or(<T as Config>::WeightInfo::partially_execute_instruction(
+                    partial_amounts.len() as u32,
+                )),
+            )?;
+            Self::base_partially_execute_instruction(origin, id, partial_amounts, &mut weight_meter)
+                .map_err(|e| DispatchErrorWithPostInfo {
+                    post_info: Some(weight_meter.consumed()).into(),
+                    error: e.error,
+                })
+        }
+
+        /// Executes multiple instructions of a venue at once, by netting their fungible legs. For each asset,
+        /// only the net amount sent or received by each portfolio is transferred, while non-fungible legs are
+        /// transferred as is. Compliance rules and statistics restrictions are verified for every fungible leg,
+        /// in order, while only balances are verified for the net transfers. All instructions must be executable,
+        /// otherwise no instruction is executed.
+        ///
+        /// # Arguments
+        /// * `venue_id`: The [`VenueId`] of the venue all instructions belong to.
+        /// * `instruction_ids`: The [`InstructionId`] of each instruction being netted.
+        /// * `number_of_assets`: The total [`AssetCount`] of all instructions.
+        /// * `weight_limit`: An optional maximum [`Weight`] value to be charged for executing the instructions.
+        ///
+        /// # Permissions
+        /// * Venue creator
+        #[weight = <T as Config>::WeightInfo::execute_netted_instructions_weight_limit(weight_limit, instruction_ids.len() as u32, number_of_assets)]
+        pub fn execute_netted_instructions(
+            origin,
+            venue_id: VenueId,
+            instruction_ids: BoundedBTreeSet<InstructionId, T::MaxNumberOfNettedInstructions>,
+            number_of_assets: AssetCount,
+            weight_limit: Option<Weight>
+        ) -> DispatchResultWithPostInfo {
+            let mut weight_meter = Self::ensure_valid_weight_meter(
+                Self::execute_manual_instruction_minimum_weight(),
+                weight_limit.unwrap_or(<T as Config>::WeightInfo::execute_netted_instructions_weight_limit(
+                    &None,
+                    instruction_ids.len() as u32,
+                    &number_of_assets,
+                )),
+            )?;
+            Self::base_execute_netted_instructions(
+                origin,
+                venue_id,
+                instruction_ids.into_inner(),
+                &number_of_assets,
+                &mut weight_meter
+            )
+            .map_err(|e| DispatchErrorWithPostInfo {
+                post_info: Some(weight_meter.consumed()).into(),
+                error: e.error,
+            })
+        }
     }
 }
 
 impl<T: Config> Module<T> {
-    fn lock_via_leg(leg: &Leg) -> DispatchResult {
+    /// Locks the assets of `leg` and reserves its transfer fee, if any, in the sender's portfolio.
+    fn lock_via_leg(id: InstructionId, leg_id: LegId, leg: &Leg) -> DispatchResult {
         match leg {
             Leg::Fungible {
                 sender,
                 asset_id,
                 amount,
                 ..
-            } => T::Portfolio::lock_tokens(&sender, &asset_id, *amount),
+            } => T::Portfolio::lock_tokens(&sender, &asset_id, *amount)?,
             Leg::NonFungible { sender, nfts, .. } => with_transaction(|| {
                 for nft_id in nfts.ids() {
                     T::Portfolio::lock_nft(&sender, nfts.asset_id(), &nft_id)?;
                 }
                 Ok(())
-            }),
-            Leg::OffChain { .. } => Err(Error::<T>::OffChainAssetCantBeLocked.into()),
+            })?,
+            Leg::OffChain { .. } => return Err(Error::<T>::OffChainAssetCantBeLocked.into()),
+        }
+        if let Some(leg_fee) = Self::leg_transfer_fee(leg_id, leg) {
+            T::Portfolio::lock_tokens(&leg_fee.payer, &leg_fee.asset_id, leg_fee.amount)?;
+            ReservedTransferFees::insert(id, leg_id, leg_fee);
         }
+        Ok(())
     }
 
-    fn unlock_via_leg(leg: &Leg) -> DispatchResult {
+    /// Unlocks the assets of `leg` and its reserved transfer fee.
+    /// The reservation is kept in storage, so that the fee can be charged when the leg is executed.
+    fn unlock_via_leg(id: InstructionId, leg_id: LegId, leg: &Leg) -> DispatchResult {
         match leg {
             Leg::Fungible {
                 sender,
                 asset_id,
                 amount,
                 ..
-            } => T::Portfolio::unlock_tokens(&sender, &asset_id, *amount),
+            } => T::Portfolio::unlock_tokens(&sender, &asset_id, *amount)?,
             Leg::NonFungible { sender, nfts, .. } => with_transaction(|| {
                 for nft_id in nfts.ids() {
                     T::Portfolio::unlock_nft(&sender, nfts.asset_id(), &nft_id)?;
                 }
                 Ok(())
-            }),
-            Leg::OffChain { .. } => Err(Error::<T>::OffChainAssetCantBeLocked.into()),
+            })?,
+            Leg::OffChain { .. } => return Err(Error::<T>::OffChainAssetCantBeLocked.into()),
         }
+        if let Some(leg_fee) = ReservedTransferFees::get(id, leg_id) {
+            T::Portfolio::unlock_tokens(&leg_fee.payer, &leg_fee.asset_id, leg_fee.amount)?;
+        }
+        Ok(())
     }
 
     /// Ensure origin call permission and the given instruction validity.
@@ -1146,19 +1399,32 @@ impl<T: Config> Module<T> {
             secondary_key,
             &[AffirmationStatus::Affirmed],
         )?;
-        // Unlock tokens that were previously locked during the affirmation
         let filtered_legs = Self::filtered_legs(id, &portfolios);
         // If the fee was estimated in advance, the input values must be at least equal to the actual values
         if let Some(affirmation_count) = affirmation_count {
-            Self::ensure_valid_affirmation_count(&filtered_legs, &affirmation_count)?;
+            Self::ensure_valid_affirmation_count(&id, &filtered_legs, &affirmation_count)?;
         }
+        Self::unsafe_reset_affirmations(did, id, &filtered_legs, &portfolios)?;
+        Ok(filtered_legs)
+    }
+
+    /// Unlocks the assets that were locked when `portfolios` affirmed the instruction and sets their affirmation status to pending.
+    /// `filtered_legs` must be the legs of the instruction filtered by `portfolios`.
+    fn unsafe_reset_affirmations(
+        did: IdentityId,
+        id: InstructionId,
+        filtered_legs: &FilteredLegs,
+        portfolios: &BTreeSet<PortfolioId>,
+    ) -> DispatchResult {
+        // Unlock tokens that were previously locked during the affirmation
         for (leg_id, leg) in filtered_legs.sender_subset() {
             match Self::instruction_leg_status(id, leg_id) {
                 LegStatus::ExecutionToBeSkipped(_, _) => {
                     return Err(Error::<T>::UnexpectedLegStatus.into())
                 }
                 LegStatus::ExecutionPending => {
-                    Self::unlock_via_leg(&leg)?;
+                    Self::unlock_via_leg(id, leg_id, &leg)?;
+                    ReservedTransferFees::remove(id, leg_id);
                 }
                 LegStatus::PendingTokenLock => {
                     return Err(Error::<T>::InstructionNotAffirmed.into());
@@ -1168,7 +1434,7 @@ impl<T: Config> Module<T> {
         }
 
         // Updates storage.
-        for portfolio in &portfolios {
+        for portfolio in portfolios {
             UserAffirmations::insert(portfolio, id, AffirmationStatus::Pending);
             AffirmsReceived::remove(id, portfolio);
             Self::deposit_event(RawEvent::AffirmationWithdrawn(did, *portfolio, id));
@@ -1177,7 +1443,7 @@ impl<T: Config> Module<T> {
         InstructionAffirmsPending::mutate(id, |affirms_pending| {
             *affirms_pending += u64::try_from(portfolios.len()).unwrap_or_default()
         });
-        Ok(filtered_legs)
+        Ok(())
     }
 
     fn ensure_instruction_validity(
@@ -1241,45 +1507,8 @@ impl<T: Config> Module<T> {
     ) -> DispatchResult {
         let mut failed_leg_id = None;
         let tx_result = with_transaction(|| {
-            // Ensures the number of pending affirmations is zero
-            let n_pending_affirmations = InstructionAffirmsPending::take(instruction_id);
-            ensure!(
-                n_pending_affirmations == 0,
-                Error::<T>::NotAllAffirmationsHaveBeenReceived
-            );
-            // Ensures the instruction is pending or has failed at least one time
-            let instruction_status = InstructionStatuses::<T>::get(instruction_id);
-            ensure!(
-                instruction_status == InstructionStatus::Pending
-                    || instruction_status == InstructionStatus::Failed,
-                Error::<T>::InvalidInstructionStatusForExecution
-            );
-            // Ensures all mediator's affirmations are still valid
-            Self::ensure_non_expired_affirmations(&instruction_id)?;
-
-            // The order of execution of the legs matter in some edge cases around compliance.
-            let mut instruction_legs: Vec<(LegId, Leg)> =
-                InstructionLegs::drain_prefix(&instruction_id).collect();
-            instruction_legs.sort_by_key(|leg_id_leg| leg_id_leg.0);
-
-            // Ensures all affirmations have been received
-            Self::ensure_no_missing_affirmation(&instruction_id, &instruction_legs)?;
-
-            let instruction_asset_count = AssetCount::from_legs(&instruction_legs);
-            weight_meter
-                .check_accrue(<T as Config>::WeightInfo::execute_instruction_paused(
-                    instruction_asset_count.fungible(),
-                    instruction_asset_count.non_fungible(),
-                    instruction_asset_count.off_chain(),
-                ))
-                .map_err(|_| Error::<T>::WeightLimitExceeded)?;
-
-            // Ensures the venue is allowed for all tickers in the instruction
-            let instruction_details = InstructionDetails::<T>::take(instruction_id);
-            Self::ensure_allowed_venue(&instruction_legs, instruction_details.venue_id)?;
-
-            // Attempts to release the locks
-            Self::release_locks(instruction_id, &instruction_legs)?;
+            let (instruction_legs, instruction_details) =
+                Self::prepare_instruction_execution(instruction_id, weight_meter)?;
 
             // Transfer all fungible an non fungible assets
             let instruction_memo = InstructionMemos::get(&instruction_id);
@@ -1291,21 +1520,12 @@ impl<T: Config> Module<T> {
                 weight_meter,
             ) {
                 Ok(_) => {
-                    // Remove remaning storage
-                    if let Some(venue_id) = instruction_details.venue_id {
-                        VenueInstructions::remove(venue_id, instruction_id);
-                    }
-                    let _ = InstructionLegStatus::<T>::clear_prefix(
+                    Self::finalize_instruction_execution(
                         instruction_id,
+                        instruction_details.venue_id,
                         instruction_legs.len() as u32,
-                        None,
-                    );
-                    // Change instruction status
-                    InstructionStatuses::<T>::insert(
-                        instruction_id,
-                        InstructionStatus::Success(System::<T>::block_number()),
+                        caller_did,
                     );
-                    Self::deposit_event(RawEvent::InstructionExecuted(caller_did, instruction_id));
                     Ok(())
                 }
                 Err(leg_id) => {
@@ -1327,6 +1547,86 @@ impl<T: Config> Module<T> {
         tx_result
     }
 
+    /// Ensures the instruction of the given `instruction_id` can be executed and releases the locks of all its legs.
+    /// Returns the sorted legs and the details of the instruction, which are removed from storage.
+    fn prepare_instruction_execution(
+        instruction_id: InstructionId,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<(Vec<(LegId, Leg)>, Instruction<T::Moment, T::BlockNumber>), DispatchError> {
+        // Ensures the number of pending affirmations is zero
+        let n_pending_affirmations = InstructionAffirmsPending::take(instruction_id);
+        ensure!(
+            n_pending_affirmations == 0,
+            Error::<T>::NotAllAffirmationsHaveBeenReceived
+        );
+        // Ensures the instruction is pending or has failed at least one time
+        let instruction_status = InstructionStatuses::<T>::get(instruction_id);
+        ensure!(
+            instruction_status == InstructionStatus::Pending
+                || instruction_status == InstructionStatus::Failed,
+            Error::<T>::InvalidInstructionStatusForExecution
+        );
+        // Hash locked instructions can only be executed by revealing their preimage
+        ensure!(
+            !InstructionHashLocks::<T>::contains_key(instruction_id),
+            Error::<T>::InstructionIsHashLocked
+        );
+        // Ensures all mediator's affirmations are still valid
+        Self::ensure_non_expired_affirmations(&instruction_id)?;
+
+        // The order of execution of the legs matter in some edge cases around compliance.
+        let mut instruction_legs: Vec<(LegId, Leg)> =
+            InstructionLegs::drain_prefix(&instruction_id).collect();
+        instruction_legs.sort_by_key(|leg_id_leg| leg_id_leg.0);
+
+        // Ensures all affirmations have been received
+        Self::ensure_no_missing_affirmation(&instruction_id, &instruction_legs)?;
+
+        let instruction_asset_count = AssetCount::from_legs(&instruction_legs);
+        weight_meter
+            .check_accrue(<T as Config>::WeightInfo::execute_instruction_paused(
+                instruction_asset_count.fungible(),
+                instruction_asset_count.non_fungible(),
+                instruction_asset_count.off_chain(),
+            ))
+            .map_err(|_| Error::<T>::WeightLimitExceeded)?;
+
+        // Ensures the venue is allowed for all tickers in the instruction
+        let instruction_details = InstructionDetails::<T>::take(instruction_id);
+        Self::ensure_allowed_venue(&instruction_legs, instruction_details.venue_id)?;
+        Self::ensure_settlement_not_paused(
+            &instruction_legs,
+            instruction_details.venue_id.as_ref(),
+        )?;
+
+        // Attempts to release the locks
+        Self::release_locks(instruction_id, &instruction_legs)?;
+
+        Ok((instruction_legs, instruction_details))
+    }
+
+    /// Removes the remaining storage of an executed instruction and sets its status to [`InstructionStatus::Success`].
+    fn finalize_instruction_execution(
+        instruction_id: InstructionId,
+        venue_id: Option<VenueId>,
+        n_legs: u32,
+        caller_did: IdentityId,
+    ) {
+        // Remove remaning storage
+        if let Some(venue_id) = venue_id {
+            VenueInstructions::remove(venue_id, instruction_id);
+        }
+        InstructionRevisions::remove(instruction_id);
+        let _ = InstructionLegStatus::<T>::clear_prefix(instruction_id, n_legs, None);
+        let _ = ReservedTransferFees::clear_prefix(instruction_id, n_legs, None);
+        // Change instruction status
+        InstructionStatuses::<T>::insert(
+            instruction_id,
+            InstructionStatus::Success(System::<T>::block_number()),
+        );
+        Self::deposit_event(RawEvent::InstructionExecuted(caller_did, instruction_id));
+    }
+
     /// Returns `Ok` if all mediator's affirmation are still valid. Otherwise, returns an error.
     /// This call also removes all elements from the `InstructionMediatorsAffirmations` storage.
     fn ensure_non_expired_affirmations(instruction_id: &InstructionId) -> DispatchResult {
@@ -1459,19 +1759,104 @@ impl<T: Config> Module<T> {
                     }
                     Leg::OffChain { .. } => {}
                 }
+                if let Some(leg_fee) = ReservedTransferFees::take(instruction_id, leg_id) {
+                    if Self::charge_leg_transfer_fee(
+                        instruction_id,
+                        leg_fee,
+                        instruction_memo.clone(),
+                        caller_did,
+                        weight_meter,
+                    )
+                    .is_err()
+                    {
+                        return Err(*leg_id);
+                    }
+                }
             }
         }
         Ok(())
     }
 
+    /// Returns the [`LegTransferFee`] charged for executing `leg`, if the asset being transferred has a [`TransferFee`].
+    /// No fee is charged if the computed amount is zero or if the sender is the beneficiary of the fee.
+    pub fn leg_transfer_fee(leg_id: LegId, leg: &Leg) -> Option<LegTransferFee> {
+        let (payer, transferred_asset, fee_amount, fee) = match leg {
+            Leg::Fungible {
+                sender,
+                asset_id,
+                amount,
+                ..
+            } => {
+                let fee = pallet_asset::TransferFees::get(asset_id)?;
+                (*sender, *asset_id, fee.fungible_fee(*amount), fee)
+            }
+            Leg::NonFungible { sender, nfts, .. } => {
+                let fee = pallet_asset::TransferFees::get(nfts.asset_id())?;
+                (
+                    *sender,
+                    *nfts.asset_id(),
+                    fee.nft_fee(nfts.len() as u64),
+                    fee,
+                )
+            }
+            Leg::OffChain { .. } => return None,
+        };
+        if fee_amount == 0 || payer == fee.beneficiary {
+            return None;
+        }
+        Some(LegTransferFee {
+            leg_id,
+            payer,
+            beneficiary: fee.beneficiary,
+            asset_id: fee.fee_asset_id(transferred_asset),
+            amount: fee_amount,
+        })
+    }
+
+    /// Transfers the fee from the payer to the beneficiary portfolio.
+    /// Compliance and transfer restrictions of the fee asset are enforced as for any other transfer.
+    fn charge_leg_transfer_fee(
+        instruction_id: InstructionId,
+        leg_fee: LegTransferFee,
+        instruction_memo: Option<Memo>,
+        caller_did: IdentityId,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        <Asset<T>>::base_transfer(
+            leg_fee.payer,
+            leg_fee.beneficiary,
+            leg_fee.asset_id,
+            leg_fee.amount,
+            Some(instruction_id),
+            instruction_memo,
+            caller_did,
+            weight_meter,
+        )?;
+        Self::deposit_event(RawEvent::TransferFeeCharged(
+            caller_did,
+            instruction_id,
+            leg_fee,
+        ));
+        Ok(())
+    }
+
+    /// Returns the [`LegTransferFee`] reserved for all legs pending execution in the instruction.
+    pub fn instruction_transfer_fees(instruction_id: &InstructionId) -> Vec<LegTransferFee> {
+        ReservedTransferFees::iter_prefix_values(instruction_id).collect()
+    }
+
     /// Clears the storage for a rejected instruction and updates the instruction status to
     /// [`InstructionStatus::Rejected`].
     fn prune_rejected_instruction(instruction_id: InstructionId) {
+        if InstructionHashLocks::<T>::take(instruction_id).is_some() {
+            let _ = T::Scheduler::cancel_named(instruction_id.hash_lock_expiry_name());
+        }
         let instruction_details = InstructionDetails::<T>::take(&instruction_id);
         if let Some(venue_id) = instruction_details.venue_id {
             VenueInstructions::remove(venue_id, instruction_id);
         }
         InstructionAffirmsPending::remove(instruction_id);
+        InstructionRevisions::remove(instruction_id);
         let _ = InstructionMediatorsAffirmations::<T>::clear_prefix(
             instruction_id,
             T::MaxInstructionMediators::get(),
@@ -1485,6 +1870,12 @@ impl<T: Config> Module<T> {
             instruction_legs.len() as u32,
             None,
         );
+        let _ = HashLockedLegs::clear_prefix(&instruction_id, instruction_legs.len() as u32, None);
+        let _ = ReservedTransferFees::clear_prefix(
+            &instruction_id,
+            instruction_legs.len() as u32,
+            None,
+        );
         for (leg_id, leg) in instruction_legs {
             match leg {
                 Leg::Fungible {
@@ -1529,10 +1920,10 @@ impl<T: Config> Module<T> {
         let filtered_legs = Self::filtered_legs(id, &portfolios);
         // If the fee was estimated in advance, the input values must be at least equal to the actual values
         if let Some(affirmation_count) = affirmation_count {
-            Self::ensure_valid_affirmation_count(&filtered_legs, &affirmation_count)?
+            Self::ensure_valid_affirmation_count(&id, &filtered_legs, &affirmation_count)?
         }
         for (leg_id, leg) in filtered_legs.sender_subset() {
-            Self::lock_via_leg(&leg)?;
+            Self::lock_via_leg(id, leg_id, &leg)?;
             <InstructionLegStatus<T>>::insert(id, leg_id, LegStatus::ExecutionPending);
         }
 
@@ -1554,7 +1945,7 @@ impl<T: Config> Module<T> {
     fn release_locks(id: InstructionId, instruction_legs: &[(LegId, Leg)]) -> DispatchResult {
         for (leg_id, leg) in instruction_legs {
             if let LegStatus::ExecutionPending = Self::instruction_leg_status(id, leg_id) {
-                Self::unlock_via_leg(&leg)?;
+                Self::unlock_via_leg(id, *leg_id, &leg)?;
             }
         }
         Ok(())
@@ -1565,6 +1956,7 @@ impl<T: Config> Module<T> {
     fn maybe_schedule_instruction(affirms_pending: u64, id: InstructionId, weight_limit: Weight) {
         if affirms_pending == 0
             && Self::instruction_details(id).settlement_type == SettlementType::SettleOnAffirmation
+            && !InstructionHashLocks::<T>::contains_key(id)
         {
             // Schedule instruction to be executed in the next block.
             let execution_at = System::<T>::block_number() + One::one();
@@ -1634,10 +2026,14 @@ impl<T: Config> Module<T> {
         let filtered_legs = Self::filtered_legs(instruction_id, &portfolios);
         // If the fee was estimated in advance, the input values must be at least equal to the actual values
         if let Some(affirmation_count) = affirmation_count {
-            Self::ensure_valid_affirmation_count(&filtered_legs, &affirmation_count)?
+            Self::ensure_valid_affirmation_count(
+                &instruction_id,
+                &filtered_legs,
+                &affirmation_count,
+            )?
         }
         for (leg_id, leg) in filtered_legs.sender_subset() {
-            Self::lock_via_leg(&leg)?;
+            Self::lock_via_leg(instruction_id, leg_id, &leg)?;
             <InstructionLegStatus<T>>::insert(instruction_id, leg_id, LegStatus::ExecutionPending);
         }
 
@@ -1987,6 +2383,22 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Ensures that settlement is not paused for any of the assets in the instruction, neither for all venues
+    /// nor for the venue of the instruction.
+    fn ensure_settlement_not_paused(
+        instruction_legs: &[(LegId, Leg)],
+        venue_id: Option<&VenueId>,
+    ) -> DispatchResult {
+        let asset_ids: BTreeSet<AssetId> = instruction_legs
+            .iter()
+            .filter_map(|(_, leg)| leg.asset_id().copied())
+            .collect();
+        for asset_id in asset_ids {
+            <Asset<T>>::ensure_settlement_not_paused(&asset_id, venue_id)?;
+        }
+        Ok(())
+    }
+
     /// If `tickers` doesn't contain the given `asset_id` and venue_filtering is enabled, ensures that venue_id is in the allowed list
     fn ensure_venue_filtering(
         tickers: &mut BTreeSet<AssetId>,
@@ -2194,6 +2606,161 @@ impl<T: Config> Module<T> {
         Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
     }
 
+    fn base_execute_netted_instructions(
+        origin: T::RuntimeOrigin,
+        venue_id: VenueId,
+        instruction_ids: BTreeSet<InstructionId>,
+        input_cost: &AssetCount,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResultWithPostInfo {
+        let caller_did = Identity::<T>::ensure_perms(origin)?;
+        Self::venue_for_management(venue_id, caller_did)?;
+        ensure!(!instruction_ids.is_empty(), Error::<T>::NoInstructionsToNet);
+
+        // Ensures all instructions belong to the venue and can be executed
+        let mut all_legs = Vec::new();
+        for instruction_id in &instruction_ids {
+            let instruction_details = Self::ensure_instruction_validity(*instruction_id, true)?;
+            ensure!(
+                instruction_details.venue_id == Some(venue_id),
+                Error::<T>::InstructionNotInVenue
+            );
+            all_legs.extend(InstructionLegs::iter_prefix_values(instruction_id));
+        }
+        Self::ensure_valid_cost(&AssetCount::from_legs(&all_legs), input_cost)?;
+
+        // Releases all locks and collects the net position of each portfolio. Compliance and statistics restrictions
+        // are enforced for each gross fungible transfer, against the balances resulting from the previous gross transfers,
+        // while only balances are verified for the net transfers.
+        let mut net_positions = NetPositions::default();
+        let mut gross_balances: BTreeMap<(AssetId, IdentityId), Balance> = BTreeMap::new();
+        let mut gross_legs = Vec::new();
+        let mut executed_instructions = Vec::new();
+        for instruction_id in &instruction_ids {
+            let (instruction_legs, instruction_details) =
+                Self::prepare_instruction_execution(*instruction_id, weight_meter)?;
+            let _ = T::Scheduler::cancel_named(instruction_id.execution_name());
+
+            for (leg_id, leg) in instruction_legs.iter() {
+                if Self::instruction_leg_status(instruction_id, leg_id)
+                    != LegStatus::ExecutionPending
+                {
+                    continue;
+                }
+                if let Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    amount,
+                } = leg
+                {
+                    let sender_balance = *gross_balances
+                        .entry((*asset_id, sender.did))
+                        .or_insert_with(|| Asset::<T>::balance_of(asset_id, sender.did));
+                    let receiver_balance = *gross_balances
+                        .entry((*asset_id, receiver.did))
+                        .or_insert_with(|| Asset::<T>::balance_of(asset_id, receiver.did));
+                    Asset::<T>::validate_netted_transfer(
+                        *asset_id,
+                        sender,
+                        receiver,
+                        sender_balance,
+                        receiver_balance,
+                        *amount,
+                        weight_meter,
+                    )?;
+                    if sender.did != receiver.did {
+                        gross_balances.insert(
+                            (*asset_id, sender.did),
+                            sender_balance.saturating_sub(*amount),
+                        );
+                        gross_balances.insert(
+                            (*asset_id, receiver.did),
+                            receiver_balance.saturating_add(*amount),
+                        );
+                    }
+                    net_positions
+                        .try_add_transfer(*instruction_id, *sender, *receiver, *asset_id, *amount)
+                        .map_err(|_| Error::<T>::NetPositionOverflow)?;
+                }
+                gross_legs.push((*instruction_id, *leg_id, leg.clone()));
+            }
+            executed_instructions.push((
+                *instruction_id,
+                instruction_details.venue_id,
+                instruction_legs.len() as u32,
+            ));
+        }
+
+        // The net transfers pair portfolios which may not share any instruction, so they are not subject to compliance
+        // and statistics restrictions, which were verified for the gross legs. Only balances and statistics are updated.
+        // Each net transfer's event identifies all the instructions it settles.
+        for net_transfer in net_positions.net_transfers() {
+            Asset::<T>::unchecked_net_transfer(
+                net_transfer.sender,
+                net_transfer.receiver,
+                net_transfer.asset_id,
+                net_transfer.amount,
+                caller_did,
+                weight_meter,
+            )?;
+            Self::deposit_event(RawEvent::NetTransferExecuted(
+                caller_did,
+                venue_id,
+                net_transfer,
+            ));
+        }
+
+        // Non-fungible legs are never netted and are transferred after all net positions have been settled.
+        // Fees are charged for each gross transfer.
+        for (instruction_id, leg_id, leg) in gross_legs {
+            let instruction_memo = InstructionMemos::get(instruction_id);
+            if matches!(leg, Leg::Fungible { .. }) {
+                Self::deposit_event(RawEvent::FungibleLegNetted(
+                    caller_did,
+                    instruction_id,
+                    leg_id,
+                ));
+            }
+            if let Leg::NonFungible {
+                sender,
+                receiver,
+                nfts,
+            } = &leg
+            {
+                Nft::<T>::base_nft_transfer(
+                    *sender,
+                    *receiver,
+                    nfts.clone(),
+                    instruction_id,
+                    instruction_memo.clone(),
+                    caller_did,
+                    weight_meter,
+                )?;
+            }
+            if let Some(leg_fee) = ReservedTransferFees::take(instruction_id, leg_id) {
+                Self::charge_leg_transfer_fee(
+                    instruction_id,
+                    leg_fee,
+                    instruction_memo,
+                    caller_did,
+                    weight_meter,
+                )?;
+            }
+        }
+
+        for (instruction_id, venue_id, n_legs) in executed_instructions {
+            Self::finalize_instruction_execution(instruction_id, venue_id, n_legs, caller_did);
+        }
+
+        Self::deposit_event(RawEvent::InstructionsNetted(
+            caller_did,
+            venue_id,
+            instruction_ids.into_iter().collect(),
+        ));
+        Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
+    }
+
     /// Returns `Ok` if `origin` represents the root, otherwise returns an `Err` with the consumed weight for this function.
     fn ensure_root_origin(origin: T::RuntimeOrigin) -> Result<(), DispatchErrorWithPostInfo> {
         ensure_root(origin).map_err(|e| DispatchErrorWithPostInfo {
@@ -2355,11 +2922,17 @@ impl<T: Config> Module<T> {
         )))
     }
 
-    /// Returns `Ok` if the number of assets in [`AffirmationCount`] is greater or equal to the actual number of assets.
+    /// Returns `Ok` if the number of assets in [`AffirmationCount`] is greater or equal to the actual number of assets
+    /// and if the instruction has not been amended since the count was computed.
     fn ensure_valid_affirmation_count(
+        instruction_id: &InstructionId,
         filtered_legs: &FilteredLegs,
         affirmation_count: &AffirmationCount,
     ) -> DispatchResult {
+        ensure!(
+            affirmation_count.revision() == InstructionRevisions::get(instruction_id),
+            Error::<T>::InstructionRevisionMismatch
+        );
         Self::ensure_valid_cost(
             filtered_legs.sender_asset_count(),
             affirmation_count.sender_asset_count(),
@@ -2479,6 +3052,530 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Returns `Ok` if `hash_lock` expires in the future and can be used with `settlement_type`, otherwise returns an error.
+    fn ensure_valid_hash_lock(
+        hash_lock: &HashLock<T::BlockNumber>,
+        settlement_type: &SettlementType<T::BlockNumber>,
+    ) -> DispatchResult {
+        ensure!(
+            hash_lock.expiry > System::<T>::block_number(),
+            Error::<T>::InvalidHashLockExpiry
+        );
+        match settlement_type {
+            SettlementType::SettleOnAffirmation => Ok(()),
+            SettlementType::SettleManual(block_number) => {
+                ensure!(
+                    *block_number < hash_lock.expiry,
+                    Error::<T>::InvalidHashLockExpiry
+                );
+                Ok(())
+            }
+            SettlementType::SettleOnBlock(_) => {
+                Err(Error::<T>::InvalidSettlementTypeForHashLock.into())
+            }
+        }
+    }
+
+    /// Stores the `hash_lock` and the `hash_locked_legs` for `instruction_id` and schedules the release of the instruction's
+    /// assets at its expiry block. Returns an error if the release can't be scheduled.
+    fn set_hash_lock(
+        instruction_id: InstructionId,
+        hash_lock: HashLock<T::BlockNumber>,
+        hash_locked_legs: BTreeSet<LegId>,
+    ) -> DispatchResult {
+        let instruction_asset_count = Self::get_instruction_asset_count(&instruction_id);
+        let weight_limit = <T as Config>::WeightInfo::expire_hash_lock(
+            instruction_asset_count.fungible(),
+            instruction_asset_count.non_fungible(),
+            instruction_asset_count.off_chain(),
+        );
+        let call = Call::<T>::expire_hash_lock {
+            id: instruction_id,
+            weight_limit,
+        }
+        .into();
+        // Without the scheduled release the locked assets could only be freed by rejecting the instruction.
+        T::Scheduler::schedule_named(
+            instruction_id.hash_lock_expiry_name(),
+            DispatchTime::At(hash_lock.expiry),
+            None,
+            SETTLEMENT_HASH_LOCK_EXPIRY_PRIORITY,
+            RawOrigin::Root.into(),
+            call,
+        )
+        .map_err(|_| Error::<T>::HashLockSchedulingFailed)?;
+        InstructionHashLocks::<T>::insert(instruction_id, hash_lock.clone());
+        for leg_id in hash_locked_legs {
+            HashLockedLegs::insert(instruction_id, leg_id, true);
+        }
+        Self::deposit_event(RawEvent::InstructionHashLocked(instruction_id, hash_lock));
+        Ok(())
+    }
+
+    /// Returns the hash locked legs of the instruction, sorted by [`LegId`].
+    fn get_hash_locked_legs(id: &InstructionId) -> Vec<(LegId, Leg)> {
+        let mut hash_locked_legs: Vec<(LegId, Leg)> = HashLockedLegs::iter_key_prefix(id)
+            .filter_map(|leg_id| InstructionLegs::get(id, leg_id).map(|leg| (leg_id, leg)))
+            .collect();
+        hash_locked_legs.sort_by_key(|(leg_id, _)| *leg_id);
+        hash_locked_legs
+    }
+
+    /// Verifies `preimage` against the instruction's hash lock and executes its hash locked legs.
+    fn base_execute_with_preimage(
+        origin: T::RuntimeOrigin,
+        id: InstructionId,
+        preimage: Vec<u8>,
+        input_cost: &AssetCount,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResultWithPostInfo {
+        ensure_string_limited::<T>(&preimage)?;
+        let (caller_did, _, instruction_details) =
+            Self::ensure_origin_perm_and_instruction_validity(origin, id, true)?;
+
+        let hash_lock =
+            InstructionHashLocks::<T>::get(id).ok_or(Error::<T>::InstructionNotHashLocked)?;
+        ensure!(
+            System::<T>::block_number() < hash_lock.expiry,
+            Error::<T>::HashLockExpired
+        );
+        ensure!(
+            hash_lock.is_preimage(&preimage),
+            Error::<T>::InvalidPreimage
+        );
+
+        let hash_locked_legs = Self::get_hash_locked_legs(&id);
+        let hash_locked_asset_count = AssetCount::from_legs(&hash_locked_legs);
+        Self::ensure_valid_cost(&hash_locked_asset_count, input_cost)?;
+
+        let all_legs_locked =
+            hash_locked_legs.len() == InstructionLegs::iter_key_prefix(&id).count();
+        if all_legs_locked {
+            // The hash lock is only removed if the execution succeeds.
+            InstructionHashLocks::<T>::remove(id);
+            let _ = HashLockedLegs::clear_prefix(id, hash_locked_legs.len() as u32, None);
+            // We use execute_instruction here directly and not the execute_instruction_retryable variant
+            // because the preimage must not be revealed if the instruction fails.
+            Self::execute_instruction(id, caller_did, weight_meter)?;
+        } else {
+            Self::execute_hash_locked_legs(
+                id,
+                instruction_details,
+                hash_locked_legs,
+                caller_did,
+                weight_meter,
+            )?;
+        }
+        let _ = T::Scheduler::cancel_named(id.hash_lock_expiry_name());
+        Self::deposit_event(RawEvent::HashLockPreimageRevealed(caller_did, id, preimage));
+
+        Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
+    }
+
+    /// Transfers the `hash_locked_legs` of the instruction and removes them from storage. The remaining legs
+    /// stay pending and, if all affirmations have been received, are scheduled according to the settlement type.
+    fn execute_hash_locked_legs(
+        id: InstructionId,
+        instruction_details: Instruction<T::Moment, T::BlockNumber>,
+        hash_locked_legs: Vec<(LegId, Leg)>,
+        caller_did: IdentityId,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        // All parties must have affirmed the instruction, so that all hash locked assets are locked
+        ensure!(
+            Self::instruction_affirms_pending(id) == 0,
+            Error::<T>::NotAllAffirmationsHaveBeenReceived
+        );
+        Self::ensure_valid_mediator_affirmations(&id)?;
+
+        let hash_locked_asset_count = AssetCount::from_legs(&hash_locked_legs);
+        weight_meter
+            .check_accrue(<T as Config>::WeightInfo::execute_instruction_paused(
+                hash_locked_asset_count.fungible(),
+                hash_locked_asset_count.non_fungible(),
+                hash_locked_asset_count.off_chain(),
+            ))
+            .map_err(|_| Error::<T>::WeightLimitExceeded)?;
+
+        let mut failed_leg_id = None;
+        let tx_result = with_transaction(|| {
+            Self::ensure_allowed_venue(&hash_locked_legs, instruction_details.venue_id)?;
+            Self::ensure_settlement_not_paused(
+                &hash_locked_legs,
+                instruction_details.venue_id.as_ref(),
+            )?;
+            Self::release_locks(id, &hash_locked_legs)?;
+
+            let instruction_memo = InstructionMemos::get(&id);
+            if let Err(leg_id) = Self::transfer_pending_legs(
+                id,
+                &hash_locked_legs,
+                instruction_memo,
+                caller_did,
+                weight_meter,
+            ) {
+                failed_leg_id = Some(leg_id);
+                return Err(Error::<T>::FailedToReleaseLockOrTransferAssets.into());
+            }
+
+            // Removes the executed legs and the affirmations of the portfolios that are no longer a counter party
+            InstructionHashLocks::<T>::remove(id);
+            let remaining_portfolios: BTreeSet<PortfolioId> = InstructionLegs::iter_prefix(&id)
+                .filter(|(leg_id, _)| !HashLockedLegs::get(id, leg_id))
+                .flat_map(|(_, leg)| match leg {
+                    Leg::Fungible {
+                        sender, receiver, ..
+                    }
+                    | Leg::NonFungible {
+                        sender, receiver, ..
+                    } => vec![sender, receiver],
+                    Leg::OffChain { .. } => Vec::new(),
+                })
+                .collect();
+            for (leg_id, leg) in &hash_locked_legs {
+                InstructionLegs::remove(id, leg_id);
+                InstructionLegStatus::<T>::remove(id, leg_id);
+                HashLockedLegs::remove(id, leg_id);
+                match leg {
+                    Leg::Fungible {
+                        sender, receiver, ..
+                    }
+                    | Leg::NonFungible {
+                        sender, receiver, ..
+                    } => {
+                        for portfolio in [sender, receiver] {
+                            if !remaining_portfolios.contains(portfolio) {
+                                UserAffirmations::remove(portfolio, id);
+                                AffirmsReceived::remove(id, portfolio);
+                            }
+                        }
+                    }
+                    Leg::OffChain { .. } => {
+                        OffChainAffirmations::remove(id, leg_id);
+                    }
+                }
+                Self::deposit_event(RawEvent::HashLockedLegExecuted(caller_did, id, *leg_id));
+            }
+            Ok(())
+        });
+
+        // Since with_transaction reverts events as well, the events have to be emitted here
+        if let Some(failed_leg_id) = failed_leg_id {
+            Self::deposit_event(RawEvent::LegFailedExecution(caller_did, id, failed_leg_id));
+        }
+        tx_result?;
+
+        let instruction_asset_count = Self::get_instruction_asset_count(&id);
+        let weight_limit = Self::execute_scheduled_instruction_weight_limit(
+            instruction_asset_count.fungible(),
+            instruction_asset_count.non_fungible(),
+            instruction_asset_count.off_chain(),
+        );
+        Self::maybe_schedule_instruction(0, id, weight_limit);
+        Ok(())
+    }
+
+    /// Releases all locked assets of the hash locked instruction of the given `id` and rejects it.
+    /// Returns the actual weight consumed.
+    fn base_expire_hash_lock(id: InstructionId) -> Result<Weight, DispatchError> {
+        // The instruction has already been executed or rejected
+        if !InstructionHashLocks::<T>::contains_key(id) {
+            return Ok(<T as Config>::WeightInfo::expire_hash_lock(0, 0, 0));
+        }
+        let legs: Vec<(LegId, Leg)> = InstructionLegs::iter_prefix(&id).collect();
+        let instruction_asset_count = AssetCount::from_legs(&legs);
+        Self::release_locks(id, &legs)?;
+        let _ = T::Scheduler::cancel_named(id.execution_name());
+        Self::prune_rejected_instruction(id);
+        Self::deposit_event(RawEvent::HashLockExpired(id));
+        Ok(<T as Config>::WeightInfo::expire_hash_lock(
+            instruction_asset_count.fungible(),
+            instruction_asset_count.non_fungible(),
+            instruction_asset_count.off_chain(),
+        ))
+    }
+
+    /// Returns the minimum weight for calling the `execute_with_preimage` extrinsic.
+    fn execute_with_preimage_minimum_weight() -> Weight {
+        <T as Config>::WeightInfo::execute_with_preimage(0, 0, 0)
+    }
+
+    /// Returns `Ok` if `did` is the creator of the given `venue_id`. Instructions without a venue are not allowed.
+    fn ensure_instruction_venue_creator(
+        did: IdentityId,
+        venue_id: Option<VenueId>,
+    ) -> DispatchResult {
+        let venue_id = venue_id.ok_or(Error::<T>::InstructionHasNoVenue)?;
+        Self::venue_for_management(venue_id, did)?;
+        Ok(())
+    }
+
+    /// Updates the amount of all legs in `amendments`, resets the affirmations of the parties of the amended legs
+    /// and increments the revision of the instruction.
+    fn base_amend_instruction(
+        origin: T::RuntimeOrigin,
+        id: InstructionId,
+        amendments: BTreeMap<LegId, Balance>,
+        input_cost: Option<AssetCount>,
+    ) -> DispatchResultWithPostInfo {
+        let (caller_did, _, instruction_details) =
+            Self::ensure_origin_perm_and_instruction_validity(origin, id, false)?;
+        Self::ensure_instruction_venue_creator(caller_did, instruction_details.venue_id)?;
+        ensure!(
+            Self::instruction_status(id) == InstructionStatus::Pending,
+            Error::<T>::InstructionNotPending
+        );
+        ensure!(!amendments.is_empty(), Error::<T>::InvalidLegAmendment);
+
+        let instruction_asset_count = Self::get_instruction_asset_count(&id);
+        // If the fee was estimated in advance, the input values must be at least equal to the actual values
+        if let Some(input_cost) = input_cost {
+            Self::ensure_valid_cost(&instruction_asset_count, &input_cost)?;
+        }
+
+        // Tracks the amended legs and the parties that must affirm the instruction again
+        let mut amended_legs = Vec::with_capacity(amendments.len());
+        let mut portfolios = BTreeSet::new();
+        let mut mediators = BTreeSet::new();
+        for (leg_id, new_amount) in &amendments {
+            ensure!(*new_amount > 0, Error::<T>::ZeroAmount);
+            let mut leg = InstructionLegs::get(id, leg_id).ok_or(Error::<T>::LegNotFound)?;
+            match &mut leg {
+                Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    amount,
+                } => {
+                    ensure!(*amount != *new_amount, Error::<T>::InvalidLegAmendment);
+                    *amount = *new_amount;
+                    portfolios.insert(*sender);
+                    // Receivers that have pre-approved the asset don't have to affirm again
+                    if !T::Portfolio::skip_portfolio_affirmation(receiver, asset_id) {
+                        portfolios.insert(*receiver);
+                    }
+                    mediators.extend(MandatoryMediators::<T>::get(&*asset_id).iter());
+                }
+                Leg::OffChain { amount, .. } => {
+                    ensure!(*amount != *new_amount, Error::<T>::InvalidLegAmendment);
+                    *amount = *new_amount;
+                }
+                Leg::NonFungible { .. } => return Err(Error::<T>::InvalidLegAmendment.into()),
+            }
+            amended_legs.push((*leg_id, leg));
+        }
+
+        // Only the portfolios that have already affirmed must be reset. This must be done before updating the legs,
+        // since the locked amount is the amount before the amendment.
+        let affirmed_portfolios: BTreeSet<PortfolioId> = portfolios
+            .into_iter()
+            .filter(|portfolio| {
+                Self::user_affirmations(portfolio, id) == AffirmationStatus::Affirmed
+            })
+            .collect();
+        let filtered_legs = Self::filtered_legs(id, &affirmed_portfolios);
+        Self::unsafe_reset_affirmations(caller_did, id, &filtered_legs, &affirmed_portfolios)?;
+
+        // Tracks the number of off-chain and mediator affirmations that have been reset
+        let mut n_reset = 0;
+        for (leg_id, leg) in amended_legs {
+            // The receipt of an amended off-chain leg is no longer valid
+            if let Leg::OffChain { .. } = leg {
+                if OffChainAffirmations::get(id, leg_id) == AffirmationStatus::Affirmed {
+                    OffChainAffirmations::insert(id, leg_id, AffirmationStatus::Pending);
+                    <InstructionLegStatus<T>>::insert(id, leg_id, LegStatus::PendingTokenLock);
+                    n_reset += 1;
+                }
+            }
+            InstructionLegs::insert(id, leg_id, leg);
+        }
+        for mediator in mediators {
+            if let MediatorAffirmationStatus::Affirmed { .. } =
+                InstructionMediatorsAffirmations::<T>::get(id, mediator)
+            {
+                InstructionMediatorsAffirmations::<T>::insert(
+                    id,
+                    mediator,
+                    MediatorAffirmationStatus::Pending,
+                );
+                n_reset += 1;
+            }
+        }
+        InstructionAffirmsPending::mutate(id, |affirms_pending| {
+            *affirms_pending = affirms_pending.saturating_add(n_reset)
+        });
+        if (n_reset > 0 || !affirmed_portfolios.is_empty())
+            && instruction_details.settlement_type == SettlementType::SettleOnAffirmation
+        {
+            // Cancel the scheduled task for the execution of the instruction
+            let _ = T::Scheduler::cancel_named(id.execution_name());
+        }
+
+        let revision = InstructionRevisions::mutate(id, |revision| {
+            *revision = revision.saturating_add(1);
+            *revision
+        });
+        Self::deposit_event(RawEvent::InstructionAmended(
+            caller_did, id, revision, amendments,
+        ));
+        Ok(PostDispatchInfo::from(Some(
+            <T as Config>::WeightInfo::amend_instruction(
+                instruction_asset_count.fungible(),
+                instruction_asset_count.non_fungible(),
+                instruction_asset_count.off_chain(),
+            ),
+        )))
+    }
+
+    /// Transfers the given amount of each fungible leg in `partial_amounts` and keeps the remaining amount pending.
+    fn base_partially_execute_instruction(
+        origin: T::RuntimeOrigin,
+        id: InstructionId,
+        partial_amounts: BTreeMap<LegId, Balance>,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResultWithPostInfo {
+        let (caller_did, _, instruction_details) =
+            Self::ensure_origin_perm_and_instruction_validity(origin, id, true)?;
+        Self::ensure_instruction_venue_creator(caller_did, instruction_details.venue_id)?;
+        ensure!(
+            Self::instruction_status(id) == InstructionStatus::Pending,
+            Error::<T>::InstructionNotPending
+        );
+        ensure!(
+            !InstructionHashLocks::<T>::contains_key(id),
+            Error::<T>::InstructionIsHashLocked
+        );
+        // All parties must have affirmed the instruction
+        ensure!(
+            Self::instruction_affirms_pending(id) == 0,
+            Error::<T>::NotAllAffirmationsHaveBeenReceived
+        );
+        Self::ensure_valid_mediator_affirmations(&id)?;
+        ensure!(
+            !partial_amounts.is_empty(),
+            Error::<T>::InvalidPartialExecution
+        );
+
+        let instruction_memo = InstructionMemos::get(&id);
+        let mut tickers = BTreeSet::new();
+        for (leg_id, partial_amount) in partial_amounts {
+            let leg = InstructionLegs::get(id, leg_id).ok_or(Error::<T>::LegNotFound)?;
+            let (sender, receiver, asset_id, amount) = match leg {
+                Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    amount,
+                } => (sender, receiver, asset_id, amount),
+                Leg::NonFungible { .. } | Leg::OffChain { .. } => {
+                    return Err(Error::<T>::InvalidPartialExecution.into())
+                }
+            };
+            ensure!(
+                partial_amount > 0 && partial_amount < amount,
+                Error::<T>::InvalidPartialExecution
+            );
+            ensure!(
+                Self::instruction_leg_status(id, leg_id) == LegStatus::ExecutionPending,
+                Error::<T>::UnexpectedLegStatus
+            );
+            Self::ensure_venue_filtering(&mut tickers, asset_id, &instruction_details.venue_id)?;
+            <Asset<T>>::ensure_settlement_not_paused(
+                &asset_id,
+                instruction_details.venue_id.as_ref(),
+            )?;
+
+            T::Portfolio::unlock_tokens(&sender, &asset_id, partial_amount)?;
+            <Asset<T>>::base_transfer(
+                sender,
+                receiver,
+                asset_id,
+                partial_amount,
+                Some(id),
+                instruction_memo.clone(),
+                caller_did,
+                weight_meter,
+            )?;
+            // The fee is charged from the reservation for the executed amount only, so a flat fee is only charged once
+            if let Some(mut reserved_fee) = ReservedTransferFees::get(id, leg_id) {
+                let executed_leg = Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    amount: partial_amount,
+                };
+                let fee_amount = Self::leg_transfer_fee(leg_id, &executed_leg)
+                    .map_or(0, |leg_fee| leg_fee.amount)
+                    .min(reserved_fee.amount);
+                if fee_amount > 0 {
+                    T::Portfolio::unlock_tokens(
+                        &reserved_fee.payer,
+                        &reserved_fee.asset_id,
+                        fee_amount,
+                    )?;
+                    Self::charge_leg_transfer_fee(
+                        id,
+                        LegTransferFee {
+                            amount: fee_amount,
+                            ..reserved_fee.clone()
+                        },
+                        instruction_memo.clone(),
+                        caller_did,
+                        weight_meter,
+                    )?;
+                    // Safe, since the fee amount is at most the reserved amount
+                    reserved_fee.amount -= fee_amount;
+                    if reserved_fee.amount == 0 {
+                        ReservedTransferFees::remove(id, leg_id);
+                    } else {
+                        ReservedTransferFees::insert(id, leg_id, reserved_fee);
+                    }
+                }
+            }
+            // Safe, since the partial amount is less than the leg's amount
+            let remaining_amount = amount - partial_amount;
+            InstructionLegs::insert(
+                id,
+                leg_id,
+                Leg::Fungible {
+                    sender,
+                    receiver,
+                    asset_id,
+                    amount: remaining_amount,
+                },
+            );
+            Self::deposit_event(RawEvent::LegPartiallyExecuted(
+                caller_did,
+                id,
+                leg_id,
+                partial_amount,
+                remaining_amount,
+            ));
+        }
+
+        Ok(PostDispatchInfo::from(Some(weight_meter.consumed())))
+    }
+
+    /// Returns `Ok` if none of the mediator's affirmations have expired.
+    /// Unlike [`Self::ensure_non_expired_affirmations`], this doesn't remove the affirmations from storage.
+    fn ensure_valid_mediator_affirmations(instruction_id: &InstructionId) -> DispatchResult {
+        let current_timestamp = <pallet_timestamp::Pallet<T>>::get();
+        for (_, mediator_affirmation) in
+            InstructionMediatorsAffirmations::<T>::iter_prefix(instruction_id)
+        {
+            if let MediatorAffirmationStatus::Affirmed {
+                expiry: Some(expiry),
+            } = mediator_affirmation
+            {
+                ensure!(
+                    expiry > current_timestamp,
+                    Error::<T>::MediatorAffirmationExpired
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the worst case weight for an instruction with `f` fungible legs, `n` nfts being transferred and `o` offchain assets.
     fn execute_scheduled_instruction_weight_limit(f: u32, n: u32, o: u32) -> Weight {
         <T as Config>::WeightInfo::execute_scheduled_instruction(f, n, o)
@@ -2616,6 +3713,7 @@ impl<T: Config> Module<T> {
             filtered_legs.receiver_asset_count().clone(),
             filtered_legs.unfiltered_asset_count().off_chain(),
         )
+        .with_revision(InstructionRevisions::get(instruction_id))
     }
 
     /// Returns a vector containing all errors for the transfer. An empty vec means there's no error.
@@ -2675,16 +3773,34 @@ impl<T: Config> Module<T> {
             InstructionStatus::Pending | InstructionStatus::Failed => {}
         }
 
+        if InstructionHashLocks::<T>::contains_key(instruction_id) {
+            execution_errors.push(Error::<T>::InstructionIsHashLocked.into());
+        }
+
         let instruction_legs: Vec<(LegId, Leg)> =
             InstructionLegs::iter_prefix(&instruction_id).collect();
         let venue_id = Self::instruction_details(instruction_id).venue_id;
         if let Err(e) = Self::ensure_allowed_venue(&instruction_legs, venue_id) {
             execution_errors.push(e);
         }
+        if let Err(e) = Self::ensure_settlement_not_paused(&instruction_legs, venue_id.as_ref()) {
+            execution_errors.push(e);
+        }
 
         for (leg_id, leg) in instruction_legs {
             let leg_status = Self::instruction_leg_status(instruction_id, leg_id);
             if leg_status == LegStatus::ExecutionPending {
+                if let Some(leg_fee) = ReservedTransferFees::get(instruction_id, leg_id) {
+                    let fee_errors = <Asset<T>>::asset_transfer_report(
+                        &leg_fee.payer,
+                        &leg_fee.beneficiary,
+                        &leg_fee.asset_id,
+                        leg_fee.amount,
+                        false,
+                        weight_meter,
+                    );
+                    execution_errors.extend_from_slice(&fee_errors);
+                }
                 let transfer_errors = Self::transfer_report(leg, true, weight_meter);
                 execution_errors.extend_from_slice(&transfer_errors);
             }
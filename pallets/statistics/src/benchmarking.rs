@@ -77,6 +77,13 @@ fn make_transfer_conditions(stats: &BTreeSet<StatType>, count: u32) -> BTreeSet<
                         claim_type_to_stat_claim(claim_type).expect("Unsupported ClaimType");
                     TransferCondition::ClaimOwnership(claim, issuer, p0, p40)
                 }
+                (StatOpType::InvestorVolume, _) => {
+                    TransferCondition::MaxInvestorVolume(30, ONE_UNIT * POLY)
+                }
+                (StatOpType::AssetVolume, _) => {
+                    TransferCondition::MaxAssetVolume(30, ONE_UNIT * POLY)
+                }
+                (StatOpType::AcquisitionLots, _) => TransferCondition::MinHoldingPeriod(0),
             },
         )
         .collect()
@@ -259,7 +266,7 @@ benchmarks! {
         assert!(Module::<T>::check_transfer_condition(
             &transfer_condition,
             asset_id,
-            &alice.did(),
+            &PortfolioId::default_portfolio(alice.did()),
             &bob.did(),
             0,
             ONE_UNIT,
@@ -280,7 +287,7 @@ benchmarks! {
         assert!(Module::<T>::check_transfer_condition(
             &transfer_condition,
             asset_id,
-            &alice.did(),
+            &PortfolioId::default_portfolio(alice.did()),
             &bob.did(),
             0,
             ONE_UNIT,
@@ -306,7 +313,7 @@ benchmarks! {
         assert!(Module::<T>::check_transfer_condition(
             &transfer_condition,
             asset_id,
-            &alice.did(),
+            &PortfolioId::default_portfolio(alice.did()),
             &bob.did(),
             0,
             ONE_UNIT,
@@ -328,7 +335,7 @@ benchmarks! {
         assert!(Module::<T>::check_transfer_condition(
             &transfer_condition,
             asset_id,
-            &alice.did(),
+            &PortfolioId::default_portfolio(alice.did()),
             &bob.did(),
             0,
             ONE_UNIT,
@@ -360,7 +367,7 @@ benchmarks! {
         assert!(Module::<T>::check_transfer_condition(
             &transfer_condition,
             asset_id,
-            &alice.did(),
+            &PortfolioId::default_portfolio(alice.did()),
             &bob.did(),
             0,
             ONE_UNIT,
@@ -472,7 +479,7 @@ benchmarks! {
             Module::<T>::verify_requirements::<T::MaxTransferConditionsPerAsset>(
                 &transfer_conditions.try_into().unwrap(),
                 asset_id,
-                &alice.did(),
+                &PortfolioId::default_portfolio(alice.did()),
                 &bob.did(),
                 ONE_UNIT,
                 1,
@@ -519,4 +526,102 @@ benchmarks! {
             )
         );
     }
+
+    volume_restriction {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset::<T>(&alice, true, None, b"MyAsset", true);
+        let transfer_condition = TransferCondition::MaxInvestorVolume(T::MaxVolumePeriods::get(), ONE_UNIT * POLY);
+        let key1 = Stat1stKey { asset_id, stat_type: transfer_condition.get_stat_type() };
+        // Fills all periods of the rolling window
+        let mut volumes = PeriodVolumes::default();
+        for period in 0..T::MaxVolumePeriods::get() as u64 {
+            volumes.add(period, ONE_UNIT, 0);
+        }
+        AssetVolumeStats::insert(key1, Some(alice.did()), volumes);
+    }: {
+        assert!(Module::<T>::check_transfer_condition(
+            &transfer_condition,
+            asset_id,
+            &PortfolioId::default_portfolio(alice.did()),
+            &bob.did(),
+            0,
+            ONE_UNIT,
+            ONE_UNIT * POLY,
+            None,
+            &mut weight_meter
+        ).unwrap());
+    }
+
+    update_asset_volume_stats {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset::<T>(&alice, true, None, b"MyAsset", true);
+        let stat_type = StatType { operation_type: StatOpType::InvestorVolume, claim_issuer: None };
+        let key1 = Stat1stKey { asset_id, stat_type };
+        let mut volumes = PeriodVolumes::default();
+        for period in 0..T::MaxVolumePeriods::get() as u64 {
+            volumes.add(period, ONE_UNIT, 0);
+        }
+        AssetVolumeStats::insert(key1, Some(alice.did()), volumes);
+    }: {
+        Module::<T>::update_asset_volume_stats(key1, Some(alice.did()), ONE_UNIT, &mut weight_meter).unwrap();
+    }
+
+    holding_period_restriction {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset::<T>(&alice, true, None, b"MyAsset", true);
+        let transfer_condition = TransferCondition::MinHoldingPeriod(0);
+        let key1 = Stat1stKey { asset_id, stat_type: transfer_condition.get_stat_type() };
+        let mut lots = AcquisitionLots::default();
+        for acquired_at in 0..T::MaxAcquisitionLots::get() as u64 {
+            lots.add(acquired_at, 1, T::MaxAcquisitionLots::get() as usize);
+        }
+        PortfolioAcquisitionLots::insert(key1, PortfolioId::default_portfolio(alice.did()), lots);
+    }: {
+        assert!(Module::<T>::check_transfer_condition(
+            &transfer_condition,
+            asset_id,
+            &PortfolioId::default_portfolio(alice.did()),
+            &bob.did(),
+            0,
+            ONE_UNIT,
+            ONE_UNIT * POLY,
+            None,
+            &mut weight_meter
+        ).unwrap());
+    }
+
+    update_acquisition_lots {
+        let alice = UserBuilder::<T>::default().generate_did().build("Alice");
+        let bob = UserBuilder::<T>::default().generate_did().build("Bob");
+        let mut weight_meter = WeightMeter::max_limit_no_minimum();
+
+        let asset_id = create_and_issue_sample_asset::<T>(&alice, true, None, b"MyAsset", true);
+        let stat_type = StatType { operation_type: StatOpType::AcquisitionLots, claim_issuer: None };
+        let key1 = Stat1stKey { asset_id, stat_type };
+        let mut lots = AcquisitionLots::default();
+        for acquired_at in 0..T::MaxAcquisitionLots::get() as u64 {
+            lots.add(acquired_at, ONE_UNIT, T::MaxAcquisitionLots::get() as usize);
+        }
+        let alice_portfolio = PortfolioId::default_portfolio(alice.did());
+        let bob_portfolio = PortfolioId::default_portfolio(bob.did());
+        PortfolioAcquisitionLots::insert(key1, alice_portfolio, lots.clone());
+        PortfolioAcquisitionLots::insert(key1, bob_portfolio, lots);
+    }: {
+        Module::<T>::update_acquisition_lots(
+            key1,
+            Some(&alice_portfolio),
+            Some(&bob_portfolio),
+            ONE_UNIT,
+            &mut weight_meter
+        )
+        .unwrap();
+    }
 }
@@ -24,19 +24,21 @@ use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_module, decl_storage, ensure, BoundedBTreeSet};
+use sp_runtime::SaturatedConversion;
 use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
 
 use polymesh_common_utilities::asset::AssetFnTrait;
 pub use polymesh_common_utilities::traits::statistics::{Config, Event, WeightInfo};
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::statistics::{
-    Percentage, Stat1stKey, Stat2ndKey, StatOpType, StatType, StatUpdate,
+    AcquisitionLots, Percentage, PeriodVolumes, Stat1stKey, Stat2ndKey, StatOpType, StatType,
+    StatUpdate,
 };
 use polymesh_primitives::transfer_compliance::{
     AssetTransferCompliance, TransferCondition, TransferConditionExemptKey,
 };
 use polymesh_primitives::{
-    storage_migrate_on, storage_migration_ver, Balance, IdentityId, WeightMeter,
+    storage_migrate_on, storage_migration_ver, Balance, IdentityId, PortfolioId, WeightMeter,
 };
 
 type Identity<T> = pallet_identity::Module<T>;
@@ -62,6 +64,16 @@ decl_storage! {
         pub TransferConditionExemptEntities get(fn transfer_condition_exempt_entities):
             double_map hasher(blake2_128_concat) TransferConditionExemptKey, hasher(blake2_128_concat) IdentityId => bool;
 
+        /// The volume transferred in each period for the volume stats of an asset.
+        /// For [`StatOpType::InvestorVolume`] the volume sent by each identity is stored, while for
+        /// [`StatOpType::AssetVolume`] the total volume is stored under `None`.
+        pub AssetVolumeStats get(fn asset_volume_stats):
+            double_map hasher(blake2_128_concat) Stat1stKey, hasher(blake2_128_concat) Option<IdentityId> => PeriodVolumes;
+
+        /// The acquisition lots of each portfolio for the [`StatOpType::AcquisitionLots`] stats of an asset.
+        pub PortfolioAcquisitionLots get(fn portfolio_acquisition_lots):
+            double_map hasher(blake2_128_concat) Stat1stKey, hasher(blake2_128_concat) PortfolioId => AcquisitionLots;
+
         /// Storage migration version.
         StorageVersion get(fn storage_version) build(|_| Version::new(3)): Version;
     }
@@ -73,6 +85,9 @@ decl_module! {
 
         const MaxStatsPerAsset: u32 = T::MaxStatsPerAsset::get();
         const MaxTransferConditionsPerAsset: u32 = T::MaxTransferConditionsPerAsset::get();
+        const VolumePeriodLength: u64 = T::VolumePeriodLength::get();
+        const MaxVolumePeriods: u32 = T::MaxVolumePeriods::get();
+        const MaxAcquisitionLots: u32 = T::MaxAcquisitionLots::get();
 
         /// initialize the default event for this module
         fn deposit_event() = default;
@@ -134,6 +149,7 @@ decl_module! {
         /// # Errors
         /// - `TransferConditionLimitReached` - too many transfer condititon enabled for `asset_id`.
         /// - `StatTypeMissing` - a transfer condition requires a stat type that is not enabled for the `asset_id`.
+        /// - `InvalidVolumeWindow` - a volume transfer condition has zero periods or more than `MaxVolumePeriods`.
         /// - `UnauthorizedAgent` if `origin` is not agent-permissioned for `asset_id`.
         ///
         /// # Permissions
@@ -188,6 +204,14 @@ impl<T: Config> Module<T> {
         let stat_types: BoundedBTreeSet<_, T::MaxStatsPerAsset> = stat_types
             .try_into()
             .map_err(|_| Error::<T>::StatTypeLimitReached)?;
+        // Volume and lot stats are kept per investor or per asset, never per claim.
+        ensure!(
+            stat_types
+                .iter()
+                .all(|stat_type| stat_type.claim_issuer.is_none()
+                    || stat_type.operation_type.supports_claims()),
+            Error::<T>::StatTypeClaimNotSupported
+        );
 
         // Get list of StatTypes required by current TransferConditions.
         let required_types = AssetTransferCompliances::<T>::get(&asset_id)
@@ -215,14 +239,20 @@ impl<T: Config> Module<T> {
         // Cleanup storage for old types to be removed.
         for stat_type in &remove_types {
             // Cleanup storage for this stat type, since it is being removed.
+            let key1 = Stat1stKey {
+                asset_id,
+                stat_type: *stat_type,
+            };
             #[allow(deprecated)]
-            AssetStats::remove_prefix(
-                Stat1stKey {
-                    asset_id,
-                    stat_type: *stat_type,
-                },
-                None,
-            );
+            AssetStats::remove_prefix(key1, None);
+            if stat_type.operation_type.is_volume() {
+                #[allow(deprecated)]
+                AssetVolumeStats::remove_prefix(key1, None);
+            }
+            if stat_type.operation_type == StatOpType::AcquisitionLots {
+                #[allow(deprecated)]
+                PortfolioAcquisitionLots::remove_prefix(key1, None);
+            }
         }
 
         // Save new stat types.
@@ -300,6 +330,12 @@ impl<T: Config> Module<T> {
                     Self::is_asset_stat_active(&asset_id, &stat_type),
                     Error::<T>::StatTypeMissing
                 );
+                if let Some(periods) = condition.volume_periods() {
+                    ensure!(
+                        periods > 0 && periods <= T::MaxVolumePeriods::get(),
+                        Error::<T>::InvalidVolumeWindow
+                    );
+                }
             }
 
             AssetTransferCompliances::<T>::mutate(&asset_id, |old| {
@@ -485,10 +521,13 @@ impl<T: Config> Module<T> {
     }
 
     /// Update asset stats.
+    ///
+    /// `from_balance` and `to_balance` are the identity balances after the update,
+    /// and the portfolio balances must also have already been updated.
     pub fn update_asset_stats(
         asset_id: AssetId,
-        from_did: Option<&IdentityId>,
-        to_did: Option<&IdentityId>,
+        from_portfolio: Option<&PortfolioId>,
+        to_portfolio: Option<&PortfolioId>,
         from_balance: Option<Balance>,
         to_balance: Option<Balance>,
         amount: Balance,
@@ -498,6 +537,8 @@ impl<T: Config> Module<T> {
         if amount == 0u128 {
             return Ok(());
         }
+        let from_did = from_portfolio.map(|portfolio| &portfolio.did);
+        let to_did = to_portfolio.map(|portfolio| &portfolio.did);
 
         Self::consume_weight_meter(
             weight_meter,
@@ -542,11 +583,217 @@ impl<T: Config> Module<T> {
                         weight_meter,
                     )?;
                 }
+                StatOpType::InvestorVolume => {
+                    // Only transfers between investors are counted, not mints or burns.
+                    if from_balance.is_some() && to_balance.is_some() {
+                        Self::update_asset_volume_stats(
+                            key1,
+                            from_did.copied(),
+                            amount,
+                            weight_meter,
+                        )?;
+                    }
+                }
+                StatOpType::AssetVolume => {
+                    if from_balance.is_some() && to_balance.is_some() {
+                        Self::update_asset_volume_stats(key1, None, amount, weight_meter)?;
+                    }
+                }
+                StatOpType::AcquisitionLots => {
+                    Self::update_acquisition_lots(
+                        key1,
+                        from_portfolio,
+                        to_portfolio,
+                        amount,
+                        weight_meter,
+                    )?;
+                }
             }
         }
         Ok(())
     }
 
+    /// Returns the current timestamp, in milliseconds.
+    fn now() -> u64 {
+        pallet_timestamp::Pallet::<T>::get().saturated_into()
+    }
+
+    /// Returns the current period of the volume stats.
+    fn current_volume_period() -> u64 {
+        Self::now() / T::VolumePeriodLength::get().max(1)
+    }
+
+    /// Removes the units sent by `from_portfolio`, oldest first, and records a new lot for the units received by `to_portfolio`.
+    /// Units moved between portfolios of the same identity keep their acquisition time.
+    fn update_acquisition_lots(
+        key1: Stat1stKey,
+        from_portfolio: Option<&PortfolioId>,
+        to_portfolio: Option<&PortfolioId>,
+        amount: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        Self::consume_weight_meter(
+            weight_meter,
+            <T as Config>::WeightInfo::update_acquisition_lots(),
+        )?;
+        let taken_lots = from_portfolio
+            .map(|from_portfolio| Self::take_acquisition_lots(key1, from_portfolio, amount))
+            .unwrap_or_default();
+        if let Some(to_portfolio) = to_portfolio {
+            let max_lots = T::MaxAcquisitionLots::get() as usize;
+            let same_identity = from_portfolio.map(|p| p.did) == Some(to_portfolio.did);
+            PortfolioAcquisitionLots::mutate(key1, to_portfolio, |lots| {
+                if same_identity {
+                    lots.merge(taken_lots, max_lots);
+                } else {
+                    lots.add(Self::now(), amount, max_lots);
+                }
+            });
+        }
+        Ok(())
+    }
+
+    /// Removes `amount` units, oldest first, from the acquisition lots of `portfolio` and returns the removed lots.
+    /// The balance of `portfolio` must have already been reduced by `amount`.
+    fn take_acquisition_lots(
+        key1: Stat1stKey,
+        portfolio: &PortfolioId,
+        amount: Balance,
+    ) -> AcquisitionLots {
+        let balance = T::Asset::portfolio_asset_balance(portfolio, &key1.asset_id);
+        let mut lots = PortfolioAcquisitionLots::get(key1, portfolio);
+        let taken_lots = lots.take(amount, balance.saturating_add(amount));
+        if lots.lots().is_empty() {
+            PortfolioAcquisitionLots::remove(key1, portfolio);
+        } else {
+            PortfolioAcquisitionLots::insert(key1, portfolio, lots);
+        }
+        taken_lots
+    }
+
+    /// Moves the acquisition lots of `amount` units of `asset_id` between two portfolios of the same identity.
+    /// Must be called after the portfolio balances have been updated.
+    pub fn move_acquisition_lots(
+        asset_id: AssetId,
+        from_portfolio: &PortfolioId,
+        to_portfolio: &PortfolioId,
+        amount: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        if amount == 0 {
+            return Ok(());
+        }
+        let stat_type = StatType {
+            operation_type: StatOpType::AcquisitionLots,
+            claim_issuer: None,
+        };
+        if !Self::active_asset_stats(asset_id).contains(&stat_type) {
+            return Ok(());
+        }
+        Self::update_acquisition_lots(
+            Stat1stKey {
+                asset_id,
+                stat_type,
+            },
+            Some(from_portfolio),
+            Some(to_portfolio),
+            amount,
+            weight_meter,
+        )
+    }
+
+    /// Returns the acquisition lots of `asset_id` held by `portfolio`.
+    pub fn acquisition_lots(asset_id: AssetId, portfolio: &PortfolioId) -> AcquisitionLots {
+        PortfolioAcquisitionLots::get(Self::acquisition_lots_key(asset_id), portfolio)
+    }
+
+    /// Replaces the acquisition lots of `asset_id` held by `portfolio`, if the asset tracks them.
+    /// Used when a reorganization rescales the balance of `portfolio` without changing the acquisition time of its units.
+    pub fn set_acquisition_lots(asset_id: AssetId, portfolio: &PortfolioId, lots: AcquisitionLots) {
+        let key1 = Self::acquisition_lots_key(asset_id);
+        if lots.lots().is_empty() || !Self::active_asset_stats(asset_id).contains(&key1.stat_type) {
+            PortfolioAcquisitionLots::remove(key1, portfolio);
+        } else {
+            PortfolioAcquisitionLots::insert(key1, portfolio, lots);
+        }
+    }
+
+    /// Returns the key of the acquisition lots of `asset_id`.
+    fn acquisition_lots_key(asset_id: AssetId) -> Stat1stKey {
+        Stat1stKey {
+            asset_id,
+            stat_type: StatType {
+                operation_type: StatOpType::AcquisitionLots,
+                claim_issuer: None,
+            },
+        }
+    }
+
+    /// Verify holding period restrictions.
+    /// Returns `true` if `from_portfolio` has held at least `amount` units for `holding_period` milliseconds.
+    fn verify_holding_period_restriction(
+        key1: Stat1stKey,
+        from_portfolio: &PortfolioId,
+        to_did: &IdentityId,
+        amount: Balance,
+        holding_period: u64,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<bool, DispatchError> {
+        Self::consume_weight_meter(
+            weight_meter,
+            <T as Config>::WeightInfo::holding_period_restriction(),
+        )?;
+        // Moving units between portfolios of the same identity is not a sale.
+        if from_portfolio.did == *to_did {
+            return Ok(true);
+        }
+        let balance = T::Asset::portfolio_asset_balance(from_portfolio, &key1.asset_id);
+        let acquired_before = Self::now().saturating_sub(holding_period);
+        let matured_balance = PortfolioAcquisitionLots::get(key1, from_portfolio)
+            .matured_balance(balance, acquired_before);
+        Ok(amount <= matured_balance)
+    }
+
+    /// Adds `amount` to the volume of the current period and prunes the periods that can't be part of any window.
+    fn update_asset_volume_stats(
+        key1: Stat1stKey,
+        did: Option<IdentityId>,
+        amount: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        Self::consume_weight_meter(
+            weight_meter,
+            <T as Config>::WeightInfo::update_asset_volume_stats(),
+        )?;
+        let current_period = Self::current_volume_period();
+        let oldest_period =
+            current_period.saturating_sub(T::MaxVolumePeriods::get().saturating_sub(1).into());
+        AssetVolumeStats::mutate(key1, did, |volumes| {
+            volumes.add(current_period, amount, oldest_period)
+        });
+        Ok(())
+    }
+
+    /// Verify rolling-window volume restrictions.
+    /// Returns `true` if the volume transferred in the last `periods` periods plus `amount` doesn't exceed `max_volume`.
+    fn verify_volume_restriction(
+        key1: Stat1stKey,
+        did: Option<IdentityId>,
+        periods: u32,
+        amount: Balance,
+        max_volume: Balance,
+        weight_meter: &mut WeightMeter,
+    ) -> Result<bool, DispatchError> {
+        Self::consume_weight_meter(
+            weight_meter,
+            <T as Config>::WeightInfo::volume_restriction(),
+        )?;
+        let first_period =
+            Self::current_volume_period().saturating_sub(periods.saturating_sub(1).into());
+        let volume = AssetVolumeStats::get(key1, did).volume_since(first_period);
+        Ok(volume.saturating_add(amount) <= max_volume)
+    }
+
     /// Verify asset investor count restrictions.
     fn verify_asset_count_restriction(
         key1: Stat1stKey,
@@ -752,7 +999,7 @@ impl<T: Config> Module<T> {
     fn check_transfer_condition(
         condition: &TransferCondition,
         asset_id: AssetId,
-        from_did: &IdentityId,
+        from_portfolio: &PortfolioId,
         to_did: &IdentityId,
         to_balance: Balance,
         amount: Balance,
@@ -760,6 +1007,7 @@ impl<T: Config> Module<T> {
         count_changes: Option<(bool, bool)>,
         weight_meter: &mut WeightMeter,
     ) -> Result<bool, DispatchError> {
+        let from_did = &from_portfolio.did;
         let stat_type = condition.get_stat_type();
         let key1 = Stat1stKey {
             asset_id,
@@ -807,6 +1055,27 @@ impl<T: Config> Module<T> {
                     weight_meter,
                 )?
             }
+            TransferCondition::MaxInvestorVolume(periods, max) => Self::verify_volume_restriction(
+                key1,
+                Some(*from_did),
+                *periods,
+                amount,
+                *max,
+                weight_meter,
+            )?,
+            TransferCondition::MaxAssetVolume(periods, max) => {
+                Self::verify_volume_restriction(key1, None, *periods, amount, *max, weight_meter)?
+            }
+            TransferCondition::MinHoldingPeriod(holding_period) => {
+                Self::verify_holding_period_restriction(
+                    key1,
+                    from_portfolio,
+                    to_did,
+                    amount,
+                    *holding_period,
+                    weight_meter,
+                )?
+            }
         };
         if passed {
             Ok(true)
@@ -816,9 +1085,9 @@ impl<T: Config> Module<T> {
         }
     }
 
-    /// Returns `true` if the [`TransferCondition`] operation is of type [`StatOpType::Count`] and `sender_did`
-    /// is in the exemption list or if [`TransferCondition`] operation is of type [`StatOpType::Balance`] and
-    /// `receiver_did` is in the exemption list, otherwise returns `false`.
+    /// Returns `true` if the [`TransferCondition`] operation is of type [`StatOpType::Count`], a volume type or
+    /// [`StatOpType::AcquisitionLots`] and `sender_did` is in the exemption list or if [`TransferCondition`] operation is of type [`StatOpType::Balance`]
+    /// and `receiver_did` is in the exemption list, otherwise returns `false`.
     fn is_exempt(
         asset_id: AssetId,
         transfer_condition: &TransferCondition,
@@ -827,8 +1096,11 @@ impl<T: Config> Module<T> {
     ) -> bool {
         let transfer_condition_exempt_key = transfer_condition.get_exempt_key(asset_id);
         match transfer_condition_exempt_key.op {
-            // Count transfer conditions require the sender to be exempt.
-            StatOpType::Count => {
+            // Count, volume and holding period transfer conditions require the sender to be exempt.
+            StatOpType::Count
+            | StatOpType::InvestorVolume
+            | StatOpType::AssetVolume
+            | StatOpType::AcquisitionLots => {
                 Self::transfer_condition_exempt_entities(transfer_condition_exempt_key, sender_did)
             }
             // Percent ownersip transfer conditions require the receiver to be exempt.
@@ -840,10 +1112,16 @@ impl<T: Config> Module<T> {
     }
 
     /// Verify transfer restrictions for a transfer.
+    /// Returns `true` if the asset has transfer conditions that are not paused.
+    pub fn has_transfer_conditions(asset_id: &AssetId) -> bool {
+        let asset_transfer_requirements = AssetTransferCompliances::<T>::get(asset_id);
+        !asset_transfer_requirements.paused && !asset_transfer_requirements.requirements.is_empty()
+    }
+
     pub fn verify_transfer_restrictions(
         asset_id: AssetId,
-        sender_did: &IdentityId,
-        receiver_did: &IdentityId,
+        sender_portfolio: &PortfolioId,
+        receiver_portfolio: &PortfolioId,
         sender_balance: Balance,
         receiver_balance: Balance,
         transfer_amount: Balance,
@@ -860,8 +1138,8 @@ impl<T: Config> Module<T> {
         Self::verify_requirements(
             &asset_transfer_requirements.requirements,
             asset_id,
-            sender_did,
-            receiver_did,
+            sender_portfolio,
+            &receiver_portfolio.did,
             sender_balance,
             receiver_balance,
             transfer_amount,
@@ -874,7 +1152,7 @@ impl<T: Config> Module<T> {
     fn verify_requirements<S: Get<u32>>(
         transfer_conditions: &BoundedBTreeSet<TransferCondition, S>,
         asset_id: AssetId,
-        sender_did: &IdentityId,
+        sender_portfolio: &PortfolioId,
         receiver_did: &IdentityId,
         sender_balance: Balance,
         receiver_balance: Balance,
@@ -893,7 +1171,7 @@ impl<T: Config> Module<T> {
             if !Self::check_transfer_condition(
                 &transfer_condition,
                 asset_id,
-                sender_did,
+                sender_portfolio,
                 receiver_did,
                 receiver_balance,
                 transfer_amount,
@@ -909,9 +1187,10 @@ impl<T: Config> Module<T> {
     }
 
     /// Returns a vector containing all [`TransferCondition`] that are not being respected for the transfer. An empty vec means there's no error.
+    /// Holding periods are checked against the acquisition lots of `sender_portfolio`, as when the transfer is executed.
     pub fn transfer_restrictions_report(
         asset_id: AssetId,
-        sender_did: &IdentityId,
+        sender_portfolio: &PortfolioId,
         receiver_did: &IdentityId,
         transfer_amount: Balance,
         weight_meter: &mut WeightMeter,
@@ -925,7 +1204,7 @@ impl<T: Config> Module<T> {
             return Ok(failed_conditions);
         }
 
-        let sender_current_balance = T::Asset::asset_balance(&asset_id, sender_did);
+        let sender_current_balance = T::Asset::asset_balance(&asset_id, &sender_portfolio.did);
         let receiver_current_balance = T::Asset::asset_balance(&asset_id, receiver_did);
 
         let count_changes = Self::investor_count_changes(
@@ -938,7 +1217,7 @@ impl<T: Config> Module<T> {
             if !Self::check_transfer_condition(
                 &condition,
                 asset_id,
-                sender_did,
+                sender_portfolio,
                 receiver_did,
                 receiver_current_balance,
                 transfer_amount,
@@ -979,5 +1258,9 @@ decl_error! {
         TransferConditionLimitReached,
         /// The maximum weight limit for executing the function was exceeded.
         WeightLimitExceeded,
+        /// The number of periods of a volume transfer condition must be between one and `MaxVolumePeriods`.
+        InvalidVolumeWindow,
+        /// The operation type of the StatType can't be scoped to a claim.
+        StatTypeClaimNotSupported,
     }
 }
@@ -61,13 +61,14 @@ use frame_support::{
     traits::{Currency, Get, GetCallMetadata},
     weights::{WeightToFee, WeightToFeeCoefficient, WeightToFeePolynomial},
 };
+use polymesh_common_utilities::constants::currency::ONE_UNIT;
 use polymesh_common_utilities::traits::{
     group::GroupTrait,
     identity::IdentityFnTrait,
     relayer::SubsidiserTrait,
-    transaction_payment::{CddAndFeeDetails, ChargeTxFee},
+    transaction_payment::{AssetFeeHandler, CddAndFeeDetails, ChargeTxFee},
 };
-use polymesh_primitives::TransactionError;
+use polymesh_primitives::{asset::AssetId, Balance, TransactionError};
 use scale_info::TypeInfo;
 use sp_runtime::{
     traits::{
@@ -355,6 +356,11 @@ pub trait Config: frame_system::Config + pallet_timestamp::Config {
     // Polymesh note: This was specifically added for Polymesh
     /// Identity functionality.
     type Identity: IdentityFnTrait<Self::AccountId>;
+
+    // Polymesh note: This was specifically added for Polymesh
+    /// Used to charge transaction fees in an approved asset, instead of POLYX,
+    /// to payers that opted in.
+    type AssetFeeHandler: AssetFeeHandler<Self::AccountId>;
 }
 
 decl_storage! {
@@ -374,6 +380,8 @@ decl_event! {
         /// A transaction fee `actual_fee`, of which `tip` was added to the minimum inclusion fee,
         /// has been paid by `who`.
         TransactionFeePaid { who: AccountId, actual_fee: Balance, tip: Balance },
+        /// A transaction fee `actual_fee`, in units of `asset_id`, has been paid by `who`.
+        TransactionFeePaidInAsset { who: AccountId, asset_id: AssetId, actual_fee: Balance },
     }
 }
 
@@ -662,6 +670,49 @@ where
         T::WeightToFee::weight_to_fee(&capped_weight)
     }
 
+    // Polymesh note: This was specifically added for Polymesh
+    /// Returns the asset, and its rate, in which the payer of `call`, signed by `who`, pays fees.
+    pub fn fee_asset_of(who: &T::AccountId, call: &T::RuntimeCall) -> Option<(AssetId, Balance)> {
+        let payer = T::CddHandler::get_valid_payer(call, who).ok().flatten()?;
+        T::AssetFeeHandler::fee_asset(&payer)
+    }
+
+    // Polymesh note: This was specifically added for Polymesh
+    /// Converts `fee`, in POLYX, into an amount of an asset worth `rate` per POLYX, rounding up.
+    pub fn fee_in_asset(fee: BalanceOf<T>, rate: Balance) -> Balance {
+        let fee: Balance = fee.saturated_into();
+        fee.saturating_mul(rate).saturating_add(ONE_UNIT - 1) / ONE_UNIT
+    }
+
+    // Polymesh note: This was specifically added for Polymesh
+    /// Converts the partial fee of `info` into an asset worth `rate` per POLYX.
+    pub fn dispatch_info_in_asset(
+        info: RuntimeDispatchInfo<BalanceOf<T>>,
+        rate: Balance,
+    ) -> RuntimeDispatchInfo<BalanceOf<T>> {
+        RuntimeDispatchInfo {
+            partial_fee: Self::fee_in_asset(info.partial_fee, rate).saturated_into(),
+            ..info
+        }
+    }
+
+    // Polymesh note: This was specifically added for Polymesh
+    /// Converts each part of `details` into an asset worth `rate` per POLYX.
+    pub fn fee_details_in_asset(
+        details: FeeDetails<BalanceOf<T>>,
+        rate: Balance,
+    ) -> FeeDetails<BalanceOf<T>> {
+        let convert = |fee| Self::fee_in_asset(fee, rate).saturated_into();
+        FeeDetails {
+            inclusion_fee: details.inclusion_fee.map(|fee| InclusionFee {
+                base_fee: convert(fee.base_fee),
+                len_fee: convert(fee.len_fee),
+                adjusted_weight_fee: convert(fee.adjusted_weight_fee),
+            }),
+            tip: convert(details.tip),
+        }
+    }
+
     /// Polymesh-Note :- Change for the supporting the test
     #[cfg(debug_assertions)]
     pub fn put_next_fee_multiplier(m: Multiplier) {
@@ -688,8 +739,23 @@ pub type WithdrawFeeInfo<T, AccountId> = (
     BalanceOf<T>,
     <<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo,
     Option<AccountId>,
+    Option<AssetFeePaid<AccountId>>,
 );
 
+// Polymesh note: This was specifically added for Polymesh
+/// A transaction fee withdrawn in an asset instead of POLYX.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct AssetFeePaid<AccountId> {
+    /// The account of the fee collector, which paid the fee and the tip in POLYX.
+    pub collector: AccountId,
+    /// The asset the fee was paid in.
+    pub asset_id: AssetId,
+    /// The amount of the asset worth one POLYX.
+    pub rate: Balance,
+    /// The amount of the asset withdrawn, which is locked until the fee is corrected.
+    pub amount: Balance,
+}
+
 /// Require the transactor pay for themselves and maybe include a tip to gain additional priority
 /// in the queue.
 #[derive(Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
@@ -722,13 +788,24 @@ where
         // Only mess with balances if fee is not zero.
         if fee.is_zero() {
             let liquidity_info = Default::default();
-            return Ok((fee, liquidity_info, None));
+            return Ok((fee, liquidity_info, None, None));
         }
 
         // Get the payer for this transaction.
         let payer_key =
             T::CddHandler::get_valid_payer(call, &who)?.ok_or(InvalidTransaction::Payment)?;
 
+        // Polymesh: A sponsor might have capped the fee it pays for this call.
+        if let Some(max_fee) = T::CddHandler::get_max_fee(call) {
+            let fee: Balance = fee.into();
+            if fee > max_fee {
+                return Err(InvalidTransaction::Custom(
+                    TransactionError::SponsoredFeeTooHigh as u8,
+                )
+                .into());
+            }
+        }
+
         // Check if the payer is being subsidised.
         let metadata = call.get_call_metadata();
         let subsidiser = T::Subsidiser::check_subsidy(
@@ -737,6 +814,29 @@ where
             Some(metadata.pallet_name.as_bytes()),
         )?;
 
+        // Polymesh: Unless subsidised, the payer might have opted in to pay fees in an asset.
+        // The fee collector is then paid in the asset and pays the fee and the tip in POLYX.
+        if subsidiser.is_none() {
+            if let Some((asset_id, rate)) = T::AssetFeeHandler::fee_asset(&payer_key) {
+                let collector =
+                    T::AssetFeeHandler::fee_collector_key().ok_or(InvalidTransaction::Payment)?;
+                let amount = Module::<T>::fee_in_asset(fee, rate);
+                T::AssetFeeHandler::withdraw_fee(&payer_key, asset_id, amount)?;
+                let liquidity_info =
+                    <<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::withdraw_fee(
+                        &collector, call, info, fee, tip,
+                    )?;
+                T::CddHandler::set_payer_context(Some(payer_key));
+                let asset_fee = AssetFeePaid {
+                    collector,
+                    asset_id,
+                    rate,
+                    amount,
+                };
+                return Ok((fee, liquidity_info, None, Some(asset_fee)));
+            }
+        }
+
         // key to pay the fee.
         let fee_key = subsidiser.as_ref().unwrap_or(&payer_key);
         let liquidity_info =
@@ -744,7 +844,7 @@ where
                 fee_key, call, info, fee, tip,
             )?;
         T::CddHandler::set_payer_context(Some(payer_key));
-        Ok((fee, liquidity_info, subsidiser))
+        Ok((fee, liquidity_info, subsidiser, None))
     }
 
     // Polymesh: Used to allow GC/CDD member to include a `tip`.
@@ -812,6 +912,8 @@ where
         <<T as Config>::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo,
         // Polymesh: Subsidiser
         Option<Self::AccountId>,
+        // Polymesh: Fee paid in an asset instead of POLYX, the imbalance is then the fee collector's.
+        Option<AssetFeePaid<Self::AccountId>>,
     );
     fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
         Ok(())
@@ -826,7 +928,7 @@ where
     ) -> TransactionValidity {
         let tip = self.ensure_valid_tip(who, info)?;
 
-        let (_fee, _, _) = self.withdraw_fee(who, call, info, len)?;
+        let (_fee, _, _, _) = self.withdraw_fee(who, call, info, len)?;
         // Polymesh: `tip` can only be used by GC/CDD members.
         Ok(ValidTransaction {
             priority: tip.saturated_into::<TransactionPriority>(),
@@ -842,8 +944,8 @@ where
         len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
         let tip = self.ensure_valid_tip(who, info)?;
-        let (_fee, imbalance, subsidiser) = self.withdraw_fee(who, call, info, len)?;
-        Ok((tip, who.clone(), imbalance, subsidiser))
+        let (_fee, imbalance, subsidiser, asset_fee) = self.withdraw_fee(who, call, info, len)?;
+        Ok((tip, who.clone(), imbalance, subsidiser, asset_fee))
     }
 
     fn post_dispatch(
@@ -853,7 +955,7 @@ where
         len: usize,
         _result: &DispatchResult,
     ) -> Result<(), TransactionValidityError> {
-        let (tip, who, imbalance, subsidiser) = match pre {
+        let (tip, who, imbalance, subsidiser, asset_fee) = match pre {
             Some(pre) => pre,
             None => return Ok(()),
         };
@@ -863,6 +965,47 @@ where
         // If payer context is empty, the fee is returned to the caller account.
         let payer = T::CddHandler::get_payer_from_context().unwrap_or(who.clone());
 
+        // Polymesh: Refund the overpaid part of a fee paid in an asset,
+        // and correct the fee the collector paid in POLYX.
+        if let Some(asset_fee) = asset_fee {
+            let actual_asset_fee = Module::<T>::fee_in_asset(actual_fee, asset_fee.rate);
+            let refund_amount = asset_fee.amount.saturating_sub(actual_asset_fee);
+            // The withdrawn fee is locked in the collector's portfolio, so the refund only fails
+            // if that portfolio was removed, in which case the payer is not refunded.
+            let actual_asset_fee = match T::AssetFeeHandler::refund_fee(
+                &payer,
+                asset_fee.asset_id,
+                asset_fee.amount,
+                refund_amount,
+            ) {
+                Ok(()) => actual_asset_fee,
+                Err(_) => asset_fee.amount,
+            };
+            Module::<T>::deposit_event(Event::<T>::TransactionFeePaidInAsset {
+                who: payer,
+                asset_id: asset_fee.asset_id,
+                actual_fee: actual_asset_fee,
+            });
+
+            T::OnChargeTransaction::correct_and_deposit_fee(
+                &asset_fee.collector,
+                info,
+                post_info,
+                actual_fee,
+                tip,
+                imbalance,
+            )?;
+            Module::<T>::deposit_event(Event::<T>::TransactionFeePaid {
+                who: asset_fee.collector,
+                actual_fee,
+                tip,
+            });
+
+            // It clears the identity and payer in the context after transaction.
+            T::CddHandler::clear_context();
+            return Ok(());
+        }
+
         // `fee_key` is either a subsidiser or the original payer.
         let fee_key = if let Some(subsidiser_key) = subsidiser {
             // Debit the actual fee from the subsidy.
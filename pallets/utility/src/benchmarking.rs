@@ -154,4 +154,33 @@ benchmarks! {
         let alice = user::<T>("Alice", 0);
         let call = Box::new(frame_system::Call::remark { remark: vec![] }.into());
     }: _(alice.origin, index, call)
+
+    // POLYMESH:
+    sponsored_call {
+        let (sponsor, user) = make_relay_tx_users::<T>();
+        let call: <T as Config>::RuntimeCall = make_calls::<T>(1).pop().unwrap();
+        let envelope = SponsorEnvelope {
+            sponsor: sponsor.account(),
+            user: user.account(),
+            call_hash: call.using_encoded(blake2_256),
+            max_fee: 1_000_000,
+            expiry: 100u32.into(),
+        };
+
+        // Sponsor signs the envelope.
+        let raw_signature: [u8; 64] = sponsor
+            .sign(&envelope.encode())
+            .expect("Data cannot be signed")
+            .0;
+        let encoded = MultiSignature::from(Signature::from_raw(raw_signature)).encode();
+        let signature = T::OffChainSignature::decode(&mut &encoded[..])
+            .expect("OffChainSignature cannot be decoded from a MultiSignature");
+    }: _(user.origin.clone(), envelope, signature, Box::new(call))
+    verify {
+        assert_last_event::<T>(Event::SponsoredCall {
+            sponsor: sponsor.account(),
+            user: user.account(),
+            result: Ok(()),
+        }.into());
+    }
 }
@@ -61,6 +61,7 @@
 //! ## POLYMESH
 //! * Removed `as_derivative`.
 //! * Added `relay_tx`.
+//! * Added `sponsored_call`.
 //! * Added as deprecated: `batch_old`, `batch_atomic`, `batch_optimistic`.
 
 // Ensure we're `no_std` when compiling for Wasm.
@@ -72,23 +73,23 @@ use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchClass;
 use frame_support::dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo};
 use frame_support::dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, Weight};
-use frame_support::ensure;
 use frame_support::traits::GetCallMetadata;
 use frame_support::traits::{IsSubType, OriginTrait, UnfilteredDispatchable};
+use frame_support::{ensure, BoundedVec};
 use frame_system::{ensure_root, ensure_signed, RawOrigin};
 use scale_info::TypeInfo;
 use sp_core::Get;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::TrailingZeroInput;
 use sp_runtime::traits::{BadOrigin, Dispatchable};
-use sp_runtime::{traits::Verify, DispatchError, RuntimeDebug};
+use sp_runtime::traits::{One, TrailingZeroInput};
+use sp_runtime::{traits::Verify, DispatchError, DispatchResult, RuntimeDebug};
 use sp_std::prelude::*;
 
 use pallet_permissions::with_call_metadata;
 use polymesh_common_utilities::balances::{CheckCdd, Config as BalancesConfig};
 use polymesh_common_utilities::identity::{AuthorizationNonce, Config as IdentityConfig};
 use polymesh_common_utilities::Context;
-use polymesh_primitives::IdentityId;
+use polymesh_primitives::{Balance, IdentityId};
 
 type Identity<T> = pallet_identity::Module<T>;
 
@@ -102,6 +103,7 @@ pub trait WeightInfo {
     fn ensure_root() -> Weight;
     fn relay_tx() -> Weight;
     fn as_derivative() -> Weight;
+    fn sponsored_call() -> Weight;
 }
 
 // POLYMESH:
@@ -130,6 +132,27 @@ impl<C> UniqueCall<C> {
             call: Box::new(call),
         }
     }
+
+    /// Returns the wrapped call.
+    pub fn call(&self) -> &C {
+        &self.call
+    }
+}
+
+/// A sponsor's one-shot commitment to pay the transaction fee of a call made by `user`.
+/// POLYMESH: used for `sponsored_call`
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SponsorEnvelope<AccountId, BlockNumber> {
+    /// The account paying the transaction fee.
+    pub sponsor: AccountId,
+    /// The account allowed to submit the sponsored call.
+    pub user: AccountId,
+    /// The `blake2_256` hash of the encoded sponsored call.
+    pub call_hash: [u8; 32],
+    /// The maximum transaction fee, in POLYX, the sponsor pays.
+    pub max_fee: Balance,
+    /// The envelope can only be used before this block.
+    pub expiry: BlockNumber,
 }
 
 pub use pallet::*;
@@ -169,6 +192,16 @@ pub mod pallet {
 
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The maximum number of blocks a sponsor envelope can be valid for.
+        /// POLYMESH: added.
+        #[pallet::constant]
+        type MaxEnvelopeLifetime: Get<Self::BlockNumber>;
+
+        /// The maximum number of used sponsor envelopes removed each block once expired.
+        /// POLYMESH: added.
+        #[pallet::constant]
+        type MaxExpiredEnvelopesPerBlock: Get<u32>;
     }
 
     #[pallet::event]
@@ -194,6 +227,13 @@ pub mod pallet {
             target: T::AccountId,
             result: DispatchResult,
         },
+        /// A call was dispatched with its transaction fee paid by a sponsor.
+        /// POLYMESH: event.
+        SponsoredCall {
+            sponsor: T::AccountId,
+            user: T::AccountId,
+            result: DispatchResult,
+        },
     }
 
     // Align the call size to 1KB. As we are currently compiling the runtime for native/wasm
@@ -220,6 +260,11 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        // POLYMESH: Envelopes expiring at block `n` can no longer be used.
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            Self::remove_expired_envelopes(n)
+        }
+
         fn integrity_test() {
             // If you hit this error, you need to try to `Box` big dispatchable parameters.
             assert!(
@@ -247,6 +292,18 @@ pub mod pallet {
         InvalidNonce,
         /// Decoding derivative account Id failed.
         UnableToDeriveAccountId,
+        /// The sponsor envelope was not issued for this caller and call.
+        /// POLYMESH error
+        SponsorEnvelopeMismatch,
+        /// The sponsor envelope has expired.
+        /// POLYMESH error
+        SponsorEnvelopeExpired,
+        /// The sponsor envelope has already been used.
+        /// POLYMESH error
+        SponsorEnvelopeUsed,
+        /// The sponsor envelope expires after `MaxEnvelopeLifetime` blocks from now.
+        /// POLYMESH error
+        SponsorEnvelopeLifetimeTooLong,
     }
 
     /// Nonce for `relay_tx`.
@@ -256,6 +313,29 @@ pub mod pallet {
     pub(super) type Nonces<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, AuthorizationNonce, ValueQuery>;
 
+    /// Sponsor envelopes already used, keyed by expiry block and envelope hash.
+    /// Entries are removed once their expiry block is reached.
+    /// POLYMESH: added.
+    #[pallet::storage]
+    #[pallet::getter(fn used_sponsor_envelope)]
+    pub(super) type UsedSponsorEnvelopes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        Blake2_128Concat,
+        [u8; 32],
+        bool,
+        ValueQuery,
+    >;
+
+    /// The next expiry block whose used sponsor envelopes must be removed,
+    /// and the cursor of the envelopes left to remove for that block, if any.
+    /// POLYMESH: added.
+    #[pallet::storage]
+    #[pallet::getter(fn expired_envelopes_cursor)]
+    pub(super) type ExpiredEnvelopesCursor<T: Config> =
+        StorageValue<_, (T::BlockNumber, Option<BoundedVec<u8, ConstU32<256>>>), OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Send a batch of dispatch calls.
@@ -585,6 +665,37 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             Self::base_as_derivative(origin, index, call)
         }
+
+        /// Dispatches `call` from the caller with the transaction fee paid by a sponsor.
+        ///
+        /// The sponsor signs `envelope`, committing to pay up to `envelope.max_fee` for this
+        /// exact call made by the caller, before `envelope.expiry`.
+        /// Each envelope can only be used once.
+        /// Protocol fees charged by `call` are still paid by the caller.
+        ///
+        /// # Parameters
+        /// - `envelope`: The sponsor's commitment.
+        /// - `signature`: Signature of the sponsor over the encoded `envelope`.
+        /// - `call`: Call to dispatch, whose hash must match `envelope.call_hash`.
+        ///
+        /// POLYMESH: added.
+        #[pallet::call_index(10)]
+        #[pallet::weight({
+                let dispatch_info = call.get_dispatch_info();
+                (
+                    <T as Config>::WeightInfo::sponsored_call()
+                        .saturating_add(dispatch_info.weight),
+                    dispatch_info.class,
+                )
+            })]
+        pub fn sponsored_call(
+            origin: OriginFor<T>,
+            envelope: SponsorEnvelope<T::AccountId, T::BlockNumber>,
+            signature: T::OffChainSignature,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            Self::base_sponsored_call(origin, envelope, signature, call)
+        }
     }
 }
 
@@ -701,6 +812,99 @@ impl<T: Config> Pallet<T> {
             .map(|_| Some(weight).into())
     }
 
+    fn base_sponsored_call(
+        origin: T::RuntimeOrigin,
+        envelope: SponsorEnvelope<T::AccountId, T::BlockNumber>,
+        signature: T::OffChainSignature,
+        call: Box<<T as Config>::RuntimeCall>,
+    ) -> DispatchResultWithPostInfo {
+        let user = ensure_signed(origin.clone())?;
+        Self::ensure_valid_sponsorship(&user, &envelope, &signature, &call)?;
+
+        // Burn the envelope, whatever the result of the call.
+        UsedSponsorEnvelopes::<T>::insert(
+            envelope.expiry,
+            envelope.using_encoded(blake2_256),
+            true,
+        );
+
+        let dispatch_info = call.get_dispatch_info();
+        // The sponsor only pays the transaction fee.
+        let call_result = Self::run_with_temporary_payer(origin, Some(user.clone()), call, false);
+        // Get the actual weight of this call.
+        let weight = extract_actual_weight(&call_result, &dispatch_info);
+
+        Self::deposit_event(Event::<T>::SponsoredCall {
+            sponsor: envelope.sponsor,
+            user,
+            result: call_result.map(|_| ()).map_err(|e| e.error),
+        });
+
+        let base_weight = <T as Config>::WeightInfo::sponsored_call();
+        Ok(Some(base_weight.saturating_add(weight)).into())
+    }
+
+    /// Ensures that `envelope` is signed by its sponsor, unused, not expired,
+    /// and was issued for `user` dispatching `call`.
+    pub fn ensure_valid_sponsorship(
+        user: &T::AccountId,
+        envelope: &SponsorEnvelope<T::AccountId, T::BlockNumber>,
+        signature: &T::OffChainSignature,
+        call: &<T as Config>::RuntimeCall,
+    ) -> DispatchResult {
+        ensure!(
+            &envelope.user == user && envelope.call_hash == call.using_encoded(blake2_256),
+            Error::<T>::SponsorEnvelopeMismatch
+        );
+        let now = frame_system::Pallet::<T>::block_number();
+        ensure!(now < envelope.expiry, Error::<T>::SponsorEnvelopeExpired);
+        ensure!(
+            envelope.expiry <= now.saturating_add(T::MaxEnvelopeLifetime::get()),
+            Error::<T>::SponsorEnvelopeLifetimeTooLong
+        );
+        ensure!(
+            !UsedSponsorEnvelopes::<T>::get(envelope.expiry, envelope.using_encoded(blake2_256)),
+            Error::<T>::SponsorEnvelopeUsed
+        );
+        ensure!(
+            signature.verify(envelope.encode().as_slice(), &envelope.sponsor),
+            Error::<T>::InvalidSignature
+        );
+        Ok(())
+    }
+
+    /// Removes at most `MaxExpiredEnvelopesPerBlock` used envelopes that expired up to block `n`,
+    /// resuming from where the previous block stopped. Returns the weight consumed.
+    fn remove_expired_envelopes(n: T::BlockNumber) -> Weight {
+        let (mut expiry, mut cursor) = ExpiredEnvelopesCursor::<T>::get().unwrap_or((n, None));
+        let mut budget = T::MaxExpiredEnvelopesPerBlock::get();
+        let (mut reads, mut writes) = (1u64, 1u64);
+        // Each step costs at least one unit of the budget, even if no envelope expired at `expiry`.
+        while expiry <= n && budget > 0 {
+            let removed = UsedSponsorEnvelopes::<T>::clear_prefix(
+                expiry,
+                budget,
+                cursor.as_ref().map(|cursor| cursor.as_slice()),
+            );
+            reads = reads.saturating_add(removed.loops.into());
+            writes = writes.saturating_add(removed.backend.into());
+            budget = budget.saturating_sub(removed.loops.max(1));
+            match removed.maybe_cursor {
+                Some(next_cursor) => {
+                    // A cursor that doesn't fit is dropped, the removal then restarts for `expiry`.
+                    cursor = BoundedVec::try_from(next_cursor).ok();
+                    break;
+                }
+                None => {
+                    expiry = expiry.saturating_add(One::one());
+                    cursor = None;
+                }
+            }
+        }
+        ExpiredEnvelopesCursor::<T>::put((expiry, cursor));
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
     /// Derive a derivative account ID from the owner account and the index.
     pub fn derivative_account_id(
         origin_account_id: T::AccountId,
@@ -28,7 +28,9 @@ pub mod pallet_compliance_manager;
 pub mod pallet_contracts;
 pub mod pallet_corporate_actions;
 pub mod pallet_corporate_ballot;
+pub mod pallet_corporate_reorganization;
 pub mod pallet_external_agents;
+pub mod pallet_fixed_income;
 pub mod pallet_grandpa;
 pub mod pallet_group;
 pub mod pallet_identity;
@@ -762,4 +762,299 @@ impl pallet_asset::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(6))
             .saturating_add(DbWeight::get().writes(4))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:2 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset TransferFees (r:0 w:1)
+    // Proof Skipped: Asset TransferFees (max_values: None, max_size: None, mode: Measured)
+    fn set_transfer_fee() -> Weight {
+        // Minimum execution time: 41_208 nanoseconds.
+        Weight::from_ref_time(43_117_000)
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset TransferFees (r:1 w:1)
+    // Proof Skipped: Asset TransferFees (max_values: None, max_size: None, mode: Measured)
+    fn remove_transfer_fee() -> Weight {
+        // Minimum execution time: 32_614 nanoseconds.
+        Weight::from_ref_time(34_052_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: CddServiceProviders ActiveMembers (r:1 w:0)
+    // Proof Skipped: CddServiceProviders ActiveMembers (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Identity DidRecords (r:1 w:0)
+    // Proof Skipped: Identity DidRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Claims (r:2 w:0)
+    // Proof Skipped: Identity Claims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:10 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IdentityRecoveryCounter (r:1 w:1)
+    // Proof Skipped: Asset IdentityRecoveryCounter (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Identity NumberOfGivenAuths (r:1 w:1)
+    // Proof Skipped: Identity NumberOfGivenAuths (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity MultiPurposeNonce (r:1 w:1)
+    // Proof Skipped: Identity MultiPurposeNonce (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Identity Authorizations (r:0 w:10)
+    // Proof Skipped: Identity Authorizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity AuthorizationsGiven (r:0 w:10)
+    // Proof Skipped: Identity AuthorizationsGiven (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IdentityRecoveries (r:0 w:1)
+    // Proof Skipped: Asset IdentityRecoveries (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[1, 100]`.
+    fn initiate_identity_recovery(n: u32) -> Weight {
+        // Minimum execution time: 72_634 nanoseconds.
+        Weight::from_ref_time(70_218_413)
+            // Standard Error: 21_592
+            .saturating_add(Weight::from_ref_time(18_843_271).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(9))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes(4))
+            .saturating_add(DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity Authorizations (r:1 w:1)
+    // Proof Skipped: Identity Authorizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset IdentityRecoveries (r:1 w:1)
+    // Proof Skipped: Asset IdentityRecoveries (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity AuthorizationsGiven (r:0 w:1)
+    // Proof Skipped: Identity AuthorizationsGiven (max_values: None, max_size: None, mode: Measured)
+    fn confirm_identity_recovery() -> Weight {
+        // Minimum execution time: 58_127 nanoseconds.
+        Weight::from_ref_time(60_954_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Not benchmarked yet, this must be regenerated from the `veto_identity_recovery` benchmark.
+    // Estimated as removing the recovery and revoking the authorizations of `n` pending assets.
+    fn veto_identity_recovery(n: u32) -> Weight {
+        Weight::from_ref_time(35_219_000)
+            .saturating_add(Weight::from_ref_time(10_000_000).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    // Not benchmarked yet, this must be regenerated from the `execute_identity_recovery` benchmark.
+    // Estimated as moving the free balance and vesting schedules of `a` assets from each of the `p` portfolios,
+    // moving up to `l` portfolio locks and scanning and moving up to `n` NFTs.
+    fn execute_identity_recovery(a: u32, p: u32, l: u32, n: u32) -> Weight {
+        Weight::from_ref_time(85_640_000)
+            .saturating_add(Weight::from_ref_time(29_451_608).saturating_mul(a.into()))
+            .saturating_add(
+                Weight::from_ref_time(64_172_350)
+                    .saturating_mul(a.into())
+                    .saturating_mul(p.into()),
+            )
+            .saturating_add(Weight::from_ref_time(15_000_000).saturating_mul(l.into()))
+            .saturating_add(Weight::from_ref_time(31_084_519).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(
+                DbWeight::get().reads((9_u64).saturating_mul(a.into()).saturating_mul(p.into())),
+            )
+            .saturating_add(DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(l.into())))
+            .saturating_add(DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(
+                DbWeight::get().writes((8_u64).saturating_mul(a.into()).saturating_mul(p.into())),
+            )
+            .saturating_add(DbWeight::get().writes((4_u64).saturating_mul(l.into())))
+            .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset PausedOperations (r:1 w:1)
+    // Proof Skipped: Asset PausedOperations (max_values: None, max_size: None, mode: Measured)
+    fn pause_asset_operations() -> Weight {
+        // Minimum execution time: 41_035 nanoseconds.
+        Weight::from_ref_time(41_876_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset PausedOperations (r:1 w:1)
+    // Proof Skipped: Asset PausedOperations (max_values: None, max_size: None, mode: Measured)
+    fn unpause_asset_operations() -> Weight {
+        // Minimum execution time: 40_817 nanoseconds.
+        Weight::from_ref_time(41_392_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Not benchmarked yet, this must be regenerated from the `add_vesting_schedule` benchmark.
+    // Estimated as `revoke_vesting_schedule`, which also moves units between portfolios under `n` schedules,
+    // plus the compliance check of the transfer.
+    fn add_vesting_schedule(n: u32) -> Weight {
+        Weight::from_ref_time(148_930_442)
+            .saturating_add(Weight::from_ref_time(412_530).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(20))
+            .saturating_add(DbWeight::get().writes(6))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset VestingSchedules (r:1 w:1)
+    // Proof Skipped: Asset VestingSchedules (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 20]`.
+    fn claim_vested_assets(n: u32) -> Weight {
+        // Minimum execution time: 29_466 nanoseconds.
+        Weight::from_ref_time(30_871_205)
+            // Standard Error: 2_356
+            .saturating_add(Weight::from_ref_time(289_114).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset VestingSchedules (r:1 w:1)
+    // Proof Skipped: Asset VestingSchedules (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:2)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:1 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[1, 20]`.
+    fn revoke_vesting_schedule(n: u32) -> Weight {
+        // Minimum execution time: 121_384 nanoseconds.
+        Weight::from_ref_time(126_930_442)
+            // Standard Error: 6_912
+            .saturating_add(Weight::from_ref_time(351_207).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(15))
+            .saturating_add(DbWeight::get().writes(5))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Asset Allowances (r:0 w:1)
+    // Proof Skipped: Asset Allowances (max_values: None, max_size: None, mode: Measured)
+    fn approve() -> Weight {
+        // Minimum execution time: 27_614 nanoseconds.
+        Weight::from_ref_time(28_310_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Not benchmarked yet, this must be regenerated from the `transfer_from` benchmark.
+    // Estimated as two compliant transfers, the allowed transfer and the payment of its transfer fee.
+    fn transfer_from() -> Weight {
+        Weight::from_ref_time(232_190_000)
+            .saturating_add(DbWeight::get().reads(32))
+            .saturating_add(DbWeight::get().writes(9))
+    }
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetTransferCompliances (r:1 w:0)
+    // Proof Skipped: Statistics AssetTransferCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FeeAssetRates (r:0 w:1)
+    // Proof Skipped: Asset FeeAssetRates (max_values: None, max_size: None, mode: Measured)
+    fn set_fee_asset_rate() -> Weight {
+        // Minimum execution time: 18_105 nanoseconds.
+        Weight::from_ref_time(19_336_000)
+            .saturating_add(DbWeight::get().reads(3))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FeeCollector (r:0 w:1)
+    // Proof Skipped: Asset FeeCollector (max_values: Some(1), max_size: None, mode: Measured)
+    fn set_fee_collector() -> Weight {
+        // Minimum execution time: 17_243 nanoseconds.
+        Weight::from_ref_time(18_027_000)
+            .saturating_add(DbWeight::get().reads(1))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FeeAssetRates (r:1 w:0)
+    // Proof Skipped: Asset FeeAssetRates (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetTransferCompliances (r:1 w:0)
+    // Proof Skipped: Statistics AssetTransferCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset FeeAssets (r:0 w:1)
+    // Proof Skipped: Asset FeeAssets (max_values: None, max_size: None, mode: Measured)
+    fn set_fee_asset() -> Weight {
+        // Minimum execution time: 23_617 nanoseconds.
+        Weight::from_ref_time(24_890_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Not benchmarked yet, these must be regenerated from the `pause_venue_settlement` and
+    // `unpause_venue_settlement` benchmarks.
+    // Estimated as `pause_asset_operations`, which has the same permission checks and a single storage write.
+    fn pause_venue_settlement() -> Weight {
+        Self::pause_asset_operations()
+    }
+    // Estimated as `unpause_asset_operations`.
+    fn unpause_venue_settlement() -> Weight {
+        Self::unpause_asset_operations()
+    }
 }
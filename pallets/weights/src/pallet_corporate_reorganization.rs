@@ -0,0 +1,134 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_corporate_reorganization
+//!
+//! The pallet hasn't been benchmarked yet, these weights are estimates based on
+//! the storage accesses of each call and must be regenerated from its benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use polymesh_runtime_common::{RocksDbWeight as DbWeight, Weight};
+
+/// Weights for pallet_corporate_reorganization using the Substrate node and recommended hardware.
+pub struct SubstrateWeight;
+impl pallet_corporate_actions::reorganization::WeightInfo for SubstrateWeight {
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: CorporateAction CorporateActions (r:1 w:0)
+    // Proof Skipped: CorporateAction CorporateActions (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateReorganization Reorganizations (r:1 w:1)
+    // Proof Skipped: CorporateReorganization Reorganizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Storage: Asset TransfersFrozen (r:1 w:1)
+    // Not benchmarked yet, this must be regenerated from the `reorganize` benchmark.
+    // Estimated as attaching a distribution, plus freezing the asset.
+    fn reorganize() -> Weight {
+        Weight::from_ref_time(84_935_000)
+            .saturating_add(DbWeight::get().reads(12))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: CorporateReorganization Reorganizations (r:1 w:1)
+    // Proof Skipped: CorporateReorganization Reorganizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateReorganization PortfolioReorganized (r:p w:p)
+    // Proof Skipped: CorporateReorganization PortfolioReorganized (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction CorporateActions (r:1 w:0)
+    // Proof Skipped: CorporateAction CorporateActions (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Asset PausedOperations (r:1 w:0)
+    // Proof Skipped: Asset PausedOperations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:2 w:1)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:2 w:1)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:1)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:2 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: ComplianceManager AssetCompliances (r:1 w:0)
+    // Proof Skipped: ComplianceManager AssetCompliances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLocks (r:p*l w:p*l)
+    // Storage: Portfolio PortfolioLockedByLocks (r:1 w:2)
+    // Storage: Asset VestingSchedules (r:2 w:2)
+    // Storage: Statistics PortfolioAcquisitionLots (r:2 w:2)
+    // Not benchmarked yet, this must be regenerated from the `apply_reorganization` benchmark.
+    // Estimated as checking the agent and the reorganization once, plus, for each of the `p` portfolios,
+    // the previous per-holder weight and rescaling `l` portfolio locks,
+    // the vesting schedules and the acquisition lots of the portfolio.
+    fn apply_reorganization(p: u32, l: u32) -> Weight {
+        let pl = (p as u64).saturating_mul(l.into());
+        Weight::from_ref_time(48_000_000)
+            .saturating_add(Weight::from_ref_time(183_382_000).saturating_mul(p.into()))
+            .saturating_add(Weight::from_ref_time(12_000_000).saturating_mul(pl))
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().reads((22_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().reads(pl))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(DbWeight::get().writes((13_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().writes(pl))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: CorporateReorganization Reorganizations (r:1 w:1)
+    // Proof Skipped: CorporateReorganization Reorganizations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset TransfersFrozen (r:0 w:1)
+    // Not benchmarked yet, this must be regenerated from the `close_reorganization` benchmark.
+    // Estimated as reclaiming a distribution, plus unfreezing the asset.
+    fn close_reorganization() -> Weight {
+        Weight::from_ref_time(48_120_000)
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+}
@@ -0,0 +1,292 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for pallet_fixed_income
+//!
+//! The pallet hasn't been benchmarked yet, these weights are estimates based on
+//! the storage accesses of each call and must be regenerated from its benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use polymesh_runtime_common::{RocksDbWeight as DbWeight, Weight};
+
+/// Weights for pallet_fixed_income using the Substrate node and recommended hardware.
+pub struct SubstrateWeight;
+impl pallet_corporate_actions::fixed_income::WeightInfo for SubstrateWeight {
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof Skipped: Timestamp Now (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Checkpoint SchedulesMaxComplexity (r:1 w:0)
+    // Proof Skipped: Checkpoint SchedulesMaxComplexity (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:1)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint ScheduleIdSequence (r:1 w:1)
+    // Proof Skipped: Checkpoint ScheduleIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: ProtocolFee Coefficient (r:1 w:0)
+    // Proof Skipped: ProtocolFee Coefficient (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: ProtocolFee BaseFees (r:1 w:0)
+    // Proof Skipped: ProtocolFee BaseFees (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint ScheduledCheckpoints (r:0 w:1)
+    // Proof Skipped: Checkpoint ScheduledCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint ScheduleRefCount (r:0 w:1)
+    // Proof Skipped: Checkpoint ScheduleRefCount (max_values: None, max_size: None, mode: Measured)
+    // Not benchmarked yet, this must be regenerated from the `create_fixed_income` benchmark.
+    // Estimated as creating a checkpoint schedule, plus validating one coupon date per coupon.
+    fn create_fixed_income(c: u32) -> Weight {
+        Weight::from_ref_time(93_264_417)
+            // Standard Error: 1_104
+            .saturating_add(Weight::from_ref_time(311_526).saturating_mul(c.into()))
+            .saturating_add(DbWeight::get().reads(14))
+            .saturating_add(DbWeight::get().writes(6))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:1)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof Skipped: Timestamp Now (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:1)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint ScheduledCheckpoints (r:1 w:1)
+    // Proof Skipped: Checkpoint ScheduledCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint SchedulePoints (r:1 w:1)
+    // Proof Skipped: Checkpoint SchedulePoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint Timestamps (r:1 w:1)
+    // Proof Skipped: Checkpoint Timestamps (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint TotalSupply (r:1 w:1)
+    // Proof Skipped: Checkpoint TotalSupply (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction CAIdSequence (r:1 w:1)
+    // Proof Skipped: CorporateAction CAIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction MaxDetailsLength (r:1 w:0)
+    // Proof Skipped: CorporateAction MaxDetailsLength (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: CorporateAction DefaultTargetIdentities (r:1 w:0)
+    // Proof Skipped: CorporateAction DefaultTargetIdentities (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction DefaultWithholdingTax (r:1 w:0)
+    // Proof Skipped: CorporateAction DefaultWithholdingTax (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction DidWithholdingTax (r:1 w:0)
+    // Proof Skipped: CorporateAction DidWithholdingTax (max_values: None, max_size: None, mode: Measured)
+    // Storage: CapitalDistribution Distributions (r:1 w:1)
+    // Proof Skipped: CapitalDistribution Distributions (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction CorporateActions (r:1 w:1)
+    // Proof Skipped: CorporateAction CorporateActions (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: ProtocolFee Coefficient (r:1 w:0)
+    // Proof Skipped: ProtocolFee Coefficient (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: ProtocolFee BaseFees (r:1 w:0)
+    // Proof Skipped: ProtocolFee BaseFees (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome Coupons (r:0 w:1)
+    // Proof Skipped: FixedIncome Coupons (max_values: None, max_size: None, mode: Measured)
+    // Storage: CorporateAction Details (r:0 w:1)
+    // Proof Skipped: CorporateAction Details (max_values: None, max_size: None, mode: Measured)
+    // Not benchmarked yet, this must be regenerated from the `distribute_coupon` benchmark.
+    // Estimated as initiating a corporate action and attaching a distribution to it.
+    fn distribute_coupon() -> Weight {
+        Weight::from_ref_time(189_741_000)
+            .saturating_add(DbWeight::get().reads(23))
+            .saturating_add(DbWeight::get().writes(14))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof Skipped: Timestamp Now (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset IssuanceClosed (r:0 w:1)
+    // Not benchmarked yet, this must be regenerated from the `mature` benchmark.
+    // Estimated as locking the principal in the funding portfolio, plus closing the issuance.
+    fn mature() -> Weight {
+        Weight::from_ref_time(63_105_000)
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:3 w:3)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:2 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockAmounts (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockAmounts (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:2 w:1)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:2 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:2 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:4 w:4)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetStats (r:2 w:2)
+    // Proof Skipped: Statistics AssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:2 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Frozen (r:1 w:0)
+    // Proof Skipped: Asset Frozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:2 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Not benchmarked yet, this must be regenerated from the `redeem` benchmark.
+    // Estimated as redeeming the units, plus moving the principal without compliance checks.
+    fn redeem() -> Weight {
+        Weight::from_ref_time(241_408_000)
+            .saturating_add(DbWeight::get().reads(28))
+            .saturating_add(DbWeight::get().writes(14))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:3 w:3)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:2 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockAmounts (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockAmounts (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:2 w:1)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:2 w:0)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:2 w:0)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset BalanceOf (r:4 w:4)
+    // Proof Skipped: Asset BalanceOf (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics AssetStats (r:2 w:2)
+    // Proof Skipped: Statistics AssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Statistics ActiveAssetStats (r:2 w:0)
+    // Proof Skipped: Statistics ActiveAssetStats (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetCount (r:2 w:2)
+    // Proof Skipped: Portfolio PortfolioAssetCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Frozen (r:1 w:0)
+    // Proof Skipped: Asset Frozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio Portfolios (r:2 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLocks (r:l w:l)
+    // Storage: Portfolio PortfolioLockedByLocks (r:1 w:2)
+    // Storage: Asset VestingSchedules (r:2 w:2)
+    // Storage: Statistics PortfolioAcquisitionLots (r:2 w:2)
+    // Not benchmarked yet, this must be regenerated from the `push_redemption` benchmark.
+    // Estimated as `redeem`, plus checking the agent's permissions and releasing `l` portfolio locks,
+    // the vesting schedules and the acquisition lots of the portfolio.
+    fn push_redemption(l: u32) -> Weight {
+        Weight::from_ref_time(258_596_000)
+            .saturating_add(Weight::from_ref_time(12_000_000).saturating_mul(l.into()))
+            .saturating_add(DbWeight::get().reads(37))
+            .saturating_add(DbWeight::get().reads(l.into()))
+            .saturating_add(DbWeight::get().writes(20))
+            .saturating_add(DbWeight::get().writes(l.into()))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: ExternalAgents GroupOfAgent (r:1 w:0)
+    // Proof Skipped: ExternalAgents GroupOfAgent (max_values: None, max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:0)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Not benchmarked yet, this must be regenerated from the `close_fixed_income` benchmark.
+    // Estimated as unlocking the remaining principal in the funding portfolio.
+    fn close_fixed_income() -> Weight {
+        Weight::from_ref_time(49_872_000)
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome FixedIncomes (r:1 w:1)
+    // Proof Skipped: FixedIncome FixedIncomes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint CachedNextCheckpoints (r:1 w:1)
+    // Proof Skipped: Checkpoint CachedNextCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof Skipped: Timestamp Now (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Checkpoint CheckpointIdSequence (r:1 w:1)
+    // Proof Skipped: Checkpoint CheckpointIdSequence (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint ScheduledCheckpoints (r:1 w:1)
+    // Proof Skipped: Checkpoint ScheduledCheckpoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint SchedulePoints (r:1 w:1)
+    // Proof Skipped: Checkpoint SchedulePoints (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint Timestamps (r:1 w:1)
+    // Proof Skipped: Checkpoint Timestamps (max_values: None, max_size: None, mode: Measured)
+    // Storage: Checkpoint TotalSupply (r:1 w:1)
+    // Proof Skipped: Checkpoint TotalSupply (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: FixedIncome DefaultedCoupons (r:0 w:1)
+    // Not benchmarked yet, this must be regenerated from the `default_coupon` benchmark.
+    // Estimated as finding the coupon's checkpoint and checking the funding portfolio's free balance.
+    fn default_coupon() -> Weight {
+        Weight::from_ref_time(84_517_000)
+            .saturating_add(DbWeight::get().reads(12))
+            .saturating_add(DbWeight::get().writes(8))
+    }
+}
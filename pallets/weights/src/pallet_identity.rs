@@ -497,4 +497,26 @@ impl pallet_identity::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(3))
             .saturating_add(DbWeight::get().writes(3))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity CustomClaimsInverse (r:1 w:1)
+    // Proof Skipped: Identity CustomClaimsInverse (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity CustomClaimIdSequence (r:1 w:1)
+    // Proof Skipped: Identity CustomClaimIdSequence (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Identity CustomClaims (r:0 w:1)
+    // Proof Skipped: Identity CustomClaims (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity CustomClaimValueTypes (r:0 w:1)
+    // Proof Skipped: Identity CustomClaimValueTypes (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[1, 2048]`.
+    /// The range of component `v` is `[1, 2048]`.
+    fn register_custom_claim_type_with_value(n: u32, v: u32) -> Weight {
+        // Minimum execution time: 28_540 nanoseconds.
+        Weight::from_ref_time(32_871_512)
+            // Standard Error: 371
+            .saturating_add(Weight::from_ref_time(6_412).saturating_mul(n.into()))
+            // Standard Error: 371
+            .saturating_add(Weight::from_ref_time(3_105).saturating_mul(v.into()))
+            .saturating_add(DbWeight::get().reads(3))
+            .saturating_add(DbWeight::get().writes(4))
+    }
 }
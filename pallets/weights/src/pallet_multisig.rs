@@ -485,4 +485,226 @@ impl pallet_multisig::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(4))
             .saturating_add(DbWeight::get().writes(1))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig SignerWeights (r:1 w:1)
+    // Proof Skipped: MultiSig SignerWeights (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NumberOfSigners (r:1 w:0)
+    // Proof Skipped: MultiSig NumberOfSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSignsRequired (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSignsRequired (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig CallPolicies (r:1 w:0)
+    // Proof Skipped: MultiSig CallPolicies (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NextProposalId (r:1 w:0)
+    // Proof Skipped: MultiSig NextProposalId (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:0 w:1)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[1, 50]`.
+    fn set_signer_weights(n: u32) -> Weight {
+        // Minimum execution time: 41_210 nanoseconds.
+        Weight::from_ref_time(39_873_221)
+            // Standard Error: 9_412
+            .saturating_add(Weight::from_ref_time(8_530_417).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:2 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig AdminDid (r:1 w:0)
+    // Proof Skipped: MultiSig AdminDid (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig SignerWeights (r:1 w:1)
+    // Proof Skipped: MultiSig SignerWeights (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NumberOfSigners (r:1 w:0)
+    // Proof Skipped: MultiSig NumberOfSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSignsRequired (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSignsRequired (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig CallPolicies (r:1 w:0)
+    // Proof Skipped: MultiSig CallPolicies (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NextProposalId (r:1 w:0)
+    // Proof Skipped: MultiSig NextProposalId (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:0 w:1)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[1, 50]`.
+    fn set_signer_weights_via_admin(n: u32) -> Weight {
+        // Minimum execution time: 58_937 nanoseconds.
+        Weight::from_ref_time(57_206_983)
+            // Standard Error: 9_412
+            .saturating_add(Weight::from_ref_time(8_614_062).saturating_mul(n.into()))
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(DbWeight::get().writes(1))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NumberOfSigners (r:1 w:0)
+    // Proof Skipped: MultiSig NumberOfSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig SignerWeights (r:1 w:0)
+    // Proof Skipped: MultiSig SignerWeights (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NextProposalId (r:1 w:0)
+    // Proof Skipped: MultiSig NextProposalId (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig CallPolicies (r:0 w:1)
+    // Proof Skipped: MultiSig CallPolicies (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:0 w:1)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `p` is `[0, 16]`.
+    fn set_call_policies(p: u32) -> Weight {
+        // Minimum execution time: 33_318 nanoseconds.
+        Weight::from_ref_time(35_092_644)
+            // Standard Error: 4_871
+            .saturating_add(Weight::from_ref_time(1_104_357).saturating_mul(p.into()))
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Identity KeyRecords (r:2 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig AdminDid (r:1 w:0)
+    // Proof Skipped: MultiSig AdminDid (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NumberOfSigners (r:1 w:0)
+    // Proof Skipped: MultiSig NumberOfSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig SignerWeights (r:1 w:0)
+    // Proof Skipped: MultiSig SignerWeights (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig NextProposalId (r:1 w:0)
+    // Proof Skipped: MultiSig NextProposalId (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig CallPolicies (r:0 w:1)
+    // Proof Skipped: MultiSig CallPolicies (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:0 w:1)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `p` is `[0, 16]`.
+    fn set_call_policies_via_admin(p: u32) -> Weight {
+        // Minimum execution time: 51_704 nanoseconds.
+        Weight::from_ref_time(53_461_902)
+            // Standard Error: 4_871
+            .saturating_add(Weight::from_ref_time(1_098_211).saturating_mul(p.into()))
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: MultiSig NextProposalId (r:1 w:1)
+    // Proof Skipped: MultiSig NextProposalId (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSignsRequired (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSignsRequired (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig CallPolicies (r:1 w:0)
+    // Proof Skipped: MultiSig CallPolicies (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity IsDidFrozen (r:1 w:0)
+    // Proof Skipped: Identity IsDidFrozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalExecuteAfter (r:1 w:1)
+    // Proof Skipped: MultiSig ProposalExecuteAfter (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Votes (r:1 w:1)
+    // Proof Skipped: MultiSig Votes (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalVoteCounts (r:0 w:1)
+    // Proof Skipped: MultiSig ProposalVoteCounts (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposals (r:0 w:1)
+    // Proof Skipped: MultiSig Proposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalStates (r:0 w:1)
+    // Proof Skipped: MultiSig ProposalStates (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposers (r:0 w:1)
+    // Proof Skipped: MultiSig Proposers (max_values: None, max_size: None, mode: Measured)
+    fn create_scheduled_proposal() -> Weight {
+        // Minimum execution time: 92_417 nanoseconds.
+        Weight::from_ref_time(95_806_000)
+            .saturating_add(DbWeight::get().reads(9))
+            .saturating_add(DbWeight::get().writes(8))
+    }
+    // Storage: MultiSig ProposalStates (r:1 w:0)
+    // Proof Skipped: MultiSig ProposalStates (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:1 w:0)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposers (r:1 w:0)
+    // Proof Skipped: MultiSig Proposers (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSignsRequired (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSignsRequired (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig CallPolicies (r:1 w:0)
+    // Proof Skipped: MultiSig CallPolicies (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity IsDidFrozen (r:1 w:0)
+    // Proof Skipped: Identity IsDidFrozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Votes (r:1 w:1)
+    // Proof Skipped: MultiSig Votes (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalVoteCounts (r:1 w:1)
+    // Proof Skipped: MultiSig ProposalVoteCounts (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposals (r:1 w:1)
+    // Proof Skipped: MultiSig Proposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalExecuteAfter (r:1 w:0)
+    // Proof Skipped: MultiSig ProposalExecuteAfter (max_values: None, max_size: None, mode: Measured)
+    fn amend_proposal() -> Weight {
+        // Minimum execution time: 88_604 nanoseconds.
+        Weight::from_ref_time(93_115_000)
+            .saturating_add(DbWeight::get().reads(12))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: MultiSig ProposalStates (r:1 w:1)
+    // Proof Skipped: MultiSig ProposalStates (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:1 w:0)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposers (r:1 w:0)
+    // Proof Skipped: MultiSig Proposers (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity IsDidFrozen (r:1 w:0)
+    // Proof Skipped: Identity IsDidFrozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalVoteCounts (r:1 w:0)
+    // Proof Skipped: MultiSig ProposalVoteCounts (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig SignerWeights (r:1 w:0)
+    // Proof Skipped: MultiSig SignerWeights (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig Proposals (r:0 w:1)
+    // Proof Skipped: MultiSig Proposals (max_values: None, max_size: None, mode: Measured)
+    fn cancel_proposal() -> Weight {
+        // Minimum execution time: 41_975 nanoseconds.
+        Weight::from_ref_time(43_602_000)
+            .saturating_add(DbWeight::get().reads(8))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity IsDidFrozen (r:1 w:0)
+    // Proof Skipped: Identity IsDidFrozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalStates (r:1 w:1)
+    // Proof Skipped: MultiSig ProposalStates (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:1 w:0)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: MultiSig Proposals (r:0 w:1)
+    // Proof Skipped: MultiSig Proposals (max_values: None, max_size: None, mode: Measured)
+    fn veto() -> Weight {
+        // Minimum execution time: 36_258 nanoseconds.
+        Weight::from_ref_time(37_940_000)
+            .saturating_add(DbWeight::get().reads(6))
+            .saturating_add(DbWeight::get().writes(2))
+    }
+    // Storage: MultiSig MultiSigSigners (r:1 w:0)
+    // Proof Skipped: MultiSig MultiSigSigners (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity IsDidFrozen (r:1 w:0)
+    // Proof Skipped: Identity IsDidFrozen (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig ProposalStates (r:1 w:0)
+    // Proof Skipped: MultiSig ProposalStates (max_values: None, max_size: None, mode: Measured)
+    // Storage: MultiSig LastInvalidProposal (r:1 w:0)
+    // Proof Skipped: MultiSig LastInvalidProposal (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    fn execute_scheduled_proposal() -> Weight {
+        // Minimum execution time: 31_870 nanoseconds.
+        Weight::from_ref_time(33_095_000).saturating_add(DbWeight::get().reads(6))
+    }
 }
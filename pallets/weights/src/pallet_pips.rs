@@ -125,8 +125,14 @@ impl pallet_pips::WeightInfo for SubstrateWeight {
     // Proof Skipped: Pips ProposalResult (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips ProposalVotes (r:1 w:1)
     // Proof Skipped: Pips ProposalVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips VoteWeightingMode (r:1 w:0)
+    // Proof Skipped: Pips VoteWeightingMode (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Pips WeightedProposalResult (r:1 w:1)
+    // Proof Skipped: Pips WeightedProposalResult (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips LiveQueue (r:1 w:1)
     // Proof Skipped: Pips LiveQueue (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Pips WeightedVotes (r:0 w:1)
+    // Proof Skipped: Pips WeightedVotes (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips ProposalMetadata (r:0 w:1)
     // Proof Skipped: Pips ProposalMetadata (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips Deposits (r:0 w:1)
@@ -138,8 +144,8 @@ impl pallet_pips::WeightInfo for SubstrateWeight {
     fn propose_from_community() -> Weight {
         // Minimum execution time: 111_459 nanoseconds.
         Weight::from_ref_time(139_712_000)
-            .saturating_add(DbWeight::get().reads(13))
-            .saturating_add(DbWeight::get().writes(11))
+            .saturating_add(DbWeight::get().reads(15))
+            .saturating_add(DbWeight::get().writes(13))
     }
     // Storage: Pips PipIdSequence (r:1 w:1)
     // Proof Skipped: Pips PipIdSequence (max_values: Some(1), max_size: None, mode: Measured)
@@ -167,23 +173,44 @@ impl pallet_pips::WeightInfo for SubstrateWeight {
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips VoteWeightingMode (r:1 w:0)
+    // Proof Skipped: Pips VoteWeightingMode (max_values: Some(1), max_size: None, mode: Measured)
     // Storage: Pips Proposals (r:1 w:0)
     // Proof Skipped: Pips Proposals (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips ProposalStates (r:1 w:0)
     // Proof Skipped: Pips ProposalStates (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips ProposalResult (r:1 w:1)
     // Proof Skipped: Pips ProposalResult (max_values: None, max_size: None, mode: Measured)
-    // Storage: Pips Deposits (r:1 w:1)
+    // Storage: Pips WeightedProposalResult (r:1 w:1)
+    // Proof Skipped: Pips WeightedProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Deposits (r:101 w:101)
     // Proof Skipped: Pips Deposits (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedVotes (r:1 w:1)
+    // Proof Skipped: Pips WeightedVotes (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips ProposalVotes (r:1 w:1)
     // Proof Skipped: Pips ProposalVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips DelegatedVotes (r:1 w:1)
+    // Proof Skipped: Pips DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegators (r:2 w:0)
+    // Proof Skipped: Pips Delegators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegations (r:100 w:0)
+    // Proof Skipped: Pips Delegations (max_values: None, max_size: None, mode: Measured)
     // Storage: Pips LiveQueue (r:1 w:1)
     // Proof Skipped: Pips LiveQueue (max_values: Some(1), max_size: None, mode: Measured)
-    fn vote() -> Weight {
-        // Minimum execution time: 92_100 nanoseconds.
-        Weight::from_ref_time(94_063_000)
-            .saturating_add(DbWeight::get().reads(7))
-            .saturating_add(DbWeight::get().writes(4))
+    // Storage: Balances Locks (r:101 w:101)
+    // Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:101 w:101)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// The range of component `d` is `[0, 100]`.
+    fn vote(d: u32) -> Weight {
+        // Minimum execution time: 98_713 nanoseconds.
+        Weight::from_ref_time(103_274_512)
+            // Standard Error: 21_406
+            .saturating_add(Weight::from_ref_time(41_902_377).saturating_mul(d.into()))
+            .saturating_add(DbWeight::get().reads(13))
+            .saturating_add(DbWeight::get().reads((8_u64).saturating_mul(d.into())))
+            .saturating_add(DbWeight::get().writes(7))
+            .saturating_add(DbWeight::get().writes((8_u64).saturating_mul(d.into())))
     }
     // Storage: Pips ProposalStates (r:1 w:1)
     // Proof Skipped: Pips ProposalStates (max_values: None, max_size: None, mode: Measured)
@@ -418,4 +445,143 @@ impl pallet_pips::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(1608))
             .saturating_add(DbWeight::get().writes(1608))
     }
+    // Storage: Pips VoteWeightingMode (r:1 w:1)
+    // Proof Skipped: Pips VoteWeightingMode (max_values: Some(1), max_size: None, mode: Measured)
+    fn set_vote_weighting() -> Weight {
+        // Minimum execution time: 14_893 nanoseconds.
+        Weight::from_ref_time(16_102_000)
+            .saturating_add(DbWeight::get().reads(1))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips VoteWeightingMode (r:1 w:0)
+    // Proof Skipped: Pips VoteWeightingMode (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Pips Proposals (r:1 w:0)
+    // Proof Skipped: Pips Proposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ProposalStates (r:1 w:0)
+    // Proof Skipped: Pips ProposalStates (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ProposalResult (r:1 w:1)
+    // Proof Skipped: Pips ProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedProposalResult (r:1 w:1)
+    // Proof Skipped: Pips WeightedProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Deposits (r:101 w:101)
+    // Proof Skipped: Pips Deposits (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedVotes (r:1 w:1)
+    // Proof Skipped: Pips WeightedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ConvictionLocks (r:1 w:1)
+    // Proof Skipped: Pips ConvictionLocks (max_values: None, max_size: None, mode: Measured)
+    // Storage: Balances Locks (r:101 w:101)
+    // Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:101 w:101)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    // Storage: Pips ProposalVotes (r:1 w:1)
+    // Proof Skipped: Pips ProposalVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips DelegatedVotes (r:1 w:1)
+    // Proof Skipped: Pips DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegators (r:2 w:0)
+    // Proof Skipped: Pips Delegators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegations (r:100 w:0)
+    // Proof Skipped: Pips Delegations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips LiveQueue (r:1 w:1)
+    // Proof Skipped: Pips LiveQueue (max_values: Some(1), max_size: None, mode: Measured)
+    /// The range of component `d` is `[0, 100]`.
+    fn vote_with_conviction(d: u32) -> Weight {
+        // Minimum execution time: 114_906 nanoseconds.
+        Weight::from_ref_time(119_385_071)
+            // Standard Error: 23_118
+            .saturating_add(Weight::from_ref_time(42_117_504).saturating_mul(d.into()))
+            .saturating_add(DbWeight::get().reads(16))
+            .saturating_add(DbWeight::get().reads((8_u64).saturating_mul(d.into())))
+            .saturating_add(DbWeight::get().writes(10))
+            .saturating_add(DbWeight::get().writes((8_u64).saturating_mul(d.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ConvictionLocks (r:1 w:1)
+    // Proof Skipped: Pips ConvictionLocks (max_values: None, max_size: None, mode: Measured)
+    // Storage: Balances Locks (r:1 w:1)
+    // Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:1 w:1)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn unlock_conviction_deposit() -> Weight {
+        // Minimum execution time: 48_216 nanoseconds.
+        Weight::from_ref_time(50_835_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegations (r:2 w:1)
+    // Proof Skipped: Pips Delegations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegators (r:1 w:1)
+    // Proof Skipped: Pips Delegators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Balances Locks (r:1 w:1)
+    // Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:1 w:1)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    // Storage: Pips LiveQueue (r:1 w:1)
+    // Proof Skipped: Pips LiveQueue (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Pips Proposals (r:1000 w:0)
+    // Proof Skipped: Pips Proposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips DelegatedVotes (r:2000 w:1000)
+    // Proof Skipped: Pips DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ProposalVotes (r:2000 w:1000)
+    // Proof Skipped: Pips ProposalVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedVotes (r:1000 w:1000)
+    // Proof Skipped: Pips WeightedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ProposalResult (r:1000 w:1000)
+    // Proof Skipped: Pips ProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedProposalResult (r:1000 w:1000)
+    // Proof Skipped: Pips WeightedProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Deposits (r:1000 w:1000)
+    // Proof Skipped: Pips Deposits (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `p` is `[0, 1000]`.
+    fn delegate(p: u32) -> Weight {
+        // Minimum execution time: 61_407 nanoseconds.
+        Weight::from_ref_time(66_512_830)
+            // Standard Error: 18_245
+            .saturating_add(Weight::from_ref_time(38_604_219).saturating_mul(p.into()))
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().reads((10_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().writes(5))
+            .saturating_add(DbWeight::get().writes((6_u64).saturating_mul(p.into())))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegations (r:2 w:1)
+    // Proof Skipped: Pips Delegations (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Delegators (r:1 w:1)
+    // Proof Skipped: Pips Delegators (max_values: None, max_size: None, mode: Measured)
+    // Storage: Balances Locks (r:1 w:1)
+    // Proof Skipped: Balances Locks (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Account (r:1 w:1)
+    // Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    // Storage: Pips LiveQueue (r:1 w:1)
+    // Proof Skipped: Pips LiveQueue (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Pips Proposals (r:1000 w:0)
+    // Proof Skipped: Pips Proposals (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips DelegatedVotes (r:2000 w:1000)
+    // Proof Skipped: Pips DelegatedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ProposalVotes (r:1000 w:1000)
+    // Proof Skipped: Pips ProposalVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedVotes (r:1000 w:1000)
+    // Proof Skipped: Pips WeightedVotes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips ProposalResult (r:1000 w:1000)
+    // Proof Skipped: Pips ProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips WeightedProposalResult (r:1000 w:1000)
+    // Proof Skipped: Pips WeightedProposalResult (max_values: None, max_size: None, mode: Measured)
+    // Storage: Pips Deposits (r:1000 w:1000)
+    // Proof Skipped: Pips Deposits (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `p` is `[0, 1000]`.
+    fn revoke_delegation(p: u32) -> Weight {
+        // Minimum execution time: 58_930 nanoseconds.
+        Weight::from_ref_time(63_181_406)
+            // Standard Error: 17_832
+            .saturating_add(Weight::from_ref_time(37_215_642).saturating_mul(p.into()))
+            .saturating_add(DbWeight::get().reads(7))
+            .saturating_add(DbWeight::get().reads((9_u64).saturating_mul(p.into())))
+            .saturating_add(DbWeight::get().writes(5))
+            .saturating_add(DbWeight::get().writes((6_u64).saturating_mul(p.into())))
+    }
 }
@@ -174,6 +174,11 @@ impl pallet_portfolio::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().writes(1))
             .saturating_add(DbWeight::get().writes((2_u64).saturating_mul(f.into())))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            // Not benchmarked yet, the acquisition lots moved with each fungible asset.
+            .saturating_add(DbWeight::get().reads_writes(
+                (3_u64).saturating_mul(f.into()),
+                (2_u64).saturating_mul(f.into()),
+            ))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
@@ -243,4 +248,46 @@ impl pallet_portfolio::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(4))
             .saturating_add(DbWeight::get().writes(5))
     }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio Portfolios (r:1 w:0)
+    // Proof Skipped: Portfolio Portfolios (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Asset Assets (r:1 w:0)
+    // Proof Skipped: Asset Assets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioAssetBalances (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedAssets (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioLockedAssets (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLockedByLocks (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedByLocks (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio NextPortfolioLockId (r:1 w:1)
+    // Proof Skipped: Portfolio NextPortfolioLockId (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLocks (r:0 w:1)
+    // Proof Skipped: Portfolio PortfolioLocks (max_values: None, max_size: None, mode: Measured)
+    fn lock_portfolio_assets() -> Weight {
+        // Minimum execution time: 61_491 nanoseconds.
+        Weight::from_ref_time(63_118_000)
+            .saturating_add(DbWeight::get().reads(9))
+            .saturating_add(DbWeight::get().writes(3))
+    }
+    // Storage: Identity KeyRecords (r:1 w:0)
+    // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioLocks (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLocks (max_values: None, max_size: None, mode: Measured)
+    // Storage: Portfolio PortfolioCustodian (r:1 w:0)
+    // Proof Skipped: Portfolio PortfolioCustodian (max_values: None, max_size: None, mode: Measured)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Portfolio PortfolioLockedByLocks (r:1 w:1)
+    // Proof Skipped: Portfolio PortfolioLockedByLocks (max_values: None, max_size: None, mode: Measured)
+    fn release_portfolio_lock() -> Weight {
+        // Minimum execution time: 42_770 nanoseconds.
+        Weight::from_ref_time(44_352_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(2))
+    }
 }
@@ -89,11 +89,17 @@ impl pallet_relayer::WeightInfo for SubstrateWeight {
     // Proof Skipped: Identity NumberOfGivenAuths (max_values: None, max_size: None, mode: Measured)
     // Storage: Identity AuthorizationsGiven (r:0 w:1)
     // Proof Skipped: Identity AuthorizationsGiven (max_values: None, max_size: None, mode: Measured)
+    // Storage: System Number (r:1 w:0)
+    // Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    // Storage: Relayer SubsidyPeriods (r:0 w:1)
+    // Proof Skipped: Relayer SubsidyPeriods (max_values: None, max_size: None, mode: Measured)
+    // Storage: Relayer SubsidyScopes (r:0 w:1)
+    // Proof Skipped: Relayer SubsidyScopes (max_values: None, max_size: None, mode: Measured)
     fn accept_paying_key() -> Weight {
-        // Minimum execution time: 91_288 nanoseconds.
-        Weight::from_ref_time(95_355_000)
-            .saturating_add(DbWeight::get().reads(14))
-            .saturating_add(DbWeight::get().writes(6))
+        // Minimum execution time: 98_174 nanoseconds.
+        Weight::from_ref_time(103_902_000)
+            .saturating_add(DbWeight::get().reads(15))
+            .saturating_add(DbWeight::get().writes(8))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
@@ -101,40 +107,50 @@ impl pallet_relayer::WeightInfo for SubstrateWeight {
     // Proof Skipped: Relayer Subsidies (max_values: None, max_size: None, mode: Measured)
     // Storage: Identity AccountKeyRefCount (r:2 w:2)
     // Proof Skipped: Identity AccountKeyRefCount (max_values: None, max_size: None, mode: Measured)
+    // Storage: Relayer SubsidyScopes (r:0 w:1)
+    // Proof Skipped: Relayer SubsidyScopes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Relayer SubsidyPeriods (r:0 w:1)
+    // Proof Skipped: Relayer SubsidyPeriods (max_values: None, max_size: None, mode: Measured)
     fn remove_paying_key() -> Weight {
-        // Minimum execution time: 34_642 nanoseconds.
-        Weight::from_ref_time(37_337_000)
+        // Minimum execution time: 36_109 nanoseconds.
+        Weight::from_ref_time(39_214_000)
             .saturating_add(DbWeight::get().reads(4))
-            .saturating_add(DbWeight::get().writes(3))
+            .saturating_add(DbWeight::get().writes(5))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
     // Storage: Relayer Subsidies (r:1 w:1)
     // Proof Skipped: Relayer Subsidies (max_values: None, max_size: None, mode: Measured)
+    // Storage: Relayer SubsidyScopes (r:1 w:0)
+    // Proof Skipped: Relayer SubsidyScopes (max_values: None, max_size: None, mode: Measured)
     fn update_polyx_limit() -> Weight {
         // Minimum execution time: 26_710 nanoseconds.
         Weight::from_ref_time(28_843_000)
-            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().reads(3))
             .saturating_add(DbWeight::get().writes(1))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
     // Storage: Relayer Subsidies (r:1 w:1)
     // Proof Skipped: Relayer Subsidies (max_values: None, max_size: None, mode: Measured)
+    // Storage: Relayer SubsidyScopes (r:1 w:0)
+    // Proof Skipped: Relayer SubsidyScopes (max_values: None, max_size: None, mode: Measured)
     fn increase_polyx_limit() -> Weight {
         // Minimum execution time: 27_793 nanoseconds.
         Weight::from_ref_time(28_082_000)
-            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().reads(3))
             .saturating_add(DbWeight::get().writes(1))
     }
     // Storage: Identity KeyRecords (r:1 w:0)
     // Proof Skipped: Identity KeyRecords (max_values: None, max_size: None, mode: Measured)
     // Storage: Relayer Subsidies (r:1 w:1)
     // Proof Skipped: Relayer Subsidies (max_values: None, max_size: None, mode: Measured)
+    // Storage: Relayer SubsidyScopes (r:1 w:0)
+    // Proof Skipped: Relayer SubsidyScopes (max_values: None, max_size: None, mode: Measured)
     fn decrease_polyx_limit() -> Weight {
         // Minimum execution time: 27_131 nanoseconds.
         Weight::from_ref_time(28_433_000)
-            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().reads(3))
             .saturating_add(DbWeight::get().writes(1))
     }
 }
@@ -1119,4 +1119,50 @@ impl pallet_settlement::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().writes((7_u64).saturating_mul(n.into())))
             .saturating_add(DbWeight::get().writes((3_u64).saturating_mul(o.into())))
     }
+    // Not benchmarked yet, these must be regenerated from the `add_instruction_with_hash_lock`,
+    // `execute_with_preimage` and `expire_hash_lock` benchmarks.
+    // Estimated as `add_instruction` plus storing the hash lock, its locked legs and scheduling its expiry.
+    fn add_instruction_with_hash_lock(f: u32, n: u32, o: u32) -> Weight {
+        Self::add_instruction(f, n, o)
+            .saturating_add(DbWeight::get().reads_writes(2, 3))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul((f + n + o).into())))
+    }
+    // Estimated as `execute_manual_instruction` plus hashing the preimage and clearing the hash lock.
+    fn execute_with_preimage(f: u32, n: u32, o: u32) -> Weight {
+        Self::execute_manual_instruction(f, n, o)
+            .saturating_add(Weight::from_ref_time(25_000_000))
+            .saturating_add(DbWeight::get().reads_writes(3, 3))
+            .saturating_add(DbWeight::get().reads_writes(
+                (1_u64).saturating_mul((f + n + o).into()),
+                (1_u64).saturating_mul((f + n + o).into()),
+            ))
+    }
+    // Estimated as `reject_instruction` plus clearing the hash lock and its locked legs.
+    fn expire_hash_lock(f: u32, n: u32, o: u32) -> Weight {
+        Self::reject_instruction(f, n, o)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul((f + n + o).into())))
+    }
+    // Not benchmarked yet, these must be regenerated from the `amend_instruction` and
+    // `partially_execute_instruction` benchmarks.
+    // Estimated as `withdraw_affirmation` plus writing the amended legs and the instruction revision.
+    fn amend_instruction(f: u32, n: u32, o: u32) -> Weight {
+        Self::withdraw_affirmation(f, n, o)
+            .saturating_add(DbWeight::get().reads_writes(2, 1))
+            .saturating_add(DbWeight::get().writes((1_u64).saturating_mul((f + o).into())))
+    }
+    // Estimated as `execute_manual_instruction` for `f` fungible legs plus writing the remaining amount of each leg.
+    fn partially_execute_instruction(f: u32) -> Weight {
+        Self::execute_manual_instruction(f, 0, 0).saturating_add(DbWeight::get().reads_writes(
+            (1_u64).saturating_mul(f.into()),
+            (1_u64).saturating_mul(f.into()),
+        ))
+    }
+    // Not benchmarked yet, this must be regenerated from the `execute_netted_instructions` benchmark.
+    // Estimated as executing all `f` fungible legs in a single instruction plus the fixed cost of each other instruction.
+    fn execute_netted_instructions(i: u32, f: u32) -> Weight {
+        Self::execute_manual_instruction(f, 0, 0).saturating_add(
+            Self::execute_manual_instruction(0, 0, 0).saturating_mul(i.saturating_sub(1).into()),
+        )
+    }
 }
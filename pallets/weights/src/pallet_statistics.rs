@@ -241,4 +241,40 @@ impl pallet_statistics::WeightInfo for SubstrateWeight {
         // Minimum execution time: 8_413 nanoseconds.
         Weight::from_ref_time(9_024_000).saturating_add(DbWeight::get().reads(1))
     }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Statistics AssetVolumeStats (r:1 w:0)
+    // Proof Skipped: Statistics AssetVolumeStats (max_values: None, max_size: None, mode: Measured)
+    fn volume_restriction() -> Weight {
+        // Minimum execution time: 11_802 nanoseconds.
+        Weight::from_ref_time(12_604_000).saturating_add(DbWeight::get().reads(2))
+    }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    // Storage: Statistics AssetVolumeStats (r:1 w:1)
+    // Proof Skipped: Statistics AssetVolumeStats (max_values: None, max_size: None, mode: Measured)
+    fn update_asset_volume_stats() -> Weight {
+        // Minimum execution time: 16_130 nanoseconds.
+        Weight::from_ref_time(17_215_000)
+            .saturating_add(DbWeight::get().reads(2))
+            .saturating_add(DbWeight::get().writes(1))
+    }
+    // Not benchmarked yet, these must be regenerated from the `holding_period_restriction` and
+    // `update_acquisition_lots` benchmarks.
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Statistics PortfolioAcquisitionLots (r:1 w:0)
+    fn holding_period_restriction() -> Weight {
+        // Estimated as `update_asset_volume_stats`, both decode a bounded vector of periods or lots.
+        Weight::from_ref_time(17_215_000).saturating_add(DbWeight::get().reads(3))
+    }
+    // Storage: Portfolio PortfolioAssetBalances (r:1 w:0)
+    // Storage: Statistics PortfolioAcquisitionLots (r:2 w:2)
+    // Storage: Timestamp Now (r:1 w:0)
+    fn update_acquisition_lots() -> Weight {
+        // Estimated as two `update_asset_volume_stats`, one for the sender and one for the receiver.
+        Weight::from_ref_time(34_430_000)
+            .saturating_add(DbWeight::get().reads(4))
+            .saturating_add(DbWeight::get().writes(2))
+    }
 }
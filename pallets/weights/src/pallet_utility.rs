@@ -140,4 +140,20 @@ impl pallet_utility::WeightInfo for SubstrateWeight {
             .saturating_add(DbWeight::get().reads(3))
             .saturating_add(DbWeight::get().writes(3))
     }
+    // Storage: System Number (r:1 w:0)
+    // Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    // Storage: Utility UsedSponsorEnvelopes (r:1 w:1)
+    // Proof Skipped: Utility UsedSponsorEnvelopes (max_values: None, max_size: None, mode: Measured)
+    // Storage: Identity CurrentPayer (r:1 w:1)
+    // Proof Skipped: Identity CurrentPayer (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentPalletName (r:1 w:1)
+    // Proof Skipped: Permissions CurrentPalletName (max_values: Some(1), max_size: None, mode: Measured)
+    // Storage: Permissions CurrentDispatchableName (r:1 w:1)
+    // Proof Skipped: Permissions CurrentDispatchableName (max_values: Some(1), max_size: None, mode: Measured)
+    fn sponsored_call() -> Weight {
+        // Minimum execution time: 98_417 nanoseconds.
+        Weight::from_ref_time(104_286_000)
+            .saturating_add(DbWeight::get().reads(5))
+            .saturating_add(DbWeight::get().writes(4))
+    }
 }
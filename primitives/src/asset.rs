@@ -19,10 +19,13 @@ use sp_runtime::{Deserialize, Serialize};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_128;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::Perquintill;
 use sp_std::prelude::Vec;
 
 use crate::impl_checked_inc;
 use crate::ticker::Ticker;
+use crate::{Balance, PortfolioId};
 use polymesh_primitives_derive::VecU8StrongTyped;
 
 /// An unique asset identifier.
@@ -168,7 +171,235 @@ impl AssetType {
     }
 }
 
+/// The ID of an identity recovery, moving the assets of an identity that lost its keys to a new identity.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IdentityRecoveryId(pub u64);
+impl_checked_inc!(IdentityRecoveryId);
+
+/// An operation of an asset that can be paused by its agents.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, Copy, Debug, Decode, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo
+)]
+pub enum AssetOperation {
+    /// Issuance of fungible tokens and NFTs.
+    Issue,
+    /// Redemption of fungible tokens and NFTs.
+    Redeem,
+    /// Any transfer that is not a controller transfer.
+    Transfer,
+    /// Execution of settlement instructions.
+    Settlement,
+}
+
+impl AssetOperation {
+    /// All operations that can be paused.
+    pub const ALL: [AssetOperation; 4] = [
+        AssetOperation::Issue,
+        AssetOperation::Redeem,
+        AssetOperation::Transfer,
+        AssetOperation::Settlement,
+    ];
+
+    /// Returns the bit of the operation in [`AssetOperations`].
+    fn flag(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+/// A bitmap of [`AssetOperation`].
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct AssetOperations(pub u8);
+
+impl AssetOperations {
+    /// Returns `true` if `operation` is set in the bitmap.
+    pub fn contains(&self, operation: AssetOperation) -> bool {
+        self.0 & operation.flag() != 0
+    }
+
+    /// Returns `true` if no operation is set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if at least one operation is set and all bits correspond to an [`AssetOperation`].
+    pub fn is_valid(&self) -> bool {
+        let all = Self::from_iter(AssetOperation::ALL);
+        !self.is_empty() && self.0 & !all.0 == 0
+    }
+
+    /// Sets all operations of `other`.
+    pub fn insert(&mut self, other: AssetOperations) {
+        self.0 |= other.0;
+    }
+
+    /// Unsets all operations of `other`.
+    pub fn remove(&mut self, other: AssetOperations) {
+        self.0 &= !other.0;
+    }
+
+    /// Returns all operations that are set.
+    pub fn operations(&self) -> Vec<AssetOperation> {
+        AssetOperation::ALL
+            .into_iter()
+            .filter(|operation| self.contains(*operation))
+            .collect()
+    }
+}
+
+impl FromIterator<AssetOperation> for AssetOperations {
+    fn from_iter<I: IntoIterator<Item = AssetOperation>>(operations: I) -> Self {
+        Self(
+            operations
+                .into_iter()
+                .fold(0, |bits, operation| bits | operation.flag()),
+        )
+    }
+}
+
 /// A wrapper for a funding round name.
 #[derive(Decode, Encode, TypeInfo, VecU8StrongTyped)]
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct FundingRoundName(pub Vec<u8>);
+
+/// The amount charged by a [`TransferFee`].
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferFeeAmount {
+    /// A fixed amount charged for every fungible leg or for every NFT transferred.
+    Flat(Balance),
+    /// A fraction of the fungible amount transferred, in basis points (1 = 0.01%).
+    BasisPoints(u16),
+}
+
+/// The fee charged by the settlement engine whenever an asset is transferred.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferFee {
+    /// How the fee amount is computed.
+    pub amount: TransferFeeAmount,
+    /// The asset the fee is paid in. If `None` the fee is paid in the transferred asset itself.
+    pub currency: Option<AssetId>,
+    /// The portfolio receiving the fee.
+    pub beneficiary: PortfolioId,
+}
+
+impl TransferFee {
+    /// The basis points equivalent to 100%.
+    pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+    /// Returns the [`AssetId`] the fee for a transfer of `asset_id` is paid in.
+    pub fn fee_asset_id(&self, asset_id: AssetId) -> AssetId {
+        self.currency.unwrap_or(asset_id)
+    }
+
+    /// Returns the fee due for transferring `value` units of a fungible asset.
+    /// Fees in basis points are rounded down.
+    pub fn fungible_fee(&self, value: Balance) -> Balance {
+        match self.amount {
+            TransferFeeAmount::Flat(fee) => fee,
+            TransferFeeAmount::BasisPoints(bps) => {
+                // Splits `value` to avoid overflowing on large transfers.
+                let (bps, max) = (Balance::from(bps), Balance::from(Self::MAX_BASIS_POINTS));
+                (value / max) * bps + (value % max) * bps / max
+            }
+        }
+    }
+
+    /// Returns the fee due for transferring `n_nfts` non-fungible tokens.
+    pub fn nft_fee(&self, n_nfts: u64) -> Balance {
+        match self.amount {
+            TransferFeeAmount::Flat(fee) => fee.saturating_mul(n_nfts as Balance),
+            TransferFeeAmount::BasisPoints(_) => 0,
+        }
+    }
+}
+
+/// The ID of a vesting schedule.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VestingScheduleId(pub u64);
+impl_checked_inc!(VestingScheduleId);
+
+/// When the vested units of a [`VestingSchedule`] become transferable.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VestingRelease {
+    /// Units become transferable as soon as they vest.
+    Automatic,
+    /// Vested units remain locked until they are claimed by the custodian of the portfolio.
+    OnClaim,
+}
+
+/// The vesting of an amount of an asset held in a portfolio.
+///
+/// Nothing vests before `cliff`. From then on, units vest linearly between `start` and `end`,
+/// so a schedule with `cliff == end` vests everything at once.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VestingSchedule<Moment> {
+    /// The [`VestingScheduleId`] of the schedule.
+    pub id: VestingScheduleId,
+    /// The total amount being vested.
+    pub amount: Balance,
+    /// The moment the vesting starts.
+    pub start: Moment,
+    /// The moment before which nothing vests.
+    pub cliff: Moment,
+    /// The moment everything has vested.
+    pub end: Moment,
+    /// When the vested units become transferable.
+    pub release: VestingRelease,
+    /// The amount claimed so far, for schedules released [`VestingRelease::OnClaim`].
+    pub claimed: Balance,
+}
+
+impl<Moment: Copy + PartialOrd + UniqueSaturatedInto<u64>> VestingSchedule<Moment> {
+    /// Returns `true` if `start <= cliff <= end`.
+    pub fn is_valid(&self) -> bool {
+        self.start <= self.cliff && self.cliff <= self.end
+    }
+
+    /// Returns the amount vested at `now`.
+    pub fn vested(&self, now: Moment) -> Balance {
+        if now < self.cliff {
+            return 0;
+        }
+        if now >= self.end {
+            return self.amount;
+        }
+        let start: u64 = self.start.unique_saturated_into();
+        let end: u64 = self.end.unique_saturated_into();
+        let now: u64 = now.unique_saturated_into();
+        Perquintill::from_rational(now - start, end - start) * self.amount
+    }
+
+    /// Returns the amount that can't be transferred at `now`.
+    pub fn locked(&self, now: Moment) -> Balance {
+        match self.release {
+            VestingRelease::Automatic => self.amount - self.vested(now),
+            VestingRelease::OnClaim => self.amount - self.claimed,
+        }
+    }
+}
+
+/// The right of a spender identity to move units of an asset out of a portfolio.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetAllowance<Moment> {
+    /// The amount that can still be moved by the spender.
+    pub amount: Balance,
+    /// The moment from which the allowance can no longer be used, if any.
+    pub expiry: Option<Moment>,
+}
+
+impl<Moment: PartialOrd> AssetAllowance<Moment> {
+    /// Returns `true` if the allowance can no longer be used at `now`.
+    pub fn is_expired(&self, now: &Moment) -> bool {
+        self.expiry.as_ref().map_or(false, |expiry| expiry <= now)
+    }
+}
@@ -15,9 +15,12 @@
 
 use crate::impl_checked_inc;
 use crate::Url;
-use codec::{Decode, DecodeAll, Encode};
+use codec::{Compact, Decode, DecodeAll, Encode};
 use polymesh_primitives_derive::VecU8StrongTyped;
-use scale_info::{PortableRegistry, TypeInfo};
+use scale_info::form::PortableForm;
+use scale_info::{
+    Field, PortableRegistry, TypeDef, TypeDefBitSequence, TypeDefPrimitive, TypeInfo,
+};
 #[cfg(feature = "std")]
 use sp_runtime::{Deserialize, Serialize};
 use sp_std::prelude::Vec;
@@ -31,18 +34,21 @@ pub struct AssetMetadataName(#[cfg_attr(feature = "std", serde(with = "serde_byt
 /// Asset Metadata Global Key.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetMetadataGlobalKey(pub u64);
 impl_checked_inc!(AssetMetadataGlobalKey);
 
 /// Asset Metadata Local Key.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AssetMetadataLocalKey(pub u64);
 impl_checked_inc!(AssetMetadataLocalKey);
 
 /// Asset Metadata Key.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum AssetMetadataKey {
     /// Global Metadata Key.
     Global(AssetMetadataGlobalKey),
@@ -67,6 +73,17 @@ impl From<AssetMetadataGlobalKey> for AssetMetadataKey {
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AssetMetadataValue(pub Vec<u8>);
 
+impl AssetMetadataValue {
+    /// Returns the value as a JSON string of its hex encoded bytes.
+    ///
+    /// Used for values that don't have a type definition.
+    pub fn to_json_hex(&self) -> Vec<u8> {
+        let mut json = Vec::with_capacity(self.0.len() * 2 + 4);
+        write_json_hex(&mut json, &self.0);
+        json
+    }
+}
+
 /// Asset Metadata Value details.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -203,6 +220,389 @@ impl AssetMetadataTypeDef {
             ty,
         }
     }
+
+    /// Ensures that `value` is a SCALE encoded value of the top-level type, without trailing bytes.
+    pub fn validate_value(&self, value: &[u8]) -> Result<(), codec::Error> {
+        ValueDecoder::new(&self.types, value, false)
+            .decode_all(self.ty)
+            .map(drop)
+    }
+
+    /// Decodes `value` as the top-level type and returns it as a JSON document.
+    ///
+    /// Named fields become objects, unnamed fields and sequences become arrays,
+    /// byte sequences and 256 bit integers become hex strings and 128 bit integers become decimal strings.
+    pub fn decode_value_to_json(&self, value: &[u8]) -> Result<Vec<u8>, codec::Error> {
+        ValueDecoder::new(&self.types, value, true).decode_all(self.ty)
+    }
+}
+
+/// The maximum nesting of types when decoding a metadata value.
+const MAX_VALUE_DECODE_DEPTH: u32 = 32;
+
+/// The maximum number of types visited when decoding a metadata value.
+///
+/// This bounds the cost of values made of zero-sized types, which don't consume any input.
+const MAX_VALUE_DECODE_STEPS: u32 = 32 * 1024;
+
+/// Decodes a SCALE encoded value using the types of a `PortableRegistry`,
+/// optionally rendering it as JSON.
+struct ValueDecoder<'a> {
+    types: &'a PortableRegistry,
+    input: &'a [u8],
+    json: Option<Vec<u8>>,
+    steps: u32,
+}
+
+impl<'a> ValueDecoder<'a> {
+    fn new(types: &'a PortableRegistry, input: &'a [u8], json: bool) -> Self {
+        Self {
+            types,
+            input,
+            json: json.then(Vec::new),
+            steps: 0,
+        }
+    }
+
+    /// Decodes the whole input as `ty` and returns the rendered JSON, if any.
+    fn decode_all(mut self, ty: u32) -> Result<Vec<u8>, codec::Error> {
+        self.decode(ty, 0)?;
+        if !self.input.is_empty() {
+            return Err("Metadata value has trailing bytes".into());
+        }
+        Ok(self.json.unwrap_or_default())
+    }
+
+    fn resolve(
+        &mut self,
+        ty: u32,
+        depth: u32,
+    ) -> Result<&'a scale_info::Type<PortableForm>, codec::Error> {
+        self.steps += 1;
+        if depth > MAX_VALUE_DECODE_DEPTH || self.steps > MAX_VALUE_DECODE_STEPS {
+            return Err("Metadata value type is too complex".into());
+        }
+        self.types
+            .resolve(ty)
+            .ok_or_else(|| "Unknown metadata value type".into())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], codec::Error> {
+        if self.input.len() < len {
+            return Err("Not enough data to decode metadata value".into());
+        }
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(bytes)
+    }
+
+    fn decode(&mut self, ty: u32, depth: u32) -> Result<(), codec::Error> {
+        let ty = self.resolve(ty, depth)?;
+        let depth = depth + 1;
+        match &ty.type_def {
+            TypeDef::Composite(composite) => self.decode_fields(&composite.fields, depth),
+            TypeDef::Variant(variant) => {
+                let index = u8::decode(&mut self.input)?;
+                let variant = variant
+                    .variants
+                    .iter()
+                    .find(|v| v.index == index)
+                    .ok_or("Unknown metadata value variant")?;
+                if variant.fields.is_empty() {
+                    self.write_str(&variant.name);
+                    return Ok(());
+                }
+                self.write("{");
+                self.write_str(&variant.name);
+                self.write(":");
+                self.decode_fields(&variant.fields, depth)?;
+                self.write("}");
+                Ok(())
+            }
+            TypeDef::Sequence(seq) => {
+                let len = Compact::<u32>::decode(&mut self.input)?.0;
+                self.decode_items(seq.type_param.id, len, depth)
+            }
+            TypeDef::Array(array) => self.decode_items(array.type_param.id, array.len, depth),
+            TypeDef::Tuple(tuple) => {
+                if tuple.fields.is_empty() {
+                    self.write("null");
+                    return Ok(());
+                }
+                self.write("[");
+                for (i, field) in tuple.fields.iter().enumerate() {
+                    if i > 0 {
+                        self.write(",");
+                    }
+                    self.decode(field.id, depth)?;
+                }
+                self.write("]");
+                Ok(())
+            }
+            TypeDef::Primitive(primitive) => self.decode_primitive(primitive),
+            TypeDef::Compact(compact) => self.decode_compact(compact.type_param.id, depth),
+            TypeDef::BitSequence(bits) => self.decode_bits(bits, depth),
+        }
+    }
+
+    fn decode_fields(
+        &mut self,
+        fields: &'a [Field<PortableForm>],
+        depth: u32,
+    ) -> Result<(), codec::Error> {
+        match fields {
+            [] => self.write("null"),
+            // Unwrap new types.
+            [field] if field.name.is_none() => return self.decode(field.ty.id, depth),
+            fields if fields.iter().all(|f| f.name.is_some()) => {
+                self.write("{");
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.write(",");
+                    }
+                    self.write_str(field.name.as_deref().unwrap_or_default());
+                    self.write(":");
+                    self.decode(field.ty.id, depth)?;
+                }
+                self.write("}");
+            }
+            fields => {
+                self.write("[");
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.write(",");
+                    }
+                    self.decode(field.ty.id, depth)?;
+                }
+                self.write("]");
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_items(&mut self, ty: u32, len: u32, depth: u32) -> Result<(), codec::Error> {
+        // Render bytes as a hex string.
+        if let TypeDef::Primitive(TypeDefPrimitive::U8) = &self.resolve(ty, depth)?.type_def {
+            let bytes = self.take(len as usize)?;
+            if let Some(json) = &mut self.json {
+                write_json_hex(json, bytes);
+            }
+            return Ok(());
+        }
+        self.write("[");
+        for i in 0..len {
+            if i > 0 {
+                self.write(",");
+            }
+            self.decode(ty, depth)?;
+        }
+        self.write("]");
+        Ok(())
+    }
+
+    fn decode_primitive(&mut self, primitive: &TypeDefPrimitive) -> Result<(), codec::Error> {
+        let input = &mut self.input;
+        match primitive {
+            TypeDefPrimitive::Bool => {
+                let value = bool::decode(input)?;
+                self.write(if value { "true" } else { "false" });
+            }
+            TypeDefPrimitive::Char => {
+                let value = char::from_u32(u32::decode(input)?).ok_or("Invalid char")?;
+                self.write_str(value.encode_utf8(&mut [0; 4]));
+            }
+            TypeDefPrimitive::Str => {
+                let value = Vec::<u8>::decode(input)?;
+                let value = core::str::from_utf8(&value).map_err(|_| "Invalid UTF-8 string")?;
+                self.write_str(value);
+            }
+            TypeDefPrimitive::U8 | TypeDefPrimitive::U16 | TypeDefPrimitive::U32 => {
+                let value = match primitive {
+                    TypeDefPrimitive::U8 => u8::decode(input)?.into(),
+                    TypeDefPrimitive::U16 => u16::decode(input)?.into(),
+                    _ => u32::decode(input)?.into(),
+                };
+                self.write_uint(value);
+            }
+            TypeDefPrimitive::U64 => {
+                let value = u64::decode(input)?;
+                self.write_uint(value.into());
+            }
+            TypeDefPrimitive::I8 | TypeDefPrimitive::I16 | TypeDefPrimitive::I32 => {
+                let value = match primitive {
+                    TypeDefPrimitive::I8 => i8::decode(input)?.into(),
+                    TypeDefPrimitive::I16 => i16::decode(input)?.into(),
+                    _ => i32::decode(input)?.into(),
+                };
+                self.write_int(value);
+            }
+            TypeDefPrimitive::I64 => {
+                let value = i64::decode(input)?;
+                self.write_int(value.into());
+            }
+            // 128 bit integers don't fit in the numbers of most JSON parsers.
+            TypeDefPrimitive::U128 => {
+                let value = u128::decode(input)?;
+                self.write("\"");
+                self.write_uint(value);
+                self.write("\"");
+            }
+            TypeDefPrimitive::I128 => {
+                let value = i128::decode(input)?;
+                self.write("\"");
+                self.write_int(value);
+                self.write("\"");
+            }
+            TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => {
+                let bytes = self.take(32)?;
+                if let Some(json) = &mut self.json {
+                    // Little-endian, rendered most significant byte first.
+                    let mut be = [0u8; 32];
+                    be.copy_from_slice(bytes);
+                    be.reverse();
+                    write_json_hex(json, &be);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_compact(&mut self, ty: u32, depth: u32) -> Result<(), codec::Error> {
+        match &self.resolve(ty, depth)?.type_def {
+            TypeDef::Primitive(primitive) => {
+                let max = match primitive {
+                    TypeDefPrimitive::U8 => u8::MAX.into(),
+                    TypeDefPrimitive::U16 => u16::MAX.into(),
+                    TypeDefPrimitive::U32 => u32::MAX.into(),
+                    TypeDefPrimitive::U64 => u64::MAX.into(),
+                    TypeDefPrimitive::U128 => u128::MAX,
+                    _ => return Err("Invalid compact metadata value type".into()),
+                };
+                let value = Compact::<u128>::decode(&mut self.input)?.0;
+                if value > max {
+                    return Err("Compact metadata value out of range".into());
+                }
+                if max == u128::MAX {
+                    self.write("\"");
+                    self.write_uint(value);
+                    self.write("\"");
+                } else {
+                    self.write_uint(value);
+                }
+                Ok(())
+            }
+            // Compact encoded new types, like `Compact<Permill>`.
+            TypeDef::Composite(composite) if composite.fields.len() == 1 => {
+                self.decode_compact(composite.fields[0].ty.id, depth + 1)
+            }
+            _ => Err("Invalid compact metadata value type".into()),
+        }
+    }
+
+    fn decode_bits(
+        &mut self,
+        bits: &TypeDefBitSequence<PortableForm>,
+        depth: u32,
+    ) -> Result<(), codec::Error> {
+        let store_len = match &self.resolve(bits.bit_store_type.id, depth)?.type_def {
+            TypeDef::Primitive(TypeDefPrimitive::U8) => 1,
+            TypeDef::Primitive(TypeDefPrimitive::U16) => 2,
+            TypeDef::Primitive(TypeDefPrimitive::U32) => 4,
+            TypeDef::Primitive(TypeDefPrimitive::U64) => 8,
+            _ => return Err("Invalid bit store metadata value type".into()),
+        };
+        let msb0 = self
+            .resolve(bits.bit_order_type.id, depth)?
+            .path
+            .segments
+            .last()
+            .map_or(false, |ident| ident == "Msb0");
+        let len = Compact::<u32>::decode(&mut self.input)?.0 as usize;
+        let store_bits = store_len * 8;
+        let stores = len / store_bits + usize::from(len % store_bits != 0);
+        let bytes = self.take(stores * store_len)?;
+        if self.json.is_none() {
+            return Ok(());
+        }
+        self.write("[");
+        for i in 0..len {
+            if i > 0 {
+                self.write(",");
+            }
+            // Stores are little-endian.
+            let store = &bytes[(i / store_bits) * store_len..][..store_len];
+            let bit = if msb0 {
+                store_bits - 1 - i % store_bits
+            } else {
+                i % store_bits
+            };
+            let set = (store[bit / 8] >> (bit % 8)) & 1 == 1;
+            self.write(if set { "true" } else { "false" });
+        }
+        self.write("]");
+        Ok(())
+    }
+
+    fn write(&mut self, s: &str) {
+        if let Some(json) = &mut self.json {
+            json.extend_from_slice(s.as_bytes());
+        }
+    }
+
+    /// Writes `s` as an escaped JSON string.
+    fn write_str(&mut self, s: &str) {
+        if let Some(json) = &mut self.json {
+            json.push(b'"');
+            for c in s.chars() {
+                match c {
+                    '"' => json.extend_from_slice(b"\\\""),
+                    '\\' => json.extend_from_slice(b"\\\\"),
+                    c if (c as u32) < 0x20 => {
+                        json.extend_from_slice(b"\\u00");
+                        json.push(HEX_DIGITS[c as usize >> 4]);
+                        json.push(HEX_DIGITS[c as usize & 0xf]);
+                    }
+                    c => json.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                }
+            }
+            json.push(b'"');
+        }
+    }
+
+    fn write_uint(&mut self, mut value: u128) {
+        if let Some(json) = &mut self.json {
+            let mut digits = [0u8; 39];
+            let mut start = digits.len();
+            loop {
+                start -= 1;
+                digits[start] = b'0' + (value % 10) as u8;
+                value /= 10;
+                if value == 0 {
+                    break;
+                }
+            }
+            json.extend_from_slice(&digits[start..]);
+        }
+    }
+
+    fn write_int(&mut self, value: i128) {
+        if value < 0 {
+            self.write("-");
+        }
+        self.write_uint(value.unsigned_abs());
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Writes `bytes` as a `0x` prefixed JSON hex string.
+fn write_json_hex(json: &mut Vec<u8>, bytes: &[u8]) {
+    json.extend_from_slice(b"\"0x");
+    for b in bytes {
+        json.push(HEX_DIGITS[(b >> 4) as usize]);
+        json.push(HEX_DIGITS[(b & 0xf) as usize]);
+    }
+    json.push(b'"');
 }
 
 #[cfg(test)]
@@ -234,4 +634,83 @@ mod tests {
             .expect("Missing type def.");
         assert_eq!(type_def, type_def2);
     }
+
+    /// Coupon like metadata test type.
+    #[derive(Encode, Decode, TypeInfo)]
+    struct MetadataTestType2 {
+        rate: u32,
+        maturity: Option<Moment>,
+        #[codec(compact)]
+        face_value: u128,
+        name: Vec<u8>,
+        kind: MetadataTestKind,
+    }
+
+    #[derive(Encode, Decode, TypeInfo)]
+    enum MetadataTestKind {
+        Fixed,
+        Floating(u8, bool),
+    }
+
+    fn make_test_value() -> MetadataTestType2 {
+        MetadataTestType2 {
+            rate: 500,
+            maturity: Some(1_700_000_000),
+            face_value: 1_000,
+            name: b"ab".to_vec(),
+            kind: MetadataTestKind::Floating(3, true),
+        }
+    }
+
+    #[test]
+    fn validate_metadata_value_test() {
+        let type_def = AssetMetadataTypeDef::new_from_type::<MetadataTestType2>();
+        let value = make_test_value().encode();
+        assert!(type_def.validate_value(&value).is_ok());
+
+        // Truncated value.
+        assert!(type_def.validate_value(&value[..value.len() - 1]).is_err());
+        // Trailing bytes.
+        let mut trailing = value.clone();
+        trailing.push(0);
+        assert!(type_def.validate_value(&trailing).is_err());
+        // Unknown enum variant.
+        let mut unknown_variant = value.clone();
+        *unknown_variant.get_mut(value.len() - 3).unwrap() = 2;
+        assert!(type_def.validate_value(&unknown_variant).is_err());
+        // Invalid bool.
+        let mut invalid_bool = value;
+        *invalid_bool.last_mut().unwrap() = 2;
+        assert!(type_def.validate_value(&invalid_bool).is_err());
+        // Opaque bytes.
+        assert!(type_def.validate_value(b"cow").is_err());
+    }
+
+    #[test]
+    fn decode_metadata_value_to_json_test() {
+        let type_def = AssetMetadataTypeDef::new_from_type::<MetadataTestType2>();
+        let json = type_def
+            .decode_value_to_json(&make_test_value().encode())
+            .expect("Failed to decode value.");
+        let json: serde_json::Value = serde_json::from_slice(&json).expect("Invalid JSON.");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "rate": 500,
+                "maturity": { "Some": 1_700_000_000u64 },
+                "face_value": "1000",
+                "name": "0x6162",
+                "kind": { "Floating": [3, true] },
+            })
+        );
+
+        let type_def = AssetMetadataTypeDef::new_from_type::<(MetadataTestKind, String)>();
+        let json = type_def
+            .decode_value_to_json(&(MetadataTestKind::Fixed, "a\"b\n").encode())
+            .expect("Failed to decode value.");
+        assert_eq!(json, br#"["Fixed","a\"b\u000a"]"#.to_vec());
+
+        let value = AssetMetadataValue(vec![0xab, 0x01]);
+        assert_eq!(value.to_json_hex(), br#""0xab01""#.to_vec());
+    }
 }
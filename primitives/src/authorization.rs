@@ -21,10 +21,10 @@ use serde::{Deserialize, Serialize};
 use sp_std::prelude::*;
 
 use crate::agent::AgentGroup;
-use crate::asset::AssetId;
+use crate::asset::{AssetId, IdentityRecoveryId};
 use crate::identity_id::IdentityId;
 use crate::secondary_key::Permissions;
-use crate::{Balance, PortfolioId, Ticker};
+use crate::{Balance, PortfolioId, SubsidyScope, Ticker};
 
 /// Authorization data for two step processes.
 #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, PartialOrd, Ord)]
@@ -52,11 +52,14 @@ pub enum AuthorizationData<AccountId> {
     BecomeAgent(AssetId, AgentGroup),
     /// Add Relayer paying key to user key
     /// Must be issued by the paying key.
-    /// `AddRelayerPayingKey(user_key, paying_key, polyx_limit)`
-    AddRelayerPayingKey(AccountId, AccountId, Balance),
+    /// `AddRelayerPayingKey(user_key, paying_key, polyx_limit, scope)`
+    AddRelayerPayingKey(AccountId, AccountId, Balance, Option<SubsidyScope>),
     /// Authorization to change primary key and leave it as a secondary key
     /// with the given permissions.
     RotatePrimaryKeyToSecondary(Permissions),
+    /// Authorization to move the assets of an identity that lost its keys to a new identity.
+    /// Must be accepted by an agent of the `AssetId`.
+    ConfirmIdentityRecovery(IdentityRecoveryId, AssetId),
 }
 
 impl<AccountId> AuthorizationData<AccountId> {
@@ -73,6 +76,7 @@ impl<AccountId> AuthorizationData<AccountId> {
             Self::PortfolioCustody(..) => AuthorizationType::PortfolioCustody,
             Self::AddRelayerPayingKey(..) => AuthorizationType::AddRelayerPayingKey,
             Self::RotatePrimaryKeyToSecondary(..) => AuthorizationType::RotatePrimaryKeyToSecondary,
+            Self::ConfirmIdentityRecovery(..) => AuthorizationType::ConfirmIdentityRecovery,
         }
     }
 }
@@ -101,6 +105,8 @@ pub enum AuthorizationType {
     AddRelayerPayingKey,
     /// Authorization to change primary key with an existing secondary key
     RotatePrimaryKeyToSecondary,
+    /// Authorization to confirm an identity recovery for an asset.
+    ConfirmIdentityRecovery,
 }
 
 /// Status of an Authorization after consume is called on it.
@@ -1,4 +1,21 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 /// The maximum number of members in a committee defined for the sake of weight computation.  This
 /// is not defined as a trait parameter but rather as a plain constant because this value has to be
 /// the same for all instances.
 pub const COMMITTEE_MEMBERS_MAX: u32 = 500;
+
+/// The committees of the chain.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum CommitteeKind {
+    /// The governance council.
+    Governance,
+    /// The technical committee.
+    Technical,
+    /// The upgrade committee.
+    Upgrade,
+}
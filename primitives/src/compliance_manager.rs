@@ -36,14 +36,10 @@ pub struct ComplianceRequirement {
 impl ComplianceRequirement {
     /// Dedup `ClaimType`s in `TrustedFor::Specific`.
     pub fn dedup(&mut self) {
-        let dedup_condition = |conds: &mut [Condition]| {
-            conds
-                .iter_mut()
-                .flat_map(|c| &mut c.issuers)
-                .for_each(|issuer| issuer.dedup())
-        };
-        dedup_condition(&mut self.sender_conditions);
-        dedup_condition(&mut self.receiver_conditions);
+        self.sender_conditions
+            .iter_mut()
+            .chain(self.receiver_conditions.iter_mut())
+            .for_each(Condition::dedup);
     }
 
     /// Returns an iterator for all conditions in this requirement.
@@ -233,6 +229,8 @@ pub struct ConditionReport {
     pub satisfied: bool,
     /// The [`Condition`] assessed.
     pub condition: Condition,
+    /// The [`ExpressionReport`] of the condition, if it is an expression.
+    pub expression: Option<ExpressionReport>,
 }
 
 impl ConditionReport {
@@ -241,6 +239,16 @@ impl ConditionReport {
         Self {
             satisfied,
             condition,
+            expression: None,
+        }
+    }
+
+    /// Creates a new [`ConditionReport`] instance for a condition holding an expression.
+    pub fn with_expression(condition: Condition, expression: ExpressionReport) -> Self {
+        Self {
+            satisfied: expression.is_satisfied(),
+            condition,
+            expression: Some(expression),
         }
     }
 
@@ -253,4 +261,101 @@ impl ConditionReport {
     pub fn condition(&self) -> &Condition {
         &self.condition
     }
+
+    /// Returns [`Self.expression`].
+    pub fn expression(&self) -> Option<&ExpressionReport> {
+        self.expression.as_ref()
+    }
+}
+
+/// The [`ComplianceReport`] as encoded before conditions could hold expressions (`ComplianceApi` version 2).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ComplianceReportV2 {
+    /// Set to `true` if any requirement is satisfied.
+    pub any_requirement_satisfied: bool,
+    /// Set to `true` if the asset compliance is paused.
+    pub paused_compliance: bool,
+    /// All [`RequirementReportV2`] containg the info for each of the asset's requirement.
+    pub requirements: Vec<RequirementReportV2>,
+}
+
+impl From<ComplianceReportV2> for ComplianceReport {
+    fn from(report: ComplianceReportV2) -> Self {
+        ComplianceReport::new(
+            report.requirements.into_iter().map(Into::into).collect(),
+            report.any_requirement_satisfied,
+            report.paused_compliance,
+        )
+    }
+}
+
+/// The [`RequirementReport`] as encoded before conditions could hold expressions (`ComplianceApi` version 2).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, TypeInfo)]
+pub struct RequirementReportV2 {
+    /// Set to `true` if all conditions are satisfied.
+    pub requirement_satisfied: bool,
+    /// Unique identifier of the compliance requirement.
+    pub id: u32,
+    /// All sender [`ConditionReportV2`].
+    pub sender_conditions: Vec<ConditionReportV2>,
+    /// All receiver [`ConditionReportV2`].
+    pub receiver_conditions: Vec<ConditionReportV2>,
+}
+
+impl From<RequirementReportV2> for RequirementReport {
+    fn from(report: RequirementReportV2) -> Self {
+        let from_reports = |reports: Vec<ConditionReportV2>| {
+            reports.into_iter().map(ConditionReport::from).collect()
+        };
+        RequirementReport::new(
+            from_reports(report.sender_conditions),
+            from_reports(report.receiver_conditions),
+            report.id,
+            report.requirement_satisfied,
+        )
+    }
+}
+
+/// The [`ConditionReport`] as encoded before conditions could hold expressions (`ComplianceApi` version 2).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ConditionReportV2 {
+    /// Set to `true` if the condition is satisfied.
+    pub satisfied: bool,
+    /// The [`Condition`] assessed.
+    pub condition: Condition,
+}
+
+impl From<ConditionReportV2> for ConditionReport {
+    fn from(report: ConditionReportV2) -> Self {
+        ConditionReport::new(report.condition, report.satisfied)
+    }
+}
+
+/// Holds the information for each node of a [`ConditionExpression`](crate::ConditionExpression).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, TypeInfo)]
+pub enum ExpressionReport {
+    /// The [`ConditionReport`] of a condition in the expression.
+    Condition(Box<ConditionReport>),
+    /// Set to `true` if all sub-expressions are satisfied.
+    And(bool, Vec<ExpressionReport>),
+    /// Set to `true` if any sub-expression is satisfied.
+    Or(bool, Vec<ExpressionReport>),
+    /// Set to `true` if the sub-expression is not satisfied.
+    Not(bool, Box<ExpressionReport>),
+}
+
+impl ExpressionReport {
+    /// Returns `true` if the node is satisfied.
+    pub fn is_satisfied(&self) -> bool {
+        match self {
+            Self::Condition(report) => report.satisfied,
+            Self::And(satisfied, _) | Self::Or(satisfied, _) | Self::Not(satisfied, _) => {
+                *satisfied
+            }
+        }
+    }
 }
@@ -13,7 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Claim, ClaimType, IdentityId};
+use crate::{Claim, ClaimType, ClaimValue, CustomClaimTypeId, IdentityId, Scope};
 use codec::{Decode, Encode};
 use core::iter;
 use either::Either;
@@ -48,16 +48,135 @@ pub enum ConditionType {
     IsNoneOf(Vec<Claim>),
     /// Condition to ensure that the sender/receiver is a particular identity or an external agent.
     IsIdentity(TargetIdentity),
+    /// Condition to ensure that a boolean expression over other conditions holds.
+    ///
+    /// Each condition in the expression uses its own trusted issuers,
+    /// so the condition holding the expression must not have any issuers.
+    IsSatisfied(ConditionExpression),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has a value greater than the given value.
+    ClaimValueGreaterThan(CustomClaimTypeId, Option<Scope>, ClaimValue),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has a value less than the given value.
+    ClaimValueLessThan(CustomClaimTypeId, Option<Scope>, ClaimValue),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has one of the given values.
+    ClaimValueInSet(CustomClaimTypeId, Option<Scope>, Vec<ClaimValue>),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has a value greater than or equal to the given value.
+    ClaimValueGreaterOrEqual(CustomClaimTypeId, Option<Scope>, ClaimValue),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has a value less than or equal to the given value.
+    ClaimValueLessOrEqual(CustomClaimTypeId, Option<Scope>, ClaimValue),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has a value equal to the given value.
+    ClaimValueEqual(CustomClaimTypeId, Option<Scope>, ClaimValue),
+    /// Condition to ensure that a claim of the custom claim type, with the given scope,
+    /// has a value of the same type as the given value, but a different value.
+    ClaimValueNotEqual(CustomClaimTypeId, Option<Scope>, ClaimValue),
 }
 
 impl ConditionType {
     /// Return the number of `Claim` or `TargetIdentity`.
+    ///
+    /// Expressions return `0`, as their claims are counted in their conditions.
     fn count(&self) -> usize {
         match self {
             ConditionType::IsIdentity(..)
             | ConditionType::IsPresent(..)
-            | ConditionType::IsAbsent(..) => 1,
+            | ConditionType::IsAbsent(..)
+            | ConditionType::ClaimValueGreaterThan(..)
+            | ConditionType::ClaimValueLessThan(..)
+            | ConditionType::ClaimValueInSet(..)
+            | ConditionType::ClaimValueGreaterOrEqual(..)
+            | ConditionType::ClaimValueLessOrEqual(..)
+            | ConditionType::ClaimValueEqual(..)
+            | ConditionType::ClaimValueNotEqual(..) => 1,
             ConditionType::IsNoneOf(claims) | ConditionType::IsAnyOf(claims) => claims.len(),
+            ConditionType::IsSatisfied(..) => 0,
+        }
+    }
+
+    /// Returns the custom claim type and scope of the claims compared by a claim value condition.
+    pub fn claim_value_key(&self) -> Option<(CustomClaimTypeId, Option<&Scope>)> {
+        match self {
+            ConditionType::ClaimValueGreaterThan(id, scope, _)
+            | ConditionType::ClaimValueLessThan(id, scope, _)
+            | ConditionType::ClaimValueInSet(id, scope, _)
+            | ConditionType::ClaimValueGreaterOrEqual(id, scope, _)
+            | ConditionType::ClaimValueLessOrEqual(id, scope, _)
+            | ConditionType::ClaimValueEqual(id, scope, _)
+            | ConditionType::ClaimValueNotEqual(id, scope, _) => Some((*id, scope.as_ref())),
+            _ => None,
+        }
+    }
+}
+
+/// A boolean expression over compliance conditions.
+///
+/// Allows mixing `AND`, `OR` and `NOT` of conditions within a single requirement,
+/// where each condition can have its own trusted issuers.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ConditionExpression {
+    /// Holds if the condition holds.
+    Condition(Box<Condition>),
+    /// Holds if all sub-expressions hold. An empty `And` always holds.
+    And(Vec<ConditionExpression>),
+    /// Holds if any sub-expression holds. An empty `Or` never holds.
+    Or(Vec<ConditionExpression>),
+    /// Holds if the sub-expression does not hold.
+    Not(Box<ConditionExpression>),
+}
+
+impl ConditionExpression {
+    /// Returns all conditions in the expression, including the ones nested in other expressions.
+    pub fn conditions(&self) -> Vec<&Condition> {
+        let mut conditions = Vec::new();
+        self.collect_conditions(&mut conditions);
+        conditions
+    }
+
+    fn collect_conditions<'a>(&'a self, conditions: &mut Vec<&'a Condition>) {
+        match self {
+            Self::Condition(condition) => {
+                conditions.push(condition);
+                if let ConditionType::IsSatisfied(expression) = &condition.condition_type {
+                    expression.collect_conditions(conditions);
+                }
+            }
+            Self::And(expressions) | Self::Or(expressions) => expressions
+                .iter()
+                .for_each(|expression| expression.collect_conditions(conditions)),
+            Self::Not(expression) => expression.collect_conditions(conditions),
+        }
+    }
+
+    /// Returns the number of `And`, `Or` and `Not` nodes in the expression,
+    /// including the ones nested in other expressions.
+    pub fn node_count(&self) -> usize {
+        match self {
+            Self::Condition(condition) => match &condition.condition_type {
+                ConditionType::IsSatisfied(expression) => expression.node_count(),
+                _ => 0,
+            },
+            Self::And(expressions) | Self::Or(expressions) => {
+                expressions.iter().fold(1usize, |count, expression| {
+                    count.saturating_add(expression.node_count())
+                })
+            }
+            Self::Not(expression) => expression.node_count().saturating_add(1),
+        }
+    }
+
+    /// Dedup `ClaimType`s in `TrustedFor::Specific` of all conditions in the expression.
+    pub fn dedup(&mut self) {
+        match self {
+            Self::Condition(condition) => condition.dedup(),
+            Self::And(expressions) | Self::Or(expressions) => {
+                expressions.iter_mut().for_each(Self::dedup)
+            }
+            Self::Not(expression) => expression.dedup(),
         }
     }
 }
@@ -152,7 +271,20 @@ impl Condition {
     }
 
     /// Returns worst case complexity of a condition.
+    ///
+    /// The complexity of an expression is the complexity of all its conditions
+    /// plus one for each of its `And`, `Or` and `Not` nodes.
     pub fn complexity(&self, default_issuer_count: usize) -> u32 {
+        if let ConditionType::IsSatisfied(expression) = &self.condition_type {
+            let nodes = expression.node_count().try_into().unwrap_or(u32::MAX);
+            return expression
+                .conditions()
+                .into_iter()
+                .filter(|condition| !condition.is_expression())
+                .fold(nodes, |total, condition| {
+                    total.saturating_add(condition.complexity(default_issuer_count))
+                });
+        }
         let issuers = match self.issuers.len() {
             0 => default_issuer_count,
             count => count,
@@ -186,12 +318,52 @@ impl Condition {
         (claims, issuers, claim_types)
     }
 
-    /// Returns all the claims in the condition.
+    /// Returns `true` if the condition is an expression over other conditions.
+    pub fn is_expression(&self) -> bool {
+        matches!(self.condition_type, ConditionType::IsSatisfied(..))
+    }
+
+    /// Returns the condition followed by all conditions nested in it, if it is an expression.
+    pub fn flatten(&self) -> Vec<&Condition> {
+        match &self.condition_type {
+            ConditionType::IsSatisfied(expression) => {
+                let mut conditions = expression.conditions();
+                conditions.insert(0, self);
+                conditions
+            }
+            _ => sp_std::vec![self],
+        }
+    }
+
+    /// Dedup `ClaimType`s in `TrustedFor::Specific`, including nested conditions.
+    pub fn dedup(&mut self) {
+        self.issuers.iter_mut().for_each(TrustedIssuer::dedup);
+        if let ConditionType::IsSatisfied(expression) = &mut self.condition_type {
+            expression.dedup();
+        }
+    }
+
+    /// Returns all the claims in the condition, including nested conditions.
     pub fn claims(&self) -> impl Iterator<Item = &Claim> {
+        self.flatten()
+            .into_iter()
+            .flat_map(|condition| condition.own_claims())
+    }
+
+    /// Returns the claims of the condition, excluding nested conditions.
+    fn own_claims(&self) -> impl Iterator<Item = &Claim> {
         match &self.condition_type {
             ConditionType::IsPresent(c) | ConditionType::IsAbsent(c) => Either::Left(iter::once(c)),
             ConditionType::IsAnyOf(cs) | ConditionType::IsNoneOf(cs) => Either::Right(cs.iter()),
-            ConditionType::IsIdentity(_) => Either::Right([].iter()),
+            ConditionType::IsIdentity(_)
+            | ConditionType::IsSatisfied(_)
+            | ConditionType::ClaimValueGreaterThan(..)
+            | ConditionType::ClaimValueLessThan(..)
+            | ConditionType::ClaimValueInSet(..)
+            | ConditionType::ClaimValueGreaterOrEqual(..)
+            | ConditionType::ClaimValueLessOrEqual(..)
+            | ConditionType::ClaimValueEqual(..)
+            | ConditionType::ClaimValueNotEqual(..) => Either::Right([].iter()),
         }
     }
 }
@@ -204,8 +376,9 @@ impl Condition {
 pub fn conditions_total_counts<'a>(
     conditions: impl IntoIterator<Item = &'a Condition>,
 ) -> (u32, u32, u32, u32) {
-    // Count the total number of claims, issuers, and claim_types in all conditions.
-    conditions.into_iter().fold(
+    // Count the total number of claims, issuers, and claim_types in all conditions,
+    // including the conditions nested in expressions.
+    conditions.into_iter().flat_map(Condition::flatten).fold(
         (0u32, 0u32, 0u32, 0u32),
         |(count, total_claims, total_issuers, total_claim_types), condition| {
             let (claims, issuers, claim_types) = condition.counts();
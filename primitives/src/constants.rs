@@ -19,5 +19,6 @@
 
 /// Prefixes for scheduled actions
 pub const SETTLEMENT_INSTRUCTION_EXECUTION: [u8; 27] = *b"SETTLEMENT_INSTRUCTION_EXEC";
+pub const SETTLEMENT_HASH_LOCK_EXPIRY: [u8; 27] = *b"SETTLEMENT_HASH_LOCK_EXPIRY";
 pub const PIP_EXECUTION: [u8; 8] = *b"PIP_EXEC";
 pub const PIP_EXPIRY: [u8; 10] = *b"PIP_EXPIRY";
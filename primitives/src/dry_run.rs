@@ -0,0 +1,169 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2024 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode};
+use frame_support::dispatch::{
+    DispatchResult, DispatchResultWithPostInfo, Dispatchable, GetDispatchInfo, PostDispatchInfo,
+};
+use frame_support::storage::{unhashed, with_transaction, TransactionOutcome};
+use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_io::hashing::blake2_256;
+use sp_runtime::DispatchError;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::prelude::Vec;
+
+/// The length of the prefix of a storage key identifying its storage item,
+/// i.e., the hashed names of the pallet and of the item.
+const ITEM_PREFIX_LEN: usize = 32;
+
+/// A storage key changed by a dry-run.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum StorageChange {
+    /// The key was added.
+    Inserted(#[cfg_attr(feature = "std", serde(with = "serde_bytes"))] Vec<u8>),
+    /// The value of the key was changed.
+    Modified(#[cfg_attr(feature = "std", serde(with = "serde_bytes"))] Vec<u8>),
+    /// The key was removed.
+    Removed(#[cfg_attr(feature = "std", serde(with = "serde_bytes"))] Vec<u8>),
+}
+
+/// The outcome of dispatching a call whose changes were rolled back.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct DryRunOutcome<Event> {
+    /// The result of the dispatch.
+    pub result: DispatchResult,
+    /// The events emitted by the dispatch.
+    pub events: Vec<Event>,
+    /// The actual weight of the dispatch.
+    pub actual_weight: Weight,
+    /// The storage keys changed by the dispatch.
+    pub storage_changes: Vec<StorageChange>,
+}
+
+impl<Event> DryRunOutcome<Event> {
+    /// Returns the outcome with every event mapped by `f`.
+    pub fn map_events<E>(self, f: impl FnMut(Event) -> E) -> DryRunOutcome<E> {
+        DryRunOutcome {
+            result: self.result,
+            events: self.events.into_iter().map(f).collect(),
+            actual_weight: self.actual_weight,
+            storage_changes: self.storage_changes,
+        }
+    }
+}
+
+/// Dispatches `call` with `origin` against the current state, inside a storage transaction
+/// that is rolled back, returning the outcome of the dispatch.
+///
+/// The storage items changed by the dispatch are found by comparing a digest of every item
+/// before and inside the transaction, so the whole storage is read twice.
+/// Hence, this is only meant to be called off-chain, e.g. from a runtime API.
+pub fn dry_run<T, C>(
+    call: C,
+    origin: C::RuntimeOrigin,
+) -> Result<DryRunOutcome<T::RuntimeEvent>, DispatchError>
+where
+    T: frame_system::Config,
+    C: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+{
+    let info = call.get_dispatch_info();
+    let original_digests = item_digests();
+    let (mut outcome, changed_items, changed) = with_transaction(|| {
+        let events_before = frame_system::Pallet::<T>::events().len();
+        let res: DispatchResultWithPostInfo = call.dispatch(origin);
+        let actual_weight = match &res {
+            Ok(post_info) => post_info.calc_actual_weight(&info),
+            Err(e) => e.post_info.calc_actual_weight(&info),
+        };
+        let events = frame_system::Pallet::<T>::events()
+            .into_iter()
+            .skip(events_before)
+            .map(|record| record.event)
+            .collect();
+
+        // Only the values of the changed items are kept, to be compared once rolled back.
+        let digests = item_digests();
+        let changed_items: BTreeSet<Vec<u8>> = original_digests
+            .keys()
+            .chain(digests.keys())
+            .filter(|prefix| original_digests.get(*prefix) != digests.get(*prefix))
+            .cloned()
+            .collect();
+        let changed = value_hashes(&changed_items);
+
+        let outcome = DryRunOutcome {
+            result: res.map(drop).map_err(|e| e.error),
+            events,
+            actual_weight,
+            storage_changes: Vec::new(),
+        };
+        TransactionOutcome::Rollback(Ok::<_, DispatchError>((outcome, changed_items, changed)))
+    })?;
+
+    let mut original = value_hashes(&changed_items);
+    for (key, hash) in changed {
+        match original.remove(&key) {
+            None => outcome.storage_changes.push(StorageChange::Inserted(key)),
+            Some(old) if old != hash => outcome.storage_changes.push(StorageChange::Modified(key)),
+            Some(_) => {}
+        }
+    }
+    outcome
+        .storage_changes
+        .extend(original.into_keys().map(StorageChange::Removed));
+    Ok(outcome)
+}
+
+/// Calls `f` with every key of the main storage starting with `prefix`, along with its value.
+fn for_each_key(prefix: &[u8], mut f: impl FnMut(Vec<u8>, Vec<u8>)) {
+    let mut key = prefix.to_vec();
+    if let Some(value) = unhashed::get_raw(&key) {
+        f(key.clone(), value);
+    }
+    while let Some(next) = sp_io::storage::next_key(&key).filter(|k| k.starts_with(prefix)) {
+        if let Some(value) = unhashed::get_raw(&next) {
+            f(next.clone(), value);
+        }
+        key = next;
+    }
+}
+
+/// Returns a digest of the keys and values of every storage item of the main storage,
+/// identified by the first `ITEM_PREFIX_LEN` bytes of its keys.
+fn item_digests() -> BTreeMap<Vec<u8>, [u8; 32]> {
+    let mut digests = BTreeMap::new();
+    for_each_key(&[], |key, value| {
+        let prefix = key[..key.len().min(ITEM_PREFIX_LEN)].to_vec();
+        let digest = digests.entry(prefix).or_insert([0; 32]);
+        *digest = blake2_256(&(&*digest, key, value).encode());
+    });
+    digests
+}
+
+/// Returns the hash of the value of every key of the storage items with `prefixes`.
+fn value_hashes(prefixes: &BTreeSet<Vec<u8>>) -> BTreeMap<Vec<u8>, [u8; 32]> {
+    let mut hashes = BTreeMap::new();
+    for prefix in prefixes {
+        for_each_key(prefix, |key, value| {
+            hashes.insert(key, blake2_256(&value));
+        });
+    }
+    hashes
+}
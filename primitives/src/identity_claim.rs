@@ -17,6 +17,7 @@ use crate::asset::AssetId;
 use crate::{identity_id::IdentityId, impl_checked_inc, CddId, Moment};
 
 use codec::{Decode, Encode};
+use core::cmp::Ordering;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use sp_runtime::{Deserialize, Serialize};
@@ -31,6 +32,67 @@ use super::jurisdiction::CountryCode;
 pub struct CustomClaimTypeId(pub u32);
 impl_checked_inc!(CustomClaimTypeId);
 
+/// The type of the values carried by claims of a custom claim type.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum CustomClaimValueType {
+    /// Values are integers.
+    Integer,
+    /// Values are decimal numbers with 6 decimal places.
+    Decimal,
+    /// Values are dates.
+    Date,
+    /// Values are one of the given strings.
+    Enum(Vec<Vec<u8>>),
+}
+
+impl CustomClaimValueType {
+    /// Returns `true` if `value` is a valid value of this type.
+    pub fn accepts(&self, value: &ClaimValue) -> bool {
+        match (self, value) {
+            (Self::Integer, ClaimValue::Integer(_))
+            | (Self::Decimal, ClaimValue::Decimal(_))
+            | (Self::Date, ClaimValue::Date(_)) => true,
+            (Self::Enum(options), ClaimValue::Enum(option)) => options.contains(option),
+            _ => false,
+        }
+    }
+}
+
+/// The value carried by a custom claim.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ClaimValue {
+    /// An integer.
+    Integer(i128),
+    /// A decimal number with 6 decimal places, e.g. `1_500_000` is `1.5`.
+    Decimal(i128),
+    /// A date.
+    Date(Moment),
+    /// One of the strings of a `CustomClaimValueType::Enum`.
+    Enum(Vec<u8>),
+}
+
+impl ClaimValue {
+    /// Compares two values of the same numeric or date type.
+    ///
+    /// Returns `None` if the values have different types or are enum values, which are not ordered.
+    pub fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) | (Self::Decimal(a), Self::Decimal(b)) => {
+                Some(a.cmp(b))
+            }
+            (Self::Date(a), Self::Date(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if both values have the same type.
+    pub fn is_same_type(&self, other: &Self) -> bool {
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, PartialEq, Eq, Debug, PartialOrd, Ord, Hash)]
@@ -97,6 +159,8 @@ pub enum Claim {
     Blocked(Scope),
     /// Custom claim with an optional scope.
     Custom(CustomClaimTypeId, Option<Scope>),
+    /// Custom claim with an optional scope, carrying a value of the type registered for the custom claim type.
+    CustomValue(CustomClaimTypeId, Option<Scope>, ClaimValue),
 }
 
 impl Claim {
@@ -112,7 +176,7 @@ impl Claim {
             Claim::Jurisdiction(..) => ClaimType::Jurisdiction,
             Claim::Exempted(..) => ClaimType::Exempted,
             Claim::Blocked(..) => ClaimType::Blocked,
-            Claim::Custom(cc_id, _) => ClaimType::Custom(*cc_id),
+            Claim::Custom(cc_id, _) | Claim::CustomValue(cc_id, ..) => ClaimType::Custom(*cc_id),
         }
     }
 
@@ -127,11 +191,31 @@ impl Claim {
             | Claim::Jurisdiction(.., scope)
             | Claim::Exempted(scope)
             | Claim::Blocked(scope) => Some(scope),
-            Claim::Custom(_, scope) => scope.as_ref(),
+            Claim::Custom(_, scope) | Claim::CustomValue(_, scope, _) => scope.as_ref(),
             Claim::CustomerDueDiligence(..) => None,
         }
     }
 
+    /// Returns `true` if this claim satisfies `claim` in a compliance condition.
+    ///
+    /// A `Claim::Custom` is satisfied by the claims of the same custom claim type and scope, with or without a value.
+    pub fn satisfies(&self, claim: &Claim) -> bool {
+        match (self, claim) {
+            (Claim::CustomValue(id, scope, _), Claim::Custom(expected_id, expected_scope)) => {
+                id == expected_id && scope == expected_scope
+            }
+            _ => self == claim,
+        }
+    }
+
+    /// The value of this claim, if any.
+    pub fn value(&self) -> Option<&ClaimValue> {
+        match self {
+            Claim::CustomValue(.., value) => Some(value),
+            _ => None,
+        }
+    }
+
     /// It returns a CDD claim with a default as CddId.
     pub fn default_cdd_id() -> Claim {
         Claim::CustomerDueDiligence(CddId::default())
@@ -192,3 +276,39 @@ impl From<Claim> for IdentityClaim {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn claim_values() {
+        let tiers = CustomClaimValueType::Enum(vec![b"A".to_vec(), b"B".to_vec()]);
+        assert!(tiers.accepts(&ClaimValue::Enum(b"A".to_vec())));
+        assert!(!tiers.accepts(&ClaimValue::Enum(b"C".to_vec())));
+        assert!(!tiers.accepts(&ClaimValue::Integer(1)));
+        assert!(CustomClaimValueType::Decimal.accepts(&ClaimValue::Decimal(1)));
+        assert!(!CustomClaimValueType::Decimal.accepts(&ClaimValue::Integer(1)));
+
+        let compare = |a: ClaimValue, b: ClaimValue| a.compare(&b);
+        assert_eq!(
+            compare(ClaimValue::Integer(-1), ClaimValue::Integer(1)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare(ClaimValue::Date(2), ClaimValue::Date(1)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare(ClaimValue::Integer(1), ClaimValue::Decimal(1)),
+            None
+        );
+        assert_eq!(
+            compare(
+                ClaimValue::Enum(b"A".to_vec()),
+                ClaimValue::Enum(b"A".to_vec())
+            ),
+            None
+        );
+    }
+}
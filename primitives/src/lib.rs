@@ -139,7 +139,9 @@ pub use cdd_id::CddId;
 /// Claim information.
 /// Each claim is associated with this kind of record.
 pub mod identity_claim;
-pub use identity_claim::{Claim, ClaimType, CustomClaimTypeId, IdentityClaim, Scope};
+pub use identity_claim::{
+    Claim, ClaimType, ClaimValue, CustomClaimTypeId, CustomClaimValueType, IdentityClaim, Scope,
+};
 
 // Defining and enumerating jurisdictions.
 pub mod jurisdiction;
@@ -182,7 +184,9 @@ pub use document::{Document, DocumentId, DocumentName, DocumentUri};
 
 /// Rules for claims.
 pub mod condition;
-pub use condition::{Condition, ConditionType, TargetIdentity, TrustedFor, TrustedIssuer};
+pub use condition::{
+    Condition, ConditionExpression, ConditionType, TargetIdentity, TrustedFor, TrustedIssuer,
+};
 
 /// Predicate calculation for Claims.
 pub mod proposition;
@@ -220,7 +224,10 @@ pub use nft::{NFTCollectionId, NFTCollectionKeys, NFTId, NFTMetadataAttribute, N
 
 /// Portfolio type definitions.
 pub mod portfolio;
-pub use portfolio::{Fund, FundDescription, PortfolioUpdateReason};
+pub use portfolio::{
+    Fund, FundDescription, PortfolioLock, PortfolioLockId, PortfolioLockReason,
+    PortfolioUpdateReason,
+};
 
 /// Custom WeightMeter definitions.
 pub mod weight_meter;
@@ -235,6 +242,13 @@ pub mod constants;
 /// Multisig type definitions.
 pub mod multisig;
 
+/// Dry-run of calls.
+pub mod dry_run;
+
+/// Relayer subsidy type definitions.
+pub mod subsidy;
+pub use subsidy::{SubsidyReplenishment, SubsidyScope};
+
 /// Represents custom transaction errors.
 #[repr(u8)]
 pub enum TransactionError {
@@ -248,6 +262,12 @@ pub enum TransactionError {
     InvalidAuthorization = 3,
     /// Subsidy is not available for this pallet.
     PalletNotSubsidised = 4,
+    /// The sponsor envelope is invalid, expired or already used.
+    InvalidSponsorship = 5,
+    /// The transaction fee exceeds the maximum fee accepted by the sponsor.
+    SponsoredFeeTooHigh = 6,
+    /// The call is not covered by the scope of the relayer subsidy.
+    CallNotSubsidised = 7,
 }
 
 /// Represents the target identity and the amount requested by a beneficiary.
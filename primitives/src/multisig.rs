@@ -15,17 +15,59 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::Vec;
+
+use crate::{Balance, ExtrinsicName, PalletName};
 
 /// Count of approvals and rejections of a multisig proposal.
+///
+/// Votes are weighted by the weight of the signer.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Default, Decode, Encode, Eq, PartialEq, TypeInfo)]
 pub struct ProposalVoteCount {
     /// Number of yes votes
     pub approvals: u64,
     /// Number of no votes
     pub rejections: u64,
+    /// Number of yes votes required to execute the proposal.
+    pub sigs_required: u64,
+}
+
+/// A rule overriding the number of signatures required for the proposals matching it.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct CallPolicy {
+    /// The pallet of the call.
+    pub pallet_name: PalletName,
+    /// The extrinsic of the call. `None` matches all extrinsics of the pallet.
+    pub extrinsic_name: Option<ExtrinsicName>,
+    /// The minimum value moved by the call. `None` matches calls regardless of their value.
+    pub min_value: Option<Balance>,
+    /// Number of signatures required by the proposals matching the policy.
+    pub sigs_required: u64,
+}
+
+impl CallPolicy {
+    /// Returns `true` if the call of `function_name` in `pallet_name`, moving `value`, matches the policy.
+    pub fn matches(&self, pallet_name: &str, function_name: &str, value: Option<Balance>) -> bool {
+        if self.pallet_name.0 != pallet_name {
+            return false;
+        }
+        if let Some(extrinsic_name) = &self.extrinsic_name {
+            if extrinsic_name.0 != function_name {
+                return false;
+            }
+        }
+        match self.min_value {
+            Some(min_value) => value.map_or(false, |value| value >= min_value),
+            None => true,
+        }
+    }
 }
 
 /// State of a multisig proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
 pub enum ProposalState<Moment> {
     /// Proposal is active.
@@ -39,6 +81,15 @@ pub enum ProposalState<Moment> {
     ExecutionFailed,
     /// Proposal was rejected
     Rejected,
+    /// Proposal was cancelled by its proposer
+    Cancelled,
+    /// Proposal was approved and can be executed after `execute_after`.
+    Approved {
+        /// The time after which the proposal can be executed.
+        execute_after: Moment,
+        /// Optional time limit of the proposal, kept from its `Active` state.
+        until: Option<Moment>,
+    },
 }
 
 impl<Moment> ProposalState<Moment>
@@ -64,3 +115,27 @@ where
         }
     }
 }
+
+/// Details of a multisig proposal, returned by the multisig runtime API.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct ProposalDetails<AccountId, Moment> {
+    /// The id of the proposal.
+    pub proposal_id: u64,
+    /// The signer that created the proposal.
+    pub proposer: Option<AccountId>,
+    /// The pallet of the proposed call.
+    pub pallet_name: PalletName,
+    /// The extrinsic of the proposed call.
+    pub extrinsic_name: ExtrinsicName,
+    /// The SCALE encoded proposed call.
+    pub call: Vec<u8>,
+    /// The state of the proposal, including its expiry or execution time.
+    pub state: ProposalState<Moment>,
+    /// The weighted count of the votes and the number of signatures required.
+    pub vote_count: ProposalVoteCount,
+    /// The signers that have voted on the proposal.
+    pub voted: Vec<AccountId>,
+    /// The signers that haven't voted on the proposal.
+    pub not_voted: Vec<AccountId>,
+}
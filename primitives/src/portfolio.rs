@@ -18,7 +18,7 @@ use scale_info::TypeInfo;
 
 use crate::asset::{AssetId, FundingRoundName};
 use crate::settlement::InstructionId;
-use crate::{Balance, Memo, NFTs};
+use crate::{Balance, IdentityId, Memo, NFTs};
 
 /// Describes what should be moved between portfolios. It can be either fungible or non-fungible tokens.
 #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
@@ -63,3 +63,57 @@ pub enum PortfolioUpdateReason {
     /// Tokens were transferred via a controller call.
     ControllerTransfer,
 }
+
+/// Identifier of a portfolio lock.
+#[derive(
+    Clone, Copy, Debug, Decode, Default, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo
+)]
+pub struct PortfolioLockId(pub u64);
+
+/// The reason why assets are locked in a portfolio.
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum PortfolioLockReason {
+    /// The assets are pledged as collateral.
+    Collateral,
+    /// The assets are held in escrow.
+    Escrow,
+    /// The assets are vesting.
+    Vesting,
+    /// The assets are subject to a legal hold.
+    LegalHold,
+}
+
+/// An amount of an asset locked in a portfolio, which can't be moved away until the lock is released.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct PortfolioLock<Moment> {
+    /// The [`AssetId`] of the locked asset.
+    pub asset_id: AssetId,
+    /// The locked amount.
+    pub amount: Balance,
+    /// The reason of the lock.
+    pub reason: PortfolioLockReason,
+    /// The identity that created the lock.
+    pub locked_by: IdentityId,
+    /// The time after which the custodian of the portfolio can release the lock.
+    pub release_at: Option<Moment>,
+    /// The identity that can release the lock at any time.
+    pub release_authority: Option<IdentityId>,
+}
+
+impl<Moment: PartialOrd> PortfolioLock<Moment> {
+    /// Returns `true` if `did` can release the lock at `now`.
+    ///
+    /// The release authority can release the lock at any time. The custodian of the portfolio
+    /// can release it once `release_at` has passed, or at any time if the lock has neither a
+    /// release time nor a release authority.
+    pub fn can_be_released_by(&self, did: IdentityId, custodian: IdentityId, now: Moment) -> bool {
+        if self.release_authority == Some(did) {
+            return true;
+        }
+        let expired = match &self.release_at {
+            Some(release_at) => now >= *release_at,
+            None => self.release_authority.is_none(),
+        };
+        expired && did == custodian
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     proposition::{Context, Proposition},
-    Claim, IdentityId,
+    Claim, ClaimValue, IdentityId,
 };
 use codec::{Decode, Encode};
 
@@ -48,7 +48,9 @@ impl<C: Iterator<Item = Claim>> Proposition<C> for ExistentialProposition<'_> {
                 .claims
                 .any(|ctx_claim| matches!(ctx_claim, Claim::CustomerDueDiligence(..))),
             // In regular claim evaluation, the data of the claim has to match too.
-            _ => context.claims.any(|ctx_claim| &ctx_claim == self.claim),
+            _ => context
+                .claims
+                .any(|ctx_claim| ctx_claim.satisfies(self.claim)),
         }
     }
 }
@@ -158,17 +160,42 @@ impl<C: Iterator<Item = Claim>> Proposition<C> for AnyProposition<'_> {
         context.claims.any(|ctx_claim| {
             self.claims
                 .iter()
-                .any(|valid_claim| &ctx_claim == valid_claim)
+                .any(|valid_claim| ctx_claim.satisfies(valid_claim))
         })
     }
 }
 
+// ClaimValueProposition
+// =========================================================
+
+/// Proposition that checks if the value of any claim in context satisfies `predicate`.
+/// Claims without a value are ignored.
+#[derive(Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ClaimValueProposition<F> {
+    /// Predicate over the claim values found in context.
+    pub predicate: F,
+}
+
+impl<C: Iterator<Item = Claim>, F: Fn(&ClaimValue) -> bool> Proposition<C>
+    for ClaimValueProposition<F>
+{
+    /// Evaluate proposition against `context`.
+    fn evaluate(&self, mut context: Context<C>) -> bool {
+        context
+            .claims
+            .any(|ctx_claim| ctx_claim.value().map_or(false, &self.predicate))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         proposition::{self, Context, Proposition},
-        CddId, Claim, Condition, ConditionType, CountryCode, IdentityId, Scope, TargetIdentity,
+        CddId, Claim, ClaimValue, Condition, ConditionExpression, ConditionType, CountryCode,
+        CustomClaimTypeId, IdentityId, Scope, TargetIdentity,
     };
+    use core::convert::Infallible;
     use std::convert::From;
     use std::vec::IntoIter;
 
@@ -308,4 +335,117 @@ mod tests {
             |_| false,
         ));
     }
+
+    #[test]
+    fn run_expression() {
+        use ConditionExpression::{And, Not, Or};
+
+        let scope = Scope::Identity(IdentityId::from(0));
+        let leaf = |claim: Claim| {
+            ConditionExpression::Condition(Box::new(ConditionType::IsPresent(claim).into()))
+        };
+        let us = Claim::Jurisdiction(CountryCode::US, scope.clone());
+
+        // (Accredited AND US) OR (NOT US AND KYC)
+        let expression = Or(vec![
+            And(vec![
+                leaf(Claim::Accredited(scope.clone())),
+                leaf(us.clone()),
+            ]),
+            And(vec![
+                Not(Box::new(leaf(us.clone()))),
+                leaf(Claim::KnowYourCustomer(scope.clone())),
+            ]),
+        ]);
+
+        let check = |expected, claims: Vec<Claim>| {
+            let mut evaluated = 0;
+            let out = proposition::run_expression(&expression, &mut |condition: &Condition| {
+                evaluated += 1;
+                Ok::<_, Infallible>(proposition::run(condition, mk_ctx(claims.clone()), |_| {
+                    false
+                }))
+            });
+            assert_eq!(out, Ok(expected));
+            evaluated
+        };
+
+        // The first branch holds, so the second one is not evaluated.
+        assert_eq!(
+            check(true, vec![Claim::Accredited(scope.clone()), us.clone()]),
+            2
+        );
+        assert_eq!(check(false, vec![Claim::Accredited(scope.clone())]), 4);
+        check(true, vec![Claim::KnowYourCustomer(scope.clone())]);
+        check(false, vec![Claim::KnowYourCustomer(scope.clone()), us]);
+
+        // Empty `And` always holds and empty `Or` never holds.
+        let mut never = |_: &Condition| -> Result<bool, Infallible> { unreachable!() };
+        assert_eq!(
+            proposition::run_expression(&And(vec![]), &mut never),
+            Ok(true)
+        );
+        assert_eq!(
+            proposition::run_expression(&Or(vec![]), &mut never),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn run_claim_value_proposition() {
+        let id = CustomClaimTypeId(1);
+        let net_worth = |value| Claim::CustomValue(id, None, ClaimValue::Decimal(value));
+        let run = |condition_type: ConditionType, claims: Vec<Claim>| {
+            proposition::run(&condition_type.into(), mk_ctx(claims), |_| false)
+        };
+
+        let greater = ConditionType::ClaimValueGreaterThan(id, None, ClaimValue::Decimal(100));
+        assert!(run(greater.clone(), vec![net_worth(101)]));
+        assert!(!run(greater.clone(), vec![net_worth(100)]));
+        assert!(!run(greater.clone(), vec![Claim::Custom(id, None)]));
+        assert!(!run(
+            greater,
+            vec![Claim::CustomValue(id, None, ClaimValue::Integer(101))]
+        ));
+
+        let less = ConditionType::ClaimValueLessThan(id, None, ClaimValue::Decimal(100));
+        assert!(run(less.clone(), vec![net_worth(99)]));
+        assert!(!run(less, vec![net_worth(100)]));
+
+        let tier = |tier: &[u8]| ClaimValue::Enum(tier.to_vec());
+        let in_set = ConditionType::ClaimValueInSet(id, None, vec![tier(b"A"), tier(b"B")]);
+        assert!(run(
+            in_set.clone(),
+            vec![Claim::CustomValue(id, None, tier(b"B"))]
+        ));
+        assert!(!run(in_set, vec![Claim::CustomValue(id, None, tier(b"C"))]));
+
+        let at_least = ConditionType::ClaimValueGreaterOrEqual(id, None, ClaimValue::Decimal(100));
+        assert!(run(at_least.clone(), vec![net_worth(100)]));
+        assert!(!run(at_least, vec![net_worth(99)]));
+
+        let at_most = ConditionType::ClaimValueLessOrEqual(id, None, ClaimValue::Decimal(100));
+        assert!(run(at_most.clone(), vec![net_worth(100)]));
+        assert!(!run(at_most, vec![net_worth(101)]));
+
+        let equal = ConditionType::ClaimValueEqual(id, None, ClaimValue::Decimal(100));
+        assert!(run(equal.clone(), vec![net_worth(100)]));
+        assert!(!run(equal, vec![net_worth(101)]));
+
+        let not_equal = ConditionType::ClaimValueNotEqual(id, None, ClaimValue::Decimal(100));
+        assert!(run(not_equal.clone(), vec![net_worth(101)]));
+        assert!(!run(not_equal.clone(), vec![net_worth(100)]));
+        assert!(!run(
+            not_equal,
+            vec![Claim::CustomValue(id, None, ClaimValue::Integer(101))]
+        ));
+
+        // A claim with a value satisfies a presence condition without one.
+        let present = ConditionType::IsPresent(Claim::Custom(id, None));
+        assert!(run(present.clone(), vec![net_worth(1)]));
+        assert!(!run(
+            present,
+            vec![Claim::Custom(CustomClaimTypeId(2), None)]
+        ));
+    }
 }
@@ -13,8 +13,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Claim, Condition, ConditionType, IdentityId, TargetIdentity};
+use crate::{
+    Claim, ClaimValue, Condition, ConditionExpression, ConditionType, IdentityId, TargetIdentity,
+};
 use codec::{Decode, Encode};
+use core::cmp::Ordering;
 
 use sp_std::prelude::*;
 
@@ -78,8 +81,8 @@ impl<C, F: Fn(Context<C>) -> bool> Proposition<C> for F {
 /// Base and simple propositions
 pub mod base;
 pub use base::{
-    AndProposition, AnyProposition, ExistentialProposition, IsIdentityProposition, NotProposition,
-    OrProposition,
+    AndProposition, AnyProposition, ClaimValueProposition, ExistentialProposition,
+    IsIdentityProposition, NotProposition, OrProposition,
 };
 
 // Helper functions
@@ -97,6 +100,12 @@ pub fn any(claims: &[Claim]) -> AnyProposition<'_> {
     AnyProposition { claims }
 }
 
+/// It creates a proposition to evaluate if the value of any claim in the context satisfies `predicate`.
+#[inline]
+pub fn claim_value<F: Fn(&ClaimValue) -> bool>(predicate: F) -> ClaimValueProposition<F> {
+    ClaimValueProposition { predicate }
+}
+
 /// It create a negate proposition of `proposition`.
 #[inline]
 pub fn not<P: Proposition<C>, C>(proposition: P) -> NotProposition<P> {
@@ -118,5 +127,63 @@ pub fn run<C: Iterator<Item = Claim>, E: Proposition<C>>(
             IsIdentityProposition { identity: *id }.evaluate(context)
         }
         ConditionType::IsIdentity(TargetIdentity::ExternalAgent) => ea_prop.evaluate(context),
+        // Each condition of an expression needs its own context, see `run_expression`.
+        ConditionType::IsSatisfied(..) => false,
+        ConditionType::ClaimValueGreaterThan(_, _, min) => {
+            claim_value(|value| value.compare(min) == Some(Ordering::Greater)).evaluate(context)
+        }
+        ConditionType::ClaimValueLessThan(_, _, max) => {
+            claim_value(|value| value.compare(max) == Some(Ordering::Less)).evaluate(context)
+        }
+        ConditionType::ClaimValueInSet(_, _, values) => {
+            claim_value(|value| values.contains(value)).evaluate(context)
+        }
+        ConditionType::ClaimValueGreaterOrEqual(_, _, min) => claim_value(|value| {
+            matches!(
+                value.compare(min),
+                Some(Ordering::Greater | Ordering::Equal)
+            )
+        })
+        .evaluate(context),
+        ConditionType::ClaimValueLessOrEqual(_, _, max) => claim_value(|value| {
+            matches!(value.compare(max), Some(Ordering::Less | Ordering::Equal))
+        })
+        .evaluate(context),
+        ConditionType::ClaimValueEqual(_, _, expected) => {
+            claim_value(|value| value == expected).evaluate(context)
+        }
+        ConditionType::ClaimValueNotEqual(_, _, expected) => {
+            claim_value(|value| value.is_same_type(expected) && value != expected).evaluate(context)
+        }
+    }
+}
+
+/// Helper function to run an expression, using `run_condition` to evaluate each of its conditions.
+///
+/// Sub-expressions of `And` and `Or` are evaluated from left to right,
+/// stopping as soon as the result is known.
+pub fn run_expression<E>(
+    expression: &ConditionExpression,
+    run_condition: &mut impl FnMut(&Condition) -> Result<bool, E>,
+) -> Result<bool, E> {
+    match expression {
+        ConditionExpression::Condition(condition) => run_condition(condition),
+        ConditionExpression::And(expressions) => {
+            for expression in expressions {
+                if !run_expression(expression, run_condition)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        ConditionExpression::Or(expressions) => {
+            for expression in expressions {
+                if run_expression(expression, run_condition)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        ConditionExpression::Not(expression) => Ok(!run_expression(expression, run_condition)?),
     }
 }
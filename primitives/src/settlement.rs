@@ -23,13 +23,14 @@ use codec::{Decode, Encode};
 use frame_support::weights::Weight;
 use scale_info::prelude::string::String;
 use scale_info::TypeInfo;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
 use polymesh_primitives_derive::{SliceU8StrongTyped, VecU8StrongTyped};
 
 use crate::asset::AssetId;
-use crate::constants::SETTLEMENT_INSTRUCTION_EXECUTION;
+use crate::constants::{SETTLEMENT_HASH_LOCK_EXPIRY, SETTLEMENT_INSTRUCTION_EXECUTION};
 use crate::{impl_checked_inc, Balance, IdentityId, NFTs, PortfolioId, Ticker};
 
 /// A global and unique venue ID.
@@ -137,6 +138,11 @@ impl InstructionId {
     pub fn execution_name(&self) -> Vec<u8> {
         (SETTLEMENT_INSTRUCTION_EXECUTION, self.0).encode()
     }
+
+    /// Converts an instruction id into the scheduler name used for the expiry of its [`HashLock`].
+    pub fn hash_lock_expiry_name(&self) -> Vec<u8> {
+        (SETTLEMENT_HASH_LOCK_EXPIRY, self.0).encode()
+    }
 }
 
 /// Details about an instruction.
@@ -213,6 +219,64 @@ impl Leg {
     }
 }
 
+/// The transfer fee charged by the asset of a [`Leg`] when the leg is executed.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct LegTransferFee {
+    /// The [`LegId`] of the leg being charged.
+    pub leg_id: LegId,
+    /// The [`PortfolioId`] paying the fee (i.e. the sender of the leg).
+    pub payer: PortfolioId,
+    /// The [`PortfolioId`] receiving the fee.
+    pub beneficiary: PortfolioId,
+    /// The [`AssetId`] the fee is paid in.
+    pub asset_id: AssetId,
+    /// The fee amount.
+    pub amount: Balance,
+}
+
+/// The hash function used to verify the preimage of a [`HashLock`].
+#[derive(
+    Copy, Clone, Debug, Decode, Default, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo
+)]
+pub enum HashLockAlgorithm {
+    /// SHA-256, compatible with the hash locks used by most other chains.
+    #[default]
+    Sha256,
+    /// Blake2 with a 256-bit output.
+    Blake2_256,
+}
+
+impl HashLockAlgorithm {
+    /// Returns the hash of `preimage` using `self`.
+    pub fn hash(&self, preimage: &[u8]) -> [u8; 32] {
+        match self {
+            HashLockAlgorithm::Sha256 => sp_io::hashing::sha2_256(preimage),
+            HashLockAlgorithm::Blake2_256 => sp_io::hashing::blake2_256(preimage),
+        }
+    }
+}
+
+/// An instruction level hash lock. An instruction with a hash lock can only be executed by revealing
+/// the preimage of `hash` before the `expiry` block. Once `expiry` is reached all locked assets are released.
+#[derive(Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HashLock<BlockNumber> {
+    /// The [`HashLockAlgorithm`] used for computing `hash`.
+    pub algorithm: HashLockAlgorithm,
+    /// The hash of the secret preimage.
+    pub hash: [u8; 32],
+    /// The block at which the instruction expires if the preimage has not been revealed.
+    pub expiry: BlockNumber,
+}
+
+impl<BlockNumber> HashLock<BlockNumber> {
+    /// Returns `true` if `preimage` unlocks `self`, otherwise returns `false`.
+    pub fn is_preimage(&self, preimage: &[u8]) -> bool {
+        self.algorithm.hash(preimage) == self.hash
+    }
+}
+
 /// Details about a venue.
 #[derive(Encode, Decode, TypeInfo)]
 #[derive(Clone, Default, PartialEq, Eq, Debug, PartialOrd, Ord)]
@@ -631,7 +695,8 @@ impl FilteredLegs {
     }
 }
 
-/// Holds the [`AssetCount`] for both the sender and receiver side and the number of offchain assets.
+/// Holds the [`AssetCount`] for both the sender and receiver side, the number of offchain assets
+/// and the revision of the instruction the count refers to.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
 pub struct AffirmationCount {
@@ -641,6 +706,8 @@ pub struct AffirmationCount {
     receiver_asset_count: AssetCount,
     /// The number of off-chain assets in the instruction.
     offchain_count: u32,
+    /// The number of times the instruction has been amended.
+    revision: u32,
 }
 
 impl AffirmationCount {
@@ -654,9 +721,16 @@ impl AffirmationCount {
             sender_asset_count,
             receiver_asset_count,
             offchain_count,
+            revision: 0,
         }
     }
 
+    /// Returns the [`AffirmationCount`] for the given instruction `revision`.
+    pub fn with_revision(mut self, revision: u32) -> Self {
+        self.revision = revision;
+        self
+    }
+
     /// Returns the [`AssetCount`] for the sender side.
     pub fn sender_asset_count(&self) -> &AssetCount {
         &self.sender_asset_count
@@ -672,6 +746,11 @@ impl AffirmationCount {
         self.offchain_count
     }
 
+    /// The number of times the instruction has been amended.
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
     /// The maximum number of unique portfolios.
     pub fn max_portfolios(&self) -> u32 {
         self.sender_asset_count
@@ -680,6 +759,28 @@ impl AffirmationCount {
     }
 }
 
+/// The [`AffirmationCount`] as encoded before instructions could be amended (`SettlementApi` version 2).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct AffirmationCountV2 {
+    /// The [`AssetCount`] for sender side.
+    pub sender_asset_count: AssetCount,
+    /// The [`AssetCount`] for receiver side.
+    pub receiver_asset_count: AssetCount,
+    /// The number of off-chain assets in the instruction.
+    pub offchain_count: u32,
+}
+
+impl From<AffirmationCountV2> for AffirmationCount {
+    fn from(affirmation_count: AffirmationCountV2) -> Self {
+        AffirmationCount::new(
+            affirmation_count.sender_asset_count,
+            affirmation_count.receiver_asset_count,
+            affirmation_count.offchain_count,
+        )
+    }
+}
+
 /// Stores the number of fungible, non fungible and offchain assets in an instruction, the consumed weight for executing the instruction,
 /// and if executing the instruction would fail, the error thrown.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -730,3 +831,162 @@ pub enum MediatorAffirmationStatus<T> {
         expiry: Option<T>,
     },
 }
+
+/// A fungible transfer that settles the net position of a portfolio.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct NetTransfer {
+    /// The [`PortfolioId`] of the sender.
+    pub sender: PortfolioId,
+    /// The [`PortfolioId`] of the receiver.
+    pub receiver: PortfolioId,
+    /// The [`AssetId`] being transferred.
+    pub asset_id: AssetId,
+    /// The amount being transferred.
+    pub amount: Balance,
+    /// The [`InstructionId`] of each instruction with a leg of the asset sent or received by the sender or the receiver.
+    pub instruction_ids: BTreeSet<InstructionId>,
+}
+
+/// The amount sent and received by a portfolio for an asset and the instructions these amounts come from.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct NetPosition {
+    sent: Balance,
+    received: Balance,
+    instruction_ids: BTreeSet<InstructionId>,
+}
+
+/// Tracks the amount sent and received by each portfolio, for each asset, across the fungible legs of multiple instructions.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NetPositions {
+    /// The [`NetPosition`] of each portfolio for each asset.
+    positions: BTreeMap<AssetId, BTreeMap<PortfolioId, NetPosition>>,
+}
+
+impl NetPositions {
+    /// Adds a gross transfer of `amount` from `sender` to `receiver` in the instruction of the given `instruction_id`.
+    /// Returns an error if the total amount sent or received by a portfolio overflows.
+    pub fn try_add_transfer(
+        &mut self,
+        instruction_id: InstructionId,
+        sender: PortfolioId,
+        receiver: PortfolioId,
+        asset_id: AssetId,
+        amount: Balance,
+    ) -> Result<(), String> {
+        let asset_positions = self.positions.entry(asset_id).or_default();
+        let sender_position = asset_positions.entry(sender).or_default();
+        sender_position.sent = sender_position
+            .sent
+            .checked_add(amount)
+            .ok_or_else(|| String::from("Total amount sent overflows"))?;
+        sender_position.instruction_ids.insert(instruction_id);
+        let receiver_position = asset_positions.entry(receiver).or_default();
+        receiver_position.received = receiver_position
+            .received
+            .checked_add(amount)
+            .ok_or_else(|| String::from("Total amount received overflows"))?;
+        receiver_position.instruction_ids.insert(instruction_id);
+        Ok(())
+    }
+
+    /// Returns the transfers that settle all net positions. For each asset, a portfolio is either only a sender or only
+    /// a receiver, and the final balances are the same as if all gross transfers had been executed.
+    pub fn net_transfers(&self) -> Vec<NetTransfer> {
+        let mut net_transfers = Vec::new();
+        for (asset_id, asset_positions) in &self.positions {
+            let mut senders = Vec::new();
+            let mut receivers = Vec::new();
+            for (portfolio_id, position) in asset_positions {
+                if position.sent > position.received {
+                    senders.push((*portfolio_id, position.sent - position.received));
+                } else if position.received > position.sent {
+                    receivers.push((*portfolio_id, position.received - position.sent));
+                }
+            }
+            // The total net amount sent is always equal to the total net amount received
+            let mut receivers = receivers.into_iter();
+            let mut receiver = receivers.next();
+            for (sender, mut net_sent) in senders {
+                while net_sent > 0 {
+                    let Some((receiver_id, net_received)) = receiver.as_mut() else {
+                        break;
+                    };
+                    let amount = net_sent.min(*net_received);
+                    let instruction_ids = asset_positions[&sender]
+                        .instruction_ids
+                        .union(&asset_positions[receiver_id].instruction_ids)
+                        .copied()
+                        .collect();
+                    net_transfers.push(NetTransfer {
+                        sender,
+                        receiver: *receiver_id,
+                        asset_id: *asset_id,
+                        amount,
+                        instruction_ids,
+                    });
+                    net_sent -= amount;
+                    *net_received -= amount;
+                    if *net_received == 0 {
+                        receiver = receivers.next();
+                    }
+                }
+            }
+        }
+        net_transfers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_transfers() {
+        let asset_id = AssetId::new([0; 16]);
+        let alice = PortfolioId::default_portfolio(IdentityId::from(1));
+        let bob = PortfolioId::default_portfolio(IdentityId::from(2));
+        let charlie = PortfolioId::default_portfolio(IdentityId::from(3));
+
+        let mut net_positions = NetPositions::default();
+        net_positions
+            .try_add_transfer(InstructionId(1), alice, bob, asset_id, 100)
+            .unwrap();
+        net_positions
+            .try_add_transfer(InstructionId(2), bob, alice, asset_id, 60)
+            .unwrap();
+        net_positions
+            .try_add_transfer(InstructionId(3), bob, charlie, asset_id, 40)
+            .unwrap();
+        net_positions
+            .try_add_transfer(InstructionId(4), charlie, alice, asset_id, 10)
+            .unwrap();
+
+        assert_eq!(
+            net_positions.net_transfers(),
+            vec![NetTransfer {
+                sender: alice,
+                receiver: charlie,
+                asset_id,
+                amount: 30,
+                instruction_ids: [1, 2, 3, 4].into_iter().map(InstructionId).collect(),
+            }]
+        );
+    }
+
+    #[test]
+    fn balanced_positions_have_no_net_transfers() {
+        let asset_id = AssetId::new([0; 16]);
+        let alice = PortfolioId::default_portfolio(IdentityId::from(1));
+        let bob = PortfolioId::default_portfolio(IdentityId::from(2));
+
+        let mut net_positions = NetPositions::default();
+        net_positions
+            .try_add_transfer(InstructionId(1), alice, bob, asset_id, 100)
+            .unwrap();
+        net_positions
+            .try_add_transfer(InstructionId(2), bob, alice, asset_id, 100)
+            .unwrap();
+        assert!(net_positions.net_transfers().is_empty());
+    }
+}
@@ -14,7 +14,7 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::asset::AssetId;
-use crate::{Claim, ClaimType, CountryCode, IdentityId, Scope};
+use crate::{Balance, Claim, ClaimType, CountryCode, IdentityId, Scope};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -33,6 +33,24 @@ pub enum StatOpType {
     Count,
     /// Balance - Balance stat can be used for Percentage rules, since the `total_supply` of an asset can change (burn/mint)
     Balance,
+    /// InvestorVolume - Amount sent by each investor in each period, used for rolling-window volume rules.
+    InvestorVolume,
+    /// AssetVolume - Amount of the asset transferred in each period, used for rolling-window volume rules.
+    AssetVolume,
+    /// AcquisitionLots - Acquisition time of the units held by each investor, used for holding period rules.
+    AcquisitionLots,
+}
+
+impl StatOpType {
+    /// Returns `true` if the operation type tracks the volume transferred in each period.
+    pub fn is_volume(&self) -> bool {
+        matches!(self, Self::InvestorVolume | Self::AssetVolume)
+    }
+
+    /// Returns `true` if the stats of the operation type can be scoped to a claim.
+    pub fn supports_claims(&self) -> bool {
+        matches!(self, Self::Count | Self::Balance)
+    }
 }
 
 /// The statistic type.
@@ -202,6 +220,142 @@ pub struct StatUpdate {
     pub value: Option<u128>,
 }
 
+/// The volume transferred in each period, sorted by period.
+/// Only the periods that can still be part of a rolling window are kept.
+#[derive(Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PeriodVolumes(Vec<(u64, Balance)>);
+
+impl PeriodVolumes {
+    /// Adds `amount` to the volume of `period` and removes all periods before `oldest_period`.
+    pub fn add(&mut self, period: u64, amount: Balance, oldest_period: u64) {
+        self.0.retain(|(p, _)| *p >= oldest_period);
+        match self.0.last_mut() {
+            Some((last_period, volume)) if *last_period == period => {
+                *volume = volume.saturating_add(amount);
+            }
+            _ => self.0.push((period, amount)),
+        }
+    }
+
+    /// Returns the total volume transferred from `first_period` onwards.
+    pub fn volume_since(&self, first_period: u64) -> Balance {
+        self.0
+            .iter()
+            .filter(|(p, _)| *p >= first_period)
+            .fold(0, |total, (_, volume)| total.saturating_add(*volume))
+    }
+
+    /// Returns the volume of each period.
+    pub fn periods(&self) -> &[(u64, Balance)] {
+        &self.0
+    }
+}
+
+/// The units acquired by a portfolio at each timestamp, sorted by acquisition time.
+/// Units are always sold first in, first out.
+///
+/// Balances held before the lots were tracked are not part of any lot and are considered the oldest units.
+#[derive(Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AcquisitionLots(Vec<(u64, Balance)>);
+
+impl AcquisitionLots {
+    /// Adds a lot of `amount` units acquired at `acquired_at`. If there are more than `max_lots` lots,
+    /// the two oldest lots are merged using the acquisition time of the most recent one.
+    pub fn add(&mut self, acquired_at: u64, amount: Balance, max_lots: usize) {
+        match self.0.last_mut() {
+            Some((last_acquired_at, last_amount)) if *last_acquired_at >= acquired_at => {
+                // Lots are never backdated
+                *last_amount = last_amount.saturating_add(amount);
+            }
+            _ => self.0.push((acquired_at, amount)),
+        }
+        self.truncate(max_lots);
+    }
+
+    /// Adds the lots in `lots` keeping their acquisition time.
+    /// Used when units are moved between portfolios of the same identity.
+    pub fn merge(&mut self, lots: AcquisitionLots, max_lots: usize) {
+        for (acquired_at, amount) in lots.0 {
+            match self.0.binary_search_by_key(&acquired_at, |(at, _)| *at) {
+                Ok(index) => self.0[index].1 = self.0[index].1.saturating_add(amount),
+                Err(index) => self.0.insert(index, (acquired_at, amount)),
+            }
+        }
+        self.truncate(max_lots);
+    }
+
+    /// Removes `amount` units, oldest first. `balance` is the holder's balance before the removal.
+    pub fn remove(&mut self, amount: Balance, balance: Balance) {
+        self.take(amount, balance);
+    }
+
+    /// Removes `amount` units, oldest first, and returns the removed lots. `balance` is the holder's balance before the removal.
+    /// Untracked units are not part of the returned lots.
+    pub fn take(&mut self, amount: Balance, balance: Balance) -> AcquisitionLots {
+        let mut taken = Vec::new();
+        // Untracked units are the oldest ones
+        let mut remaining = amount.saturating_sub(self.untracked(balance));
+        while remaining > 0 {
+            let Some((acquired_at, lot_amount)) = self.0.first_mut() else {
+                break;
+            };
+            if *lot_amount > remaining {
+                *lot_amount -= remaining;
+                taken.push((*acquired_at, remaining));
+                break;
+            }
+            remaining -= *lot_amount;
+            taken.push(self.0.remove(0));
+        }
+        Self(taken)
+    }
+
+    /// Returns the number of units out of `balance` that were acquired at or before `acquired_before`.
+    pub fn matured_balance(&self, balance: Balance, acquired_before: u64) -> Balance {
+        self.0
+            .iter()
+            .take_while(|(acquired_at, _)| *acquired_at <= acquired_before)
+            .fold(self.untracked(balance), |total, (_, amount)| {
+                total.saturating_add(*amount)
+            })
+            .min(balance)
+    }
+
+    /// Rescales the amount of every lot, keeping its acquisition time. Lots rescaled to zero are removed.
+    /// Used when the units are split, consolidated or converted by a reorganization.
+    pub fn rescale(&mut self, rescale: impl Fn(Balance) -> Balance) {
+        self.0
+            .iter_mut()
+            .for_each(|(_, amount)| *amount = rescale(*amount));
+        self.0.retain(|(_, amount)| *amount > 0);
+    }
+
+    /// Returns the units acquired at each timestamp.
+    pub fn lots(&self) -> &[(u64, Balance)] {
+        &self.0
+    }
+
+    /// Merges the oldest lots, using the acquisition time of the most recent one, until there are at most `max_lots` lots.
+    fn truncate(&mut self, max_lots: usize) {
+        while self.0.len() > max_lots.max(1) {
+            let (_, oldest_amount) = self.0.remove(0);
+            if let Some((_, amount)) = self.0.first_mut() {
+                *amount = amount.saturating_add(oldest_amount);
+            }
+        }
+    }
+
+    /// Returns the number of units out of `balance` that are not part of any lot.
+    fn untracked(&self, balance: Balance) -> Balance {
+        let tracked = self.0.iter().fold(0, |total: Balance, (_, amount)| {
+            total.saturating_add(*amount)
+        });
+        balance.saturating_sub(tracked)
+    }
+}
+
 /// Older v1 Transfer Managers.
 pub mod v1 {
     use super::*;
@@ -266,3 +420,88 @@ pub mod v1 {
         pub result: bool,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_volumes() {
+        let mut volumes = PeriodVolumes::default();
+        volumes.add(1, 100, 0);
+        volumes.add(1, 50, 0);
+        volumes.add(3, 10, 0);
+        assert_eq!(volumes.periods(), &[(1, 150), (3, 10)]);
+        assert_eq!(volumes.volume_since(0), 160);
+        assert_eq!(volumes.volume_since(2), 10);
+
+        // Periods before the oldest period are pruned
+        volumes.add(5, 1, 3);
+        assert_eq!(volumes.periods(), &[(3, 10), (5, 1)]);
+        assert_eq!(volumes.volume_since(4), 1);
+    }
+
+    #[test]
+    fn acquisition_lots_fifo() {
+        let mut lots = AcquisitionLots::default();
+        lots.add(10, 100, 10);
+        lots.add(20, 50, 10);
+        // 30 units were held before the lots were tracked
+        let balance = 180;
+        assert_eq!(lots.matured_balance(balance, 5), 30);
+        assert_eq!(lots.matured_balance(balance, 10), 130);
+        assert_eq!(lots.matured_balance(balance, 20), 180);
+
+        // Untracked units and then the oldest lot are sold first
+        lots.remove(80, balance);
+        assert_eq!(lots.lots(), &[(10, 50), (20, 50)]);
+        lots.remove(60, balance - 80);
+        assert_eq!(lots.lots(), &[(20, 40)]);
+        assert_eq!(lots.matured_balance(40, 10), 0);
+    }
+
+    #[test]
+    fn acquisition_lots_are_bounded() {
+        let mut lots = AcquisitionLots::default();
+        lots.add(10, 1, 2);
+        lots.add(20, 2, 2);
+        lots.add(30, 3, 2);
+        assert_eq!(lots.lots(), &[(20, 3), (30, 3)]);
+        // Lots are never backdated
+        lots.add(25, 4, 2);
+        assert_eq!(lots.lots(), &[(20, 3), (30, 7)]);
+    }
+
+    #[test]
+    fn acquisition_lots_take_and_merge() {
+        let mut lots = AcquisitionLots::default();
+        lots.add(10, 100, 10);
+        lots.add(20, 50, 10);
+        // 30 untracked units are moved first and aren't part of the taken lots
+        let taken = lots.take(140, 180);
+        assert_eq!(taken.lots(), &[(10, 100), (20, 10)]);
+        assert_eq!(lots.lots(), &[(20, 40)]);
+
+        let mut other = AcquisitionLots::default();
+        other.add(5, 1, 10);
+        other.add(20, 2, 10);
+        other.add(30, 3, 10);
+        other.merge(taken, 10);
+        assert_eq!(other.lots(), &[(5, 1), (10, 100), (20, 12), (30, 3)]);
+        // Oldest lots are merged when over the limit
+        other.merge(AcquisitionLots::default(), 2);
+        assert_eq!(other.lots(), &[(20, 113), (30, 3)]);
+    }
+
+    #[test]
+    fn acquisition_lots_rescale() {
+        let mut lots = AcquisitionLots::default();
+        lots.add(10, 3, 10);
+        lots.add(20, 10, 10);
+        // A 1 for 4 reverse split keeps the acquisition time of every lot
+        lots.rescale(|amount| amount / 4);
+        assert_eq!(lots.lots(), &[(20, 2)]);
+        lots.rescale(|amount| amount * 2);
+        assert_eq!(lots.lots(), &[(20, 4)]);
+    }
+}
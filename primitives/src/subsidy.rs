@@ -0,0 +1,64 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2024 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::{AssetPermissions, Balance, BlockNumber, ExtrinsicPermissions, Permissions};
+
+/// Limits the calls a relayer subsidy pays the transaction fees of.
+#[derive(Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SubsidyScope {
+    /// The pallets and extrinsics covered by the subsidy.
+    pub extrinsics: ExtrinsicPermissions,
+    /// The assets covered by the subsidy.
+    /// Unless all assets are covered, only calls operating on the covered assets are subsidised.
+    pub assets: AssetPermissions,
+    /// Restores the POLYX limit of the subsidy every period, if set.
+    pub replenishment: Option<SubsidyReplenishment>,
+}
+
+/// Periodic replenishment of a relayer subsidy.
+#[derive(Decode, Encode, TypeInfo)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SubsidyReplenishment {
+    /// The remaining POLYX of the subsidy at the start of each period.
+    pub polyx_limit: Balance,
+    /// The length of a period, in blocks.
+    pub period: BlockNumber,
+}
+
+impl SubsidyScope {
+    /// Returns the scope as the permissions of a secondary key, to check its complexity.
+    pub fn permissions(&self) -> Permissions {
+        Permissions {
+            asset: self.assets.clone(),
+            extrinsic: self.extrinsics.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl SubsidyReplenishment {
+    /// Returns the index of the period `block` is in.
+    pub fn period_of(&self, block: BlockNumber) -> BlockNumber {
+        block.checked_div(self.period).unwrap_or_default()
+    }
+}
@@ -15,7 +15,7 @@
 
 use crate::asset::AssetId;
 use crate::statistics::{v1, Percentage, StatClaim, StatOpType, StatType};
-use crate::{ClaimType, IdentityId};
+use crate::{Balance, ClaimType, IdentityId};
 use codec::{Decode, Encode};
 use frame_support::{pallet_prelude::Get, BoundedBTreeSet};
 use scale_info::TypeInfo;
@@ -45,6 +45,22 @@ pub enum TransferCondition {
     /// * min/max % ownership for Accredited/non-accredited.
     /// (StatClaim, Issuer, Min, Max)
     ClaimOwnership(StatClaim, IdentityId, Percentage, Percentage),
+
+    /// Maximum amount a single investor can send within a rolling window.
+    /// The window is the current period and the previous `periods - 1` periods.
+    /// Can be used for volume limits such as Rule 144.
+    /// (Periods, Max)
+    MaxInvestorVolume(u32, Balance),
+
+    /// Maximum amount of the asset that can be transferred within a rolling window.
+    /// The window is the current period and the previous `periods - 1` periods.
+    /// (Periods, Max)
+    MaxAssetVolume(u32, Balance),
+
+    /// Minimum time, in milliseconds, units must be held before being sold.
+    /// Units are sold first in, first out.
+    /// Can be used for Reg D / Reg S holding periods.
+    MinHoldingPeriod(u64),
 }
 
 impl TransferCondition {
@@ -59,6 +75,9 @@ impl TransferCondition {
             Self::ClaimOwnership(claim, issuer, _, _) => {
                 (StatOpType::Balance, Some((claim.claim_type(), *issuer)))
             }
+            Self::MaxInvestorVolume(..) => (StatOpType::InvestorVolume, None),
+            Self::MaxAssetVolume(..) => (StatOpType::AssetVolume, None),
+            Self::MinHoldingPeriod(..) => (StatOpType::AcquisitionLots, None),
         };
         StatType {
             operation_type,
@@ -73,6 +92,9 @@ impl TransferCondition {
             Self::MaxInvestorOwnership(_) => (StatOpType::Balance, None),
             Self::ClaimCount(claim, _, _, _) => (StatOpType::Count, Some(claim.claim_type())),
             Self::ClaimOwnership(claim, _, _, _) => (StatOpType::Balance, Some(claim.claim_type())),
+            Self::MaxInvestorVolume(..) => (StatOpType::InvestorVolume, None),
+            Self::MaxAssetVolume(..) => (StatOpType::AssetVolume, None),
+            Self::MinHoldingPeriod(..) => (StatOpType::AcquisitionLots, None),
         };
         TransferConditionExemptKey {
             asset_id,
@@ -80,6 +102,16 @@ impl TransferCondition {
             claim_type,
         }
     }
+
+    /// Returns the number of periods in the rolling window of volume conditions.
+    pub fn volume_periods(&self) -> Option<u32> {
+        match self {
+            Self::MaxInvestorVolume(periods, _) | Self::MaxAssetVolume(periods, _) => {
+                Some(*periods)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<v1::TransferManager> for TransferCondition {
@@ -17,7 +17,9 @@
 use frame_support::pallet_prelude::DispatchError;
 use sp_std::vec::Vec;
 
-use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::asset::{AssetId, AssetOperation};
+use polymesh_primitives::asset_metadata::AssetMetadataKey;
+use polymesh_primitives::settlement::VenueId;
 use polymesh_primitives::{Balance, PortfolioId};
 
 /// The maximum number of DIDs allowed in a `balance_at` RPC query.
@@ -26,7 +28,7 @@ pub const MAX_BALANCE_AT_QUERY_SIZE: usize = 100;
 pub type Error = Vec<u8>;
 
 sp_api::decl_runtime_apis! {
-    #[api_version(4)]
+    #[api_version(7)]
     pub trait AssetApi {
 
         /// Returns a vector containing all errors for the transfer. An empty vec means there's no error.
@@ -52,5 +54,51 @@ sp_api::decl_runtime_apis! {
             transfer_value: Balance,
             skip_locked_check: bool,
         ) -> Vec<DispatchError>;
+
+        /// Returns all [`AssetOperation`] that are paused for the given `asset_id`.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "asset_pausedOperations",
+        ///     "params": [
+        ///        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        ///     ]
+        /// }'
+        /// ```
+        fn paused_operations(asset_id: AssetId) -> Vec<AssetOperation>;
+
+        /// Returns the metadata value of `key` for the given `asset_id` as a UTF-8 encoded JSON document.
+        ///
+        /// The value is decoded using the type definition of `key`. Values without a type definition
+        /// are returned as a hex string.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "asset_metadataJson",
+        ///     "params": [
+        ///        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ///        { "Global": 1 }
+        ///     ]
+        /// }'
+        /// ```
+        fn asset_metadata_json(asset_id: AssetId, key: AssetMetadataKey) -> Option<Vec<u8>>;
+
+        /// Returns all venues through which the settlement of the given `asset_id` is paused.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "asset_pausedSettlementVenues",
+        ///     "params": [
+        ///        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        ///     ]
+        /// }'
+        /// ```
+        fn paused_settlement_venues(asset_id: AssetId) -> Vec<VenueId>;
     }
 }
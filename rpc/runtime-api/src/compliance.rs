@@ -18,13 +18,20 @@
 use frame_support::dispatch::DispatchError;
 
 use polymesh_primitives::asset::AssetId;
-use polymesh_primitives::compliance_manager::ComplianceReport;
+use polymesh_primitives::compliance_manager::{ComplianceReport, ComplianceReportV2};
 use polymesh_primitives::IdentityId;
 
 sp_api::decl_runtime_apis! {
 
-    #[api_version(2)]
+    #[api_version(3)]
     pub trait ComplianceApi {
+        #[changed_in(3)]
+        fn compliance_report(
+            asset_id: &AssetId,
+            sender_identity: &IdentityId,
+            receiver_identity: &IdentityId
+        ) -> Result<ComplianceReportV2, DispatchError>;
+
         /// Checks all compliance requirements for the given ticker
         ///
         /// ```ignore
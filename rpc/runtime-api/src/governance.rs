@@ -0,0 +1,45 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2024 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for dry-running governance proposals.
+
+use codec::Codec;
+use frame_support::dispatch::DispatchError;
+
+use pallet_pips::PipId;
+use polymesh_primitives::committee::CommitteeKind;
+use polymesh_primitives::dry_run::DryRunOutcome;
+
+sp_api::decl_runtime_apis! {
+    /// Governance runtime API.
+    ///
+    /// The changes of the simulations are rolled back.
+    #[api_version(2)]
+    pub trait GovernanceApi<Event, Hash> where
+        Event: Codec,
+        Hash: Codec
+    {
+        /// Simulates the execution of the PIP with `id` against the current state, returning the
+        /// dispatch result, the emitted events, the actual weight and the changed storage keys.
+        fn dry_run_pip(id: PipId) -> Result<DryRunOutcome<Event>, DispatchError>;
+
+        /// Simulates the execution of the pending `proposal` of `committee` against the current state,
+        /// as if it was approved by the committee.
+        fn dry_run_committee_proposal(
+            committee: CommitteeKind,
+            proposal: Hash
+        ) -> Result<DryRunOutcome<Event>, DispatchError>;
+    }
+}
@@ -18,9 +18,12 @@
 
 pub mod asset;
 pub mod compliance;
+pub mod governance;
 pub mod identity;
+pub mod multisig;
 pub mod nft;
 pub mod pips;
+pub mod portfolio;
 pub mod settlement;
 pub mod statistics;
 pub mod transaction_payment;
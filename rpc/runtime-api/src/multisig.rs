@@ -0,0 +1,68 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for Multisig module.
+
+use codec::Codec;
+use frame_support::dispatch::DispatchError;
+use sp_std::vec::Vec;
+
+use polymesh_primitives::multisig::ProposalDetails;
+
+sp_api::decl_runtime_apis! {
+    /// Multisig runtime API.
+    #[api_version(1)]
+    pub trait MultisigApi<AccountId, Moment> where
+        AccountId: Codec,
+        Moment: Codec
+    {
+        /// Returns the proposals of `multisig` that can still be approved or executed, with the pallet and
+        /// extrinsic names of their SCALE encoded call, vote tallies, expiry and the signers that have and haven't voted.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "multisig_pendingProposals",
+        ///     "params": ["5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z"]
+        ///   }'
+        /// ```
+        fn pending_proposals(multisig: AccountId) -> Vec<ProposalDetails<AccountId, Moment>>;
+
+        /// Returns the details of a proposal, or `None` if the proposal is no longer stored.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "multisig_proposalDetails",
+        ///     "params": ["5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z", 0]
+        ///   }'
+        /// ```
+        fn proposal_details(multisig: AccountId, proposal_id: u64) -> Option<ProposalDetails<AccountId, Moment>>;
+
+        /// Returns a vector containing all errors for executing the proposal now. An empty vec means there's no error.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "multisig_executionReport",
+        ///     "params": ["5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z", 0]
+        ///   }'
+        /// ```
+        fn execution_report(multisig: AccountId, proposal_id: u64) -> Vec<DispatchError>;
+    }
+}
@@ -15,7 +15,7 @@
 
 //! Runtime API definition for pips module.
 use codec::Codec;
-use pallet_pips::{PipId, VoteCount};
+use pallet_pips::{PipId, VoteCount, VoteCountV1};
 use sp_std::vec::Vec;
 
 /// This module contains some types which require transformations to avoid serde issues with
@@ -40,6 +40,14 @@ pub mod capped {
             ayes: u64,
             /// Stake against
             nays: u64,
+            /// Weighted support for
+            ayes_weight: u64,
+            /// Weighted support against
+            nays_weight: u64,
+            /// Stake for, cast with delegated power
+            delegated_ayes: u64,
+            /// Stake against, cast with delegated power
+            delegated_nays: u64,
         },
         /// Proposal was not for given index.
         ProposalNotFound,
@@ -48,9 +56,20 @@ pub mod capped {
     impl From<CoreVoteCount> for VoteCount {
         fn from(vote_count: CoreVoteCount) -> Self {
             match vote_count {
-                CoreVoteCount::ProposalFound { ayes, nays } => VoteCount::ProposalFound {
+                CoreVoteCount::ProposalFound {
+                    ayes,
+                    nays,
+                    ayes_weight,
+                    nays_weight,
+                    delegated_ayes,
+                    delegated_nays,
+                } => VoteCount::ProposalFound {
                     ayes: ayes.saturated_into(),
                     nays: nays.saturated_into(),
+                    ayes_weight: ayes_weight.saturated_into(),
+                    nays_weight: nays_weight.saturated_into(),
+                    delegated_ayes: delegated_ayes.saturated_into(),
+                    delegated_nays: delegated_nays.saturated_into(),
                 },
                 CoreVoteCount::ProposalNotFound => VoteCount::ProposalNotFound,
             }
@@ -77,18 +96,25 @@ pub mod capped {
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with Pips governance.
+    #[api_version(3)]
     pub trait PipsApi<AccountId>
     where
         AccountId: Codec,
     {
-        /// Retrieve votes for a proposal for a given `id`.
+        #[changed_in(3)]
+        fn get_votes(id: PipId) -> VoteCountV1;
+
+        /// Retrieve the raw and weighted votes for a proposal for a given `id`.
         fn get_votes(id: PipId) -> VoteCount;
 
         /// Retrieve proposals started by `address`.
         fn proposed_by(address: AccountId) -> Vec<PipId>;
 
-        /// Retrieve proposals `address` voted on.
+        /// Retrieve proposals `address` voted on directly.
         fn voted_on(address: AccountId) -> Vec<PipId>;
+
+        /// Retrieve proposals on which a vote was cast with the delegated power of `address`.
+        fn delegated_voted_on(address: AccountId) -> Vec<PipId>;
     }
 }
 
@@ -101,11 +127,15 @@ mod tests {
         let votes = VoteCount::ProposalFound {
             ayes: 3141,
             nays: 5926,
+            ayes_weight: 56,
+            nays_weight: 76,
+            delegated_ayes: 1000,
+            delegated_nays: 0,
         };
 
         assert_eq!(
             serde_json::to_string(&votes).unwrap(),
-            r#"{"ProposalFound":{"ayes":3141,"nays":5926}}"#,
+            r#"{"ProposalFound":{"ayes":3141,"nays":5926,"ayes_weight":56,"nays_weight":76,"delegated_ayes":1000,"delegated_nays":0}}"#,
         );
 
         // should not panic
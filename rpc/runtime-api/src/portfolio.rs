@@ -0,0 +1,44 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2024 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for Portfolio module.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use polymesh_primitives::{PortfolioId, PortfolioLock, PortfolioLockId};
+
+sp_api::decl_runtime_apis! {
+    /// Portfolio runtime API.
+    #[api_version(1)]
+    pub trait PortfolioApi<Moment> where
+        Moment: Codec
+    {
+        /// Returns all locks of `portfolio_id`, with their locked asset and amount, reason,
+        /// release time and release authority.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "portfolio_portfolioLocks",
+        ///     "params": [
+        ///        { "did": "0x0100000000000000000000000000000000000000000000000000000000000000", "kind": "Default"}
+        ///     ]
+        ///   }'
+        /// ```
+        fn portfolio_locks(portfolio_id: PortfolioId) -> Vec<(PortfolioLockId, PortfolioLock<Moment>)>;
+    }
+}
@@ -19,12 +19,13 @@ use frame_support::dispatch::DispatchError;
 use sp_std::vec::Vec;
 
 use polymesh_primitives::settlement::{
-    AffirmationCount, ExecuteInstructionInfo, InstructionId, Leg,
+    AffirmationCount, AffirmationCountV2, ExecuteInstructionInfo, InstructionId, Leg,
+    LegTransferFee,
 };
 use polymesh_primitives::PortfolioId;
 
 sp_api::decl_runtime_apis! {
-    #[api_version(2)]
+    #[api_version(4)]
     pub trait SettlementApi {
         /// Returns an [`ExecuteInstructionInfo`] instance containing the consumed weight and the number of fungible and non fungible
         /// tokens in the instruction. Executing an instruction includes verifying the compliance and transfer restrictions of all assets
@@ -40,8 +41,11 @@ sp_api::decl_runtime_apis! {
         /// ```
         fn get_execute_instruction_info(instruction_id: &InstructionId) -> Option<ExecuteInstructionInfo>;
 
+        #[changed_in(3)]
+        fn get_affirmation_count(instruction_id: InstructionId, portfolios: Vec<PortfolioId>) -> AffirmationCountV2;
+
         /// Returns an [`AffirmationCount`] instance containing the number of assets being sent/received from `portfolios`,
-        /// and the number of off-chain assets in the instruction.
+        /// the number of off-chain assets and the current revision of the instruction.
         ///
         /// ```ignore
         /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
@@ -86,5 +90,17 @@ sp_api::decl_runtime_apis! {
         ///   }'
         /// ```
         fn get_execute_instruction_report(instruction_id: InstructionId) -> Vec<DispatchError>;
+
+        /// Returns the [`LegTransferFee`] that will be charged for each leg pending execution in the instruction.
+        ///
+        /// ```ignore
+        /// curl http://localhost:9933 -H "Content-Type: application/json" -d '{
+        ///     "id":1,
+        ///     "jsonrpc":"2.0",
+        ///     "method": "settlement_getInstructionTransferFees",
+        ///     "params": [1]
+        ///   }'
+        /// ```
+        fn get_instruction_transfer_fees(instruction_id: InstructionId) -> Vec<LegTransferFee>;
     }
 }
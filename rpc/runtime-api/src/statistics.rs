@@ -20,17 +20,26 @@ use sp_std::vec::Vec;
 
 use polymesh_primitives::asset::AssetId;
 use polymesh_primitives::transfer_compliance::TransferCondition;
-use polymesh_primitives::{Balance, IdentityId};
+use polymesh_primitives::{Balance, IdentityId, PortfolioId};
 
 sp_api::decl_runtime_apis! {
-    #[api_version(0)]
+    #[api_version(1)]
     pub trait StatisticsApi {
-        /// Returns a vector containing all [`TransferCondition`] that are not being respected for the transfer. An empty vec means there's no error.
+        #[changed_in(1)]
         fn transfer_restrictions_report(
             asset_id: AssetId,
             sender_did: &IdentityId,
             receiver_did: &IdentityId,
             transfer_amount: Balance,
         ) -> Result<Vec<TransferCondition>, DispatchError>;
+
+        /// Returns a vector containing all [`TransferCondition`] that are not being respected for the transfer. An empty vec means there's no error.
+        /// Holding periods are checked against the acquisition lots of `sender_portfolio`.
+        fn transfer_restrictions_report(
+            asset_id: AssetId,
+            sender_portfolio: &PortfolioId,
+            receiver_did: &IdentityId,
+            transfer_amount: Balance,
+        ) -> Result<Vec<TransferCondition>, DispatchError>;
     }
 }
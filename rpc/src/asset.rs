@@ -24,7 +24,9 @@ use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 
 pub use node_rpc_runtime_api::asset::AssetApi as AssetRuntimeApi;
-use polymesh_primitives::asset::AssetId;
+use polymesh_primitives::asset::{AssetId, AssetOperation};
+use polymesh_primitives::asset_metadata::AssetMetadataKey;
+use polymesh_primitives::settlement::VenueId;
 use polymesh_primitives::{Balance, PortfolioId};
 
 use crate::Error;
@@ -41,6 +43,28 @@ pub trait AssetApi<BlockHash> {
         skip_locked_check: bool,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<DispatchError>>;
+
+    #[method(name = "asset_pausedOperations")]
+    fn paused_operations(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AssetOperation>>;
+
+    #[method(name = "asset_metadataJson")]
+    fn asset_metadata_json(
+        &self,
+        asset_id: AssetId,
+        key: AssetMetadataKey,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<serde_json::Value>>;
+
+    #[method(name = "asset_pausedSettlementVenues")]
+    fn paused_settlement_venues(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VenueId>>;
 }
 
 /// An implementation of asset specific RPC methods.
@@ -95,4 +119,74 @@ where
             .into()
         })
     }
+
+    fn paused_operations(
+        &self,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AssetOperation>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.paused_operations(at_hash, asset_id).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to call asset_paused_operations runtime",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
+
+    fn asset_metadata_json(
+        &self,
+        asset_id: AssetId,
+        key: AssetMetadataKey,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<serde_json::Value>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let json = api
+            .asset_metadata_json(at_hash, asset_id, key)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call asset_metadata_json runtime",
+                    Some(e.to_string()),
+                ))
+            })?;
+        json.map(|json| serde_json::from_slice(&json))
+            .transpose()
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::DecodeError.into(),
+                    "Unable to parse asset metadata JSON",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
+
+    fn paused_settlement_venues(
+        &self,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<VenueId>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.paused_settlement_venues(at_hash, asset_id)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call asset_paused_settlement_venues runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
 }
@@ -19,7 +19,7 @@ use frame_support::dispatch::DispatchError;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use jsonrpsee::types::error::{CallError, ErrorObject};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 
@@ -75,14 +75,38 @@ where
         // If the block hash is not supplied assume the best block.
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
 
-        api.compliance_report(at_hash, &asset_id, &sender_identity, &receiver_identity)
-            .map_err(|e| {
+        fn map_err(error: impl ToString, desc: &'static str) -> CallError {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                desc,
+                Some(error.to_string()),
+            ))
+        }
+
+        let api_version = api
+            .api_version::<dyn ComplianceRuntimeApi<Block>>(at_hash)
+            .map_err(|e| map_err(e, "Failed to get compliance runtime api version"))?
+            .ok_or_else(|| {
                 CallError::Custom(ErrorObject::owned(
                     Error::RuntimeError.into(),
-                    "Unable to call compliance_report runtime",
-                    Some(e.to_string()),
+                    "Compliance runtime api wasn't found in the runtime",
+                    None::<String>,
                 ))
-                .into()
-            })
+            })?;
+
+        if api_version < 3 {
+            #[allow(deprecated)]
+            api.compliance_report_before_version_3(
+                at_hash,
+                &asset_id,
+                &sender_identity,
+                &receiver_identity,
+            )
+            .map(|report| report.map(ComplianceReport::from))
+            .map_err(|e| map_err(e, "Unable to call compliance_report runtime").into())
+        } else {
+            api.compliance_report(at_hash, &asset_id, &sender_identity, &receiver_identity)
+                .map_err(|e| map_err(e, "Unable to call compliance_report runtime").into())
+        }
     }
 }
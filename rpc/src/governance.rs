@@ -0,0 +1,117 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2024 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::sync::Arc;
+
+use codec::{Codec, Encode};
+use frame_support::dispatch::DispatchError;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_rpc_runtime_api::governance::GovernanceApi as GovernanceRuntimeApi;
+use pallet_pips::PipId;
+use polymesh_primitives::committee::CommitteeKind;
+use polymesh_primitives::dry_run::DryRunOutcome;
+
+/// Governance RPC methods.
+///
+/// The events of the dry-runs are SCALE encoded, to be decoded with the metadata of the runtime.
+#[rpc(client, server)]
+pub trait GovernanceApi<BlockHash, Hash> {
+    /// Simulates the execution of the PIP with `id` against the state at `at`.
+    #[method(name = "governance_dryRunPip")]
+    fn dry_run_pip(
+        &self,
+        id: PipId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Result<DryRunOutcome<Bytes>, DispatchError>>;
+
+    /// Simulates the execution of the pending `proposal` of `committee` against the state at `at`,
+    /// as if it was approved by the committee.
+    #[method(name = "governance_dryRunCommitteeProposal")]
+    fn dry_run_committee_proposal(
+        &self,
+        committee: CommitteeKind,
+        proposal: Hash,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Result<DryRunOutcome<Bytes>, DispatchError>>;
+}
+
+/// An implementation of governance specific RPC methods.
+pub struct Governance<T, U, Event> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<(U, Event)>,
+}
+
+impl<T, U, Event> Governance<T, U, Event> {
+    /// Create new `Governance` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Governance {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, Event, Hash> GovernanceApiServer<<Block as BlockT>::Hash, Hash>
+    for Governance<C, Block, Event>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: GovernanceRuntimeApi<Block, Event, Hash>,
+    Event: Codec + Send + Sync + 'static,
+    Hash: Codec + Send + Sync + 'static,
+{
+    fn dry_run_pip(
+        &self,
+        id: PipId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Result<DryRunOutcome<Bytes>, DispatchError>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .dry_run_pip(at, id)
+                .map(|res| res.map(encode_events)),
+            "Unable to query `dry_run_pip`."
+        )
+    }
+
+    fn dry_run_committee_proposal(
+        &self,
+        committee: CommitteeKind,
+        proposal: Hash,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Result<DryRunOutcome<Bytes>, DispatchError>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .dry_run_committee_proposal(at, committee, proposal)
+                .map(|res| res.map(encode_events)),
+            "Unable to query `dry_run_committee_proposal`."
+        )
+    }
+}
+
+/// Returns `outcome` with its events SCALE encoded.
+fn encode_events<Event: Encode>(outcome: DryRunOutcome<Event>) -> DryRunOutcome<Bytes> {
+    outcome.map_events(|event| event.encode().into())
+}
@@ -51,7 +51,9 @@ macro_rules! rpc_forward_call {
 
 pub mod asset;
 pub mod compliance;
+pub mod governance;
 pub mod identity;
+pub mod multisig;
 pub mod nft;
 pub mod pips;
 pub mod settlement;
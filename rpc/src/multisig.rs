@@ -0,0 +1,135 @@
+// This file is part of the Polymesh distribution (https://github.com/PolymeshAssociation/Polymesh).
+// Copyright (c) 2020 Polymesh Association
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use frame_support::dispatch::DispatchError;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use node_rpc_runtime_api::multisig::MultisigApi as MultisigRuntimeApi;
+use polymesh_primitives::multisig::ProposalDetails;
+
+/// Multisig RPC methods.
+#[rpc(client, server)]
+pub trait MultisigApi<BlockHash, AccountId, Moment> {
+    /// Returns the proposals of `multisig` that can still be approved or executed.
+    /// The proposed call is SCALE encoded, and identified by its pallet and extrinsic names.
+    #[method(name = "multisig_pendingProposals")]
+    fn pending_proposals(
+        &self,
+        multisig: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalDetails<AccountId, Moment>>>;
+
+    /// Returns the details of the proposal given by `proposal_id`, with its SCALE encoded call.
+    #[method(name = "multisig_proposalDetails")]
+    fn proposal_details(
+        &self,
+        multisig: AccountId,
+        proposal_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalDetails<AccountId, Moment>>>;
+
+    /// Returns all errors for executing the proposal given by `proposal_id` now.
+    #[method(name = "multisig_executionReport")]
+    fn execution_report(
+        &self,
+        multisig: AccountId,
+        proposal_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<DispatchError>>;
+}
+
+/// An implementation of multisig specific RPC methods.
+pub struct Multisig<T, U> {
+    client: Arc<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+impl<T, U> Multisig<T, U> {
+    /// Creates a new `Multisig` with the given reference to the client.
+    pub fn new(client: Arc<T>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Moment> MultisigApiServer<<Block as BlockT>::Hash, AccountId, Moment>
+    for Multisig<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MultisigRuntimeApi<Block, AccountId, Moment>,
+    AccountId: Codec,
+    Moment: Codec,
+{
+    fn pending_proposals(
+        &self,
+        multisig: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProposalDetails<AccountId, Moment>>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .pending_proposals(at, multisig),
+            "Unable to call pending_proposals runtime"
+        )
+    }
+
+    fn proposal_details(
+        &self,
+        multisig: AccountId,
+        proposal_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ProposalDetails<AccountId, Moment>>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.proposal_details(
+                at,
+                multisig,
+                proposal_id
+            ),
+            "Unable to call proposal_details runtime"
+        )
+    }
+
+    fn execution_report(
+        &self,
+        multisig: AccountId,
+        proposal_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DispatchError>> {
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.execution_report(
+                at,
+                multisig,
+                proposal_id
+            ),
+            "Unable to call execution_report runtime"
+        )
+    }
+}
@@ -19,14 +19,16 @@ use codec::Codec;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use jsonrpsee::types::error::{CallError, ErrorObject};
-use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_api::{ApiExt, ApiRef, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use sp_std::{prelude::*, vec::Vec};
 
 use node_rpc_runtime_api::pips::capped::VoteCount;
 pub use node_rpc_runtime_api::pips::{self as runtime_api, PipsApi as PipsRuntimeApi};
-use pallet_pips::PipId;
+use pallet_pips::{PipId, VoteCount as CoreVoteCount};
+
+use crate::Error;
 
 /// Pips RPC methods.
 #[rpc(client, server)]
@@ -39,10 +41,18 @@ pub trait PipsApi<BlockHash, AccountId> {
     #[method(name = "pips_proposedBy")]
     fn proposed_by(&self, address: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<PipId>>;
 
-    /// Retrieves proposal `address` indices voted on
+    /// Retrieves proposal `address` indices voted on directly
 
     #[method(name = "pips_votedOn")]
     fn voted_on(&self, address: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<PipId>>;
+
+    /// Retrieves proposal indices on which a vote was cast with the delegated power of `address`.
+    #[method(name = "pips_delegatedVotedOn")]
+    fn delegated_voted_on(
+        &self,
+        address: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PipId>>;
 }
 
 /// An implementation of pips specific RPC methods.
@@ -61,6 +71,37 @@ impl<T, U> Pips<T, U> {
     }
 }
 
+impl<C, Block> Pips<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+{
+    /// Returns the version of the pips runtime API of the runtime at `at_hash`.
+    fn api_version<AccountId>(&self, at_hash: <Block as BlockT>::Hash) -> Result<u32, CallError>
+    where
+        C::Api: PipsRuntimeApi<Block, AccountId>,
+        AccountId: Codec,
+    {
+        self.client
+            .runtime_api()
+            .api_version::<dyn PipsRuntimeApi<Block, AccountId>>(at_hash)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Failed to get pips runtime api version",
+                    Some(e.to_string()),
+                ))
+            })?
+            .ok_or_else(|| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Pips runtime api wasn't found in the runtime",
+                    None::<String>,
+                ))
+            })
+    }
+}
+
 impl<C, Block, AccountId> PipsApiServer<<Block as BlockT>::Hash, AccountId> for Pips<C, Block>
 where
     Block: BlockT,
@@ -69,13 +110,27 @@ where
     AccountId: Codec,
 {
     fn get_votes(&self, id: PipId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<VoteCount> {
-        rpc_forward_call!(
-            self,
-            at,
-            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.get_votes(at, id),
-            "Unable to query `get_votes`."
-        )
-        .map(VoteCount::from)
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        if self.api_version::<AccountId>(at_hash)? < 3 {
+            rpc_forward_call!(
+                self,
+                at,
+                |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| {
+                    #[allow(deprecated)]
+                    api.get_votes_before_version_3(at, id)
+                },
+                "Unable to query `get_votes`."
+            )
+            .map(|vote_count| VoteCount::from(CoreVoteCount::from(vote_count)))
+        } else {
+            rpc_forward_call!(
+                self,
+                at,
+                |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api.get_votes(at, id),
+                "Unable to query `get_votes`."
+            )
+            .map(VoteCount::from)
+        }
     }
 
     fn proposed_by(
@@ -103,4 +158,28 @@ where
             "Unable to query `voted_on`."
         )
     }
+
+    fn delegated_voted_on(
+        &self,
+        address: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PipId>> {
+        // Votes can only be delegated from version 3 of the runtime API on.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        if self.api_version::<AccountId>(at_hash)? < 3 {
+            return Err(CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to query `delegated_voted_on`.",
+                Some("The runtime doesn't support delegated votes"),
+            ))
+            .into());
+        }
+        rpc_forward_call!(
+            self,
+            at,
+            |api: ApiRef<<C as ProvideRuntimeApi<Block>>::Api>, at| api
+                .delegated_voted_on(at, address),
+            "Unable to query `delegated_voted_on`."
+        )
+    }
 }
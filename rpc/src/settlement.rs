@@ -20,13 +20,13 @@ use frame_support::dispatch::DispatchError;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use jsonrpsee::types::error::{CallError, ErrorObject};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 
 pub use node_rpc_runtime_api::settlement::SettlementApi as SettlementRuntimeApi;
 use polymesh_primitives::settlement::{
-    AffirmationCount, ExecuteInstructionInfo, InstructionId, Leg,
+    AffirmationCount, ExecuteInstructionInfo, InstructionId, Leg, LegTransferFee,
 };
 use polymesh_primitives::PortfolioId;
 
@@ -63,6 +63,13 @@ pub trait SettlementApi<BlockHash> {
         instruction_id: InstructionId,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<DispatchError>>;
+
+    #[method(name = "settlement_getInstructionTransferFees")]
+    fn get_instruction_transfer_fees(
+        &self,
+        instruction_id: InstructionId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<LegTransferFee>>;
 }
 
 /// An implementation of Settlement specific RPC methods.
@@ -117,15 +124,34 @@ where
         // If the block hash is not supplied assume the best block.
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
 
-        api.get_affirmation_count(at_hash, instruction_id, portfolios)
-            .map_err(|e| {
+        fn map_err(error: impl ToString, desc: &'static str) -> CallError {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                desc,
+                Some(error.to_string()),
+            ))
+        }
+
+        let api_version = api
+            .api_version::<dyn SettlementRuntimeApi<Block>>(at_hash)
+            .map_err(|e| map_err(e, "Failed to get settlement runtime api version"))?
+            .ok_or_else(|| {
                 CallError::Custom(ErrorObject::owned(
                     Error::RuntimeError.into(),
-                    "Unable to call get_affirmation_count runtime",
-                    Some(e.to_string()),
+                    "Settlement runtime api wasn't found in the runtime",
+                    None::<String>,
                 ))
-                .into()
-            })
+            })?;
+
+        if api_version < 3 {
+            #[allow(deprecated)]
+            api.get_affirmation_count_before_version_3(at_hash, instruction_id, portfolios)
+                .map(AffirmationCount::from)
+                .map_err(|e| map_err(e, "Unable to call get_affirmation_count runtime").into())
+        } else {
+            api.get_affirmation_count(at_hash, instruction_id, portfolios)
+                .map_err(|e| map_err(e, "Unable to call get_affirmation_count runtime").into())
+        }
     }
 
     fn get_transfer_report(
@@ -168,4 +194,24 @@ where
                 .into()
             })
     }
+
+    fn get_instruction_transfer_fees(
+        &self,
+        instruction_id: InstructionId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<LegTransferFee>> {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block.
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_instruction_transfer_fees(at_hash, instruction_id)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to call get_instruction_transfer_fees runtime",
+                    Some(e.to_string()),
+                ))
+                .into()
+            })
+    }
 }
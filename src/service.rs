@@ -100,6 +100,7 @@ pub trait RuntimeApiCollection:
     + pallet_group_rpc_runtime_api::GroupApi<Block>
     + node_rpc_runtime_api::nft::NFTApi<Block>
     + node_rpc_runtime_api::settlement::SettlementApi<Block>
+    + node_rpc_runtime_api::multisig::MultisigApi<Block, AccountId, Moment>
 where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -125,7 +126,8 @@ where
         + node_rpc_runtime_api::asset::AssetApi<Block>
         + pallet_group_rpc_runtime_api::GroupApi<Block>
         + node_rpc_runtime_api::nft::NFTApi<Block>
-        + node_rpc_runtime_api::settlement::SettlementApi<Block>,
+        + node_rpc_runtime_api::settlement::SettlementApi<Block>
+        + node_rpc_runtime_api::multisig::MultisigApi<Block, AccountId, Moment>,
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }